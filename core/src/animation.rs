@@ -1,4 +1,10 @@
 //! Animate your applications.
+mod spring;
+mod timeline;
+
+pub use spring::{Components, Spring};
+pub use timeline::{Keyframe, Timeline};
+
 use crate::time::{Duration, Instant};
 
 pub use lilt::{Easing, FloatRepresentable as Float, Interpolable};
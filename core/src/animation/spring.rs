@@ -0,0 +1,340 @@
+use crate::time::{Duration, Instant};
+use crate::{Color, Point, Size, Vector};
+
+/// A physically based animation of a value.
+///
+/// A [`Spring`] pulls its value towards a target, like a mass attached to a
+/// spring. Changing the target while in motion preserves its velocity, which
+/// makes springs a great fit for interruptible gestures.
+///
+/// Any value made of [`Components`] can be animated (e.g. a [`Point`] or a
+/// [`Color`]); every component is pulled by the same spring.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring<T> {
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    precision: f32,
+    from: T,
+    velocity: T,
+    target: T,
+    started: Option<Instant>,
+}
+
+/// A value made of `f32` components that a [`Spring`] can animate.
+pub trait Components: Copy {
+    /// Combines every component of the value with the matching component of
+    /// `other` using the given function.
+    fn zip(self, other: Self, f: impl FnMut(f32, f32) -> f32) -> Self;
+}
+
+impl Components for f32 {
+    fn zip(self, other: Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        f(self, other)
+    }
+}
+
+impl Components for Point {
+    fn zip(self, other: Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Point::new(f(self.x, other.x), f(self.y, other.y))
+    }
+}
+
+impl Components for Vector {
+    fn zip(self, other: Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Vector::new(f(self.x, other.x), f(self.y, other.y))
+    }
+}
+
+impl Components for Size {
+    fn zip(self, other: Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Size::new(f(self.width, other.width), f(self.height, other.height))
+    }
+}
+
+impl Components for Color {
+    fn zip(self, other: Self, mut f: impl FnMut(f32, f32) -> f32) -> Self {
+        Color {
+            r: f(self.r, other.r),
+            g: f(self.g, other.g),
+            b: f(self.b, other.b),
+            a: f(self.a, other.a),
+        }
+    }
+}
+
+impl<T> Spring<T>
+where
+    T: Components,
+{
+    /// Creates a new [`Spring`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            precision: 0.001,
+            from: value,
+            velocity: value.zip(value, |_, _| 0.0),
+            target: value,
+            started: None,
+        }
+    }
+
+    /// Sets the stiffness of the [`Spring`].
+    ///
+    /// Stiffer springs move faster.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Sets the damping of the [`Spring`].
+    ///
+    /// Damping slows the [`Spring`] down; without enough of it, the
+    /// [`Spring`] will oscillate around its target.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Sets the mass attached to the [`Spring`].
+    ///
+    /// Heavier masses are slower and oscillate longer.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Sets the initial velocity of the [`Spring`], in units per second.
+    pub fn velocity(mut self, velocity: T) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Sets the precision used to decide when the [`Spring`] has settled.
+    ///
+    /// A [`Spring`] settles once both its distance to the target and its
+    /// speed are below the precision.
+    pub fn precision(mut self, precision: f32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Pulls the [`Spring`] towards the given target from the given time.
+    pub fn go(mut self, target: T, at: Instant) -> Self {
+        self.go_mut(target, at);
+        self
+    }
+
+    /// Pulls the [`Spring`] towards the given target from the given time,
+    /// by reference.
+    ///
+    /// The current value and velocity of the [`Spring`] are preserved.
+    pub fn go_mut(&mut self, target: T, at: Instant) {
+        let (value, velocity) = self.state(at);
+
+        self.from = value;
+        self.velocity = velocity;
+        self.target = target;
+        self.started = Some(at);
+    }
+
+    /// Moves the [`Spring`] to the given value and velocity at the given
+    /// time, keeping its target.
+    ///
+    /// This is useful to release a [`Spring`] after a gesture, using the
+    /// velocity of the gesture.
+    pub fn set_mut(&mut self, value: T, velocity: T, at: Instant) {
+        self.from = value;
+        self.velocity = velocity;
+        self.started = Some(at);
+    }
+
+    /// Returns the value of the [`Spring`] at the given time.
+    pub fn value(&self, at: Instant) -> T {
+        self.state(at).0
+    }
+
+    /// Returns the velocity of the [`Spring`] at the given time, in units
+    /// per second.
+    pub fn velocity_at(&self, at: Instant) -> T {
+        self.state(at).1
+    }
+
+    /// Returns the target of the [`Spring`].
+    pub fn target(&self) -> T {
+        self.target
+    }
+
+    /// Returns true if the [`Spring`] is still moving at the given time.
+    pub fn is_animating(&self, at: Instant) -> bool {
+        let (value, velocity) = self.state(at);
+        let mut is_settled = true;
+
+        let _ = value.zip(self.target, |value, target| {
+            is_settled &= (value - target).abs() < self.precision;
+            value
+        });
+
+        let _ = velocity.zip(velocity, |velocity, _| {
+            is_settled &= velocity.abs() < self.precision;
+            velocity
+        });
+
+        !is_settled
+    }
+
+    /// Returns the remaining [`Duration`] of the [`Spring`] until it settles,
+    /// approximated by stepping through its motion.
+    pub fn remaining(&self, at: Instant) -> Duration {
+        const STEP: Duration = Duration::from_millis(16);
+        const LIMIT: Duration = Duration::from_secs(60);
+
+        let mut remaining = Duration::ZERO;
+
+        while self.is_animating(at + remaining) && remaining < LIMIT {
+            remaining += STEP;
+        }
+
+        remaining
+    }
+
+    fn state(&self, at: Instant) -> (T, T) {
+        let Some(started) = self.started else {
+            return (self.from, self.velocity);
+        };
+
+        let t = at.saturating_duration_since(started).as_secs_f32();
+        let [[x_x0, x_v0], [v_x0, v_v0]] = self.oscillate(t);
+
+        let displacement = self.from.zip(self.target, |from, target| from - target);
+
+        let value = self
+            .target
+            .zip(displacement, |target, x0| target + x_x0 * x0)
+            .zip(self.velocity, |value, v0| value + x_v0 * v0);
+
+        let velocity = displacement.zip(self.velocity, |x0, v0| v_x0 * x0 + v_v0 * v0);
+
+        (value, velocity)
+    }
+
+    /// Solves the damped harmonic oscillator of the [`Spring`] after `t`
+    /// seconds.
+    ///
+    /// The oscillator is linear, so the solution is the matrix mapping the
+    /// initial displacement and velocity to the current ones.
+    fn oscillate(&self, t: f32) -> [[f32; 2]; 2] {
+        let (x_x0, v_x0) = self.solve(1.0, 0.0, t);
+        let (x_v0, v_v0) = self.solve(0.0, 1.0, t);
+
+        [[x_x0, x_v0], [v_x0, v_v0]]
+    }
+
+    /// Returns the displacement from the target and the velocity of the
+    /// oscillator after `t` seconds, given its initial ones.
+    fn solve(&self, x0: f32, v0: f32, t: f32) -> (f32, f32) {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2.0 * (self.stiffness * self.mass).sqrt());
+
+        if (zeta - 1.0).abs() < 1e-3 {
+            // Critically damped
+            let c = v0 + omega * x0;
+            let envelope = (-omega * t).exp();
+
+            (
+                envelope * (x0 + c * t),
+                envelope * (c - omega * (x0 + c * t)),
+            )
+        } else if zeta < 1.0 {
+            // Underdamped
+            let decay = zeta * omega;
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let b = (v0 + decay * x0) / omega_d;
+
+            let (sin, cos) = (omega_d * t).sin_cos();
+            let envelope = (-decay * t).exp();
+
+            let x = x0 * cos + b * sin;
+            let dx = -x0 * omega_d * sin + b * omega_d * cos;
+
+            (envelope * x, envelope * (dx - decay * x))
+        } else {
+            // Overdamped
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seconds(start: Instant, seconds: f32) -> Instant {
+        start + Duration::from_secs_f32(seconds)
+    }
+
+    #[test]
+    fn spring_settles_at_its_target() {
+        let start = Instant::now();
+
+        for damping in [10.0, 26.0, 2.0 * 170.0f32.sqrt(), 60.0] {
+            let spring = Spring::new(0.0).damping(damping).go(100.0, start);
+
+            assert!(spring.is_animating(seconds(start, 0.1)));
+            assert!(!spring.is_animating(seconds(start, 10.0)));
+            assert!((spring.value(seconds(start, 10.0)) - 100.0).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn spring_preserves_velocity_when_retargeted() {
+        let start = Instant::now();
+        let now = seconds(start, 0.05);
+
+        let mut spring = Spring::new(0.0).go(100.0, start);
+        let (value, velocity) = (spring.value(now), spring.velocity_at(now));
+
+        spring.go_mut(-100.0, now);
+
+        assert_eq!(spring.value(now), value);
+        assert!((spring.velocity_at(now) - velocity).abs() < 0.01);
+        assert!(spring.value(seconds(start, 0.06)) > value);
+    }
+
+    #[test]
+    fn springs_pull_every_component() {
+        let start = Instant::now();
+        let spring = Spring::new(Point::ORIGIN).go(Point::new(100.0, -50.0), start);
+
+        let value = spring.value(seconds(start, 0.1));
+
+        assert!(value.x > 0.0 && value.x < 100.0);
+        assert!((value.y / value.x + 0.5).abs() < 0.001);
+
+        assert!(spring.is_animating(seconds(start, 0.1)));
+        assert!(!spring.is_animating(seconds(start, 10.0)));
+    }
+
+    #[test]
+    fn damped_springs_do_not_overshoot() {
+        let start = Instant::now();
+        let spring = Spring::new(0.0).damping(60.0).go(1.0, start);
+
+        assert!((0..100).all(|i| spring.value(seconds(start, i as f32 * 0.05)) <= 1.0));
+
+        let wobbly = Spring::new(0.0).damping(5.0).go(1.0, start);
+
+        assert!((0..100).any(|i| wobbly.value(seconds(start, i as f32 * 0.05)) > 1.0));
+    }
+}
@@ -0,0 +1,253 @@
+use crate::animation::{Easing, Interpolable};
+use crate::time::{Duration, Instant};
+
+/// A sequence of keyframes describing the values of some state through time.
+///
+/// A [`Timeline`] starts at an initial value and then transitions through
+/// each of its [`Keyframe`]s in order, holding the last one once finished.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timeline<T> {
+    initial: T,
+    keyframes: Vec<Keyframe<T>>,
+    delay: Duration,
+    stagger: Duration,
+    items: usize,
+    started: Option<Instant>,
+}
+
+/// A value that a [`Timeline`] transitions to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyframe<T> {
+    value: T,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T> Keyframe<T> {
+    /// Creates a new [`Keyframe`] reaching the given value after the given
+    /// [`Duration`].
+    pub fn new(value: T, duration: Duration) -> Self {
+        Self {
+            value,
+            duration,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Sets the [`Easing`] used to reach the [`Keyframe`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+impl<T> From<(T, Duration)> for Keyframe<T> {
+    fn from((value, duration): (T, Duration)) -> Self {
+        Self::new(value, duration)
+    }
+}
+
+impl<T> Timeline<T>
+where
+    T: Interpolable + Clone,
+{
+    /// Creates a new [`Timeline`] with the given initial value.
+    pub fn new(initial: T) -> Self {
+        Self {
+            initial,
+            keyframes: Vec::new(),
+            delay: Duration::ZERO,
+            stagger: Duration::ZERO,
+            items: 1,
+            started: None,
+        }
+    }
+
+    /// Adds a [`Keyframe`] to the end of the [`Timeline`].
+    pub fn then(mut self, keyframe: impl Into<Keyframe<T>>) -> Self {
+        self.keyframes.push(keyframe.into());
+        self
+    }
+
+    /// Holds the last value of the [`Timeline`] for the given [`Duration`].
+    pub fn hold(self, duration: Duration) -> Self {
+        let value = self.last().clone();
+
+        self.then(Keyframe::new(value, duration))
+    }
+
+    /// Appends the keyframes of another [`Timeline`], transitioning to its
+    /// initial value instantly.
+    pub fn chain(self, other: Self) -> Self {
+        let timeline = self
+            .hold(other.delay)
+            .then(Keyframe::new(other.initial, Duration::ZERO));
+
+        other.keyframes.into_iter().fold(timeline, Self::then)
+    }
+
+    /// Sets a delay before the [`Timeline`] starts once started.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Staggers the [`Timeline`] for a group of the given amount of items,
+    /// where every item starts `step` later than the previous one.
+    ///
+    /// The value of each item can be obtained with
+    /// [`staggered`](Self::staggered).
+    pub fn stagger(mut self, step: Duration, items: usize) -> Self {
+        self.stagger = step;
+        self.items = items;
+        self
+    }
+
+    /// Starts the [`Timeline`] at the given time.
+    pub fn start(mut self, at: Instant) -> Self {
+        self.start_mut(at);
+        self
+    }
+
+    /// Starts the [`Timeline`] at the given time, by reference.
+    pub fn start_mut(&mut self, at: Instant) {
+        self.started = Some(at);
+    }
+
+    /// Returns the total [`Duration`] of the [`Timeline`], including its delay
+    /// and the offset of its last staggered item.
+    pub fn duration(&self) -> Duration {
+        self.delay
+            + self.offset(self.items.saturating_sub(1))
+            + self
+                .keyframes
+                .iter()
+                .map(|keyframe| keyframe.duration)
+                .sum::<Duration>()
+    }
+
+    /// Returns true if the [`Timeline`] is in progress at the given time.
+    pub fn is_animating(&self, at: Instant) -> bool {
+        self.started
+            .is_some_and(|started| at.saturating_duration_since(started) < self.duration())
+    }
+
+    /// Returns the value of the [`Timeline`] at the given time.
+    pub fn value(&self, at: Instant) -> T {
+        let Some(started) = self.started else {
+            return self.initial.clone();
+        };
+
+        self.value_after(at.saturating_duration_since(started))
+    }
+
+    /// Returns the value of the [`Timeline`] at the given time for the item
+    /// with the given index in its staggered group.
+    ///
+    /// See [`stagger`](Self::stagger).
+    pub fn staggered(&self, index: usize, at: Instant) -> T {
+        let Some(started) = self.started else {
+            return self.initial.clone();
+        };
+
+        self.value_after(
+            at.saturating_duration_since(started)
+                .saturating_sub(self.offset(index)),
+        )
+    }
+
+    fn offset(&self, index: usize) -> Duration {
+        self.stagger
+            .saturating_mul(u32::try_from(index).unwrap_or(u32::MAX))
+    }
+
+    fn value_after(&self, elapsed: Duration) -> T {
+        let Some(mut elapsed) = elapsed.checked_sub(self.delay) else {
+            return self.initial.clone();
+        };
+
+        let mut from = &self.initial;
+
+        for keyframe in &self.keyframes {
+            if elapsed < keyframe.duration {
+                let progress = elapsed.as_secs_f32() / keyframe.duration.as_secs_f32();
+
+                return from.interpolated(keyframe.value.clone(), keyframe.easing.value(progress));
+            }
+
+            elapsed -= keyframe.duration;
+            from = &keyframe.value;
+        }
+
+        from.clone()
+    }
+
+    fn last(&self) -> &T {
+        self.keyframes
+            .last()
+            .map_or(&self.initial, |keyframe| &keyframe.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn timeline_goes_through_its_keyframes() {
+        let start = Instant::now();
+
+        let timeline = Timeline::new(0.0)
+            .then((10.0, Duration::from_millis(100)))
+            .hold(Duration::from_millis(100))
+            .then((0.0, Duration::from_millis(200)))
+            .delay(Duration::from_millis(50));
+
+        assert_eq!(timeline.value(start), 0.0);
+        assert!(!timeline.is_animating(start));
+
+        let timeline = timeline.start(start);
+
+        assert_eq!(timeline.duration(), Duration::from_millis(450));
+        assert_eq!(timeline.value(millis(start, 25)), 0.0);
+        assert_eq!(timeline.value(millis(start, 100)), 5.0);
+        assert_eq!(timeline.value(millis(start, 200)), 10.0);
+        assert_eq!(timeline.value(millis(start, 350)), 5.0);
+        assert_eq!(timeline.value(millis(start, 1000)), 0.0);
+
+        assert!(timeline.is_animating(millis(start, 449)));
+        assert!(!timeline.is_animating(millis(start, 450)));
+    }
+
+    #[test]
+    fn timeline_staggers_and_chains() {
+        let start = Instant::now();
+
+        let fade_in = Timeline::new(0.0).then((1.0, Duration::from_millis(100)));
+        let fade_out = Timeline::new(1.0)
+            .then((0.0, Duration::from_millis(100)))
+            .delay(Duration::from_millis(100));
+
+        let timeline = fade_in.chain(fade_out);
+
+        assert_eq!(timeline.duration(), Duration::from_millis(300));
+
+        let timeline = timeline.stagger(Duration::from_millis(50), 3).start(start);
+
+        assert_eq!(timeline.staggered(0, millis(start, 50)), 0.5);
+        assert_eq!(timeline.staggered(1, millis(start, 50)), 0.0);
+        assert_eq!(timeline.staggered(1, millis(start, 100)), 0.5);
+        assert_eq!(timeline.staggered(2, millis(start, 350)), 0.5);
+
+        assert_eq!(timeline.duration(), Duration::from_millis(400));
+        assert!(timeline.is_animating(millis(start, 350)));
+        assert!(!timeline.is_animating(millis(start, 400)));
+
+        assert_eq!(timeline.value(millis(start, 150)), 1.0);
+        assert_eq!(timeline.value(millis(start, 250)), 0.5);
+    }
+}
@@ -0,0 +1,46 @@
+//! Drag payloads between widgets.
+use std::any::{self, Any};
+use std::fmt;
+use std::sync::Arc;
+
+/// A drag in progress, carrying a type-erased payload.
+///
+/// A widget can start a [`Drag`] with [`Shell::start_drag`] and any other
+/// widget can inspect it with [`Shell::drag`] until the runtime ends it
+/// when the pointer is released.
+///
+/// [`Shell::start_drag`]: crate::Shell::start_drag
+/// [`Shell::drag`]: crate::Shell::drag
+#[derive(Clone)]
+pub struct Drag {
+    payload: Arc<dyn Any + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl Drag {
+    /// Creates a new [`Drag`] carrying the given payload.
+    pub fn new<T: Any + Send + Sync>(payload: T) -> Self {
+        Self {
+            payload: Arc::new(payload),
+            type_name: any::type_name::<T>(),
+        }
+    }
+
+    /// Returns the payload of the [`Drag`], if it is of type `T`.
+    pub fn payload<T: Any>(&self) -> Option<&T> {
+        self.payload.downcast_ref()
+    }
+
+    /// Returns true if the payload of the [`Drag`] is of type `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.payload.is::<T>()
+    }
+}
+
+impl fmt::Debug for Drag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Drag")
+            .field("payload", &self.type_name)
+            .finish()
+    }
+}
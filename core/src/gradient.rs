@@ -1,14 +1,18 @@
 //! Colors that transition progressively.
-use crate::{Color, Radians};
+use crate::{Color, Point, Radians, Rectangle, Size};
 
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
-/// or conically (TBD).
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
+/// or conically.
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction at a specific angle.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from a focal point to an ellipse.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around a center point, starting at a specific angle.
+    Conic(Conic),
 }
 
 impl Gradient {
@@ -16,6 +20,8 @@ impl Gradient {
     pub fn scale_alpha(self, factor: f32) -> Self {
         match self {
             Gradient::Linear(linear) => Gradient::Linear(linear.scale_alpha(factor)),
+            Gradient::Radial(radial) => Gradient::Radial(radial.scale_alpha(factor)),
+            Gradient::Conic(conic) => Gradient::Conic(conic.scale_alpha(factor)),
         }
     }
 }
@@ -26,6 +32,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 /// A point along the gradient vector where the specified [`color`] is unmixed.
 ///
@@ -64,18 +82,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) = self.stops.binary_search_by(|stop| match stop {
-                None => Ordering::Greater,
-                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-            });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -94,10 +101,222 @@ impl Linear {
     /// Scales the alpha channel of the [`Linear`] gradient by the given
     /// factor.
     pub fn scale_alpha(mut self, factor: f32) -> Self {
-        for stop in self.stops.iter_mut().flatten() {
-            stop.color.a *= factor;
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+}
+
+/// A radial gradient.
+///
+/// Its geometry is relative to the bounds of the filled area; that is,
+/// `Point::new(0.5, 0.5)` is the center of the bounds and a radius of `0.5`
+/// reaches their edges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The relative center of the ending ellipse of the gradient.
+    pub center: Point,
+    /// The relative horizontal and vertical radii of the ending ellipse of the gradient.
+    pub radii: Size,
+    /// The relative point where the gradient starts.
+    ///
+    /// It should be inside the ending ellipse.
+    pub focal: Point,
+    /// [`ColorStop`]s along the radial gradient path.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] gradient centered at the given relative [`Point`]
+    /// and with the given relative radii.
+    ///
+    /// The focal point of the gradient will be its center.
+    pub fn new(center: impl Into<Point>, radii: impl Into<Size>) -> Self {
+        let center = center.into();
+
+        Self {
+            center,
+            radii: radii.into(),
+            focal: center,
+            stops: [None; 8],
+        }
+    }
+
+    /// Creates a new circular [`Radial`] gradient filling the bounds, centered
+    /// at the middle of them.
+    pub fn circle() -> Self {
+        Self::new(Point::new(0.5, 0.5), Size::new(0.5, 0.5))
+    }
+
+    /// Sets the relative focal point of the [`Radial`] gradient.
+    pub fn focal(mut self, focal: impl Into<Point>) -> Self {
+        self.focal = focal.into();
+        self
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Radial`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+
+    /// Returns the absolute center, radii, and focal point of the [`Radial`]
+    /// gradient inside the given bounds.
+    pub fn to_absolute(&self, bounds: &Rectangle) -> (Point, Size, Point) {
+        let absolute = |point: Point| {
+            Point::new(
+                bounds.x + point.x * bounds.width,
+                bounds.y + point.y * bounds.height,
+            )
+        };
+
+        (
+            absolute(self.center),
+            Size::new(
+                self.radii.width * bounds.width,
+                self.radii.height * bounds.height,
+            ),
+            absolute(self.focal),
+        )
+    }
+}
+
+/// A conic gradient.
+///
+/// Colors are interpolated clockwise around its center, with the start angle
+/// pointing upwards at `0` radians—like the angle of a [`Linear`] gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The relative center of the gradient inside the bounds of the filled area.
+    pub center: Point,
+    /// The angle where the gradient starts.
+    pub angle: Radians,
+    /// [`ColorStop`]s around the conic gradient path.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] gradient centered at the given relative [`Point`]
+    /// and starting at the given angle in [`Radians`].
+    pub fn new(center: impl Into<Point>, angle: impl Into<Radians>) -> Self {
+        Self {
+            center: center.into(),
+            angle: angle.into(),
+            stops: [None; 8],
         }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Conic`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+
+    /// Returns the absolute center of the [`Conic`] gradient inside the
+    /// given bounds.
+    pub fn to_absolute(&self, bounds: &Rectangle) -> Point {
+        Point::new(
+            bounds.x + self.center.x * bounds.width,
+            bounds.y + self.center.y * bounds.height,
+        )
+    }
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) = stops.binary_search_by(|stop| match stop {
+            None => Ordering::Greater,
+            Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+        });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
+    };
+}
+
+fn scale_alpha(stops: &mut [Option<ColorStop>; 8], factor: f32) {
+    for stop in stops.iter_mut().flatten() {
+        stop.color.a *= factor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radial_to_absolute() {
+        let bounds = Rectangle::new(Point::new(10.0, 20.0), Size::new(200.0, 100.0));
+
+        let radial =
+            Radial::new(Point::new(0.5, 0.5), Size::new(0.5, 0.25)).focal(Point::new(0.25, 0.0));
+
+        assert_eq!(
+            radial.to_absolute(&bounds),
+            (
+                Point::new(110.0, 70.0),
+                Size::new(100.0, 25.0),
+                Point::new(60.0, 20.0),
+            )
+        );
+    }
+
+    #[test]
+    fn conic_to_absolute() {
+        let bounds = Rectangle::new(Point::new(10.0, 20.0), Size::new(200.0, 100.0));
+        let conic = Conic::new(Point::new(1.0, 0.5), Radians(0.0));
+
+        assert_eq!(conic.to_absolute(&bounds), Point::new(210.0, 70.0));
+    }
 }
@@ -1,5 +1,6 @@
 //! Load and draw raster graphics.
 use crate::border;
+use crate::time::Duration;
 use crate::{Bytes, Radians, Rectangle, Size};
 
 use rustc_hash::FxHasher;
@@ -177,6 +178,71 @@ impl std::fmt::Debug for Handle {
     }
 }
 
+/// The frames of an animated image.
+///
+/// Images that are not animated have a single [`Frame`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frames<H = Handle> {
+    frames: Arc<[Frame<H>]>,
+}
+
+impl<H> Frames<H> {
+    /// Creates new [`Frames`] from the given list of [`Frame`].
+    pub fn new(frames: impl Into<Arc<[Frame<H>]>>) -> Self {
+        Self {
+            frames: frames.into(),
+        }
+    }
+
+    /// Creates new [`Frames`] for an image that is not animated.
+    pub fn still(handle: H) -> Self {
+        Self::new([Frame {
+            handle,
+            delay: Duration::ZERO,
+        }])
+    }
+
+    /// Returns the [`Frame`] with the given index, if any.
+    pub fn get(&self, index: usize) -> Option<&Frame<H>> {
+        self.frames.get(index)
+    }
+
+    /// Returns the amount of [`Frame`] in the [`Frames`].
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if there are no [`Frame`] in the [`Frames`].
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns true if there is more than one [`Frame`] in the [`Frames`].
+    pub fn is_animated(&self) -> bool {
+        self.frames.len() > 1
+    }
+
+    /// Returns an iterator over the [`Frame`] in the [`Frames`].
+    pub fn iter(&self) -> impl Iterator<Item = &Frame<H>> {
+        self.frames.iter()
+    }
+
+    /// Returns the total duration of a single loop of the [`Frames`].
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+}
+
+/// A single frame of an animated image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame<H = Handle> {
+    /// The handle of the decoded image of the [`Frame`].
+    pub handle: H,
+
+    /// The amount of time the [`Frame`] must be displayed for.
+    pub delay: Duration,
+}
+
 /// The unique identifier of some [`Handle`] data.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Id(_Id);
@@ -302,6 +368,30 @@ pub trait Renderer: crate::Renderer {
     /// If you need a measurement right away, consider using [`Renderer::load_image`].
     fn measure_image(&self, handle: &Self::Handle) -> Option<Size<u32>>;
 
+    /// Decodes the [`Frames`] of an animated image.
+    ///
+    /// Each [`Frame`] has its own handle, which can be drawn like any other
+    /// image. The [`Renderer`] will keep every [`Frame`] in memory for as long
+    /// as any of them is drawn; so looping animations do not need to upload
+    /// their frames again.
+    ///
+    /// Images that are not animated produce a single [`Frame`] with the
+    /// original handle.
+    ///
+    /// If the frames are not already decoded, this method will block! You
+    /// should generally use [`Renderer::frames`] in drawing logic instead.
+    fn load_frames(&self, handle: &Self::Handle) -> Result<Frames<Self::Handle>, Error>;
+
+    /// Returns the [`Frames`] of an animated image, if already decoded.
+    ///
+    /// If the frames are not decoded yet, the [`Renderer`] may choose to return
+    /// `None`, decode them in the background, and then trigger a redraw.
+    ///
+    /// If you need the frames right away, consider using [`Renderer::load_frames`].
+    fn frames(&self, handle: &Self::Handle) -> Option<Result<Frames<Self::Handle>, Error>> {
+        Some(self.load_frames(handle))
+    }
+
     /// Draws an [`Image`] inside the provided `bounds`.
     ///
     /// If the image is not already loaded, the [`Renderer`] may choose to render
@@ -0,0 +1,321 @@
+//! Describe keyboard shortcuts and chords.
+use crate::SmolStr;
+use crate::keyboard::Modifiers;
+use crate::keyboard::key::{self, Key, Named};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A single key combination of a [`Shortcut`] (e.g. `Ctrl+S`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stroke {
+    /// The [`Key`] of the [`Stroke`].
+    pub key: Key,
+    /// The [`Modifiers`] that must be held when pressing the [`Key`].
+    pub modifiers: Modifiers,
+}
+
+impl Stroke {
+    /// Creates a new [`Stroke`] with the given [`Key`] and [`Modifiers`].
+    pub fn new(key: impl Into<Key>, modifiers: Modifiers) -> Self {
+        Self {
+            key: normalize(key.into()),
+            modifiers,
+        }
+    }
+
+    /// Creates the [`Stroke`] produced by a key press.
+    ///
+    /// Keys in non-latin layouts are translated to their latin character
+    /// using the [`key::Physical`] key, if possible.
+    ///
+    /// Returns `None` if the key is a modifier.
+    pub fn from_key_press(
+        key: &Key,
+        physical_key: key::Physical,
+        modifiers: Modifiers,
+    ) -> Option<Self> {
+        if let Key::Named(
+            Named::Shift
+            | Named::Control
+            | Named::Alt
+            | Named::AltGraph
+            | Named::Super
+            | Named::Meta
+            | Named::Hyper,
+        ) = key
+        {
+            return None;
+        }
+
+        let key = match key.to_latin(physical_key) {
+            Some(c) => Key::Character(SmolStr::new(c.encode_utf8(&mut [0; 4]))),
+            None => key.clone(),
+        };
+
+        Some(Self::new(key, modifiers))
+    }
+}
+
+impl fmt::Display for Stroke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, "Super"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match &self.key {
+            Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            Key::Named(named) => write!(f, "{named:?}"),
+            Key::Unidentified => write!(f, "?"),
+        }
+    }
+}
+
+/// A keyboard shortcut.
+///
+/// A [`Shortcut`] is a sequence of one or more [`Stroke`]s. Shortcuts with
+/// multiple strokes are known as chords (e.g. `Ctrl+K Ctrl+S`).
+///
+/// Shortcuts can be parsed from strings, with strokes separated by spaces
+/// and keys separated by `+`:
+///
+/// ```
+/// use iced_core::keyboard::{Key, Modifiers, Shortcut};
+///
+/// let save: Shortcut = "Ctrl+K Ctrl+S".parse().unwrap();
+///
+/// assert_eq!(
+///     save,
+///     Shortcut::new(Key::Character("k".into()), Modifiers::CTRL)
+///         .then(Key::Character("s".into()), Modifiers::CTRL)
+/// );
+/// assert_eq!(save.to_string(), "Ctrl+K Ctrl+S");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    strokes: Vec<Stroke>,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] with a single [`Stroke`].
+    pub fn new(key: impl Into<Key>, modifiers: Modifiers) -> Self {
+        Self {
+            strokes: vec![Stroke::new(key, modifiers)],
+        }
+    }
+
+    /// Adds another [`Stroke`] to the [`Shortcut`], turning it into a chord.
+    pub fn then(mut self, key: impl Into<Key>, modifiers: Modifiers) -> Self {
+        self.strokes.push(Stroke::new(key, modifiers));
+        self
+    }
+
+    /// Returns the [`Stroke`]s of the [`Shortcut`].
+    pub fn strokes(&self) -> &[Stroke] {
+        &self.strokes
+    }
+
+    /// Returns true if the [`Shortcut`] is a chord of multiple [`Stroke`]s.
+    pub fn is_chord(&self) -> bool {
+        self.strokes.len() > 1
+    }
+
+    /// Parses a [`Shortcut`] from a string.
+    ///
+    /// Modifiers can be any of `Ctrl`, `Alt`, `Shift`, `Super` and `Cmd`,
+    /// where `Cmd` is `Super` on macOS and `Ctrl` elsewhere.
+    pub fn parse(shortcut: &str) -> Result<Self, ParseError> {
+        let strokes = shortcut
+            .split_whitespace()
+            .map(parse_stroke)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if strokes.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(Self { strokes })
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseError;
+
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        Self::parse(shortcut)
+    }
+}
+
+impl TryFrom<&str> for Shortcut {
+    type Error = ParseError;
+
+    fn try_from(shortcut: &str) -> Result<Self, Self::Error> {
+        Self::parse(shortcut)
+    }
+}
+
+impl From<Stroke> for Shortcut {
+    fn from(stroke: Stroke) -> Self {
+        Self {
+            strokes: vec![stroke],
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, stroke) in self.strokes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{stroke}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error produced when parsing a [`Shortcut`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// The string contains no strokes.
+    #[error("the shortcut is empty")]
+    Empty,
+    /// A stroke has no key.
+    #[error("the stroke {0:?} has no key")]
+    MissingKey(String),
+    /// A modifier name is not supported.
+    #[error("unknown modifier {0:?}")]
+    UnknownModifier(String),
+    /// A key name is not supported.
+    #[error("unknown key {0:?}")]
+    UnknownKey(String),
+}
+
+fn parse_stroke(stroke: &str) -> Result<Stroke, ParseError> {
+    let mut modifiers = Modifiers::empty();
+    let mut parts = stroke.split('+').peekable();
+
+    // A trailing `+` is the plus key itself (e.g. `Ctrl++`)
+    let key = if stroke == "+" || stroke.ends_with("++") {
+        "+"
+    } else {
+        stroke.rsplit('+').next().unwrap_or_default()
+    };
+
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() || (key == "+" && part.is_empty()) {
+            break;
+        }
+
+        modifiers |= match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => Modifiers::CTRL,
+            "alt" | "option" => Modifiers::ALT,
+            "shift" => Modifiers::SHIFT,
+            "super" | "logo" | "meta" | "win" => Modifiers::LOGO,
+            "cmd" | "command" => Modifiers::COMMAND,
+            _ => return Err(ParseError::UnknownModifier(part.to_owned())),
+        };
+    }
+
+    if key.is_empty() {
+        return Err(ParseError::MissingKey(stroke.to_owned()));
+    }
+
+    Ok(Stroke::new(parse_key(key)?, modifiers))
+}
+
+fn parse_key(key: &str) -> Result<Key, ParseError> {
+    let mut chars = key.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(Key::Character(SmolStr::new(c.encode_utf8(&mut [0; 4]))));
+    }
+
+    let named = match key.to_ascii_lowercase().as_str() {
+        "enter" | "return" => Named::Enter,
+        "esc" | "escape" => Named::Escape,
+        "tab" => Named::Tab,
+        "space" => Named::Space,
+        "backspace" => Named::Backspace,
+        "del" | "delete" => Named::Delete,
+        "ins" | "insert" => Named::Insert,
+        "home" => Named::Home,
+        "end" => Named::End,
+        "pageup" => Named::PageUp,
+        "pagedown" => Named::PageDown,
+        "up" | "arrowup" => Named::ArrowUp,
+        "down" | "arrowdown" => Named::ArrowDown,
+        "left" | "arrowleft" => Named::ArrowLeft,
+        "right" | "arrowright" => Named::ArrowRight,
+        "f1" => Named::F1,
+        "f2" => Named::F2,
+        "f3" => Named::F3,
+        "f4" => Named::F4,
+        "f5" => Named::F5,
+        "f6" => Named::F6,
+        "f7" => Named::F7,
+        "f8" => Named::F8,
+        "f9" => Named::F9,
+        "f10" => Named::F10,
+        "f11" => Named::F11,
+        "f12" => Named::F12,
+        _ => return Err(ParseError::UnknownKey(key.to_owned())),
+    };
+
+    Ok(Key::Named(named))
+}
+
+/// Characters are compared case-insensitively, since the case of a key
+/// depends on the modifiers.
+fn normalize(key: Key) -> Key {
+    match key {
+        Key::Character(c) if c.chars().any(char::is_uppercase) => {
+            Key::Character(SmolStr::new(c.to_lowercase()))
+        }
+        key => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_shortcuts() {
+        let shortcut = Shortcut::parse("Ctrl+Shift+P").unwrap();
+
+        assert_eq!(
+            shortcut,
+            Shortcut::new(
+                Key::Character("p".into()),
+                Modifiers::CTRL | Modifiers::SHIFT
+            )
+        );
+        assert_eq!(shortcut.to_string(), "Ctrl+Shift+P");
+
+        assert_eq!(
+            Shortcut::parse("Ctrl++ F5").unwrap(),
+            Shortcut::new(Key::Character("+".into()), Modifiers::CTRL)
+                .then(Named::F5, Modifiers::empty())
+        );
+
+        assert_eq!(Shortcut::parse(" "), Err(ParseError::Empty));
+        assert_eq!(
+            Shortcut::parse("Ctrl+"),
+            Err(ParseError::MissingKey("Ctrl+".to_owned()))
+        );
+        assert_eq!(
+            Shortcut::parse("Hyper+K"),
+            Err(ParseError::UnknownModifier("Hyper".to_owned()))
+        );
+    }
+}
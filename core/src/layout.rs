@@ -3,6 +3,8 @@ mod limits;
 mod node;
 
 pub mod flex;
+pub mod flow;
+pub mod grid;
 
 pub use limits::Limits;
 pub use node::Node;
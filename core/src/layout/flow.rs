@@ -0,0 +1,162 @@
+//! Distribute elements in lines that wrap when running out of space.
+use crate::Element;
+
+use crate::layout::flex::Axis;
+use crate::layout::{Limits, Node};
+use crate::widget;
+use crate::{Alignment, Length, Padding, Point, Size};
+
+/// The distribution of the items of a line along the main axis of a flow
+/// layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    /// Items are packed at the start of the line.
+    #[default]
+    Start,
+
+    /// Items are packed at the center of the line.
+    Center,
+
+    /// Items are packed at the end of the line.
+    End,
+
+    /// Items are spread evenly, with the first and last items touching
+    /// the edges of the line.
+    SpaceBetween,
+}
+
+impl From<Alignment> for Justify {
+    fn from(alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Start => Self::Start,
+            Alignment::Center => Self::Center,
+            Alignment::End => Self::End,
+        }
+    }
+}
+
+/// Computes the flow layout with the given axis and limits, wrapping items
+/// to a new line whenever they do not fit in the main axis.
+///
+/// The items of each line are aligned in the cross axis with `align_items`.
+/// In the main axis, each line is aligned within the widest line with
+/// `align_lines`; unless a `justify` strategy is provided, which distributes
+/// the items of each line in all the available space instead. Lines are
+/// separated by `line_spacing`.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Theme, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    line_spacing: f32,
+    align_items: Alignment,
+    align_lines: Alignment,
+    justify: Option<Justify>,
+    items: &mut [Element<'_, Message, Theme, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.width(width).height(height).shrink(padding);
+    let child_limits = limits.loose();
+    let max_main = axis.main(limits.max());
+
+    let mut nodes = Vec::with_capacity(items.len());
+    let mut lines: Vec<Line> = Vec::new();
+    let mut line = Line::default();
+
+    // FIRST PASS
+    // We lay out every item and break the lines.
+    for (child, tree) in items.iter_mut().zip(trees) {
+        let node = child.as_widget_mut().layout(tree, renderer, &child_limits);
+        let size = node.size();
+
+        if line.items > 0 && line.main + spacing + axis.main(size) > max_main {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        if line.items > 0 {
+            line.main += spacing;
+        }
+
+        line.items += 1;
+        line.main += axis.main(size);
+        line.cross = line.cross.max(axis.cross(size));
+
+        nodes.push(node);
+    }
+
+    if line.items > 0 {
+        lines.push(line);
+    }
+
+    let intrinsic_main = lines.iter().map(|line| line.main).fold(0.0, f32::max);
+    let intrinsic_cross = lines.iter().map(|line| line.cross).sum::<f32>()
+        + line_spacing * lines.len().saturating_sub(1) as f32;
+
+    let (intrinsic_width, intrinsic_height) = axis.pack(intrinsic_main, intrinsic_cross);
+    let size = limits.resolve(width, height, Size::new(intrinsic_width, intrinsic_height));
+
+    let (available, justify) = match justify {
+        Some(justify) if axis.main(size).is_finite() => (axis.main(size), justify),
+        Some(justify) => (intrinsic_main, justify),
+        None => (intrinsic_main, Justify::from(align_lines)),
+    };
+
+    // SECOND PASS
+    // We position the items of every line, justifying and aligning them.
+    let (pad_main, pad_cross) = axis.pack(padding.left, padding.top);
+    let mut cross = pad_cross;
+    let mut nodes_left = nodes.as_mut_slice();
+
+    for line in &lines {
+        let (line_nodes, rest) = nodes_left.split_at_mut(line.items);
+        nodes_left = rest;
+
+        let free = (available - line.main).max(0.0);
+
+        let (mut main, gap) = match justify {
+            Justify::Start => (0.0, spacing),
+            Justify::Center => (free / 2.0, spacing),
+            Justify::End => (free, spacing),
+            Justify::SpaceBetween if line.items > 1 => {
+                (0.0, spacing + free / (line.items - 1) as f32)
+            }
+            Justify::SpaceBetween => (0.0, spacing),
+        };
+
+        for node in line_nodes {
+            let (x, y) = axis.pack(pad_main + main, cross);
+
+            node.move_to_mut(Point::new(x, y));
+
+            match axis {
+                Axis::Horizontal => {
+                    node.align_mut(Alignment::Start, align_items, Size::new(0.0, line.cross));
+                }
+                Axis::Vertical => {
+                    node.align_mut(align_items, Alignment::Start, Size::new(line.cross, 0.0));
+                }
+            }
+
+            main += axis.main(node.size()) + gap;
+        }
+
+        cross += line.cross + line_spacing;
+    }
+
+    Node::with_children(size.expand(padding), nodes)
+}
+
+#[derive(Debug, Default)]
+struct Line {
+    items: usize,
+    main: f32,
+    cross: f32,
+}
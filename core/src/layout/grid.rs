@@ -0,0 +1,336 @@
+//! Distribute elements on a two-dimensional grid of tracks.
+use crate::Element;
+
+use crate::layout::{Limits, Node};
+use crate::widget;
+use crate::{Alignment, Length, Padding, Point, Size};
+
+/// The tracks of a grid layout occupied by an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Area {
+    /// The first row of the [`Area`].
+    pub row: usize,
+    /// The first column of the [`Area`].
+    pub column: usize,
+    /// The amount of rows spanned by the [`Area`].
+    pub rows: usize,
+    /// The amount of columns spanned by the [`Area`].
+    pub columns: usize,
+}
+
+impl Area {
+    /// Creates an [`Area`] spanning a single track at the given row and column.
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            rows: 1,
+            columns: 1,
+        }
+    }
+
+    /// Returns whether the [`Area`] contains the given row and column.
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        (self.row..self.row + self.rows).contains(&row)
+            && (self.column..self.column + self.columns).contains(&column)
+    }
+}
+
+/// An item placed on a grid layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    /// The [`Area`] occupied by the item.
+    pub area: Area,
+    /// The horizontal alignment of the item inside its [`Area`].
+    pub align_x: Alignment,
+    /// The vertical alignment of the item inside its [`Area`].
+    pub align_y: Alignment,
+}
+
+/// Computes the grid layout of the given items, sizing the column and row
+/// tracks and placing every item in its [`Cell`].
+///
+/// Tracks are sized with a [`Length`]:
+/// - [`Length::Fixed`] tracks have the given size.
+/// - [`Length::Shrink`] tracks fit the items inside them.
+/// - [`Length::Fill`] and [`Length::FillPortion`] tracks share the space
+///   left by the other tracks. If the grid itself shrinks in that axis,
+///   they fit their items instead.
+///
+/// Items placed outside of the given tracks create implicit [`Length::Shrink`]
+/// tracks.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Theme, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: Size,
+    columns: &[Length],
+    rows: &[Length],
+    cells: &[Cell],
+    items: &mut [Element<'_, Message, Theme, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.width(width).height(height).shrink(padding);
+    let max = limits.max();
+    let compression = limits.compression();
+
+    let columns = tracks(
+        columns,
+        cells
+            .iter()
+            .map(|cell| cell.area.column + cell.area.columns),
+    );
+    let rows = tracks(
+        rows,
+        cells.iter().map(|cell| cell.area.row + cell.area.rows),
+    );
+
+    let fits_columns = compression.width || max.width.is_infinite();
+    let fits_rows = compression.height || max.height.is_infinite();
+
+    // FIRST PASS
+    // We measure the items inside of intrinsic columns, compressing them
+    // to their intrinsic width.
+    let intrinsic_widths: Vec<_> = items
+        .iter_mut()
+        .zip(trees.iter_mut())
+        .zip(cells)
+        .filter(|(_, cell)| {
+            spans(&columns, cell.area.column, cell.area.columns)
+                .iter()
+                .any(|track| is_intrinsic(*track, fits_columns))
+        })
+        .map(|((item, tree), cell)| {
+            let limits = Limits::with_compression(Size::ZERO, max, Size::new(true, true));
+            let node = item.as_widget_mut().layout(tree, renderer, &limits);
+
+            (cell.area.column, cell.area.columns, node.size().width)
+        })
+        .collect();
+
+    let widths = distribute(
+        &columns,
+        max.width,
+        spacing.width,
+        fits_columns,
+        intrinsic_widths,
+    );
+
+    // SECOND PASS
+    // We measure the items inside of intrinsic rows, now that we know the
+    // width of their area.
+    let intrinsic_heights: Vec<_> = items
+        .iter_mut()
+        .zip(trees.iter_mut())
+        .zip(cells)
+        .filter(|(_, cell)| {
+            spans(&rows, cell.area.row, cell.area.rows)
+                .iter()
+                .any(|track| is_intrinsic(*track, fits_rows))
+        })
+        .map(|((item, tree), cell)| {
+            let width = extent(&widths, cell.area.column, cell.area.columns, spacing.width);
+
+            let limits = Limits::with_compression(
+                Size::ZERO,
+                Size::new(width, max.height),
+                Size::new(false, true),
+            );
+            let node = item.as_widget_mut().layout(tree, renderer, &limits);
+
+            (cell.area.row, cell.area.rows, node.size().height)
+        })
+        .collect();
+
+    let heights = distribute(
+        &rows,
+        max.height,
+        spacing.height,
+        fits_rows,
+        intrinsic_heights,
+    );
+
+    // THIRD PASS
+    // We lay out every item in its area and align it.
+    let nodes = items
+        .iter_mut()
+        .zip(trees)
+        .zip(cells)
+        .map(|((item, tree), cell)| {
+            let Area {
+                row,
+                column,
+                rows,
+                columns,
+            } = cell.area;
+
+            let area = Size::new(
+                extent(&widths, column, columns, spacing.width),
+                extent(&heights, row, rows, spacing.height),
+            );
+
+            let position = Point::new(
+                padding.left + offset(&widths, column, spacing.width),
+                padding.top + offset(&heights, row, spacing.height),
+            );
+
+            item.as_widget_mut()
+                .layout(tree, renderer, &Limits::new(Size::ZERO, area))
+                .move_to(position)
+                .align(cell.align_x, cell.align_y, area)
+        })
+        .collect();
+
+    let intrinsic_size = Size::new(
+        extent(&widths, 0, widths.len(), spacing.width),
+        extent(&heights, 0, heights.len(), spacing.height),
+    );
+
+    let size = limits.resolve(width, height, intrinsic_size);
+
+    Node::with_children(size.expand(padding), nodes)
+}
+
+/// Returns the given tracks, followed by any implicit tracks needed to
+/// reach the given ends.
+fn tracks(tracks: &[Length], ends: impl Iterator<Item = usize>) -> Vec<Length> {
+    let count = ends.fold(tracks.len(), usize::max);
+
+    let mut tracks = tracks.to_vec();
+    tracks.resize(count, Length::Shrink);
+    tracks
+}
+
+fn spans(tracks: &[Length], start: usize, span: usize) -> &[Length] {
+    &tracks[start.min(tracks.len())..(start + span).min(tracks.len())]
+}
+
+fn is_intrinsic(track: Length, fits: bool) -> bool {
+    match track {
+        Length::Shrink => true,
+        Length::Fill | Length::FillPortion(_) => fits,
+        Length::Fixed(_) => false,
+    }
+}
+
+/// Sizes the given tracks in the available space, given the intrinsic size
+/// of the items spanning them as `(start, span, size)`.
+fn distribute(
+    tracks: &[Length],
+    available: f32,
+    spacing: f32,
+    fits: bool,
+    mut items: Vec<(usize, usize, f32)>,
+) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Length::Fixed(size) => *size,
+            _ => 0.0,
+        })
+        .collect();
+
+    // Items spanning fewer tracks are satisfied first
+    items.sort_by_key(|(_, span, _)| *span);
+
+    for (start, span, size) in items {
+        let end = (start + span).min(tracks.len());
+
+        let intrinsic: Vec<usize> = (start..end)
+            .filter(|i| is_intrinsic(tracks[*i], fits))
+            .collect();
+
+        let current = extent(&sizes, start, span, spacing);
+
+        if intrinsic.is_empty() || current >= size {
+            continue;
+        }
+
+        let share = (size - current) / intrinsic.len() as f32;
+
+        for i in intrinsic {
+            sizes[i] += share;
+        }
+    }
+
+    if !fits {
+        let total_spacing = spacing * tracks.len().saturating_sub(1) as f32;
+
+        let fill_sum: u16 = tracks.iter().map(Length::fill_factor).sum();
+
+        let remaining = (available
+            - total_spacing
+            - tracks
+                .iter()
+                .zip(&sizes)
+                .filter(|(track, _)| track.fill_factor() == 0)
+                .map(|(_, size)| size)
+                .sum::<f32>())
+        .max(0.0);
+
+        for (track, size) in tracks.iter().zip(&mut sizes) {
+            let factor = track.fill_factor();
+
+            if factor != 0 {
+                *size = remaining * f32::from(factor) / f32::from(fill_sum);
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the size of a span of tracks, including the spacing between them.
+fn extent(sizes: &[f32], start: usize, span: usize, spacing: f32) -> f32 {
+    let tracks = &sizes[start.min(sizes.len())..(start + span).min(sizes.len())];
+
+    tracks.iter().sum::<f32>() + spacing * tracks.len().saturating_sub(1) as f32
+}
+
+/// Returns the position of a track.
+fn offset(sizes: &[f32], track: usize, spacing: f32) -> f32 {
+    sizes[..track.min(sizes.len())]
+        .iter()
+        .map(|size| size + spacing)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_tracks_share_the_remaining_space() {
+        let tracks = [Length::Fixed(100.0), Length::Fill, Length::FillPortion(3)];
+
+        assert_eq!(
+            distribute(&tracks, 520.0, 10.0, false, Vec::new()),
+            vec![100.0, 100.0, 300.0]
+        );
+    }
+
+    #[test]
+    fn intrinsic_tracks_fit_their_items() {
+        let tracks = [Length::Shrink, Length::Shrink, Length::Fixed(50.0)];
+
+        let sizes = distribute(
+            &tracks,
+            f32::INFINITY,
+            10.0,
+            true,
+            vec![(0, 1, 40.0), (0, 3, 200.0), (1, 1, 20.0)],
+        );
+
+        // The spanning item grows the intrinsic tracks evenly
+        assert_eq!(sizes, vec![75.0, 55.0, 50.0]);
+        assert_eq!(extent(&sizes, 0, 3, 10.0), 200.0);
+        assert_eq!(offset(&sizes, 2, 10.0), 150.0);
+    }
+}
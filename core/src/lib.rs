@@ -14,6 +14,7 @@ pub mod animation;
 pub mod border;
 pub mod clipboard;
 pub mod color;
+pub mod drag;
 pub mod event;
 pub mod font;
 pub mod gradient;
@@ -57,6 +58,7 @@ pub use border::Border;
 pub use clipboard::Clipboard;
 pub use color::Color;
 pub use content_fit::ContentFit;
+pub use drag::Drag;
 pub use element::Element;
 pub use event::Event;
 pub use font::Font;
@@ -124,6 +124,7 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        operation.container(None, layout.bounds());
         operation.traverse(&mut |operation| {
             self.children
                 .iter_mut()
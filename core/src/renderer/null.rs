@@ -156,6 +156,14 @@ impl text::Paragraph for () {
     fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
         vec![]
     }
+
+    fn hit_offset(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn range_bounds(&self, _range: std::ops::Range<usize>) -> Vec<Rectangle> {
+        vec![]
+    }
 }
 
 impl text::Editor for () {
@@ -174,10 +182,22 @@ impl text::Editor for () {
         }
     }
 
+    fn cursors(&self) -> Vec<text::editor::Cursor> {
+        vec![self.cursor()]
+    }
+
     fn selection(&self) -> text::editor::Selection {
         text::editor::Selection::Caret(Point::ORIGIN)
     }
 
+    fn selections(&self) -> Vec<text::editor::Selection> {
+        vec![self.selection()]
+    }
+
+    fn range_bounds(&self, _range: std::ops::Range<text::editor::Position>) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
     fn copy(&self) -> Option<String> {
         None
     }
@@ -190,10 +210,20 @@ impl text::Editor for () {
         0
     }
 
+    fn visible_lines(&self) -> Vec<text::editor::VisibleLine> {
+        Vec::new()
+    }
+
+    fn folds(&self) -> Vec<std::ops::Range<usize>> {
+        Vec::new()
+    }
+
     fn perform(&mut self, _action: text::editor::Action) {}
 
     fn move_to(&mut self, _cursor: text::editor::Cursor) {}
 
+    fn move_to_all(&mut self, _cursors: &[text::editor::Cursor]) {}
+
     fn bounds(&self) -> Size {
         Size::ZERO
     }
@@ -225,6 +255,16 @@ impl text::Editor for () {
         _format_highlight: impl Fn(&H::Highlight) -> text::highlighter::Format<Self::Font>,
     ) {
     }
+
+    fn format_line(
+        &mut self,
+        _line: usize,
+        _formats: Vec<(
+            std::ops::Range<usize>,
+            text::highlighter::Format<Self::Font>,
+        )>,
+    ) {
+    }
 }
 
 impl image::Renderer for () {
@@ -239,6 +279,10 @@ impl image::Renderer for () {
         Some(Size::new(100, 100))
     }
 
+    fn load_frames(&self, handle: &Self::Handle) -> Result<image::Frames, image::Error> {
+        Ok(image::Frames::still(handle.clone()))
+    }
+
     fn draw_image(&mut self, _image: Image, _bounds: Rectangle, _clip_bounds: Rectangle) {}
 }
 
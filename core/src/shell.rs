@@ -1,4 +1,5 @@
 use crate::clipboard;
+use crate::drag::Drag;
 use crate::event;
 use crate::window;
 use crate::{Clipboard, InputMethod};
@@ -18,6 +19,8 @@ pub struct Shell<'a, Message> {
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
     clipboard: Clipboard,
+    drag: Option<Drag>,
+    is_drag_changed: bool,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -34,9 +37,20 @@ impl<'a, Message> Shell<'a, Message> {
                 reads: Vec::new(),
                 write: None,
             },
+            drag: None,
+            is_drag_changed: false,
         }
     }
 
+    /// Sets the [`Drag`] in progress that widgets will see through the [`Shell`].
+    ///
+    /// This is useful to share the [`Drag`] of a [`Shell`] with a local one.
+    #[must_use]
+    pub fn with_drag(mut self, drag: Option<Drag>) -> Self {
+        self.drag = drag;
+        self
+    }
+
     /// Returns true if the [`Shell`] contains no published messages
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -112,6 +126,32 @@ impl<'a, Message> Shell<'a, Message> {
         &mut self.clipboard
     }
 
+    /// Returns the [`Drag`] in progress, if any.
+    #[must_use]
+    pub fn drag(&self) -> Option<&Drag> {
+        self.drag.as_ref()
+    }
+
+    /// Starts the given [`Drag`], replacing the one in progress.
+    ///
+    /// The runtime will end the [`Drag`] once the current pointer is released.
+    pub fn start_drag(&mut self, drag: Drag) {
+        self.drag = Some(drag);
+        self.is_drag_changed = true;
+    }
+
+    /// Ends the [`Drag`] in progress, if any.
+    pub fn end_drag(&mut self) {
+        self.drag = None;
+        self.is_drag_changed = true;
+    }
+
+    /// Returns whether the [`Drag`] in progress has been started or ended.
+    #[must_use]
+    pub fn is_drag_changed(&self) -> bool {
+        self.is_drag_changed
+    }
+
     /// Requests the current [`InputMethod`] strategy.
     ///
     /// __Important__: This request will only be honored by the
@@ -183,5 +223,10 @@ impl<'a, Message> Shell<'a, Message> {
 
         self.input_method.merge(&other.input_method);
         self.clipboard.merge(&mut other.clipboard);
+
+        if other.is_drag_changed {
+            self.drag = other.drag.take();
+            self.is_drag_changed = true;
+        }
     }
 }
@@ -2,10 +2,12 @@
 pub mod editor;
 pub mod highlighter;
 pub mod paragraph;
+pub mod selection;
 
 pub use editor::Editor;
 pub use highlighter::Highlighter;
 pub use paragraph::Paragraph;
+pub use selection::Selection;
 
 use crate::alignment;
 use crate::{Background, Border, Color, Padding, Pixels, Point, Rectangle, Size};
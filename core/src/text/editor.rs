@@ -4,6 +4,7 @@ use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::Arc;
 
 /// A component that can be used by widgets to edit multi-line text.
@@ -18,11 +19,26 @@ pub trait Editor: Sized + Default {
     fn is_empty(&self) -> bool;
 
     /// Returns the current [`Cursor`] of the [`Editor`].
+    ///
+    /// This is the primary cursor when the [`Editor`] has multiple cursors.
     fn cursor(&self) -> Cursor;
 
+    /// Returns all the cursors of the [`Editor`], starting with the primary one.
+    fn cursors(&self) -> Vec<Cursor>;
+
     /// Returns the current [`Selection`] of the [`Editor`].
+    ///
+    /// This is the selection of the primary cursor when the [`Editor`] has
+    /// multiple cursors.
     fn selection(&self) -> Selection;
 
+    /// Returns the [`Selection`] of every cursor of the [`Editor`], starting
+    /// with the primary one.
+    fn selections(&self) -> Vec<Selection>;
+
+    /// Returns the visible regions covered by the given range of text.
+    fn range_bounds(&self, range: Range<Position>) -> Vec<Rectangle>;
+
     /// Returns the current selected text of the [`Editor`].
     fn copy(&self) -> Option<String>;
 
@@ -32,12 +48,27 @@ pub trait Editor: Sized + Default {
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the lines of the [`Editor`] currently in view, from top to bottom.
+    ///
+    /// Folded lines are never visible.
+    fn visible_lines(&self) -> Vec<VisibleLine>;
+
+    /// Returns the folded regions of the [`Editor`].
+    ///
+    /// See [`Action::Fold`].
+    fn folds(&self) -> Vec<Range<usize>>;
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
-    /// Moves the cursor to the given position.
+    /// Moves the cursor to the given position, dropping any additional cursors.
     fn move_to(&mut self, cursor: Cursor);
 
+    /// Replaces all the cursors of the [`Editor`] with the given ones.
+    ///
+    /// The first [`Cursor`] becomes the primary one.
+    fn move_to_all(&mut self, cursors: &[Cursor]);
+
     /// Returns the current boundaries of the [`Editor`].
     fn bounds(&self) -> Size;
 
@@ -67,6 +98,17 @@ pub trait Editor: Sized + Default {
         highlighter: &mut H,
         format_highlight: impl Fn(&H::Highlight) -> highlighter::Format<Self::Font>,
     );
+
+    /// Sets the formats of the given line of the [`Editor`], laid on top
+    /// of its highlights.
+    ///
+    /// The ranges of the formats are byte offsets into the line. The formats
+    /// of a line are dropped once it is edited.
+    fn format_line(
+        &mut self,
+        line: usize,
+        formats: Vec<(Range<usize>, highlighter::Format<Self::Font>)>,
+    );
 }
 
 /// An interaction with an [`Editor`].
@@ -88,17 +130,42 @@ pub enum Action {
     Click(Point),
     /// Drag the mouse on the [`Editor`] to the given [`Point`].
     Drag(Point),
+    /// Add a new cursor at the given [`Point`], or remove the cursor
+    /// already there.
+    AddCursor(Point),
+    /// Select the word at the current cursor or, if there is a selection
+    /// already, add a new cursor selecting its next occurrence.
+    SelectNextOccurrence,
+    /// Place a cursor in every line of the box spanned by the given points,
+    /// selecting the columns in between.
+    SelectBox {
+        /// The corner of the box where the selection started.
+        from: Point,
+        /// The corner of the box where the selection ends.
+        to: Point,
+    },
+    /// Fold the given range of lines.
+    ///
+    /// The first line of the range stays visible, while the rest are hidden
+    /// until the region is unfolded or any of its hidden lines is edited.
+    Fold(Range<usize>),
+    /// Unfold the folded region starting at the given line.
+    Unfold(usize),
     /// Scroll the [`Editor`] a certain amount of lines.
     Scroll {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Undo the last group of [`Edit`] actions.
+    Undo,
+    /// Redo the last undone group of [`Edit`] actions.
+    Redo,
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
     }
 }
 
@@ -119,6 +186,13 @@ pub enum Edit {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Replace the text in every given range.
+    Replace {
+        /// The ranges of text to replace.
+        ranges: Vec<Range<Position>>,
+        /// The replacement text.
+        text: Arc<String>,
+    },
 }
 
 /// A cursor movement.
@@ -228,6 +302,17 @@ pub struct Line<'a> {
     pub ending: LineEnding,
 }
 
+/// A line of an [`Editor`] in view.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisibleLine {
+    /// The index of the line.
+    pub index: usize,
+    /// The distance from the top of the [`Editor`] to the top of the line.
+    pub y: f32,
+    /// The height of the line, including all of its wrapped visual lines.
+    pub height: f32,
+}
+
 /// The line ending of a [`Line`].
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum LineEnding {
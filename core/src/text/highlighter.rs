@@ -29,6 +29,15 @@ pub trait Highlighter: 'static {
     /// Notifies the [`Highlighter`] that the line at the given index has changed.
     fn change_line(&mut self, line: usize);
 
+    /// Notifies the [`Highlighter`] that the given range of lines has been
+    /// replaced with `count` new lines.
+    ///
+    /// Edits are always followed by a [`change_line`](Self::change_line)
+    /// notification of the topmost changed line. A [`Highlighter`] that
+    /// tracks the whole text, like an incremental parser, can use them to
+    /// keep its lines aligned.
+    fn replace_lines(&mut self, _lines: Range<usize>, _count: usize) {}
+
     /// Highlights the given line.
     ///
     /// If a line changed prior to this, the first line provided here will be the
@@ -40,6 +49,33 @@ pub trait Highlighter: 'static {
     /// If `change_line` has been called, this will normally be the least index
     /// that changed.
     fn current_line(&self) -> usize;
+
+    /// Integrates the highlights computed in the background since the last
+    /// poll, if any.
+    ///
+    /// When new highlights land, the [`Highlighter`] must rewind its
+    /// [`current_line`](Self::current_line) to the first updated line, so
+    /// the lines after it are fed again.
+    ///
+    /// Polling only happens on redraws, so a [`Highlighter`] computing in
+    /// the background is responsible for waking up the UI when its
+    /// highlights land.
+    ///
+    /// A synchronous [`Highlighter`] is always [`Status::Idle`].
+    fn poll(&mut self) -> Status {
+        Status::Idle
+    }
+}
+
+/// The status of a [`Highlighter`] computing highlights in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Highlighter`] has no pending work.
+    Idle,
+    /// The [`Highlighter`] is computing highlights that have not landed yet.
+    Busy,
+    /// New highlights have landed and the lines should be fed again.
+    Updated,
 }
 
 /// A highlighter that highlights nothing.
@@ -70,12 +106,26 @@ impl Highlighter for PlainText {
 }
 
 /// The format of some text.
+///
+/// A [`Format`] can be built by chaining its methods, starting from
+/// [`Format::default`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
 pub struct Format<Font> {
     /// The [`Color`] of the text.
     pub color: Option<Color>,
     /// The `Font` of the text.
     pub font: Option<Font>,
+    /// The [`Underline`] of the text.
+    pub underline: Option<Underline>,
+    /// The [`Color`] of the underline of the text.
+    ///
+    /// If `None`, the underline has the color of the text.
+    pub underline_color: Option<Color>,
+    /// Whether the text is struck through.
+    pub strikethrough: bool,
+    /// The background [`Color`] of the text.
+    pub background: Option<Color>,
 }
 
 impl<Font> Default for Format<Font> {
@@ -83,6 +133,59 @@ impl<Font> Default for Format<Font> {
         Self {
             color: None,
             font: None,
+            underline: None,
+            underline_color: None,
+            strikethrough: false,
+            background: None,
         }
     }
 }
+
+impl<Font> Format<Font> {
+    /// Sets the [`Color`] of the text.
+    pub fn color(mut self, color: impl Into<Option<Color>>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Sets the `Font` of the text.
+    pub fn font(mut self, font: impl Into<Option<Font>>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the [`Underline`] of the text.
+    pub fn underline(mut self, underline: impl Into<Option<Underline>>) -> Self {
+        self.underline = underline.into();
+        self
+    }
+
+    /// Sets the [`Color`] of the underline of the text.
+    pub fn underline_color(mut self, color: impl Into<Option<Color>>) -> Self {
+        self.underline_color = color.into();
+        self
+    }
+
+    /// Sets whether the text is struck through.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Sets the background [`Color`] of the text.
+    pub fn background(mut self, background: impl Into<Option<Color>>) -> Self {
+        self.background = background.into();
+        self
+    }
+}
+
+/// The style of the line drawn under some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Underline {
+    /// A straight line.
+    Straight,
+    /// A wavy line; like the ones marking misspelled words.
+    Wavy,
+    /// A dotted line.
+    Dotted,
+}
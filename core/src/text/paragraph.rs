@@ -5,6 +5,8 @@ use crate::text::{
 };
 use crate::{Pixels, Point, Rectangle, Size};
 
+use std::ops::Range;
+
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
     /// The font of this [`Paragraph`].
@@ -70,6 +72,15 @@ pub trait Paragraph: Sized + Default {
     /// A [`Span`] can have multiple bounds for each line it's on.
     fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
 
+    /// Tests whether the provided point is within the boundaries of the
+    /// [`Paragraph`], returning the byte offset of the nearest character
+    /// in its whole contents.
+    fn hit_offset(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of the given byte range of the contents of the
+    /// [`Paragraph`]; one for each visual line the range is on.
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
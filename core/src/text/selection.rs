@@ -0,0 +1,229 @@
+//! Select and copy the contents of a [`Paragraph`].
+use crate::clipboard;
+use crate::keyboard;
+use crate::mouse;
+use crate::renderer;
+use crate::text::Paragraph;
+use crate::{Color, Drag, Event, Point, Rectangle, Shell};
+
+use std::ops::Range;
+
+/// The selection of some selectable text.
+///
+/// Dragging the mouse selects text, a double click selects a word, and a triple
+/// click selects a line. While dragging, any other [`Selection`] in the same
+/// user interface will extend itself to include the text between the origin
+/// of the drag and the pointer; so the contents of multiple sibling widgets
+/// can be selected at once.
+///
+/// The selected text is copied to the clipboard with `Ctrl+C` (or `Cmd+C`).
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    range: Option<Range<usize>>,
+    last_click: Option<mouse::Click>,
+}
+
+/// The [`Drag`] payload of an ongoing selection.
+#[derive(Debug, Clone, Copy)]
+struct Selecting {
+    origin: Point,
+}
+
+impl Selection {
+    /// Returns the selected byte range of the contents, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        self.range.clone()
+    }
+
+    /// Returns the selected text of the given contents, if any.
+    pub fn text<'a>(&self, content: &'a str) -> Option<&'a str> {
+        content.get(self.range.clone()?)
+    }
+
+    /// Processes an [`Event`] and updates the [`Selection`] accordingly.
+    ///
+    /// The `content` must be the whole text of the [`Paragraph`] laid out in the
+    /// given `bounds`.
+    pub fn update<Message>(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        paragraph: &impl Paragraph,
+        content: &str,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let anchor = bounds.anchor(
+            paragraph.min_bounds(),
+            paragraph.align_x(),
+            paragraph.align_y(),
+        );
+
+        let offset_at = |position: Point| {
+            let mut offset = if position.y < bounds.y {
+                0
+            } else if position.y >= bounds.y + bounds.height {
+                content.len()
+            } else {
+                paragraph
+                    .hit_offset(Point::new(position.x - anchor.x, position.y - anchor.y))
+                    .map(|offset| offset.min(content.len()))
+                    .unwrap_or(if position.x < bounds.center_x() {
+                        0
+                    } else {
+                        content.len()
+                    })
+            };
+
+            while !content.is_char_boundary(offset) {
+                offset -= 1;
+            }
+
+            offset
+        };
+
+        let range = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if shell.is_event_captured() {
+                    return;
+                }
+
+                let Some(position) = cursor.position_over(bounds) else {
+                    self.last_click = None;
+                    self.select(None, shell);
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, self.last_click);
+                self.last_click = Some(click);
+
+                let offset = offset_at(position);
+
+                match click.kind() {
+                    mouse::click::Kind::Single => {
+                        shell.start_drag(Drag::new(Selecting { origin: position }));
+
+                        None
+                    }
+                    mouse::click::Kind::Double => Some(word(content, offset)),
+                    mouse::click::Kind::Triple => Some(line(content, offset)),
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let Some(Selecting { origin }) = shell
+                    .drag()
+                    .and_then(|drag| drag.payload::<Selecting>())
+                    .copied()
+                else {
+                    return;
+                };
+
+                let Some(position) = cursor.land().position() else {
+                    return;
+                };
+
+                let from = offset_at(origin);
+                let to = offset_at(position);
+
+                Some(from.min(to)..from.max(to))
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) if key.to_latin(*physical_key) == Some('c') && modifiers.command() => {
+                if shell.is_event_captured() {
+                    return;
+                }
+
+                let Some(selection) = self.text(content) else {
+                    return;
+                };
+
+                // Siblings append their selections in order
+                if let Some(clipboard::Content::Text(text)) = &mut shell.clipboard_mut().write {
+                    text.push('\n');
+                    text.push_str(selection);
+                } else {
+                    shell.write_clipboard(clipboard::Content::Text(selection.to_owned()));
+                }
+
+                return;
+            }
+            _ => return,
+        };
+
+        self.select(range, shell);
+    }
+
+    /// Draws the highlight of the [`Selection`] of the given [`Paragraph`].
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        paragraph: &Renderer::Paragraph,
+        bounds: Rectangle,
+        color: Color,
+    ) where
+        Renderer: crate::text::Renderer,
+    {
+        let Some(range) = self.range.clone() else {
+            return;
+        };
+
+        let anchor = bounds.anchor(
+            paragraph.min_bounds(),
+            paragraph.align_x(),
+            paragraph.align_y(),
+        );
+
+        for highlight in paragraph.range_bounds(range) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: highlight + (anchor - Point::ORIGIN),
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+
+    fn select<Message>(&mut self, range: Option<Range<usize>>, shell: &mut Shell<'_, Message>) {
+        let range = range.filter(|range| !range.is_empty());
+
+        if self.range != range {
+            self.range = range;
+            shell.request_redraw();
+        }
+    }
+}
+
+/// Returns the byte range of the word at the given offset.
+fn word(content: &str, offset: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let start = content[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(index, _)| index);
+
+    let end = content[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(content.len(), |(index, _)| offset + index);
+
+    start..end
+}
+
+/// Returns the byte range of the line at the given offset, without its ending.
+fn line(content: &str, offset: usize) -> Range<usize> {
+    let start = content[..offset].rfind('\n').map_or(0, |index| index + 1);
+
+    let end = content[offset..]
+        .find(['\r', '\n'])
+        .map_or(content.len(), |index| offset + index);
+
+    start..end
+}
@@ -1,10 +1,12 @@
 //! Query or update internal widget state.
 pub mod focusable;
 pub mod scrollable;
+pub mod semantics;
 pub mod text_input;
 
 pub use focusable::Focusable;
 pub use scrollable::Scrollable;
+pub use semantics::Semantics;
 pub use text_input::TextInput;
 
 use crate::widget::Id;
@@ -52,6 +54,9 @@ pub trait Operation<T = ()>: Send {
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, _state: &mut dyn Any) {}
 
+    /// Operates on a widget that describes its [`Semantics`].
+    fn semantics(&mut self, _id: Option<&Id>, _bounds: Rectangle, _semantics: &Semantics<'_>) {}
+
     /// Finishes the [`Operation`] and returns its [`Outcome`].
     fn finish(&self) -> Outcome<T> {
         Outcome::None
@@ -98,6 +103,10 @@ where
         self.as_mut().custom(id, bounds, state);
     }
 
+    fn semantics(&mut self, id: Option<&Id>, bounds: Rectangle, semantics: &Semantics<'_>) {
+        self.as_mut().semantics(id, bounds, semantics);
+    }
+
     fn finish(&self) -> Outcome<O> {
         self.as_ref().finish()
     }
@@ -179,6 +188,10 @@ where
             self.operation.custom(id, bounds, state);
         }
 
+        fn semantics(&mut self, id: Option<&Id>, bounds: Rectangle, semantics: &Semantics<'_>) {
+            self.operation.semantics(id, bounds, semantics);
+        }
+
         fn finish(&self) -> Outcome<O> {
             Outcome::None
         }
@@ -262,6 +275,15 @@ where
                 fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
                     self.operation.custom(id, bounds, state);
                 }
+
+                fn semantics(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    semantics: &Semantics<'_>,
+                ) {
+                    self.operation.semantics(id, bounds, semantics);
+                }
             }
 
             self.operation.traverse(&mut |operation| {
@@ -301,6 +323,10 @@ where
             self.operation.custom(id, bounds, state);
         }
 
+        fn semantics(&mut self, id: Option<&Id>, bounds: Rectangle, semantics: &Semantics<'_>) {
+            self.operation.semantics(id, bounds, semantics);
+        }
+
         fn finish(&self) -> Outcome<B> {
             match self.operation.finish() {
                 Outcome::None => Outcome::None,
@@ -382,6 +408,10 @@ where
             self.operation.custom(id, bounds, state);
         }
 
+        fn semantics(&mut self, id: Option<&Id>, bounds: Rectangle, semantics: &Semantics<'_>) {
+            self.operation.semantics(id, bounds, semantics);
+        }
+
         fn finish(&self) -> Outcome<B> {
             match self.operation.finish() {
                 Outcome::None => Outcome::None,
@@ -0,0 +1,621 @@
+//! Describe widgets to assistive technologies.
+use crate::widget::Id;
+use crate::widget::operation::{Focusable, Operation, Outcome, Scrollable, TextInput};
+use crate::{Rectangle, Vector};
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::fmt;
+
+/// The semantic description of a widget.
+///
+/// Widgets describe themselves by calling [`Operation::semantics`]
+/// during [`Widget::operate`](crate::Widget::operate). Any widgets
+/// operated inside the subsequent [`Operation::traverse`] call are
+/// considered its children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Semantics<'a> {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The label of the widget, if any.
+    ///
+    /// If no label is provided, assistive technologies will
+    /// normally compute one from the text of its children.
+    pub label: Option<Cow<'a, str>>,
+
+    /// The description of the widget, if any.
+    pub description: Option<Cow<'a, str>>,
+
+    /// The current [`Value`] of the widget, if any.
+    pub value: Option<Value<'a>>,
+
+    /// Whether the widget is disabled.
+    pub is_disabled: bool,
+
+    /// Whether the widget is focused, if it can be focused.
+    pub is_focused: Option<bool>,
+
+    /// Whether the widget is toggled, if it can be toggled.
+    pub is_toggled: Option<bool>,
+
+    /// Whether the widget is expanded, if it can be expanded.
+    pub is_expanded: Option<bool>,
+
+    /// Whether the widget is selected, if it can be selected.
+    pub is_selected: Option<bool>,
+}
+
+impl<'a> Semantics<'a> {
+    /// Creates new [`Semantics`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            description: None,
+            value: None,
+            is_disabled: false,
+            is_focused: None,
+            is_toggled: None,
+            is_expanded: None,
+            is_selected: None,
+        }
+    }
+
+    /// Sets the label of the [`Semantics`].
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description of the [`Semantics`].
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the [`Value`] of the [`Semantics`].
+    pub fn value(mut self, value: impl Into<Value<'a>>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the [`Semantics`] describe a disabled widget.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`Semantics`] describe a focused widget.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = Some(is_focused);
+        self
+    }
+
+    /// Sets whether the [`Semantics`] describe a toggled widget.
+    pub fn toggled(mut self, is_toggled: bool) -> Self {
+        self.is_toggled = Some(is_toggled);
+        self
+    }
+
+    /// Sets whether the [`Semantics`] describe an expanded widget.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = Some(is_expanded);
+        self
+    }
+
+    /// Sets whether the [`Semantics`] describe a selected widget.
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = Some(is_selected);
+        self
+    }
+
+    /// Turns the [`Semantics`] into owned [`Semantics`], detaching
+    /// them from any borrowed data.
+    pub fn into_owned(self) -> Semantics<'static> {
+        Semantics {
+            role: self.role,
+            label: self.label.map(|label| Cow::Owned(label.into_owned())),
+            description: self
+                .description
+                .map(|description| Cow::Owned(description.into_owned())),
+            value: self.value.map(Value::into_owned),
+            is_disabled: self.is_disabled,
+            is_focused: self.is_focused,
+            is_toggled: self.is_toggled,
+            is_expanded: self.is_expanded,
+            is_selected: self.is_selected,
+        }
+    }
+}
+
+/// The role of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A widget that can be pressed.
+    Button,
+
+    /// A widget that can be checked or unchecked.
+    CheckBox,
+
+    /// A widget that can be switched on or off.
+    Switch,
+
+    /// A widget that can be selected among a group of options.
+    RadioButton,
+
+    /// A widget that can be edited by typing.
+    TextInput,
+
+    /// A widget that lets the user choose a value in a range.
+    Slider,
+
+    /// A widget that lets the user choose one option from a list.
+    ComboBox,
+
+    /// A widget that can be scrolled.
+    ScrollView,
+
+    /// A widget that displays some text.
+    Label,
+
+    /// A bar of menus.
+    MenuBar,
+
+    /// A list of menu items.
+    Menu,
+
+    /// An entry of a menu.
+    MenuItem,
+
+    /// A list of tabs.
+    TabList,
+
+    /// A tab of a list of tabs.
+    Tab,
+
+    /// The content of the selected tab.
+    TabPanel,
+
+    /// A list of hierarchical items.
+    Tree,
+
+    /// An item of a tree, which may be expanded.
+    TreeItem,
+}
+
+impl Role {
+    /// Returns true if a widget with the [`Role`] is named by its contents
+    /// when it has no explicit label.
+    ///
+    /// Containers (e.g. a [`Role::ScrollView`]) are never named by their
+    /// contents.
+    pub fn is_named_by_contents(self) -> bool {
+        matches!(
+            self,
+            Role::Button
+                | Role::CheckBox
+                | Role::RadioButton
+                | Role::MenuItem
+                | Role::Tab
+                | Role::TreeItem
+        )
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Role::Button => "Button",
+            Role::CheckBox => "CheckBox",
+            Role::Switch => "Switch",
+            Role::RadioButton => "RadioButton",
+            Role::TextInput => "TextInput",
+            Role::Slider => "Slider",
+            Role::ComboBox => "ComboBox",
+            Role::ScrollView => "ScrollView",
+            Role::Label => "Label",
+            Role::MenuBar => "MenuBar",
+            Role::Menu => "Menu",
+            Role::MenuItem => "MenuItem",
+            Role::TabList => "TabList",
+            Role::Tab => "Tab",
+            Role::TabPanel => "TabPanel",
+            Role::Tree => "Tree",
+            Role::TreeItem => "TreeItem",
+        })
+    }
+}
+
+/// The current value of a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    /// Some text.
+    Text(Cow<'a, str>),
+
+    /// Some text that must not be disclosed (e.g. a password).
+    Secure,
+
+    /// A number in a range.
+    Range {
+        /// The current value.
+        value: f64,
+        /// The minimum value.
+        min: f64,
+        /// The maximum value.
+        max: f64,
+        /// The step between values.
+        step: f64,
+    },
+}
+
+impl Value<'_> {
+    /// Turns the [`Value`] into an owned [`Value`].
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::Text(text) => Value::Text(Cow::Owned(text.into_owned())),
+            Value::Secure => Value::Secure,
+            Value::Range {
+                value,
+                min,
+                max,
+                step,
+            } => Value::Range {
+                value,
+                min,
+                max,
+                step,
+            },
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(text: &'a str) -> Self {
+        Value::Text(Cow::Borrowed(text))
+    }
+}
+
+impl From<String> for Value<'_> {
+    fn from(text: String) -> Self {
+        Value::Text(Cow::Owned(text))
+    }
+}
+
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(text) => write!(f, "{text:?}"),
+            Value::Secure => write!(f, "<secure>"),
+            Value::Range {
+                value, min, max, ..
+            } => write!(f, "{value} in {min}..={max}"),
+        }
+    }
+}
+
+/// A node of a semantic [`Tree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+
+    /// The absolute bounds of the widget.
+    pub bounds: Rectangle,
+
+    /// The [`Semantics`] of the widget.
+    pub semantics: Semantics<'static>,
+
+    /// The children of the [`Node`].
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Returns the label of the [`Node`].
+    ///
+    /// If the [`Node`] has no explicit label and its [`Role`] is named by
+    /// its contents (e.g. a [`Role::Button`]), the text of its descendants
+    /// is used instead.
+    pub fn label(&self) -> Option<Cow<'_, str>> {
+        if let Some(label) = &self.semantics.label {
+            return Some(Cow::Borrowed(label));
+        }
+
+        if !self.semantics.role.is_named_by_contents() {
+            return None;
+        }
+
+        let mut text = String::new();
+        self.collect_text(&mut text);
+
+        (!text.is_empty()).then_some(Cow::Owned(text))
+    }
+
+    /// Collects the text of the descendants of the [`Node`], skipping any
+    /// nested nodes named by their own contents; so every node is visited
+    /// by a single ancestor at most.
+    fn collect_text(&self, output: &mut String) {
+        for child in &self.children {
+            match (child.semantics.role, &child.semantics.label) {
+                (Role::Label, Some(label)) => {
+                    if !output.is_empty() {
+                        output.push(' ');
+                    }
+
+                    output.push_str(label);
+                }
+                (role, _) if role.is_named_by_contents() => {}
+                _ => child.collect_text(output),
+            }
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let semantics = &self.semantics;
+
+        write!(f, "{:indent$}{}", "", semantics.role, indent = depth * 2)?;
+
+        if let Some(label) = self.label() {
+            write!(f, " {label:?}")?;
+        }
+
+        if let Some(value) = &semantics.value {
+            write!(f, " = {value}")?;
+        }
+
+        let flags = [
+            (semantics.is_disabled, "disabled"),
+            (semantics.is_focused == Some(true), "focused"),
+            (semantics.is_toggled == Some(true), "toggled"),
+            (semantics.is_expanded == Some(true), "expanded"),
+            (semantics.is_selected == Some(true), "selected"),
+        ];
+
+        for (is_set, flag) in flags {
+            if is_set {
+                write!(f, " [{flag}]")?;
+            }
+        }
+
+        writeln!(f)?;
+
+        for child in &self.children {
+            child.fmt_indented(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A tree of semantic [`Node`]s describing a user interface.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tree {
+    /// The root [`Node`]s of the [`Tree`].
+    pub roots: Vec<Node>,
+}
+
+impl Tree {
+    /// Returns an iterator over all the [`Node`]s of the [`Tree`]
+    /// in depth-first order.
+    pub fn iter(&self) -> impl Iterator<Item = &Node> {
+        let mut stack: Vec<&Node> = self.roots.iter().rev().collect();
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+
+            Some(node)
+        })
+    }
+
+    /// Returns the first [`Node`] with the given [`Id`], if any.
+    pub fn find(&self, id: &Id) -> Option<&Node> {
+        self.iter().find(|node| node.id.as_ref() == Some(id))
+    }
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for root in &self.roots {
+            root.fmt_indented(f, 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Produces an [`Operation`] that builds the semantic [`Tree`] of
+/// the widgets it traverses.
+///
+/// Any text found outside of [`Semantics`] is described as a
+/// [`Role::Label`].
+pub fn tree() -> impl Operation<Tree> {
+    struct Builder {
+        levels: Vec<Vec<Node>>,
+        translations: Vec<Vector>,
+        translation: Vector,
+        /// Whether the next traversal contains the children of the last node.
+        ///
+        /// It is only set by [`Operation::semantics`] and it is cleared by any other
+        /// callback, except [`Operation::scrollable`] (which describes the same widget).
+        /// Otherwise, a leaf widget with semantics would adopt the children of its
+        /// next sibling.
+        is_open: bool,
+    }
+
+    impl Builder {
+        fn push(&mut self, id: Option<&Id>, bounds: Rectangle, semantics: Semantics<'static>) {
+            self.levels
+                .last_mut()
+                .expect("Builder must have a level")
+                .push(Node {
+                    id: id.cloned(),
+                    bounds: bounds + self.translation,
+                    semantics,
+                    children: Vec::new(),
+                });
+        }
+    }
+
+    impl Operation<Tree> for Builder {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Tree>)) {
+            let is_open = std::mem::take(&mut self.is_open);
+
+            if is_open {
+                self.levels.push(Vec::new());
+            }
+
+            self.translations.push(self.translation);
+            operate(self);
+            let _ = self.translations.pop();
+
+            self.translation = self.translations.last().copied().unwrap_or(Vector::ZERO);
+
+            if is_open {
+                let children = self.levels.pop().unwrap_or_default();
+
+                if let Some(parent) = self.levels.last_mut().and_then(|level| level.last_mut()) {
+                    parent.children.extend(children);
+                }
+            }
+
+            self.is_open = false;
+        }
+
+        fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle) {
+            self.is_open = false;
+        }
+
+        fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, _state: &mut dyn Focusable) {
+            self.is_open = false;
+        }
+
+        fn text_input(&mut self, _id: Option<&Id>, _bounds: Rectangle, _state: &mut dyn TextInput) {
+            self.is_open = false;
+        }
+
+        fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, _state: &mut dyn Any) {
+            self.is_open = false;
+        }
+
+        fn scrollable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            self.translation -= translation;
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.is_open = false;
+
+            self.push(
+                id,
+                bounds,
+                Semantics::new(Role::Label).label(text.to_owned()),
+            );
+        }
+
+        fn semantics(&mut self, id: Option<&Id>, bounds: Rectangle, semantics: &Semantics<'_>) {
+            self.push(id, bounds, semantics.clone().into_owned());
+            self.is_open = true;
+        }
+
+        fn finish(&self) -> Outcome<Tree> {
+            Outcome::Some(Tree {
+                roots: self.levels.first().cloned().unwrap_or_default(),
+            })
+        }
+    }
+
+    Builder {
+        levels: vec![Vec::new()],
+        translations: vec![Vector::ZERO],
+        translation: Vector::ZERO,
+        is_open: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Role, Semantics, tree};
+    use crate::widget::Operation;
+    use crate::widget::operation::Outcome;
+    use crate::{Rectangle, Size};
+
+    #[test]
+    fn tree_nests_children_of_semantic_widgets() {
+        let bounds = Rectangle::with_size(Size::new(100.0, 20.0));
+        let mut operation = tree();
+
+        operation.container(None, bounds);
+        operation.traverse(&mut |operation| {
+            operation.semantics(None, bounds, &Semantics::new(Role::Button));
+            operation.traverse(&mut |operation| {
+                operation.text(None, bounds, "Submit");
+            });
+
+            operation.semantics(None, bounds, &Semantics::new(Role::CheckBox).toggled(true));
+            operation.traverse(&mut |operation| {
+                operation.text(None, bounds, "Remember me");
+            });
+        });
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("semantic tree must be built");
+        };
+
+        assert_eq!(
+            tree.to_string(),
+            "Button \"Submit\"\n\
+             \x20 Label \"Submit\"\n\
+             CheckBox \"Remember me\" [toggled]\n\
+             \x20 Label \"Remember me\"\n"
+        );
+    }
+
+    #[test]
+    fn tree_does_not_nest_siblings_under_leaves() {
+        struct Input;
+
+        impl crate::widget::operation::Focusable for Input {
+            fn is_focused(&self) -> bool {
+                false
+            }
+
+            fn focus(&mut self) {}
+
+            fn unfocus(&mut self) {}
+        }
+
+        let bounds = Rectangle::with_size(Size::new(100.0, 20.0));
+        let mut operation = tree();
+
+        operation.semantics(None, bounds, &Semantics::new(Role::TextInput));
+        operation.focusable(None, bounds, &mut Input);
+        operation.traverse(&mut |operation| {
+            operation.text(None, bounds, "Overlay");
+        });
+
+        operation.semantics(None, bounds, &Semantics::new(Role::Slider));
+        operation.container(None, bounds);
+        operation.traverse(&mut |operation| {
+            operation.text(None, bounds, "Sibling");
+        });
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("semantic tree must be built");
+        };
+
+        assert_eq!(
+            tree.to_string(),
+            "TextInput\n\
+             Label \"Overlay\"\n\
+             Slider\n\
+             Label \"Sibling\"\n"
+        );
+    }
+}
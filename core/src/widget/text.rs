@@ -26,8 +26,9 @@ use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::text::paragraph::{self, Paragraph};
+use crate::text::selection::Selection;
 use crate::widget::tree::{self, Tree};
-use crate::{Color, Element, Layout, Length, Pixels, Rectangle, Size, Theme, Widget};
+use crate::{Color, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size, Theme, Widget};
 
 pub use text::{Alignment, Ellipsis, LineHeight, Shaping, Wrapping};
 
@@ -61,6 +62,7 @@ where
 {
     fragment: text::Fragment<'a>,
     format: Format<Renderer::Font>,
+    selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -74,6 +76,7 @@ where
         Text {
             fragment: fragment.into_fragment(),
             format: Format::default(),
+            selectable: false,
             class: Theme::default(),
         }
     }
@@ -154,6 +157,17 @@ where
         self
     }
 
+    /// Sets whether the contents of the [`Text`] can be selected and copied.
+    ///
+    /// A selectable [`Text`] is selected by dragging the mouse over it, or by
+    /// double or triple clicking to select a word or a line, respectively. The
+    /// selection may continue into any other selectable text in the same user
+    /// interface and is copied to the clipboard with `Ctrl+C`.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
     where
@@ -178,7 +192,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            selection: None,
+        })
     }
 
     /// Sets the style class of the [`Text`].
@@ -192,17 +209,24 @@ where
 /// The internal state of a [`Text`] widget.
 pub type State<P> = paragraph::Plain<P>;
 
+/// The internal state of a [`Text`] widget, including its [`Selection`].
+#[derive(Debug, Default)]
+struct Internal<P: Paragraph> {
+    paragraph: State<P>,
+    selection: Selection,
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Text<'_, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State<Renderer::Paragraph>>()
+        tree::Tag::of::<Internal<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(paragraph::Plain::<Renderer::Paragraph>::default())
+        tree::State::new(Internal::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -219,7 +243,10 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         layout(
-            tree.state.downcast_mut::<State<Renderer::Paragraph>>(),
+            &mut tree
+                .state
+                .downcast_mut::<Internal<Renderer::Paragraph>>()
+                .paragraph,
             renderer,
             limits,
             &self.fragment,
@@ -227,6 +254,47 @@ where
         )
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.selectable {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<Internal<Renderer::Paragraph>>();
+
+        state.selection.update(
+            event,
+            layout.bounds(),
+            cursor,
+            state.paragraph.raw(),
+            &self.fragment,
+            shell,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -237,9 +305,21 @@ where
         _cursor_position: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let Internal {
+            paragraph: state,
+            selection,
+        } = tree.state.downcast_ref::<Internal<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
+        if self.selectable {
+            selection.draw(
+                renderer,
+                state.raw(),
+                layout.bounds(),
+                style.selection_color(defaults.text_color),
+            );
+        }
+
         draw(
             renderer,
             defaults,
@@ -395,6 +475,19 @@ pub struct Style {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+    /// The [`Color`] of the highlight of selected text.
+    ///
+    /// The default, `None`, means using a translucent version of the text color.
+    pub selection: Option<Color>,
+}
+
+impl Style {
+    /// Returns the [`Color`] of the highlight of selected text, given
+    /// the inherited text color.
+    pub fn selection_color(&self, text_color: Color) -> Color {
+        self.selection
+            .unwrap_or_else(|| self.color.unwrap_or(text_color).scale_alpha(0.3))
+    }
 }
 
 /// The theme catalog of a [`Text`].
@@ -428,13 +521,17 @@ impl Catalog for Theme {
 
 /// The default text styling; color is inherited.
 pub fn default(_theme: &Theme) -> Style {
-    Style { color: None }
+    Style {
+        color: None,
+        selection: None,
+    }
 }
 
 /// Text with the default base color.
 pub fn base(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().text),
+        selection: None,
     }
 }
 
@@ -442,6 +539,7 @@ pub fn base(theme: &Theme) -> Style {
 pub fn primary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().primary),
+        selection: None,
     }
 }
 
@@ -449,6 +547,7 @@ pub fn primary(theme: &Theme) -> Style {
 pub fn secondary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().secondary.base.color),
+        selection: None,
     }
 }
 
@@ -456,6 +555,7 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().success),
+        selection: None,
     }
 }
 
@@ -463,6 +563,7 @@ pub fn success(theme: &Theme) -> Style {
 pub fn warning(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().warning),
+        selection: None,
     }
 }
 
@@ -470,5 +571,6 @@ pub fn warning(theme: &Theme) -> Style {
 pub fn danger(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().danger),
+        selection: None,
     }
 }
@@ -1,7 +1,9 @@
 use iced::gradient;
 use iced::theme;
-use iced::widget::{checkbox, column, container, row, slider, space, text};
-use iced::{Center, Color, Element, Fill, Radians, Theme, color};
+use iced::widget::{checkbox, column, container, pick_list, row, slider, space, text};
+use iced::{Center, Color, Element, Fill, Point, Radians, Theme, color};
+
+use std::fmt;
 
 pub fn main() -> iced::Result {
     tracing_subscriber::fmt::init();
@@ -14,6 +16,7 @@ pub fn main() -> iced::Result {
 
 #[derive(Debug, Clone, Copy)]
 struct Gradient {
+    kind: Kind,
     start: Color,
     end: Color,
     angle: Radians,
@@ -22,6 +25,7 @@ struct Gradient {
 
 #[derive(Debug, Clone, Copy)]
 enum Message {
+    KindSelected(Kind),
     StartChanged(Color),
     EndChanged(Color),
     AngleChanged(Radians),
@@ -31,6 +35,7 @@ enum Message {
 impl Gradient {
     fn new() -> Self {
         Self {
+            kind: Kind::Linear,
             start: Color::WHITE,
             end: color!(0x0000ff),
             angle: Radians(0.0),
@@ -40,6 +45,7 @@ impl Gradient {
 
     fn update(&mut self, message: Message) {
         match message {
+            Message::KindSelected(kind) => self.kind = kind,
             Message::StartChanged(color) => self.start = color,
             Message::EndChanged(color) => self.end = color,
             Message::AngleChanged(angle) => self.angle = angle,
@@ -51,6 +57,7 @@ impl Gradient {
 
     fn view(&self) -> Element<'_, Message> {
         let Self {
+            kind,
             start,
             end,
             angle,
@@ -59,15 +66,38 @@ impl Gradient {
 
         let gradient_box = container(space())
             .style(move |_theme| {
-                let gradient = gradient::Linear::new(angle)
-                    .add_stop(0.0, start)
-                    .add_stop(1.0, end);
+                let gradient: gradient::Gradient = match kind {
+                    Kind::Linear => gradient::Linear::new(angle)
+                        .add_stop(0.0, start)
+                        .add_stop(1.0, end)
+                        .into(),
+                    Kind::Radial => gradient::Radial::circle()
+                        .focal(Point::new(
+                            0.5 + angle.0.sin() * 0.25,
+                            0.5 - angle.0.cos() * 0.25,
+                        ))
+                        .add_stop(0.0, start)
+                        .add_stop(1.0, end)
+                        .into(),
+                    Kind::Conic => gradient::Conic::new(Point::new(0.5, 0.5), angle)
+                        .add_stop(0.0, start)
+                        .add_stop(1.0, end)
+                        .into(),
+                };
 
                 gradient.into()
             })
             .width(Fill)
             .height(Fill);
 
+        let kind_picker = row![
+            text("Kind").width(64),
+            pick_list(Some(kind), Kind::ALL, Kind::to_string).on_select(Message::KindSelected)
+        ]
+        .spacing(8)
+        .padding(8)
+        .align_y(Center);
+
         let angle_picker = row![
             text("Angle").width(64),
             slider(Radians::RANGE, self.angle, Message::AngleChanged).step(0.01)
@@ -84,6 +114,7 @@ impl Gradient {
         .padding(8);
 
         column![
+            kind_picker,
             color_picker("Start", self.start).map(Message::StartChanged),
             color_picker("End", self.end).map(Message::EndChanged),
             angle_picker,
@@ -111,6 +142,27 @@ impl Default for Gradient {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Linear,
+    Radial,
+    Conic,
+}
+
+impl Kind {
+    const ALL: [Kind; 3] = [Kind::Linear, Kind::Radial, Kind::Conic];
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Kind::Linear => "Linear",
+            Kind::Radial => "Radial",
+            Kind::Conic => "Conic",
+        })
+    }
+}
+
 fn color_picker(label: &str, color: Color) -> Element<'_, Color> {
     row![
         text(label).width(64),
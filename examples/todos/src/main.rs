@@ -457,6 +457,7 @@ fn delete_icon() -> Text<'static> {
 fn subtle(theme: &Theme) -> text::Style {
     text::Style {
         color: Some(theme.palette().background.strongest.color),
+        selection: None,
     }
 }
 
@@ -1,7 +1,16 @@
 //! Listen to keyboard events.
+mod registry;
+
+pub use registry::{Binding, Scope, Shortcuts};
+
 use crate::core;
 use crate::core::keyboard::Event;
-use crate::subscription::{self, Subscription};
+use crate::core::keyboard::shortcut::Stroke;
+use crate::core::window;
+use crate::subscription::{self, Hasher, Subscription};
+use crate::{BoxStream, MaybeSend};
+
+use std::hash::Hash;
 
 /// Returns a [`Subscription`] that listens to ignored keyboard events.
 pub fn listen() -> Subscription<Event> {
@@ -17,3 +26,71 @@ pub fn listen() -> Subscription<Event> {
         _ => None,
     })
 }
+
+/// Returns a [`Subscription`] that produces the commands of the given
+/// [`Shortcuts`] when their shortcuts are pressed.
+///
+/// Key presses captured by a widget (e.g. while typing in a text input) are
+/// ignored and cancel any pending chord, just like a window losing focus.
+pub fn shortcuts<T>(shortcuts: Shortcuts<T>) -> Subscription<T>
+where
+    T: Hash + Clone + MaybeSend + 'static,
+{
+    struct Listen<T>(Shortcuts<T>);
+
+    impl<T> subscription::Recipe for Listen<T>
+    where
+        T: Hash + Clone + MaybeSend + 'static,
+    {
+        type Output = T;
+
+        fn hash(&self, state: &mut Hasher) {
+            std::any::TypeId::of::<Self>().hash(state);
+            self.0.hash(state);
+        }
+
+        fn stream(self: Box<Self>, input: subscription::EventStream) -> BoxStream<T> {
+            use futures::future;
+            use futures::stream::StreamExt;
+
+            let shortcuts = self.0;
+
+            crate::boxed_stream(input.filter_map(move |event| {
+                let command = match event {
+                    subscription::Event::Interaction {
+                        window,
+                        event:
+                            core::Event::Keyboard(Event::KeyPressed {
+                                key,
+                                physical_key,
+                                modifiers,
+                                ..
+                            }),
+                        status,
+                    } => match Stroke::from_key_press(&key, physical_key, modifiers) {
+                        Some(_) if status == core::event::Status::Captured => {
+                            shortcuts.cancel(window);
+                            None
+                        }
+                        Some(stroke) => shortcuts.press(window, stroke),
+                        None => None,
+                    },
+                    subscription::Event::Interaction {
+                        window,
+                        event:
+                            core::Event::Window(window::Event::Unfocused | window::Event::Closed),
+                        ..
+                    } => {
+                        shortcuts.cancel(window);
+                        None
+                    }
+                    _ => None,
+                };
+
+                future::ready(command)
+            }))
+        }
+    }
+
+    subscription::from_recipe(Listen(shortcuts))
+}
@@ -0,0 +1,357 @@
+use crate::core::keyboard::Shortcut;
+use crate::core::keyboard::shortcut::Stroke;
+use crate::core::widget;
+use crate::core::window;
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+/// A set of keyboard [`Shortcut`]s bound to commands.
+///
+/// Listen to the bindings of a [`Shortcuts`] registry with
+/// [`shortcuts`](super::shortcuts).
+///
+/// When multiple active bindings share a [`Shortcut`], the one with the most
+/// specific [`Scope`] wins; ties are won by the first binding registered.
+/// While a sequence of strokes is the prefix of an active chord, any binding
+/// matching the sequence itself is postponed in favor of the chord.
+///
+/// The pending strokes of a chord are tracked per window and shared by the
+/// clones of a [`Shortcuts`] registry. Keep the registry in your state and
+/// clone it to change its [`focused`](Self::focused) widget, so any chord in
+/// progress survives focus changes.
+#[derive(Debug, Clone)]
+pub struct Shortcuts<T> {
+    bindings: Vec<Binding<T>>,
+    focused: Option<widget::Id>,
+    pending: Arc<Mutex<HashMap<window::Id, Vec<Stroke>>>>,
+}
+
+/// A [`Shortcut`] bound to a command in some [`Scope`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Binding<T> {
+    /// The [`Shortcut`] of the [`Binding`].
+    pub shortcut: Shortcut,
+    /// The command produced when the [`Shortcut`] is pressed.
+    pub command: T,
+    /// The [`Scope`] where the [`Binding`] is active.
+    pub scope: Scope,
+    /// The description of the [`Binding`], if any.
+    ///
+    /// This can be displayed to users (e.g. in a command palette).
+    pub description: Option<String>,
+}
+
+impl<T> Binding<T> {
+    /// Creates a new global [`Binding`] of the given [`Shortcut`] and command.
+    pub fn new(shortcut: Shortcut, command: T) -> Self {
+        Self {
+            shortcut,
+            command,
+            scope: Scope::Global,
+            description: None,
+        }
+    }
+
+    /// Sets the [`Scope`] of the [`Binding`].
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Sets the description of the [`Binding`].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Where a [`Binding`] is active.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Scope {
+    /// The [`Binding`] is active everywhere.
+    #[default]
+    Global,
+    /// The [`Binding`] is only active in the window with the given [`window::Id`].
+    Window(window::Id),
+    /// The [`Binding`] is only active while the widget with the given
+    /// [`widget::Id`] is focused.
+    ///
+    /// See [`Shortcuts::focused`].
+    Focus(widget::Id),
+}
+
+impl Scope {
+    fn precedence(&self) -> u8 {
+        match self {
+            Scope::Global => 0,
+            Scope::Window(_) => 1,
+            Scope::Focus(_) => 2,
+        }
+    }
+}
+
+impl<T> Shortcuts<T> {
+    /// Creates an empty [`Shortcuts`] registry.
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            focused: None,
+            pending: Arc::default(),
+        }
+    }
+
+    /// Binds a [`Shortcut`] to the given command globally.
+    pub fn bind(self, shortcut: Shortcut, command: T) -> Self {
+        self.push(Binding::new(shortcut, command))
+    }
+
+    /// Adds a [`Binding`] to the [`Shortcuts`].
+    pub fn push(mut self, binding: Binding<T>) -> Self {
+        self.bindings.push(binding);
+        self
+    }
+
+    /// Sets the [`widget::Id`] of the focused widget, activating the
+    /// bindings with a matching [`Scope::Focus`].
+    ///
+    /// Widget focus can be queried with the `find_focused` widget
+    /// operation of the runtime.
+    pub fn focused(mut self, focused: Option<widget::Id>) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns all the [`Binding`]s of the [`Shortcuts`].
+    pub fn bindings(&self) -> &[Binding<T>] {
+        &self.bindings
+    }
+
+    /// Returns the [`Binding`]s that would be triggered by their [`Shortcut`]
+    /// in the given window, excluding any bindings overridden by others.
+    pub fn active(&self, window: Option<window::Id>) -> impl Iterator<Item = &Binding<T>> {
+        self.bindings
+            .iter()
+            .enumerate()
+            .filter(move |(_, binding)| self.applies(binding, window))
+            .filter(move |(i, binding)| {
+                !self.bindings.iter().enumerate().any(|(j, other)| {
+                    j != *i
+                        && other.shortcut == binding.shortcut
+                        && self.applies(other, window)
+                        && (other.scope.precedence(), *i) > (binding.scope.precedence(), j)
+                })
+            })
+            .map(|(_, binding)| binding)
+    }
+
+    /// Feeds a [`Stroke`] pressed in the given window to its pending strokes,
+    /// returning the command of the completed [`Shortcut`], if any.
+    pub(crate) fn press(&self, window: window::Id, stroke: Stroke) -> Option<T>
+    where
+        T: Clone,
+    {
+        let mut pending = self.pending.lock().expect("Lock pending strokes");
+
+        self.feed(pending.entry(window).or_default(), window, stroke)
+            .cloned()
+    }
+
+    /// Cancels any chord in progress in the given window.
+    pub(crate) fn cancel(&self, window: window::Id) {
+        let _ = self
+            .pending
+            .lock()
+            .expect("Lock pending strokes")
+            .remove(&window);
+    }
+
+    /// Feeds a [`Stroke`] pressed in the given window to the pending strokes,
+    /// returning the command of the completed [`Shortcut`], if any.
+    fn feed(&self, pending: &mut Vec<Stroke>, window: window::Id, stroke: Stroke) -> Option<&T> {
+        pending.push(stroke);
+
+        loop {
+            let mut active = self.active(Some(window));
+
+            if active.any(|binding| {
+                let strokes = binding.shortcut.strokes();
+
+                strokes.len() > pending.len() && strokes.starts_with(pending)
+            }) {
+                return None;
+            }
+
+            if let Some(binding) = self
+                .active(Some(window))
+                .find(|binding| binding.shortcut.strokes() == pending.as_slice())
+            {
+                pending.clear();
+
+                return Some(&binding.command);
+            }
+
+            // A broken chord may still start a new shortcut with its last stroke
+            if pending.len() > 1 {
+                let _ = pending.drain(..pending.len() - 1);
+            } else {
+                pending.clear();
+
+                return None;
+            }
+        }
+    }
+
+    fn applies(&self, binding: &Binding<T>, window: Option<window::Id>) -> bool {
+        match &binding.scope {
+            Scope::Global => true,
+            Scope::Window(id) => window == Some(*id),
+            Scope::Focus(id) => self.focused.as_ref() == Some(id),
+        }
+    }
+}
+
+impl<T> Default for Shortcuts<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Shortcuts<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bindings == other.bindings && self.focused == other.focused
+    }
+}
+
+impl<T: Eq> Eq for Shortcuts<T> {}
+
+impl<T: Hash> Hash for Shortcuts<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bindings.hash(state);
+        self.focused.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keyboard::{Key, Modifiers};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Command {
+        Save,
+        SaveAll,
+        Close,
+        Rename,
+        Kill,
+    }
+
+    fn press(key: &str, modifiers: Modifiers) -> Stroke {
+        Stroke::new(Key::Character(key.into()), modifiers)
+    }
+
+    fn shortcut(shortcut: &str) -> Shortcut {
+        shortcut.parse().expect("Shortcut must be valid")
+    }
+
+    fn shortcuts() -> Shortcuts<Command> {
+        Shortcuts::new()
+            .bind(shortcut("Ctrl+S"), Command::Save)
+            .bind(shortcut("Ctrl+K Ctrl+S"), Command::SaveAll)
+            .bind(shortcut("Ctrl+K"), Command::Kill)
+            .push(
+                Binding::new(shortcut("Ctrl+W"), Command::Close)
+                    .scope(Scope::Window(window::Id::unique())),
+            )
+            .push(
+                Binding::new(shortcut("Ctrl+S"), Command::Rename)
+                    .scope(Scope::Focus(widget::Id::new("tree")))
+                    .description("Rename the selected file"),
+            )
+    }
+
+    #[test]
+    fn chords_wait_for_their_strokes() {
+        let shortcuts = shortcuts();
+        let window = window::Id::unique();
+        let mut pending = Vec::new();
+
+        let mut feed = |key| {
+            shortcuts
+                .feed(&mut pending, window, press(key, Modifiers::CTRL))
+                .copied()
+        };
+
+        assert_eq!(feed("s"), Some(Command::Save));
+        assert_eq!(feed("k"), None);
+        assert_eq!(feed("s"), Some(Command::SaveAll));
+
+        // A broken chord starts over with its last stroke
+        assert_eq!(feed("k"), None);
+        assert_eq!(feed("x"), None);
+        assert_eq!(feed("s"), Some(Command::Save));
+    }
+
+    #[test]
+    fn specific_scopes_override_global_ones() {
+        let shortcuts = shortcuts().focused(Some(widget::Id::new("tree")));
+        let window = window::Id::unique();
+        let mut pending = Vec::new();
+
+        assert_eq!(
+            shortcuts.feed(&mut pending, window, press("s", Modifiers::CTRL)),
+            Some(&Command::Rename)
+        );
+
+        let active: Vec<_> = shortcuts
+            .active(Some(window))
+            .map(|binding| binding.command)
+            .collect();
+
+        assert_eq!(
+            active,
+            vec![Command::SaveAll, Command::Kill, Command::Rename]
+        );
+    }
+
+    #[test]
+    fn chords_are_pending_per_window() {
+        let shortcuts = shortcuts();
+        let (editor, preview) = (window::Id::unique(), window::Id::unique());
+
+        assert_eq!(shortcuts.press(editor, press("k", Modifiers::CTRL)), None);
+        assert_eq!(
+            shortcuts.press(preview, press("s", Modifiers::CTRL)),
+            Some(Command::Save)
+        );
+        assert_eq!(
+            shortcuts.press(editor, press("s", Modifiers::CTRL)),
+            Some(Command::SaveAll)
+        );
+
+        assert_eq!(shortcuts.press(editor, press("k", Modifiers::CTRL)), None);
+        shortcuts.cancel(editor);
+
+        assert_eq!(
+            shortcuts.press(editor, press("s", Modifiers::CTRL)),
+            Some(Command::Save)
+        );
+    }
+
+    #[test]
+    fn chords_survive_focus_changes() {
+        let shortcuts = shortcuts();
+        let window = window::Id::unique();
+
+        assert_eq!(shortcuts.press(window, press("k", Modifiers::CTRL)), None);
+
+        let focused = shortcuts.clone().focused(Some(widget::Id::new("tree")));
+
+        assert_ne!(focused, shortcuts);
+        assert_eq!(
+            focused.press(window, press("s", Modifiers::CTRL)),
+            Some(Command::SaveAll)
+        );
+    }
+}
@@ -3,20 +3,26 @@
 //! For a gradient that you can use as a background variant for a widget, see [`Gradient`].
 use crate::color;
 use crate::core::gradient::ColorStop;
-use crate::core::{self, Color, Point, Rectangle};
+use crate::core::{self, Color, Point, Radians, Rectangle, Size};
 
 use bytemuck::{Pod, Zeroable};
 use half::f16;
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction, outwards from a point, or around a point.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from its `focal` point to the ellipse
+    /// defined by its `center` and `radii`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors clockwise around its `center`, starting at its
+    /// `angle`.
+    Conic(Conic),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +31,25 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 impl Gradient {
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
         match self {
             Gradient::Linear(linear) => linear.pack(),
+            Gradient::Radial(radial) => radial.pack(),
+            Gradient::Conic(conic) => conic.pack(),
         }
     }
 }
@@ -63,18 +83,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) = self.stops.binary_search_by(|stop| match stop {
-                None => Ordering::Greater,
-                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-            });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -92,37 +101,149 @@ impl Linear {
 
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
-        let mut colors = [[0u32; 2]; 8];
-        let mut offsets = [f16::from(0u8); 8];
+        Packed::linear(&self.stops, self.start, self.end)
+    }
+}
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute center of the ending ellipse of the gradient.
+    pub center: Point,
+
+    /// The horizontal and vertical radii of the ending ellipse of the gradient.
+    pub radii: Size,
 
-        for (index, stop) in self.stops.iter().enumerate() {
-            let [r, g, b, a] = color::pack(stop.map_or(Color::default(), |s| s.color)).components();
+    /// The absolute position where the gradient starts.
+    ///
+    /// It should be inside the ending ellipse.
+    pub focal: Point,
 
-            colors[index] = [
-                pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-            ];
+    /// [`ColorStop`]s along the radial gradient path.
+    pub stops: [Option<ColorStop>; 8],
+}
 
-            offsets[index] = stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+impl Radial {
+    /// Creates a new [`Radial`] builder for an ellipse with the given center
+    /// and radii.
+    ///
+    /// The focal point of the gradient will be its center.
+    pub fn new(center: Point, radii: Size) -> Self {
+        Self {
+            center,
+            radii,
+            focal: center,
+            stops: [None; 8],
         }
+    }
 
-        let offsets = [
-            pack_f16s([offsets[0], offsets[1]]),
-            pack_f16s([offsets[2], offsets[3]]),
-            pack_f16s([offsets[4], offsets[5]]),
-            pack_f16s([offsets[6], offsets[7]]),
-        ];
+    /// Creates a new [`Radial`] builder for a circle with the given center
+    /// and radius.
+    pub fn circle(center: Point, radius: f32) -> Self {
+        Self::new(center, Size::new(radius, radius))
+    }
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
+    /// Sets the absolute focal point of the [`Radial`] gradient.
+    pub fn focal(mut self, focal: Point) -> Self {
+        self.focal = focal;
+        self
+    }
 
-        Packed {
-            colors,
-            offsets,
-            direction,
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
         }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::radial(&self.stops, self.center, self.radii, self.focal)
     }
 }
 
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The angle where the gradient starts, pointing upwards at `0` radians.
+    pub angle: Radians,
+
+    /// [`ColorStop`]s around the conic gradient path.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] builder around the given center and starting
+    /// at the given angle.
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::conic(&self.stops, self.center, self.angle)
+    }
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) = stops.binary_search_by(|stop| match stop {
+            None => Ordering::Greater,
+            Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+        });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
+    };
+}
+
 /// Packed [`Gradient`] data for use in shader code.
 #[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
 #[repr(C)]
@@ -131,46 +252,94 @@ pub struct Packed {
     colors: [[u32; 2]; 8],
     // 8 offsets, 8x 16 bit floats packed into 4 u32s
     offsets: [u32; 4],
+    // linear: start & end
+    // radial: center & focal
+    // conic: center
     direction: [f32; 4],
+    // kind (0 = linear, 1 = radial, 2 = conic) followed by
+    // radial: radii
+    // conic: angle
+    parameters: [f32; 4],
+}
+
+impl Packed {
+    fn linear(stops: &[Option<ColorStop>; 8], start: Point, end: Point) -> Self {
+        let (colors, offsets) = pack_stops(stops);
+
+        Self {
+            colors,
+            offsets,
+            direction: [start.x, start.y, end.x, end.y],
+            parameters: [0.0; 4],
+        }
+    }
+
+    fn radial(stops: &[Option<ColorStop>; 8], center: Point, radii: Size, focal: Point) -> Self {
+        let (colors, offsets) = pack_stops(stops);
+
+        Self {
+            colors,
+            offsets,
+            direction: [center.x, center.y, focal.x, focal.y],
+            parameters: [1.0, radii.width, radii.height, 0.0],
+        }
+    }
+
+    fn conic(stops: &[Option<ColorStop>; 8], center: Point, angle: Radians) -> Self {
+        let (colors, offsets) = pack_stops(stops);
+
+        Self {
+            colors,
+            offsets,
+            direction: [center.x, center.y, 0.0, 0.0],
+            parameters: [2.0, angle.0, 0.0, 0.0],
+        }
+    }
 }
 
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
-
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color)).components();
+            let (start, end) = linear.angle.to_distance(&bounds);
 
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
+            Packed::linear(&linear.stops, start, end)
+        }
+        core::Gradient::Radial(radial) => {
+            let (center, radii, focal) = radial.to_absolute(&bounds);
 
-                offsets[index] = stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
+            Packed::radial(&radial.stops, center, radii, focal)
+        }
+        core::Gradient::Conic(conic) => {
+            Packed::conic(&conic.stops, conic.to_absolute(&bounds), conic.angle)
+        }
+    }
+}
 
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
+/// Packs the colors and offsets of some [`ColorStop`]s.
+fn pack_stops(stops: &[Option<ColorStop>; 8]) -> ([[u32; 2]; 8], [u32; 4]) {
+    let mut colors = [[0u32; 2]; 8];
+    let mut offsets = [f16::from(0u8); 8];
 
-            let (start, end) = linear.angle.to_distance(&bounds);
+    for (index, stop) in stops.iter().enumerate() {
+        let [r, g, b, a] = color::pack(stop.map_or(Color::default(), |s| s.color)).components();
 
-            let direction = [start.x, start.y, end.x, end.y];
+        colors[index] = [
+            pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
+            pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
+        ];
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
-        }
+        offsets[index] = stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
     }
+
+    let offsets = [
+        pack_f16s([offsets[0], offsets[1]]),
+        pack_f16s([offsets[2], offsets[3]]),
+        pack_f16s([offsets[4], offsets[5]]),
+        pack_f16s([offsets[6], offsets[7]]),
+    ];
+
+    (colors, offsets)
 }
 
 /// Packs two f16s into one u32.
@@ -180,3 +349,111 @@ fn pack_f16s(f: [f16; 2]) -> u32 {
 
     one | two
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+    /// A CPU mirror of `gradient_offset` in `wgpu/src/shader/gradient.wgsl`.
+    fn offset(packed: &Packed, position: Point) -> f32 {
+        let [x, y, z, w] = packed.direction;
+        let [kind, p1, p2, _] = packed.parameters;
+
+        match kind as u32 {
+            1 => {
+                let point = [(position.x - x) / p1, (position.y - y) / p2];
+                let focal = [(z - x) / p1, (w - y) / p2];
+
+                let ray = [point[0] - focal[0], point[1] - focal[1]];
+                let distance = ray[0].hypot(ray[1]);
+
+                if distance == 0.0 {
+                    return 0.0;
+                }
+
+                let unit = [ray[0] / distance, ray[1] / distance];
+                let projection = focal[0] * unit[0] + focal[1] * unit[1];
+                let reach = (projection * projection - (focal[0] * focal[0] + focal[1] * focal[1])
+                    + 1.0)
+                    .max(0.0)
+                    .sqrt()
+                    - projection;
+
+                distance / reach
+            }
+            2 => {
+                let angle = (position.y - y).atan2(position.x - x) + FRAC_PI_2 - p1;
+
+                (angle / TAU).rem_euclid(1.0)
+            }
+            _ => {
+                let v1 = [z - x, w - y];
+                let v2 = [position.x - x, position.y - y];
+                let length = v1[0].hypot(v1[1]);
+
+                (v1[0] * v2[0] + v1[1] * v2[1]) / length / length
+            }
+        }
+    }
+
+    fn assert_near(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    #[test]
+    fn packed_layout() {
+        assert_eq!(std::mem::size_of::<Packed>(), 112);
+        assert_eq!(std::mem::align_of::<Packed>(), 4);
+    }
+
+    #[test]
+    fn packed_kind() {
+        let linear = Linear::new(Point::ORIGIN, Point::new(1.0, 0.0)).pack();
+        let radial = Radial::new(Point::new(1.0, 2.0), Size::new(3.0, 4.0))
+            .focal(Point::new(5.0, 6.0))
+            .pack();
+        let conic = Conic::new(Point::new(1.0, 2.0), Radians(PI)).pack();
+
+        assert_eq!(linear.parameters[0], 0.0);
+        assert_eq!(radial.direction, [1.0, 2.0, 5.0, 6.0]);
+        assert_eq!(radial.parameters, [1.0, 3.0, 4.0, 0.0]);
+        assert_eq!(conic.direction, [1.0, 2.0, 0.0, 0.0]);
+        assert_eq!(conic.parameters, [2.0, PI, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn radial_offset() {
+        let centered = Radial::new(Point::new(50.0, 50.0), Size::new(50.0, 25.0)).pack();
+
+        assert_near(offset(&centered, Point::new(50.0, 50.0)), 0.0);
+        assert_near(offset(&centered, Point::new(75.0, 50.0)), 0.5);
+        assert_near(offset(&centered, Point::new(100.0, 50.0)), 1.0);
+        assert_near(offset(&centered, Point::new(50.0, 75.0)), 1.0);
+
+        let focal = Radial::new(Point::new(50.0, 50.0), Size::new(50.0, 50.0))
+            .focal(Point::new(25.0, 50.0))
+            .pack();
+
+        assert_near(offset(&focal, Point::new(25.0, 50.0)), 0.0);
+        assert_near(offset(&focal, Point::new(0.0, 50.0)), 1.0);
+        assert_near(offset(&focal, Point::new(100.0, 50.0)), 1.0);
+        assert_near(offset(&focal, Point::new(50.0, 50.0)), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn conic_offset() {
+        let conic = Conic::new(Point::ORIGIN, Radians(0.0)).pack();
+
+        assert_near(offset(&conic, Point::new(0.0, -1.0)), 0.0);
+        assert_near(offset(&conic, Point::new(1.0, 0.0)), 0.25);
+        assert_near(offset(&conic, Point::new(0.0, 1.0)), 0.5);
+        assert_near(offset(&conic, Point::new(-1.0, 0.0)), 0.75);
+
+        let rotated = Conic::new(Point::ORIGIN, Radians(FRAC_PI_2)).pack();
+
+        assert_near(offset(&rotated, Point::new(1.0, 0.0)), 0.0);
+        assert_near(offset(&rotated, Point::new(0.0, -1.0)), 0.75);
+    }
+}
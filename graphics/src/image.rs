@@ -152,6 +152,99 @@ pub fn load(handle: &image::Handle) -> Result<Buffer, image::Error> {
     }
 }
 
+#[cfg(feature = "image")]
+/// Tries to decode the [`Frames`] of an animated image by its [`Handle`].
+///
+/// GIF, APNG, and WebP animations are supported when the `animated-image`
+/// feature is enabled. Any other image produces a single frame with the
+/// original [`Handle`].
+///
+/// [`Frames`]: image::Frames
+/// [`Handle`]: image::Handle
+pub fn load_frames(handle: &image::Handle) -> Result<image::Frames, image::Error> {
+    #[cfg(feature = "animated-image")]
+    {
+        let bytes = match handle {
+            image::Handle::Path(_, path) => Bytes::from(
+                std::fs::read(path)
+                    .map_err(|error| image::Error::Inaccessible(std::sync::Arc::new(error)))?,
+            ),
+            image::Handle::Bytes(_, bytes) => bytes.clone(),
+            image::Handle::Rgba { .. } => {
+                return Ok(image::Frames::still(handle.clone()));
+            }
+        };
+
+        if let Some(frames) = decode_frames(&bytes).map_err(to_error)? {
+            return Ok(frames);
+        }
+    }
+
+    Ok(image::Frames::still(handle.clone()))
+}
+
+#[cfg(feature = "animated-image")]
+fn decode_frames(bytes: &[u8]) -> Result<Option<image::Frames>, ::image::ImageError> {
+    use ::image::AnimationDecoder;
+    use ::image::codecs::{gif, png, webp};
+    use std::io::Cursor;
+
+    /// Browsers display frames with tiny delays for longer, since many
+    /// animations in the wild rely on it.
+    const MIN_DELAY: std::time::Duration = std::time::Duration::from_millis(20);
+    const DEFAULT_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
+
+    let frames = match ::image::guess_format(bytes) {
+        Ok(::image::ImageFormat::Gif) => gif::GifDecoder::new(Cursor::new(bytes))?.into_frames(),
+        Ok(::image::ImageFormat::Png) => {
+            let decoder = png::PngDecoder::new(Cursor::new(bytes))?;
+
+            if !decoder.is_apng()? {
+                return Ok(None);
+            }
+
+            decoder.apng()?.into_frames()
+        }
+        Ok(::image::ImageFormat::WebP) => {
+            let decoder = webp::WebPDecoder::new(Cursor::new(bytes))?;
+
+            if !decoder.has_animation() {
+                return Ok(None);
+            }
+
+            decoder.into_frames()
+        }
+        _ => return Ok(None),
+    };
+
+    let frames = frames
+        .map(|frame| {
+            let frame = frame?;
+            let delay = std::time::Duration::from(frame.delay());
+            let buffer = frame.into_buffer();
+
+            Ok(image::Frame {
+                handle: image::Handle::from_rgba(
+                    buffer.width(),
+                    buffer.height(),
+                    buffer.into_raw(),
+                ),
+                delay: if delay < MIN_DELAY {
+                    DEFAULT_DELAY
+                } else {
+                    delay
+                },
+            })
+        })
+        .collect::<Result<Vec<_>, ::image::ImageError>>()?;
+
+    if frames.len() < 2 {
+        return Ok(None);
+    }
+
+    Ok(Some(image::Frames::new(frames)))
+}
+
 #[cfg(feature = "image")]
 fn to_error(error: ::image::ImageError) -> image::Error {
     use std::sync::Arc;
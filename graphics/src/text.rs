@@ -384,6 +384,13 @@ pub fn to_color(color: Color) -> cosmic_text::Color {
     cosmic_text::Color::rgba(r, g, b, a)
 }
 
+/// Converts some [`cosmic_text::Color`] to a [`Color`].
+pub fn from_color(color: cosmic_text::Color) -> Color {
+    let [r, g, b, a] = color.as_rgba();
+
+    Color::from_rgba8(r, g, b, a as f32 / 255.0)
+}
+
 /// Returns the ideal hint factor given the size and scale factor of some text.
 pub fn hint_factor(_size: Pixels, _scale_factor: Option<f32>) -> Option<f32> {
     // TODO: Fix hinting in `cosmic-text`
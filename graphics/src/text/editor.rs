@@ -4,13 +4,14 @@ use crate::core::text::editor::{
 };
 use crate::core::text::highlighter::{self, Highlighter};
 use crate::core::text::{LineHeight, Wrapping};
-use crate::core::{Font, Pixels, Point, Rectangle, Size};
+use crate::core::{Color, Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use cosmic_text::Edit as _;
 
 use std::borrow::Cow;
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc, RwLock};
 
 /// A multi-line text editor.
@@ -19,10 +20,16 @@ pub struct Editor(Option<Arc<Internal>>);
 
 struct Internal {
     editor: cosmic_text::Editor<'static>,
+    carets: Vec<Caret>,
+    folds: Vec<Range<usize>>,
+    decorations: Vec<Decoration>,
+    formats: Vec<Vec<(Range<usize>, highlighter::Format<Font>)>>,
+    formats_changed: Vec<usize>,
     selection: RwLock<Option<Selection>>,
     font: Font,
     bounds: Size,
     topmost_line_changed: Option<usize>,
+    line_edits: Vec<(Range<usize>, usize)>,
     hint: bool,
     hint_factor: f32,
     version: text::Version,
@@ -39,6 +46,89 @@ impl Editor {
         buffer_from_editor(&self.internal().editor)
     }
 
+    /// Returns the backgrounds, underlines, and strikethroughs of the visible
+    /// text of the [`Editor`] as colored regions, relative to its position.
+    ///
+    /// Decorations of text without a color take the given one.
+    pub fn decorations(&self, color: Color) -> Vec<(Rectangle, Color)> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let mut regions = Vec::new();
+
+        if internal.decorations.is_empty() {
+            return regions;
+        }
+
+        let scale = 1.0 / internal.hint_factor;
+        let thickness = internal.hint_factor;
+
+        for run in buffer.layout_runs() {
+            for glyphs in run.glyphs.chunk_by(|a, b| a.metadata == b.metadata) {
+                let Some(decoration) = glyphs[0]
+                    .metadata
+                    .checked_sub(1)
+                    .and_then(|index| internal.decorations.get(index))
+                else {
+                    continue;
+                };
+
+                let left = glyphs
+                    .iter()
+                    .map(|glyph| glyph.x)
+                    .fold(f32::INFINITY, f32::min);
+
+                let right = glyphs
+                    .iter()
+                    .map(|glyph| glyph.x + glyph.w)
+                    .fold(f32::NEG_INFINITY, f32::max);
+
+                let font_size = glyphs[0].font_size;
+                let text_color = glyphs[0].color_opt.map_or(color, text::from_color);
+
+                if let Some(background) = decoration.background {
+                    regions.push((
+                        Rectangle {
+                            x: left,
+                            y: run.line_top,
+                            width: right - left,
+                            height: run.line_height,
+                        } * scale,
+                        background,
+                    ));
+                }
+
+                if let Some(underline) = decoration.underline {
+                    let color = decoration.underline_color.unwrap_or(text_color);
+
+                    regions.extend(
+                        underline_segments(
+                            underline,
+                            left..right,
+                            run.line_y + font_size * 0.1,
+                            thickness,
+                        )
+                        .map(|segment| (segment * scale, color)),
+                    );
+                }
+
+                if decoration.strikethrough {
+                    regions.push((
+                        Rectangle {
+                            x: left,
+                            y: run.line_y - font_size * 0.3 - thickness / 2.0,
+                            width: right - left,
+                            height: thickness,
+                        } * scale,
+                        text_color,
+                    ));
+                }
+            }
+        }
+
+        regions
+    }
+
     /// Creates a [`Weak`] reference to the [`Editor`].
     ///
     /// This is useful to avoid cloning the [`Editor`] when
@@ -79,6 +169,372 @@ impl Editor {
 
         result
     }
+
+    fn perform_all(&mut self, action: Action) {
+        let (carets, primary) = self.with_internal_mut(|internal| {
+            let primary = Caret::of(&internal.editor);
+
+            let mut carets = std::mem::take(&mut internal.carets);
+            carets.push(primary);
+            carets.sort_by_key(|caret| key(caret.start()));
+
+            let buffer = buffer_from_editor(&internal.editor);
+
+            (
+                carets
+                    .into_iter()
+                    .map(|caret| (caret == primary, caret.reverse(buffer)))
+                    .collect::<Vec<_>>(),
+                primary,
+            )
+        });
+
+        // Pasting as many lines as there are carets spreads them
+        let lines = match &action {
+            Action::Edit(Edit::Paste(text)) => {
+                let lines: Vec<_> = text.lines().collect();
+
+                (lines.len() == carets.len()).then_some(lines)
+            }
+            _ => None,
+        };
+
+        let mut primary = primary;
+        let mut done = Vec::with_capacity(carets.len());
+
+        // Carets are edited in order; the ones pending are positioned from the end
+        // of the buffer, so edits before them do not invalidate them
+        for (i, (is_primary, caret)) in carets.into_iter().enumerate() {
+            self.with_internal_mut(|internal| {
+                caret
+                    .unreverse(buffer_from_editor(&internal.editor))
+                    .apply(&mut internal.editor);
+            });
+
+            let action = match (&lines, &action) {
+                (Some(lines), _) => Action::Edit(Edit::Paste(Arc::new(lines[i].to_owned()))),
+                (None, Action::Edit(Edit::Replace { text, .. })) => {
+                    Action::Edit(Edit::Paste(text.clone()))
+                }
+                (None, _) => action.clone(),
+            };
+
+            editor::Editor::perform(self, action);
+
+            let caret = Caret::of(&self.internal().editor);
+
+            if is_primary {
+                primary = caret;
+            }
+
+            done.push(caret);
+        }
+
+        self.with_internal_mut(|internal| internal.set_carets(done, primary));
+    }
+}
+
+impl Internal {
+    fn carets(&self) -> Vec<Caret> {
+        let mut carets = self.carets.clone();
+        carets.push(Caret::of(&self.editor));
+        carets
+    }
+
+    fn set_carets(&mut self, mut carets: Vec<Caret>, primary: Caret) {
+        carets.sort_by_key(|caret| key(caret.start()));
+
+        let mut merged: Vec<Caret> = Vec::with_capacity(carets.len());
+
+        for caret in carets {
+            if let Some(last) = merged.last_mut()
+                && caret.overlaps(*last)
+            {
+                if caret == primary {
+                    *last = caret;
+                }
+
+                continue;
+            }
+
+            merged.push(caret);
+        }
+
+        let primary = merged
+            .iter()
+            .position(|caret| *caret == primary || caret.overlaps(primary))
+            .unwrap_or(merged.len().saturating_sub(1));
+
+        if primary < merged.len() {
+            merged.remove(primary).apply(&mut self.editor);
+        }
+
+        self.carets = merged;
+    }
+
+    fn add_cursor(&mut self, position: Point) {
+        let buffer = buffer_from_editor(&self.editor);
+
+        let Some(cursor) = buffer.hit(position.x * self.hint_factor, position.y * self.hint_factor)
+        else {
+            return;
+        };
+
+        let mut carets = self.carets();
+        let primary = Caret {
+            cursor,
+            anchor: None,
+        };
+
+        if let Some(existing) = carets
+            .iter()
+            .position(|caret| caret.anchor.is_none() && key(caret.cursor) == key(cursor))
+        {
+            if carets.len() == 1 {
+                return;
+            }
+
+            let _ = carets.remove(existing);
+            let primary = carets[carets.len() - 1];
+
+            self.set_carets(carets, primary);
+        } else {
+            carets.push(primary);
+
+            self.set_carets(carets, primary);
+        }
+    }
+
+    fn select_next_occurrence(&mut self) {
+        let buffer = buffer_from_editor(&self.editor);
+        let current = Caret::of(&self.editor);
+
+        let needle = text_between(buffer, current.start(), current.end());
+
+        if needle.is_empty() {
+            return;
+        }
+
+        let haystack = text_between(
+            buffer,
+            cosmic_text::Cursor::new(0, 0),
+            cosmic_text::Cursor::new(
+                buffer.lines.len().saturating_sub(1),
+                buffer.lines.last().map_or(0, |line| line.text().len()),
+            ),
+        );
+
+        let mut carets = self.carets();
+        let selected: Vec<_> = carets
+            .iter()
+            .map(|caret| offset(buffer, caret.start()))
+            .collect();
+
+        let from = offset(buffer, current.end());
+
+        let Some(start) = haystack[from..]
+            .match_indices(&needle)
+            .map(|(i, _)| from + i)
+            .chain(haystack[..from].match_indices(&needle).map(|(i, _)| i))
+            .find(|start| !selected.contains(start))
+        else {
+            return;
+        };
+
+        let next = Caret::new(
+            cursor_at(buffer, start + needle.len()),
+            cursor_at(buffer, start),
+        );
+
+        carets.push(next);
+
+        self.set_carets(carets, next);
+    }
+
+    fn select_box(&mut self, from: Point, to: Point) {
+        let buffer = buffer_from_editor(&self.editor);
+
+        let top = from.y.min(to.y) * self.hint_factor;
+        let bottom = from.y.max(to.y) * self.hint_factor;
+
+        let carets: Vec<_> = buffer
+            .layout_runs()
+            .filter(|run| run.line_height > 0.0)
+            .filter(|run| run.line_top + run.line_height > top && run.line_top <= bottom)
+            .filter_map(|run| {
+                let y = run.line_top + run.line_height / 2.0;
+
+                let anchor = buffer.hit(from.x * self.hint_factor, y)?;
+                let cursor = buffer.hit(to.x * self.hint_factor, y)?;
+
+                Some(Caret::new(cursor, anchor))
+            })
+            .collect();
+
+        let primary = if to.y < from.y {
+            carets.first()
+        } else {
+            carets.last()
+        };
+
+        if let Some(primary) = primary.copied() {
+            self.set_carets(carets, primary);
+        }
+    }
+
+    fn fold(&mut self, lines: Range<usize>) {
+        let buffer = buffer_from_editor(&self.editor);
+
+        if lines.len() < 2 || lines.end > buffer.lines.len() || self.folds.contains(&lines) {
+            return;
+        }
+
+        self.folds.push(lines.clone());
+        self.sync_folds(lines);
+
+        // Cursors cannot stay in hidden lines
+        if let Some(header) = fold_header(&self.folds, self.editor.cursor().line) {
+            let index = line_length(buffer_from_editor(&self.editor), header);
+
+            self.editor.set_selection(cosmic_text::Selection::None);
+            self.editor
+                .set_cursor(cosmic_text::Cursor::new(header, index));
+        }
+
+        let folds = &self.folds;
+
+        self.carets
+            .retain(|caret| fold_header(folds, caret.cursor.line).is_none());
+    }
+
+    fn unfold(&mut self, line: usize) {
+        let Some(end) = self
+            .folds
+            .iter()
+            .filter(|fold| fold.start == line)
+            .map(|fold| fold.end)
+            .max()
+        else {
+            return;
+        };
+
+        self.folds.retain(|fold| fold.start != line);
+        self.sync_folds(line..end);
+    }
+
+    /// Keeps the folds in place after an edit replaced the `changed` lines,
+    /// leaving `delta` more lines in the buffer.
+    ///
+    /// Folds with edited hidden lines are unfolded.
+    fn shift_folds(&mut self, changed: Range<usize>, delta: isize) {
+        let folds_before = self.folds.len();
+
+        self.folds.retain_mut(|fold| {
+            if fold.end <= changed.start {
+                true
+            } else if changed.end <= fold.start + 1 {
+                fold.start = fold.start.saturating_add_signed(delta);
+                fold.end = fold.end.saturating_add_signed(delta);
+
+                true
+            } else {
+                false
+            }
+        });
+
+        if self.folds.len() != folds_before {
+            self.sync_folds(0..buffer_from_editor(&self.editor).lines.len());
+        }
+    }
+
+    /// Unfolds any region hiding a cursor.
+    fn reveal(&mut self) {
+        let lines: Vec<_> = self
+            .carets()
+            .into_iter()
+            .map(|caret| caret.cursor.line)
+            .collect();
+
+        while let Some(header) = lines
+            .iter()
+            .find_map(|line| fold_header(&self.folds, *line))
+        {
+            self.unfold(header);
+        }
+    }
+
+    /// Hides or shows the given lines depending on the current folds.
+    fn sync_folds(&mut self, lines: Range<usize>) {
+        let buffer = buffer_mut_from_editor(&mut self.editor);
+
+        for (i, line) in buffer
+            .lines
+            .iter_mut()
+            .enumerate()
+            .take(lines.end)
+            .skip(lines.start)
+        {
+            let is_hidden = fold_header(&self.folds, i).is_some();
+
+            if is_hidden == is_folded(line) {
+                continue;
+            }
+
+            if is_hidden {
+                let _ =
+                    line.set_attrs_list(cosmic_text::AttrsList::new(&folded_attributes(self.font)));
+            } else {
+                let _ = line
+                    .set_attrs_list(cosmic_text::AttrsList::new(&text::to_attributes(self.font)));
+
+                self.topmost_line_changed =
+                    Some(self.topmost_line_changed.map_or(i, |line| line.min(i)));
+
+                if self
+                    .formats
+                    .get(i)
+                    .is_some_and(|formats| !formats.is_empty())
+                {
+                    self.formats_changed.push(i);
+                }
+            }
+        }
+    }
+
+    /// Drops the formats of the edited lines, shifting the ones below.
+    fn shift_formats(&mut self, changed: Range<usize>, count: usize) {
+        if changed.start >= self.formats.len() {
+            return;
+        }
+
+        let end = changed.end.min(self.formats.len());
+        let delta = count as isize - (changed.end - changed.start) as isize;
+
+        let was_formatted = self
+            .formats
+            .splice(
+                changed.start..end,
+                std::iter::repeat_with(Vec::new).take(count),
+            )
+            .any(|formats| !formats.is_empty());
+
+        self.formats_changed.retain_mut(|line| {
+            if *line < changed.start {
+                true
+            } else if *line >= changed.end {
+                *line = line.saturating_add_signed(delta);
+                true
+            } else {
+                false
+            }
+        });
+
+        // The edited lines may still keep the attributes of their old formats
+        if was_formatted {
+            self.formats_changed
+                .extend(changed.start..changed.start + count);
+        }
+    }
 }
 
 impl editor::Editor for Editor {
@@ -130,133 +586,97 @@ impl editor::Editor for Editor {
         self.buffer().lines.len()
     }
 
-    fn copy(&self) -> Option<String> {
-        self.internal().editor.copy_selection()
+    fn visible_lines(&self) -> Vec<editor::VisibleLine> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let mut lines: Vec<editor::VisibleLine> = Vec::new();
+
+        for run in buffer.layout_runs() {
+            if is_folded(&buffer.lines[run.line_i]) {
+                continue;
+            }
+
+            let y = run.line_top / internal.hint_factor;
+            let height = run.line_height / internal.hint_factor;
+
+            match lines.last_mut() {
+                Some(line) if line.index == run.line_i => {
+                    line.height += height;
+                }
+                _ => {
+                    lines.push(editor::VisibleLine {
+                        index: run.line_i,
+                        y,
+                        height,
+                    });
+                }
+            }
+        }
+
+        lines
     }
 
-    fn selection(&self) -> editor::Selection {
+    fn folds(&self) -> Vec<Range<usize>> {
+        self.internal().folds.clone()
+    }
+
+    fn copy(&self) -> Option<String> {
         let internal = self.internal();
 
-        if let Ok(Some(cursor)) = internal.selection.read().as_deref() {
-            return cursor.clone();
+        if internal.carets.is_empty() {
+            return internal.editor.copy_selection();
         }
 
-        let cursor = internal.editor.cursor();
         let buffer = buffer_from_editor(&internal.editor);
 
-        let cursor = match internal.editor.selection_bounds() {
-            Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
+        let mut carets = internal.carets();
+        carets.sort_by_key(|caret| key(caret.start()));
 
-                let visual_lines_offset = visual_lines_offset(start.line, buffer);
+        let selections: Vec<_> = carets
+            .into_iter()
+            .filter(|caret| caret.anchor.is_some())
+            .map(|caret| text_between(buffer, caret.start(), caret.end()))
+            .collect();
 
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(
-                                Rectangle {
-                                    x,
-                                    width,
-                                    y: (visual_line as i32 + visual_lines_offset) as f32
-                                        * line_height
-                                        - buffer.scroll().vertical,
-                                    height: line_height,
-                                } * (1.0 / internal.hint_factor),
-                            )
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+        (!selections.is_empty()).then(|| selections.join("\n"))
+    }
 
-                Selection::Range(regions)
-            }
-            _ => {
-                let line_height = buffer.metrics().line_height;
-
-                let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
-
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
-
-                let layout = line.layout_opt().expect("Line layout should be cached");
-
-                let mut lines = layout.iter().enumerate();
-
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
-                        let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
-
-                        let is_cursor_before_start = start > cursor.index;
-
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => cursor.index <= end,
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
+    fn selection(&self) -> editor::Selection {
+        let internal = self.internal();
 
-                Selection::Caret(Point::new(
-                    offset / internal.hint_factor,
-                    ((visual_lines_offset + visual_line as i32) as f32 * line_height
-                        - buffer.scroll().vertical)
-                        / internal.hint_factor,
-                ))
-            }
-        };
+        if let Ok(Some(cursor)) = internal.selection.read().as_deref() {
+            return cursor.clone();
+        }
+
+        let cursor = selection(
+            buffer_from_editor(&internal.editor),
+            internal.editor.cursor(),
+            internal.editor.selection_bounds(),
+            internal.hint_factor,
+        );
 
         *internal.selection.write().expect("Write to cursor cache") = Some(cursor.clone());
 
         cursor
     }
 
+    fn selections(&self) -> Vec<editor::Selection> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        std::iter::once(self.selection())
+            .chain(internal.carets.iter().map(|caret| {
+                selection(
+                    buffer,
+                    caret.cursor,
+                    caret.anchor.map(|_| (caret.start(), caret.end())),
+                    internal.hint_factor,
+                )
+            }))
+            .collect()
+    }
+
     fn cursor(&self) -> Cursor {
         let editor = &self.internal().editor;
 
@@ -285,7 +705,93 @@ impl editor::Editor for Editor {
         }
     }
 
+    fn cursors(&self) -> Vec<Cursor> {
+        std::iter::once(self.cursor())
+            .chain(self.internal().carets.iter().map(|caret| caret.to_cursor()))
+            .collect()
+    }
+
+    fn range_bounds(&self, range: Range<Position>) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        let start = Caret::from_cursor(Cursor {
+            position: range.start,
+            selection: None,
+        });
+
+        let end = Caret::from_cursor(Cursor {
+            position: range.end,
+            selection: None,
+        });
+
+        if key(end.cursor) <= key(start.cursor) {
+            return Vec::new();
+        }
+
+        match selection(
+            buffer_from_editor(&internal.editor),
+            start.cursor,
+            Some((start.cursor, end.cursor)),
+            internal.hint_factor,
+        ) {
+            Selection::Range(regions) => regions,
+            Selection::Caret(_) => Vec::new(),
+        }
+    }
+
     fn perform(&mut self, action: Action) {
+        // Every range is replaced at once using multiple cursors
+        if let Action::Edit(Edit::Replace { ranges, text }) = &action {
+            let cursors: Vec<_> = ranges
+                .iter()
+                .map(|range| Cursor {
+                    position: range.end,
+                    selection: Some(range.start),
+                })
+                .collect();
+
+            if cursors.is_empty() {
+                return;
+            }
+
+            let text = text.clone();
+
+            self.move_to_all(&cursors);
+
+            if cursors.len() == 1 {
+                self.perform(Action::Edit(Edit::Paste(text)));
+            } else {
+                self.perform_all(action);
+            }
+
+            // Only the cursor of the first replacement is kept
+            let cursor = self.cursor();
+            self.move_to(cursor);
+
+            return;
+        }
+
+        let action = match action {
+            Action::SelectNextOccurrence if self.internal().editor.selection_bounds().is_none() => {
+                Action::SelectWord
+            }
+            _ => action,
+        };
+
+        if !self.internal().carets.is_empty()
+            && matches!(
+                action,
+                Action::Move(_)
+                    | Action::Select(_)
+                    | Action::SelectWord
+                    | Action::SelectLine
+                    | Action::Edit(_)
+            )
+        {
+            self.perform_all(action);
+            return;
+        }
+
         let mut font_system = text::font_system().write().expect("Write font system");
 
         self.with_internal_mut(|internal| {
@@ -321,6 +827,8 @@ impl editor::Editor for Editor {
                             cosmic_text::Action::Motion(to_motion(motion)),
                         );
                     }
+
+                    skip_folds(editor, &internal.folds, motion, font_system.raw());
                 }
 
                 // Selection events
@@ -336,6 +844,8 @@ impl editor::Editor for Editor {
                         cosmic_text::Action::Motion(to_motion(motion)),
                     );
 
+                    skip_folds(editor, &internal.folds, motion, font_system.raw());
+
                     // Deselect if selection matches cursor position
                     if let Some((start, end)) = editor.selection_bounds()
                         && start.line == end.line
@@ -355,6 +865,8 @@ impl editor::Editor for Editor {
                     editor.set_selection(cosmic_text::Selection::Line(cursor));
                 }
                 Action::SelectAll => {
+                    internal.carets.clear();
+
                     let buffer = buffer_from_editor(editor);
 
                     if buffer.lines.len() > 1
@@ -386,11 +898,14 @@ impl editor::Editor for Editor {
                         .unwrap_or_else(|| editor.cursor())
                         .line;
 
+                    let lines_before_edit = buffer_from_editor(editor).lines.len();
+                    let lines_below_edit = lines_before_edit - 1 - bottommost_line(editor);
+
                     match edit {
                         Edit::Insert(c) => {
                             editor.action(font_system.raw(), cosmic_text::Action::Insert(c));
                         }
-                        Edit::Paste(text) => {
+                        Edit::Paste(text) | Edit::Replace { text, .. } => {
                             editor.insert_string(&text, None);
                         }
                         Edit::Indent => {
@@ -416,12 +931,38 @@ impl editor::Editor for Editor {
                         .map(|(start, _)| start)
                         .unwrap_or(cursor);
 
-                    internal.topmost_line_changed =
-                        Some(selection_start.line.min(topmost_line_before_edit));
+                    let topmost_line_changed = selection_start.line.min(topmost_line_before_edit);
+
+                    internal.topmost_line_changed = Some(
+                        internal
+                            .topmost_line_changed
+                            .map_or(topmost_line_changed, |line| line.min(topmost_line_changed)),
+                    );
+
+                    let lines_after_edit = buffer_from_editor(&internal.editor).lines.len();
+                    let lines_below_edit = lines_below_edit
+                        .min(lines_after_edit - 1 - bottommost_line(&internal.editor));
+
+                    internal.line_edits.push((
+                        topmost_line_changed..lines_before_edit - lines_below_edit,
+                        lines_after_edit - lines_below_edit - topmost_line_changed,
+                    ));
+
+                    internal.shift_folds(
+                        topmost_line_changed..lines_before_edit - lines_below_edit,
+                        lines_after_edit as isize - lines_before_edit as isize,
+                    );
+
+                    internal.shift_formats(
+                        topmost_line_changed..lines_before_edit - lines_below_edit,
+                        lines_after_edit - lines_below_edit - topmost_line_changed,
+                    );
                 }
 
                 // Mouse events
                 Action::Click(position) => {
+                    internal.carets.clear();
+
                     editor.action(
                         font_system.raw(),
                         cosmic_text::Action::Click {
@@ -455,12 +996,43 @@ impl editor::Editor for Editor {
                         },
                     );
                 }
-            }
-        });
-    }
 
-    fn move_to(&mut self, cursor: Cursor) {
-        self.with_internal_mut(|internal| {
+                // Multiple cursors
+                Action::AddCursor(position) => {
+                    internal.add_cursor(position);
+                }
+                Action::SelectNextOccurrence => {
+                    internal.select_next_occurrence();
+                    internal.reveal();
+                }
+                Action::SelectBox { from, to } => {
+                    internal.select_box(from, to);
+                }
+
+                // Folding
+                Action::Fold(lines) => {
+                    internal.fold(lines);
+                }
+                Action::Unfold(line) => {
+                    internal.unfold(line);
+                }
+
+                // The history of edits is not tracked by the editor itself
+                Action::Undo | Action::Redo => {}
+            }
+        });
+    }
+
+    fn move_to(&mut self, cursor: Cursor) {
+        self.move_to_all(&[cursor]);
+    }
+
+    fn move_to_all(&mut self, cursors: &[Cursor]) {
+        let Some((cursor, rest)) = cursors.split_first() else {
+            return;
+        };
+
+        self.with_internal_mut(|internal| {
             // TODO: Expose `Affinity`
             internal.editor.set_cursor(cosmic_text::Cursor {
                 line: cursor.position.line,
@@ -468,15 +1040,17 @@ impl editor::Editor for Editor {
                 affinity: cosmic_text::Affinity::Before,
             });
 
-            if let Some(selection) = cursor.selection {
-                internal
-                    .editor
-                    .set_selection(cosmic_text::Selection::Normal(cosmic_text::Cursor {
-                        line: selection.line,
-                        index: selection.column,
-                        affinity: cosmic_text::Affinity::Before,
-                    }));
-            }
+            internal.editor.set_selection(match cursor.selection {
+                Some(selection) => cosmic_text::Selection::Normal(cosmic_text::Cursor {
+                    line: selection.line,
+                    index: selection.column,
+                    affinity: cosmic_text::Affinity::Before,
+                }),
+                None => cosmic_text::Selection::None,
+            });
+
+            internal.carets = rest.iter().copied().map(Caret::from_cursor).collect();
+            internal.reveal();
         });
     }
 
@@ -527,14 +1101,25 @@ impl editor::Editor for Editor {
             if new_font != internal.font {
                 log::trace!("Updating font of `Editor`...");
 
-                for line in buffer.lines.iter_mut() {
-                    let _ = line.set_attrs_list(cosmic_text::AttrsList::new(&text::to_attributes(
-                        new_font,
-                    )));
+                for (i, line) in buffer.lines.iter_mut().enumerate() {
+                    let _ = line.set_attrs_list(cosmic_text::AttrsList::new(&if fold_header(
+                        &internal.folds,
+                        i,
+                    )
+                    .is_some()
+                    {
+                        folded_attributes(new_font)
+                    } else {
+                        text::to_attributes(new_font)
+                    }));
                 }
 
                 internal.font = new_font;
                 internal.topmost_line_changed = Some(0);
+
+                internal.formats_changed = (0..internal.formats.len())
+                    .filter(|line| !internal.formats[*line].is_empty())
+                    .collect();
             }
 
             let metrics = buffer.metrics();
@@ -594,6 +1179,10 @@ impl editor::Editor for Editor {
                 internal.bounds = new_bounds;
             }
 
+            for (lines, count) in internal.line_edits.drain(..) {
+                new_highlighter.replace_lines(lines, count);
+            }
+
             if let Some(topmost_line_changed) = internal.topmost_line_changed.take() {
                 log::trace!(
                     "Notifying highlighter of line \
@@ -625,11 +1214,14 @@ impl editor::Editor for Editor {
             .iter()
             .enumerate()
             .find_map(|(i, line)| {
-                let visible_lines = line
-                    .layout_opt()
-                    .as_ref()
-                    .expect("Line layout should be cached")
-                    .len() as i32;
+                let visible_lines = if is_folded(line) {
+                    0
+                } else {
+                    line.layout_opt()
+                        .as_ref()
+                        .expect("Line layout should be cached")
+                        .len() as i32
+                };
 
                 if window > visible_lines {
                     window -= visible_lines;
@@ -640,9 +1232,13 @@ impl editor::Editor for Editor {
             })
             .unwrap_or(buffer.lines.len().saturating_sub(1));
 
+        if let Some(first_formatted) = internal.formats_changed.iter().min() {
+            highlighter.change_line(*first_formatted);
+        }
+
         let current_line = highlighter.current_line();
 
-        if current_line > last_visible_line {
+        if current_line > last_visible_line && internal.formats_changed.is_empty() {
             return;
         }
 
@@ -655,36 +1251,185 @@ impl editor::Editor for Editor {
 
         let attributes = text::to_attributes(font);
 
-        for line in &mut buffer_mut_from_editor(&mut internal.editor).lines
-            [current_line..=last_visible_line]
-        {
-            let mut list = cosmic_text::AttrsList::new(&attributes);
-
-            for (range, highlight) in highlighter.highlight_line(line.text()) {
-                let format = format_highlight(&highlight);
-
-                if format.color.is_some() || format.font.is_some() {
-                    list.add_span(
-                        range,
-                        &cosmic_text::Attrs {
-                            color_opt: format.color.map(text::to_color),
-                            ..if let Some(font) = format.font {
-                                text::to_attributes(font)
-                            } else {
-                                attributes.clone()
-                            }
-                        },
-                    );
-                }
+        let folded = cosmic_text::AttrsList::new(&folded_attributes(font));
+
+        let mut formats_changed = std::mem::take(&mut internal.formats_changed);
+        formats_changed.sort_unstable();
+        formats_changed.dedup();
+
+        // Lines the highlighter is done with only need their formats reapplied
+        for line in formats_changed {
+            if line >= current_line {
+                break;
             }
 
-            let _ = line.set_attrs_list(list);
+            let Some(buffer_line) = buffer_mut_from_editor(&mut internal.editor)
+                .lines
+                .get_mut(line)
+            else {
+                break;
+            };
+
+            if fold_header(&internal.folds, line).is_some() {
+                continue;
+            }
+
+            let spans = internal.formats.get(line).cloned().unwrap_or_default();
+
+            let _ = buffer_line.set_attrs_list(attributes_list(
+                &attributes,
+                spans,
+                &mut internal.decorations,
+            ));
+        }
+
+        let last_line = last_visible_line.min(
+            buffer_from_editor(&internal.editor)
+                .lines
+                .len()
+                .saturating_sub(1),
+        );
+
+        if current_line <= last_line {
+            for (i, line) in buffer_mut_from_editor(&mut internal.editor).lines
+                [current_line..=last_line]
+                .iter_mut()
+                .enumerate()
+            {
+                let highlights = highlighter.highlight_line(line.text());
+
+                if fold_header(&internal.folds, current_line + i).is_some() {
+                    // Highlights are still consumed to keep the highlighter in sync
+                    highlights.for_each(drop);
+
+                    let _ = line.set_attrs_list(folded.clone());
+                    continue;
+                }
+
+                let mut spans: Vec<_> = highlights
+                    .map(|(range, highlight)| (range, format_highlight(&highlight)))
+                    .collect();
+
+                if let Some(formats) = internal.formats.get(current_line + i)
+                    && !formats.is_empty()
+                {
+                    spans = overlay(&spans, formats);
+                }
+
+                let _ = line.set_attrs_list(attributes_list(
+                    &attributes,
+                    spans,
+                    &mut internal.decorations,
+                ));
+            }
         }
 
         internal.editor.shape_as_needed(font_system.raw(), false);
 
         self.0 = Some(Arc::new(internal));
     }
+
+    fn format_line(
+        &mut self,
+        line: usize,
+        formats: Vec<(Range<usize>, highlighter::Format<Self::Font>)>,
+    ) {
+        if self
+            .internal()
+            .formats
+            .get(line)
+            .map_or(formats.is_empty(), |current| *current == formats)
+        {
+            return;
+        }
+
+        self.with_internal_mut(|internal| {
+            if internal.formats.len() <= line {
+                internal.formats.resize_with(line + 1, Vec::new);
+            }
+
+            internal.formats[line] = formats;
+            internal.formats_changed.push(line);
+        });
+    }
+}
+
+/// Builds the attributes of a line with the given formatted spans.
+fn attributes_list(
+    attributes: &cosmic_text::Attrs<'_>,
+    spans: impl IntoIterator<Item = (Range<usize>, highlighter::Format<Font>)>,
+    decorations: &mut Vec<Decoration>,
+) -> cosmic_text::AttrsList {
+    let mut list = cosmic_text::AttrsList::new(attributes);
+
+    for (range, format) in spans {
+        let metadata =
+            Decoration::of(&format).map_or(0, |decoration| decoration.metadata(decorations));
+
+        if format.color.is_some() || format.font.is_some() || metadata != 0 {
+            list.add_span(
+                range,
+                &cosmic_text::Attrs {
+                    color_opt: format.color.map(text::to_color),
+                    metadata,
+                    ..if let Some(font) = format.font {
+                        text::to_attributes(font)
+                    } else {
+                        attributes.clone()
+                    }
+                },
+            );
+        }
+    }
+
+    list
+}
+
+/// Lays some formats on top of the highlights of a line, splitting
+/// any overlapping spans.
+fn overlay(
+    highlights: &[(Range<usize>, highlighter::Format<Font>)],
+    formats: &[(Range<usize>, highlighter::Format<Font>)],
+) -> Vec<(Range<usize>, highlighter::Format<Font>)> {
+    let mut bounds: Vec<usize> = highlights
+        .iter()
+        .chain(formats)
+        .flat_map(|(range, _)| [range.start, range.end])
+        .collect();
+
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    bounds
+        .windows(2)
+        .filter_map(|bounds| {
+            let range = bounds[0]..bounds[1];
+
+            let covers = |(span, _): &&(Range<usize>, highlighter::Format<Font>)| {
+                span.start <= range.start && range.end <= span.end
+            };
+
+            let mut layers = highlights
+                .iter()
+                .filter(covers)
+                .chain(formats.iter().filter(covers))
+                .peekable();
+
+            let _ = layers.peek()?;
+
+            let format = layers.fold(highlighter::Format::default(), |format, (_, layer)| {
+                format
+                    .color(layer.color.or(format.color))
+                    .font(layer.font.or(format.font))
+                    .underline(layer.underline.or(format.underline))
+                    .underline_color(layer.underline_color.or(format.underline_color))
+                    .strikethrough(format.strikethrough || layer.strikethrough)
+                    .background(layer.background.or(format.background))
+            });
+
+            Some((range, format))
+        })
+        .collect()
 }
 
 impl Default for Editor {
@@ -711,10 +1456,16 @@ impl Default for Internal {
                     line_height: 1.0,
                 },
             )),
+            carets: Vec::new(),
+            folds: Vec::new(),
+            decorations: Vec::new(),
+            formats: Vec::new(),
+            formats_changed: Vec::new(),
             selection: RwLock::new(None),
             font: Font::default(),
             bounds: Size::ZERO,
             topmost_line_changed: None,
+            line_edits: Vec::new(),
             hint: false,
             hint_factor: 1.0,
             version: text::Version::default(),
@@ -731,6 +1482,183 @@ impl fmt::Debug for Internal {
     }
 }
 
+/// The decorations of some highlighted text, referenced by the metadata
+/// of its glyphs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Decoration {
+    underline: Option<highlighter::Underline>,
+    underline_color: Option<Color>,
+    strikethrough: bool,
+    background: Option<Color>,
+}
+
+impl Decoration {
+    fn of<Font>(format: &highlighter::Format<Font>) -> Option<Self> {
+        (format.underline.is_some() || format.strikethrough || format.background.is_some())
+            .then_some(Self {
+                underline: format.underline,
+                underline_color: format.underline_color,
+                strikethrough: format.strikethrough,
+                background: format.background,
+            })
+    }
+
+    /// Returns the glyph metadata referencing the [`Decoration`], adding it
+    /// to the given ones if new.
+    fn metadata(self, decorations: &mut Vec<Self>) -> usize {
+        let index = decorations
+            .iter()
+            .position(|decoration| *decoration == self)
+            .unwrap_or_else(|| {
+                decorations.push(self);
+                decorations.len() - 1
+            });
+
+        index + 1
+    }
+}
+
+/// A cursor of an [`Editor`] with the anchor of its selection, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Caret {
+    cursor: cosmic_text::Cursor,
+    anchor: Option<cosmic_text::Cursor>,
+}
+
+impl Caret {
+    fn new(cursor: cosmic_text::Cursor, anchor: cosmic_text::Cursor) -> Self {
+        Self {
+            cursor,
+            anchor: (key(anchor) != key(cursor)).then_some(anchor),
+        }
+    }
+
+    fn of(editor: &cosmic_text::Editor<'_>) -> Self {
+        let cursor = editor.cursor();
+
+        match editor.selection() {
+            cosmic_text::Selection::None => Self {
+                cursor,
+                anchor: None,
+            },
+            cosmic_text::Selection::Normal(anchor) => Self::new(cursor, anchor),
+            cosmic_text::Selection::Line(anchor) | cosmic_text::Selection::Word(anchor) => {
+                let Some((start, end)) = editor.selection_bounds() else {
+                    return Self {
+                        cursor,
+                        anchor: None,
+                    };
+                };
+
+                if key(cursor) < key(anchor) {
+                    Self::new(start, end)
+                } else {
+                    Self::new(end, start)
+                }
+            }
+        }
+    }
+
+    fn from_cursor(cursor: Cursor) -> Self {
+        let to_cursor = |position: Position| cosmic_text::Cursor {
+            line: position.line,
+            index: position.column,
+            affinity: cosmic_text::Affinity::Before,
+        };
+
+        Self::new(
+            to_cursor(cursor.position),
+            to_cursor(cursor.selection.unwrap_or(cursor.position)),
+        )
+    }
+
+    fn to_cursor(self) -> Cursor {
+        let to_position = |cursor: cosmic_text::Cursor| Position {
+            line: cursor.line,
+            column: cursor.index,
+        };
+
+        Cursor {
+            position: to_position(self.cursor),
+            selection: self.anchor.map(to_position),
+        }
+    }
+
+    fn apply(self, editor: &mut cosmic_text::Editor<'_>) {
+        editor.set_cursor(self.cursor);
+        editor.set_selection(
+            self.anchor
+                .map_or(cosmic_text::Selection::None, cosmic_text::Selection::Normal),
+        );
+    }
+
+    fn start(self) -> cosmic_text::Cursor {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+
+        if key(anchor) < key(self.cursor) {
+            anchor
+        } else {
+            self.cursor
+        }
+    }
+
+    fn end(self) -> cosmic_text::Cursor {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+
+        if key(anchor) < key(self.cursor) {
+            self.cursor
+        } else {
+            anchor
+        }
+    }
+
+    fn overlaps(self, other: Self) -> bool {
+        let (start, end) = (key(self.start()), key(self.end()));
+        let (other_start, other_end) = (key(other.start()), key(other.end()));
+
+        (start < other_end && other_start < end)
+            || (start == end && other_start <= start && start <= other_end)
+            || (other_start == other_end && start <= other_start && other_start <= end)
+    }
+
+    /// Counts the positions of the [`Caret`] from the end of the buffer, so
+    /// they stay valid while editing any text before them.
+    fn reverse(self, buffer: &cosmic_text::Buffer) -> Self {
+        let reverse = |cursor: cosmic_text::Cursor| cosmic_text::Cursor {
+            line: buffer.lines.len().saturating_sub(cursor.line),
+            index: line_length(buffer, cursor.line).saturating_sub(cursor.index),
+            ..cursor
+        };
+
+        Self {
+            cursor: reverse(self.cursor),
+            anchor: self.anchor.map(reverse),
+        }
+    }
+
+    /// Turns the positions of a reversed [`Caret`] back into regular ones.
+    fn unreverse(self, buffer: &cosmic_text::Buffer) -> Self {
+        let unreverse = |cursor: cosmic_text::Cursor| {
+            let line = buffer
+                .lines
+                .len()
+                .saturating_sub(cursor.line)
+                .min(buffer.lines.len().saturating_sub(1));
+
+            cosmic_text::Cursor {
+                line,
+                index: line_length(buffer, line).saturating_sub(cursor.index),
+                ..cursor
+            }
+        };
+
+        Self {
+            cursor: unreverse(self.cursor),
+            anchor: self.anchor.map(unreverse),
+        }
+    }
+}
+
 /// A weak reference to an [`Editor`].
 #[derive(Debug, Clone)]
 pub struct Weak {
@@ -755,12 +1683,317 @@ impl PartialEq for Weak {
     }
 }
 
+fn key(cursor: cosmic_text::Cursor) -> (usize, usize) {
+    (cursor.line, cursor.index)
+}
+
+fn line_length(buffer: &cosmic_text::Buffer, line: usize) -> usize {
+    buffer.lines.get(line).map_or(0, |line| line.text().len())
+}
+
+fn text_between(
+    buffer: &cosmic_text::Buffer,
+    start: cosmic_text::Cursor,
+    end: cosmic_text::Cursor,
+) -> String {
+    let mut text = String::new();
+
+    for (i, line) in buffer
+        .lines
+        .iter()
+        .enumerate()
+        .take(end.line + 1)
+        .skip(start.line)
+    {
+        let line = line.text();
+
+        let from = if i == start.line { start.index } else { 0 };
+        let to = if i == end.line { end.index } else { line.len() };
+
+        text.push_str(&line[from..to]);
+
+        if i < end.line {
+            text.push('\n');
+        }
+    }
+
+    text
+}
+
+fn offset(buffer: &cosmic_text::Buffer, cursor: cosmic_text::Cursor) -> usize {
+    buffer.lines[..cursor.line]
+        .iter()
+        .map(|line| line.text().len() + 1)
+        .sum::<usize>()
+        + cursor.index
+}
+
+fn cursor_at(buffer: &cosmic_text::Buffer, mut offset: usize) -> cosmic_text::Cursor {
+    for (i, line) in buffer.lines.iter().enumerate() {
+        let length = line.text().len();
+
+        if offset <= length {
+            return cosmic_text::Cursor::new(i, offset);
+        }
+
+        offset -= length + 1;
+    }
+
+    cosmic_text::Cursor::new(
+        buffer.lines.len().saturating_sub(1),
+        buffer.lines.last().map_or(0, |line| line.text().len()),
+    )
+}
+
+fn selection(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+    bounds: Option<(cosmic_text::Cursor, cosmic_text::Cursor)>,
+    hint_factor: f32,
+) -> Selection {
+    match bounds {
+        Some((start, end)) => {
+            let line_height = buffer.metrics().line_height;
+            let selected_lines = end.line - start.line + 1;
+
+            let visual_lines_offset = visual_lines_offset(start.line, buffer);
+
+            let regions = buffer
+                .lines
+                .iter()
+                .skip(start.line)
+                .take(selected_lines)
+                .enumerate()
+                .flat_map(|(i, line)| {
+                    highlight_line(
+                        line,
+                        if i == 0 { start.index } else { 0 },
+                        if i == selected_lines - 1 {
+                            end.index
+                        } else {
+                            line.text().len()
+                        },
+                    )
+                })
+                .enumerate()
+                .filter_map(|(visual_line, (x, width))| {
+                    if width > 0.0 {
+                        Some(
+                            Rectangle {
+                                x,
+                                width,
+                                y: (visual_line as i32 + visual_lines_offset) as f32 * line_height
+                                    - buffer.scroll().vertical,
+                                height: line_height,
+                            } * (1.0 / hint_factor),
+                        )
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            Selection::Range(regions)
+        }
+        _ => {
+            let line_height = buffer.metrics().line_height;
+
+            let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+            let line = buffer
+                .lines
+                .get(cursor.line)
+                .expect("Cursor line should be present");
+
+            let layout = line.layout_opt().expect("Line layout should be cached");
+
+            let mut lines = layout.iter().enumerate();
+
+            let (visual_line, offset) = lines
+                .find_map(|(i, line)| {
+                    let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
+                    let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+                    let is_cursor_before_start = start > cursor.index;
+
+                    let is_cursor_before_end = match cursor.affinity {
+                        cosmic_text::Affinity::Before => cursor.index <= end,
+                        cosmic_text::Affinity::After => cursor.index < end,
+                    };
+
+                    if is_cursor_before_start {
+                        // Sometimes, the glyph we are looking for is right
+                        // between lines. This can happen when a line wraps
+                        // on a space.
+                        // In that case, we can assume the cursor is at the
+                        // end of the previous line.
+                        // i is guaranteed to be > 0 because `start` is always
+                        // 0 for the first line, so there is no way for the
+                        // cursor to be before it.
+                        Some((i - 1, layout[i - 1].w))
+                    } else if is_cursor_before_end {
+                        let offset = line
+                            .glyphs
+                            .iter()
+                            .take_while(|glyph| cursor.index > glyph.start)
+                            .map(|glyph| glyph.w)
+                            .sum();
+
+                        Some((i, offset))
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or((
+                    layout.len().saturating_sub(1),
+                    layout.last().map(|line| line.w).unwrap_or(0.0),
+                ));
+
+            Selection::Caret(Point::new(
+                offset / hint_factor,
+                ((visual_lines_offset + visual_line as i32) as f32 * line_height
+                    - buffer.scroll().vertical)
+                    / hint_factor,
+            ))
+        }
+    }
+}
+
+/// Returns the visible line right before the folded region hiding the
+/// given line, if any.
+fn fold_header(folds: &[Range<usize>], mut line: usize) -> Option<usize> {
+    let mut header = None;
+
+    while let Some(start) = folds
+        .iter()
+        .filter(|fold| fold.start < line && line < fold.end)
+        .map(|fold| fold.start)
+        .min()
+    {
+        header = Some(start);
+        line = start;
+    }
+
+    header
+}
+
+/// Returns whether the given line is hidden by a fold.
+fn is_folded(line: &cosmic_text::BufferLine) -> bool {
+    line.attrs_list().defaults().metrics_opt.is_some()
+}
+
+/// The attributes of the lines hidden by a fold.
+///
+/// Folded lines are laid out with no height and drawn fully transparent.
+fn folded_attributes(font: Font) -> cosmic_text::Attrs<'static> {
+    text::to_attributes(font)
+        .metrics(cosmic_text::Metrics::new(1.0, 0.0))
+        .color(cosmic_text::Color::rgba(0, 0, 0, 0))
+}
+
+/// Moves the cursor of the editor out of any folded region, following the
+/// given [`Motion`].
+fn skip_folds(
+    editor: &mut cosmic_text::Editor<'static>,
+    folds: &[Range<usize>],
+    motion: Motion,
+    font_system: &mut cosmic_text::FontSystem,
+) {
+    loop {
+        let cursor = editor.cursor();
+
+        let Some(header) = fold_header(folds, cursor.line) else {
+            return;
+        };
+
+        let buffer = buffer_from_editor(editor);
+
+        let header_end = cosmic_text::Cursor::new(header, line_length(buffer, header));
+
+        match motion {
+            // Vertical motions keep going to preserve the horizontal position
+            Motion::Up | Motion::Down => {
+                editor.action(font_system, cosmic_text::Action::Motion(to_motion(motion)));
+            }
+            _ => match motion.direction() {
+                Direction::Left => editor.set_cursor(header_end),
+                Direction::Right => {
+                    let next = (cursor.line..buffer.lines.len())
+                        .find(|line| fold_header(folds, *line).is_none());
+
+                    editor.set_cursor(
+                        next.map_or(header_end, |line| cosmic_text::Cursor::new(line, 0)),
+                    );
+                }
+            },
+        }
+
+        if editor.cursor() == cursor {
+            editor.set_cursor(header_end);
+            return;
+        }
+    }
+}
+
+/// Returns the last line spanned by the cursor and selection of the editor.
+fn bottommost_line(editor: &cosmic_text::Editor<'_>) -> usize {
+    editor
+        .selection_bounds()
+        .map(|(_, end)| end)
+        .unwrap_or_else(|| editor.cursor())
+        .line
+        .max(editor.cursor().line)
+}
+
+/// Returns the regions drawing an [`Underline`] of the given style.
+///
+/// [`Underline`]: highlighter::Underline
+fn underline_segments(
+    underline: highlighter::Underline,
+    span: Range<f32>,
+    y: f32,
+    thickness: f32,
+) -> impl Iterator<Item = Rectangle> {
+    let width = span.end - span.start;
+
+    let (step, offsets): (f32, &[f32]) = match underline {
+        highlighter::Underline::Straight => (width, &[0.0]),
+        highlighter::Underline::Dotted => (thickness * 2.0, &[0.0]),
+        highlighter::Underline::Wavy => (thickness, &[0.0, 1.0, 2.0, 1.0]),
+    };
+
+    let steps = if step > 0.0 {
+        (width / step).ceil() as usize
+    } else {
+        0
+    };
+
+    (0..steps).map(move |i| {
+        let x = span.start + i as f32 * step;
+
+        Rectangle {
+            x,
+            y: y + offsets[i % offsets.len()] * thickness,
+            width: if underline == highlighter::Underline::Straight {
+                width
+            } else {
+                thickness.min(span.end - x)
+            },
+            height: thickness,
+        }
+    })
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
     to: usize,
 ) -> impl Iterator<Item = (f32, f32)> + '_ {
-    let layout = line.layout_opt().map(Vec::as_slice).unwrap_or_default();
+    let layout = if is_folded(line) {
+        &[]
+    } else {
+        line.layout_opt().map(Vec::as_slice).unwrap_or_default()
+    };
 
     layout.iter().map(move |visual_line| {
         let start = visual_line
@@ -810,6 +2043,7 @@ fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
     let visual_lines_offset: usize = buffer.lines[start..]
         .iter()
         .take(end - start)
+        .filter(|line| !is_folded(line))
         .map(|line| line.layout_opt().map(Vec::len).unwrap_or_default())
         .sum();
 
@@ -856,3 +2090,419 @@ where
         cosmic_text::BufferRef::Arc(_buffer) => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::Editor as _;
+
+    #[test]
+    fn move_to_without_selection_clears_selection() {
+        let mut editor = Editor::with_text("hello world");
+
+        editor.perform(Action::SelectAll);
+        assert!(editor.cursor().selection.is_some());
+
+        let cursor = Cursor {
+            position: Position { line: 0, column: 3 },
+            selection: None,
+        };
+
+        editor.move_to(cursor);
+
+        assert_eq!(editor.cursor(), cursor);
+    }
+
+    fn at(line: usize, column: usize) -> Cursor {
+        Cursor {
+            position: Position { line, column },
+            selection: None,
+        }
+    }
+
+    fn contents(editor: &Editor) -> String {
+        (0..editor.line_count())
+            .filter_map(|index| editor.line(index))
+            .map(|line| line.text.into_owned())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn laid_out(text: &str) -> Editor {
+        let mut editor = Editor::with_text(text);
+
+        editor.update(
+            Size::new(1000.0, 1000.0),
+            Font::default(),
+            Pixels(20.0),
+            LineHeight::Absolute(Pixels(20.0)),
+            Wrapping::None,
+            None,
+            &mut highlighter::PlainText,
+        );
+
+        editor
+    }
+
+    #[test]
+    fn edits_apply_to_every_cursor() {
+        let mut editor = Editor::with_text("one\ntwo\nthree");
+
+        editor.move_to_all(&[at(1, 3), at(0, 3), at(2, 5)]);
+
+        editor.perform(Action::Edit(Edit::Insert('!')));
+        assert_eq!(contents(&editor), "one!\ntwo!\nthree!");
+        assert_eq!(editor.cursors(), vec![at(1, 4), at(0, 4), at(2, 6)]);
+
+        editor.perform(Action::Edit(Edit::Enter));
+        assert_eq!(contents(&editor), "one!\n\ntwo!\n\nthree!\n");
+
+        editor.perform(Action::Edit(Edit::Backspace));
+        editor.perform(Action::Edit(Edit::Backspace));
+        assert_eq!(contents(&editor), "one\ntwo\nthree");
+
+        // Cursors that end up in the same position are merged
+        editor.perform(Action::Move(Motion::DocumentStart));
+        assert_eq!(editor.cursors(), vec![at(0, 0)]);
+    }
+
+    #[test]
+    fn select_next_occurrence_adds_cursors() {
+        let mut editor = Editor::with_text("foo bar foo\nbaz foo");
+
+        editor.move_to(at(0, 1));
+
+        editor.perform(Action::SelectNextOccurrence);
+        assert_eq!(editor.copy().as_deref(), Some("foo"));
+
+        editor.perform(Action::SelectNextOccurrence);
+        editor.perform(Action::SelectNextOccurrence);
+        assert_eq!(editor.cursors().len(), 3);
+        assert_eq!(editor.copy().as_deref(), Some("foo\nfoo\nfoo"));
+
+        // Every occurrence is selected already
+        editor.perform(Action::SelectNextOccurrence);
+        assert_eq!(editor.cursors().len(), 3);
+
+        editor.perform(Action::Edit(Edit::Paste(Arc::new(String::from("qux")))));
+        assert_eq!(contents(&editor), "qux bar qux\nbaz qux");
+    }
+
+    #[test]
+    fn pasting_a_line_per_cursor_spreads_them() {
+        let mut editor = Editor::with_text("a\nb");
+
+        editor.move_to_all(&[at(0, 1), at(1, 1)]);
+        editor.perform(Action::Edit(Edit::Paste(Arc::new(String::from("1\n2")))));
+
+        assert_eq!(contents(&editor), "a1\nb2");
+    }
+
+    #[test]
+    fn add_cursor_toggles_cursors() {
+        let mut editor = laid_out("first\nsecond\nthird");
+
+        editor.perform(Action::Click(Point::new(0.0, 5.0)));
+        editor.perform(Action::AddCursor(Point::new(0.0, 45.0)));
+        assert_eq!(editor.cursors(), vec![at(2, 0), at(0, 0)]);
+        assert_eq!(editor.selections().len(), 2);
+
+        editor.perform(Action::AddCursor(Point::new(0.0, 45.0)));
+        assert_eq!(editor.cursors(), vec![at(0, 0)]);
+
+        editor.perform(Action::AddCursor(Point::new(0.0, 25.0)));
+        editor.perform(Action::Click(Point::new(0.0, 5.0)));
+        assert_eq!(editor.cursors(), vec![at(0, 0)]);
+    }
+
+    #[test]
+    fn select_box_places_a_cursor_per_line() {
+        let mut editor = laid_out("first\nsecond\nthird\nfourth");
+
+        editor.perform(Action::SelectBox {
+            from: Point::new(0.0, 5.0),
+            to: Point::new(1000.0, 45.0),
+        });
+
+        assert_eq!(editor.cursors().len(), 3);
+        assert_eq!(editor.cursor().position, Position { line: 2, column: 5 });
+        assert_eq!(editor.copy().as_deref(), Some("first\nsecond\nthird"));
+
+        editor.perform(Action::Edit(Edit::Delete));
+        assert_eq!(contents(&editor), "\n\n\nfourth");
+    }
+
+    fn visible(editor: &Editor) -> Vec<(usize, f32)> {
+        editor
+            .visible_lines()
+            .into_iter()
+            .map(|line| (line.index, line.y))
+            .collect()
+    }
+
+    fn relayout(editor: &mut Editor) {
+        editor.update(
+            Size::new(1000.0, 1000.0),
+            Font::default(),
+            Pixels(20.0),
+            LineHeight::Absolute(Pixels(20.0)),
+            Wrapping::None,
+            None,
+            &mut highlighter::PlainText,
+        );
+    }
+
+    #[test]
+    fn folding_hides_lines() {
+        let mut editor = laid_out(
+            "one
+two
+three
+four",
+        );
+
+        editor.perform(Action::Fold(0..3));
+        relayout(&mut editor);
+
+        assert_eq!(editor.folds(), vec![0..3]);
+        assert_eq!(visible(&editor), vec![(0, 0.0), (3, 20.0)]);
+
+        editor.perform(Action::Unfold(0));
+        relayout(&mut editor);
+
+        assert!(editor.folds().is_empty());
+        assert_eq!(
+            visible(&editor),
+            vec![(0, 0.0), (1, 20.0), (2, 40.0), (3, 60.0)]
+        );
+    }
+
+    #[test]
+    fn motions_skip_folded_lines() {
+        let mut editor = laid_out(
+            "one
+two
+three
+four",
+        );
+
+        editor.move_to(at(1, 1));
+        editor.perform(Action::Fold(0..3));
+        relayout(&mut editor);
+
+        // Cursors in hidden lines move to the first line of the fold
+        assert_eq!(editor.cursor(), at(0, 3));
+
+        editor.perform(Action::Move(Motion::Down));
+        assert_eq!(editor.cursor().position.line, 3);
+
+        editor.perform(Action::Move(Motion::Up));
+        assert_eq!(editor.cursor().position.line, 0);
+
+        editor.perform(Action::Move(Motion::End));
+        editor.perform(Action::Move(Motion::Right));
+        assert_eq!(editor.cursor(), at(3, 0));
+
+        editor.perform(Action::Move(Motion::Left));
+        assert_eq!(editor.cursor(), at(0, 3));
+    }
+
+    #[test]
+    fn edits_move_folds_or_unfold_them() {
+        let mut editor = laid_out(
+            "one
+two
+three
+four",
+        );
+
+        editor.perform(Action::Fold(1..3));
+
+        editor.move_to(at(0, 3));
+        editor.perform(Action::Edit(Edit::Enter));
+        assert_eq!(editor.folds(), vec![2..4]);
+
+        editor.perform(Action::Edit(Edit::Backspace));
+        assert_eq!(editor.folds(), vec![1..3]);
+
+        // Editing the header keeps the fold
+        editor.move_to(at(1, 0));
+        editor.perform(Action::Edit(Edit::Insert('2')));
+        assert_eq!(editor.folds(), vec![1..3]);
+
+        // Reaching a hidden line unfolds it
+        editor.move_to(at(2, 0));
+        assert!(editor.folds().is_empty());
+        relayout(&mut editor);
+        assert_eq!(visible(&editor).len(), 4);
+    }
+
+    /// Highlights every occurrence of "word" with the same highlight.
+    struct Words(usize);
+
+    impl Highlighter for Words {
+        type Settings = ();
+        type Highlight = ();
+        type Iterator<'a> = std::vec::IntoIter<(Range<usize>, ())>;
+
+        fn new(_settings: &Self::Settings) -> Self {
+            Self(0)
+        }
+
+        fn update(&mut self, _new_settings: &Self::Settings) {}
+
+        fn change_line(&mut self, line: usize) {
+            self.0 = self.0.min(line);
+        }
+
+        fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+            self.0 += 1;
+
+            line.match_indices("word")
+                .map(|(start, word)| (start..start + word.len(), ()))
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+
+        fn current_line(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn highlights_are_decorated() {
+        let mut editor = laid_out("a word\nno match");
+        let background = Color::from_rgb(1.0, 0.0, 0.0);
+        let underline = Color::from_rgb(0.0, 0.0, 1.0);
+
+        editor.highlight(Font::default(), &mut Words(0), |()| {
+            highlighter::Format::default()
+                .underline(highlighter::Underline::Straight)
+                .underline_color(underline)
+                .strikethrough(true)
+                .background(background)
+        });
+        relayout(&mut editor);
+
+        let decorations = editor.decorations(Color::BLACK);
+        let colors: Vec<_> = decorations.iter().map(|(_, color)| *color).collect();
+
+        assert_eq!(colors, vec![background, underline, Color::BLACK]);
+
+        let (word, _) = decorations[0];
+
+        assert!(word.x > 0.0);
+        assert_eq!(word.y, 0.0);
+        assert_eq!(word.height, 20.0);
+
+        for (bounds, _) in &decorations[1..] {
+            assert_eq!(bounds.x, word.x);
+            assert_eq!(bounds.width, word.width);
+            assert!(bounds.y > 0.0 && bounds.y < 20.0);
+        }
+    }
+
+    #[test]
+    fn wavy_and_dotted_underlines_are_segmented() {
+        let straight = underline_segments(highlighter::Underline::Straight, 0.0..10.0, 5.0, 1.0);
+        let dotted = underline_segments(highlighter::Underline::Dotted, 0.0..10.0, 5.0, 1.0);
+        let wavy = underline_segments(highlighter::Underline::Wavy, 0.0..10.0, 5.0, 1.0);
+
+        assert_eq!(straight.count(), 1);
+        assert_eq!(
+            dotted.map(|dot| dot.x).collect::<Vec<_>>(),
+            vec![0.0, 2.0, 4.0, 6.0, 8.0]
+        );
+        assert_eq!(
+            wavy.take(5).map(|segment| segment.y).collect::<Vec<_>>(),
+            vec![5.0, 6.0, 7.0, 6.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn formats_are_dropped_from_edited_lines() {
+        let mut editor = laid_out("a link\nplain");
+        let link = highlighter::Format::default().underline(highlighter::Underline::Straight);
+
+        editor.format_line(0, vec![(2..6, link)]);
+        editor.format_line(1, vec![(0..5, link)]);
+        editor.highlight(Font::default(), &mut highlighter::PlainText, |_| {
+            highlighter::Format::default()
+        });
+        relayout(&mut editor);
+
+        assert_eq!(editor.decorations(Color::BLACK).len(), 2);
+
+        editor.move_to(at(0, 0));
+        editor.perform(Action::Select(Motion::End));
+        editor.perform(Action::Edit(Edit::Insert('!')));
+        relayout(&mut editor);
+        editor.highlight(Font::default(), &mut highlighter::PlainText, |_| {
+            highlighter::Format::default()
+        });
+
+        let decorations = editor.decorations(Color::BLACK);
+
+        assert_eq!(decorations.len(), 1);
+        assert!(decorations[0].0.y > 20.0);
+    }
+
+    /// Records the line edits it is notified of.
+    #[derive(Default)]
+    struct Edits(Vec<(Range<usize>, usize)>);
+
+    impl Highlighter for Edits {
+        type Settings = ();
+        type Highlight = ();
+        type Iterator<'a> = std::iter::Empty<(Range<usize>, ())>;
+
+        fn new(_settings: &Self::Settings) -> Self {
+            Self::default()
+        }
+
+        fn update(&mut self, _new_settings: &Self::Settings) {}
+
+        fn change_line(&mut self, _line: usize) {}
+
+        fn replace_lines(&mut self, lines: Range<usize>, count: usize) {
+            self.0.push((lines, count));
+        }
+
+        fn highlight_line(&mut self, _line: &str) -> Self::Iterator<'_> {
+            std::iter::empty()
+        }
+
+        fn current_line(&self) -> usize {
+            usize::MAX
+        }
+    }
+
+    #[test]
+    fn edits_notify_replaced_lines() {
+        let mut editor = laid_out("one\ntwo\nthree");
+        let mut edits = Edits::default();
+
+        editor.move_to(at(1, 3));
+        editor.perform(Action::Edit(Edit::Insert('!')));
+        editor.perform(Action::Edit(Edit::Enter));
+
+        editor.move_to(Cursor {
+            position: Position { line: 3, column: 5 },
+            selection: Some(Position { line: 0, column: 0 }),
+        });
+        editor.perform(Action::Edit(Edit::Paste(Arc::new(String::from("x\ny")))));
+
+        editor.update(
+            Size::new(1000.0, 1000.0),
+            Font::default(),
+            Pixels(20.0),
+            LineHeight::Absolute(Pixels(20.0)),
+            Wrapping::None,
+            None,
+            &mut edits,
+        );
+
+        assert_eq!(edits.0, vec![(1..2, 1), (1..2, 2), (0..4, 2)]);
+        assert_eq!(contents(&editor), "x\ny");
+    }
+}
@@ -6,6 +6,7 @@ use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -386,6 +387,61 @@ impl core::text::Paragraph for Paragraph {
         bounds
     }
 
+    fn hit_offset(&self, point: Point) -> Option<usize> {
+        let internal = self.internal();
+
+        let cursor = internal
+            .buffer
+            .hit(point.x * self.0.hint_factor, point.y * self.0.hint_factor)?;
+
+        let line_offset: usize = internal.buffer.lines[..cursor.line]
+            .iter()
+            .map(line_length)
+            .sum();
+
+        Some(line_offset + cursor.index)
+    }
+
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        let mut line_offsets = Vec::with_capacity(internal.buffer.lines.len());
+        let mut offset = 0;
+
+        for line in &internal.buffer.lines {
+            line_offsets.push(offset);
+            offset += line_length(line);
+        }
+
+        internal
+            .buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let line_offset = line_offsets.get(run.line_i).copied()?;
+
+                let start = range.start.saturating_sub(line_offset);
+                let end = range.end.checked_sub(line_offset)?;
+
+                let (left, right) = run
+                    .glyphs
+                    .iter()
+                    .filter(|glyph| glyph.start < end && glyph.end > start)
+                    .fold(None, |span: Option<(f32, f32)>, glyph| {
+                        let (left, right) = span.unwrap_or((glyph.x, glyph.x + glyph.w));
+
+                        Some((left.min(glyph.x), right.max(glyph.x + glyph.w)))
+                    })?;
+
+                Some(
+                    Rectangle::new(
+                        Point::new(left, run.line_top),
+                        Size::new(right - left, run.line_height),
+                    ) * (1.0 / self.0.hint_factor),
+                )
+            })
+            .collect()
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -427,6 +483,18 @@ impl core::text::Paragraph for Paragraph {
     }
 }
 
+/// Returns the length in bytes of a line of a [`cosmic_text::Buffer`],
+/// including its line ending.
+fn line_length(line: &cosmic_text::BufferLine) -> usize {
+    let ending = match line.ending() {
+        cosmic_text::LineEnding::None => 0,
+        cosmic_text::LineEnding::Lf | cosmic_text::LineEnding::Cr => 1,
+        cosmic_text::LineEnding::CrLf | cosmic_text::LineEnding::LfCr => 2,
+    };
+
+    line.text().len() + ending
+}
+
 impl Default for Paragraph {
     fn default() -> Self {
         Self(Arc::new(Internal::default()))
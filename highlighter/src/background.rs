@@ -0,0 +1,493 @@
+//! Highlight text off the UI thread.
+use crate::core::text::highlighter;
+use crate::futures::futures::channel::mpsc;
+use crate::futures::{BoxFuture, Executor, MaybeSend, MaybeSync, Subscription};
+use crate::{Highlight, LINES_PER_SNAPSHOT, SYNTAXES, THEMES, Theme, scope_iterator};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Mutex};
+
+use syntect::highlighting;
+use syntect::parsing;
+use two_face::re_exports::syntect;
+
+/// A syntax highlighter that parses in the background.
+///
+/// Lines are parsed off the UI thread by the [`Worker`] of its [`Settings`]
+/// and are plain text until their highlights land. The parser state is
+/// checkpointed every few lines, so an edit only highlights again from
+/// the changed line.
+#[derive(Debug)]
+pub struct Background {
+    syntax: &'static parsing::SyntaxReference,
+    theme: Theme,
+    worker: Worker,
+    lines: Vec<Arc<str>>,
+    highlights: Vec<Vec<(Range<usize>, Highlight)>>,
+    checkpoints: Vec<(parsing::ParseState, parsing::ScopeStack)>,
+    current_line: usize,
+    job: Option<Job>,
+    is_stale: bool,
+}
+
+/// The settings of a [`Background`] highlighter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    highlighter: crate::Settings,
+    worker: Worker,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] for a [`Background`] highlighter that parses
+    /// in the given [`Worker`].
+    pub fn new(highlighter: crate::Settings, worker: Worker) -> Self {
+        Self {
+            highlighter,
+            worker,
+        }
+    }
+}
+
+/// A handle to the executor where [`Background`] highlighters parse.
+///
+/// A [`Worker`] wakes the UI when new highlights land through its
+/// [`subscription`](Self::subscription). Without it, the highlights of a
+/// [`Background`] highlighter only show up on the next redraw.
+#[derive(Clone)]
+pub struct Worker(Arc<Internal>);
+
+struct Internal {
+    executor: Box<dyn Spawn>,
+    waker: Mutex<Option<mpsc::UnboundedSender<()>>>,
+    is_waking: AtomicBool,
+}
+
+impl Worker {
+    /// Creates a new [`Worker`] that parses in the given [`Executor`].
+    pub fn new<E>(executor: E) -> Self
+    where
+        E: Executor + MaybeSend + MaybeSync + 'static,
+    {
+        Self(Arc::new(Internal {
+            executor: Box::new(executor),
+            waker: Mutex::new(None),
+            is_waking: AtomicBool::new(false),
+        }))
+    }
+
+    /// Returns a [`Subscription`] that notifies when new highlights of the
+    /// [`Worker`] land.
+    ///
+    /// Any message produced by it will cause the UI to be redrawn and the
+    /// new highlights to be shown.
+    pub fn subscription(&self) -> Subscription<()> {
+        Subscription::run_with(self.clone(), |worker| {
+            let (sender, receiver) = mpsc::unbounded();
+
+            *worker.0.waker.lock().expect("Lock worker waker") = Some(sender);
+
+            receiver
+        })
+    }
+
+    fn spawn(&self, future: impl Future<Output = ()> + MaybeSend + 'static) {
+        self.0.executor.spawn(Box::pin(future));
+    }
+
+    /// Wakes the UI, unless a wake up is already pending.
+    fn wake(&self) {
+        if self.0.is_waking.swap(true, atomic::Ordering::AcqRel) {
+            return;
+        }
+
+        if let Some(waker) = self.0.waker.lock().expect("Lock worker waker").as_ref() {
+            let _ = waker.unbounded_send(());
+        }
+    }
+
+    fn acknowledge(&self) {
+        self.0.is_waking.store(false, atomic::Ordering::Release);
+    }
+}
+
+trait Spawn: MaybeSend + MaybeSync {
+    fn spawn(&self, future: BoxFuture<()>);
+}
+
+impl<E> Spawn for E
+where
+    E: Executor + MaybeSend + MaybeSync,
+{
+    fn spawn(&self, future: BoxFuture<()>) {
+        Executor::spawn(self, future);
+    }
+}
+
+impl fmt::Debug for Worker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Worker").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Worker {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for Worker {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state);
+    }
+}
+
+#[derive(Debug)]
+struct Job {
+    end: usize,
+    receiver: mpsc::UnboundedReceiver<Chunk>,
+}
+
+/// The highlights of a run of lines starting at a checkpoint.
+#[derive(Debug)]
+struct Chunk {
+    start: usize,
+    highlights: Vec<Vec<(Range<usize>, Highlight)>>,
+    checkpoint: Option<(parsing::ParseState, parsing::ScopeStack)>,
+}
+
+impl Background {
+    fn restart(&mut self) {
+        self.checkpoints = vec![(
+            parsing::ParseState::new(self.syntax),
+            parsing::ScopeStack::new(),
+        )];
+
+        self.lines.clear();
+        self.highlights.clear();
+        self.current_line = 0;
+        self.job = None;
+        self.is_stale = true;
+    }
+
+    fn land(&mut self, chunk: Chunk) {
+        self.highlights.truncate(chunk.start);
+        self.highlights.extend(chunk.highlights);
+
+        if let Some(checkpoint) = chunk.checkpoint {
+            self.checkpoints.push(checkpoint);
+        }
+    }
+
+    fn spawn(&mut self) {
+        let (parser, stack) = self
+            .checkpoints
+            .last()
+            .cloned()
+            .expect("Checkpoints must not be empty");
+
+        let start = (self.checkpoints.len() - 1) * LINES_PER_SNAPSHOT;
+        let lines = self.lines[start..].to_vec();
+        let theme = self.theme;
+        let worker = self.worker.clone();
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        self.job = Some(Job {
+            end: self.lines.len(),
+            receiver,
+        });
+
+        self.worker.spawn(async move {
+            parse(parser, stack, theme, start, &lines, |chunk| {
+                let is_sent = sender.unbounded_send(chunk).is_ok();
+
+                if is_sent {
+                    worker.wake();
+                }
+
+                is_sent
+            });
+        });
+    }
+}
+
+impl highlighter::Highlighter for Background {
+    type Settings = Settings;
+    type Highlight = Highlight;
+
+    type Iterator<'a> = Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let mut highlighter = Self {
+            syntax: SYNTAXES.find_syntax_plain_text(),
+            theme: settings.highlighter.theme,
+            worker: settings.worker.clone(),
+            lines: Vec::new(),
+            highlights: Vec::new(),
+            checkpoints: Vec::new(),
+            current_line: 0,
+            job: None,
+            is_stale: true,
+        };
+
+        highlighter.update(settings);
+        highlighter
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.syntax = SYNTAXES
+            .find_syntax_by_token(&new_settings.highlighter.token)
+            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+
+        self.theme = new_settings.highlighter.theme;
+        self.worker = new_settings.worker.clone();
+
+        self.restart();
+    }
+
+    fn change_line(&mut self, line: usize) {
+        let line = line.min(self.lines.len());
+
+        self.lines.truncate(line);
+        self.highlights.truncate(line);
+        self.checkpoints.truncate(line / LINES_PER_SNAPSHOT + 1);
+        self.current_line = line;
+
+        // Any highlights in flight are outdated
+        self.job = None;
+        self.is_stale = true;
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        if self.current_line == self.lines.len() {
+            self.lines.push(Arc::from(line));
+        }
+
+        let highlights = self.highlights.get(self.current_line);
+        self.current_line += 1;
+
+        Box::new(highlights.into_iter().flatten().cloned())
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+
+    fn poll(&mut self) -> highlighter::Status {
+        let is_stale = std::mem::take(&mut self.is_stale);
+        let mut updated = None;
+
+        self.worker.acknowledge();
+
+        while let Some(job) = &mut self.job {
+            match job.receiver.try_recv() {
+                Ok(chunk) => {
+                    updated = Some(updated.unwrap_or(chunk.start).min(chunk.start));
+                    self.land(chunk);
+                }
+                Err(mpsc::TryRecvError::Closed) => {
+                    self.job = None;
+                }
+                Err(mpsc::TryRecvError::Empty) => break,
+            }
+        }
+
+        if self
+            .job
+            .as_ref()
+            .is_some_and(|job| self.highlights.len() >= job.end)
+        {
+            self.job = None;
+        }
+
+        // Lines fed since the last job, or dropped by the executor, are
+        // parsed by a new job
+        if self.job.is_none() && self.highlights.len() < self.lines.len() {
+            self.spawn();
+        }
+
+        if let Some(line) = updated {
+            self.current_line = self.current_line.min(line);
+
+            highlighter::Status::Updated
+        } else if self.job.is_some() || is_stale {
+            highlighter::Status::Busy
+        } else {
+            highlighter::Status::Idle
+        }
+    }
+}
+
+/// Parses the given lines starting at the checkpoint of the `start` line,
+/// producing a [`Chunk`] for every checkpoint until `on_chunk` returns
+/// `false`.
+fn parse(
+    mut parser: parsing::ParseState,
+    mut stack: parsing::ScopeStack,
+    theme: Theme,
+    start: usize,
+    lines: &[Arc<str>],
+    mut on_chunk: impl FnMut(Chunk) -> bool,
+) {
+    let highlighter = highlighting::Highlighter::new(&THEMES.themes[theme.key()]);
+
+    for (i, lines) in lines.chunks(LINES_PER_SNAPSHOT).enumerate() {
+        let highlights = lines
+            .iter()
+            .map(|line| {
+                let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+
+                scope_iterator(ops, line, &mut stack, &highlighter).collect()
+            })
+            .collect();
+
+        let checkpoint =
+            (lines.len() == LINES_PER_SNAPSHOT).then(|| (parser.clone(), stack.clone()));
+
+        let chunk = Chunk {
+            start: start + i * LINES_PER_SNAPSHOT,
+            highlights,
+            checkpoint,
+        };
+
+        if !on_chunk(chunk) {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::{Highlighter as _, Status};
+    use crate::futures::futures::task;
+
+    use std::time::{Duration, Instant};
+
+    struct Thread;
+
+    impl Executor for Thread {
+        fn new() -> Result<Self, std::io::Error> {
+            Ok(Self)
+        }
+
+        fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+            let _ = std::thread::spawn(move || Self.block_on(future));
+        }
+
+        fn block_on<T>(&self, future: impl Future<Output = T>) -> T {
+            let mut future = std::pin::pin!(future);
+            let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+            loop {
+                if let task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                    return output;
+                }
+
+                std::thread::yield_now();
+            }
+        }
+    }
+
+    fn settings() -> Settings {
+        Settings::new(
+            crate::Settings::new("rs", Theme::Base16Ocean),
+            Worker::new(Thread),
+        )
+    }
+
+    fn feed(highlighter: &mut Background, lines: &[&str]) -> Vec<usize> {
+        let start = highlighter.current_line();
+
+        lines[start..]
+            .iter()
+            .map(|line| highlighter.highlight_line(line).count())
+            .collect()
+    }
+
+    fn settle(highlighter: &mut Background) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut updated = false;
+
+        loop {
+            match highlighter.poll() {
+                Status::Idle => return updated,
+                Status::Updated => updated = true,
+                Status::Busy => {}
+            }
+
+            assert!(Instant::now() < deadline, "highlights never landed");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn lines_are_plain_until_highlights_land() {
+        let lines = ["fn main() {", "    let x = 42;", "}"];
+        let mut highlighter = Background::new(&settings());
+
+        assert_eq!(feed(&mut highlighter, &lines), vec![0, 0, 0]);
+        assert!(settle(&mut highlighter));
+        assert_eq!(highlighter.current_line(), 0);
+
+        let highlights = feed(&mut highlighter, &lines);
+
+        assert!(highlights.iter().all(|count| *count > 0));
+        assert!(!settle(&mut highlighter));
+    }
+
+    #[test]
+    fn landed_highlights_wake_the_ui() {
+        let settings = settings();
+        let (sender, mut receiver) = mpsc::unbounded();
+
+        *settings.worker.0.waker.lock().unwrap() = Some(sender);
+
+        let mut highlighter = Background::new(&settings);
+        let _ = feed(&mut highlighter, &["fn main() {}"]);
+
+        assert_eq!(highlighter.poll(), Status::Busy);
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+
+        while receiver.try_recv().is_err() {
+            assert!(Instant::now() < deadline, "the UI was never woken up");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(highlighter.poll(), Status::Updated);
+    }
+
+    #[test]
+    fn edits_highlight_again_from_the_changed_line() {
+        let lines: Vec<String> = (0..LINES_PER_SNAPSHOT * 3)
+            .map(|i| format!("let x{i} = {i};"))
+            .collect();
+
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        let mut highlighter = Background::new(&settings());
+
+        let _ = feed(&mut highlighter, &lines);
+        let _ = settle(&mut highlighter);
+        let _ = feed(&mut highlighter, &lines);
+
+        assert_eq!(highlighter.checkpoints.len(), 4);
+
+        let changed = LINES_PER_SNAPSHOT * 2 + 5;
+        highlighter.change_line(changed);
+
+        assert_eq!(highlighter.current_line(), changed);
+        assert_eq!(highlighter.highlights.len(), changed);
+        assert_eq!(highlighter.checkpoints.len(), 3);
+
+        let highlights = feed(&mut highlighter, &lines);
+
+        assert!(highlights.iter().all(|count| *count == 0));
+        assert!(settle(&mut highlighter));
+        assert_eq!(highlighter.current_line(), LINES_PER_SNAPSHOT * 2);
+        assert_eq!(highlighter.highlights.len(), lines.len());
+    }
+}
@@ -1,5 +1,13 @@
 //! A syntax highlighter for iced.
 use iced_core as core;
+use iced_futures as futures;
+
+pub mod background;
+
+#[cfg(feature = "tree-sitter")]
+mod syntax_tree;
+
+pub use background::Background;
 
 use crate::core::Color;
 use crate::core::font::{self, Font};
@@ -22,10 +30,25 @@ const LINES_PER_SNAPSHOT: usize = 50;
 /// A syntax highlighter.
 #[derive(Debug)]
 pub struct Highlighter {
-    syntax: &'static parsing::SyntaxReference,
-    highlighter: highlighting::Highlighter<'static>,
-    caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
-    current_line: usize,
+    engine: Engine,
+}
+
+#[derive(Debug)]
+enum Engine {
+    Syntect(Syntect),
+    #[cfg(feature = "tree-sitter")]
+    SyntaxTree(syntax_tree::SyntaxTree),
+}
+
+impl Engine {
+    fn new(settings: &Settings) -> Self {
+        match settings.backend {
+            Backend::Syntect => Self::Syntect(Syntect::new(settings)),
+            #[cfg(feature = "tree-sitter")]
+            Backend::TreeSitter => syntax_tree::SyntaxTree::new(settings)
+                .map_or_else(|| Self::Syntect(Syntect::new(settings)), Self::SyntaxTree),
+        }
+    }
 }
 
 impl highlighter::Highlighter for Highlighter {
@@ -35,6 +58,77 @@ impl highlighter::Highlighter for Highlighter {
     type Iterator<'a> = Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
 
     fn new(settings: &Self::Settings) -> Self {
+        Self {
+            engine: Engine::new(settings),
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        match &mut self.engine {
+            Engine::Syntect(syntect) if new_settings.backend == Backend::Syntect => {
+                syntect.update(new_settings);
+            }
+            #[cfg(feature = "tree-sitter")]
+            Engine::SyntaxTree(syntax_tree) if syntax_tree.supports(new_settings) => {
+                syntax_tree.update(new_settings);
+            }
+            _ => {
+                self.engine = Engine::new(new_settings);
+            }
+        }
+    }
+
+    fn change_line(&mut self, line: usize) {
+        match &mut self.engine {
+            Engine::Syntect(syntect) => syntect.change_line(line),
+            #[cfg(feature = "tree-sitter")]
+            Engine::SyntaxTree(syntax_tree) => syntax_tree.change_line(line),
+        }
+    }
+
+    #[cfg(feature = "tree-sitter")]
+    fn replace_lines(&mut self, lines: Range<usize>, count: usize) {
+        if let Engine::SyntaxTree(syntax_tree) = &mut self.engine {
+            syntax_tree.replace_lines(lines, count);
+        }
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        match &mut self.engine {
+            Engine::Syntect(syntect) => Box::new(syntect.highlight_line(line)),
+            #[cfg(feature = "tree-sitter")]
+            Engine::SyntaxTree(syntax_tree) => Box::new(syntax_tree.highlight_line(line)),
+        }
+    }
+
+    fn current_line(&self) -> usize {
+        match &self.engine {
+            Engine::Syntect(syntect) => syntect.current_line,
+            #[cfg(feature = "tree-sitter")]
+            Engine::SyntaxTree(syntax_tree) => syntax_tree.current_line(),
+        }
+    }
+
+    fn poll(&mut self) -> highlighter::Status {
+        match &mut self.engine {
+            Engine::Syntect(_) => highlighter::Status::Idle,
+            #[cfg(feature = "tree-sitter")]
+            Engine::SyntaxTree(syntax_tree) => syntax_tree.poll(),
+        }
+    }
+}
+
+/// A syntax highlighter using the grammars of `syntect`.
+#[derive(Debug)]
+struct Syntect {
+    syntax: &'static parsing::SyntaxReference,
+    highlighter: highlighting::Highlighter<'static>,
+    caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
+    current_line: usize,
+}
+
+impl Syntect {
+    fn new(settings: &Settings) -> Self {
         let syntax = SYNTAXES
             .find_syntax_by_token(&settings.token)
             .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
@@ -44,7 +138,7 @@ impl highlighter::Highlighter for Highlighter {
         let parser = parsing::ParseState::new(syntax);
         let stack = parsing::ScopeStack::new();
 
-        Highlighter {
+        Self {
             syntax,
             highlighter,
             caches: vec![(parser, stack)],
@@ -52,7 +146,7 @@ impl highlighter::Highlighter for Highlighter {
         }
     }
 
-    fn update(&mut self, new_settings: &Self::Settings) {
+    fn update(&mut self, new_settings: &Settings) {
         self.syntax = SYNTAXES
             .find_syntax_by_token(&new_settings.token)
             .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
@@ -84,7 +178,10 @@ impl highlighter::Highlighter for Highlighter {
         self.caches.push((parser, stack));
     }
 
-    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+    fn highlight_line<'a>(
+        &'a mut self,
+        line: &str,
+    ) -> impl Iterator<Item = (Range<usize>, Highlight)> + use<'a> {
         if self.current_line / LINES_PER_SNAPSHOT >= self.caches.len() {
             let (parser, stack) = self.caches.last().expect("Caches must not be empty");
 
@@ -97,11 +194,7 @@ impl highlighter::Highlighter for Highlighter {
 
         let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
 
-        Box::new(scope_iterator(ops, line, stack, &self.highlighter))
-    }
-
-    fn current_line(&self) -> usize {
-        self.current_line
+        scope_iterator(ops, line, stack, &self.highlighter)
     }
 }
 
@@ -110,7 +203,7 @@ fn scope_iterator<'a>(
     line: &str,
     stack: &'a mut parsing::ScopeStack,
     highlighter: &'a highlighting::Highlighter<'static>,
-) -> impl Iterator<Item = (Range<usize>, Highlight)> + 'a {
+) -> impl Iterator<Item = (Range<usize>, Highlight)> + use<'a> {
     ScopeRangeIterator {
         ops,
         line_length: line.len(),
@@ -191,6 +284,7 @@ impl Stream {
 
 /// The settings of a [`Highlighter`].
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Settings {
     /// The [`Theme`] of the [`Highlighter`].
     ///
@@ -201,10 +295,46 @@ pub struct Settings {
     /// The [`Highlighter`] will use the token to automatically determine
     /// the grammar to use for highlighting.
     pub token: String,
+    /// The [`Backend`] of the [`Highlighter`].
+    pub backend: Backend,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] that highlight the language of the given
+    /// token with the given [`Theme`].
+    pub fn new(token: impl Into<String>, theme: Theme) -> Self {
+        Self {
+            theme,
+            token: token.into(),
+            backend: Backend::default(),
+        }
+    }
+
+    /// Sets the [`Backend`] of the [`Settings`].
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+}
+
+/// The parsing backend of a [`Highlighter`].
+///
+/// [`Stream`] and [`Background`] highlighters always use [`Backend::Syntect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// The regular expression grammars of `syntect`.
+    #[default]
+    Syntect,
+    /// The incremental parsers of `tree-sitter`, with highlights mapped
+    /// to the scopes of the [`Theme`].
+    ///
+    /// Languages without a `tree-sitter` grammar use [`Backend::Syntect`].
+    #[cfg(feature = "tree-sitter")]
+    TreeSitter,
 }
 
 /// A highlight produced by a [`Highlighter`].
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Highlight(highlighting::StyleModifier);
 
 impl Highlight {
@@ -245,17 +375,42 @@ impl Highlight {
         })
     }
 
+    /// Returns the [`Underline`] of this [`Highlight`].
+    ///
+    /// If `None`, the text should not be underlined.
+    ///
+    /// [`Underline`]: highlighter::Underline
+    pub fn underline(&self) -> Option<highlighter::Underline> {
+        self.0
+            .font_style
+            .filter(|style| style.contains(highlighting::FontStyle::UNDERLINE))
+            .map(|_| highlighter::Underline::Straight)
+    }
+
+    /// Returns the background color of this [`Highlight`].
+    ///
+    /// If `None`, the original background should be unchanged.
+    pub fn background(&self) -> Option<Color> {
+        self.0
+            .background
+            .map(|color| Color::from_rgba8(color.r, color.g, color.b, color.a as f32 / 255.0))
+    }
+
     /// Returns the [`Format`] of the [`Highlight`].
     ///
-    /// It contains both the [`color`] and the [`font`].
+    /// It contains the [`color`], the [`font`], the [`underline`], and
+    /// the [`background`].
     ///
     /// [`color`]: Self::color
     /// [`font`]: Self::font
+    /// [`underline`]: Self::underline
+    /// [`background`]: Self::background
     pub fn to_format(&self) -> Format<Font> {
-        Format {
-            color: self.color(),
-            font: self.font(),
-        }
+        Format::default()
+            .color(self.color())
+            .font(self.font())
+            .underline(self.underline())
+            .background(self.background())
     }
 }
 
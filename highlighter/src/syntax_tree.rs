@@ -0,0 +1,480 @@
+use crate::core::text::highlighter;
+use crate::{Highlight, Settings, THEMES};
+
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use syntect::highlighting;
+use syntect::parsing;
+use two_face::re_exports::syntect;
+
+use tree_sitter::StreamingIterator;
+
+/// The scopes of a `syntect` theme matching the capture names of the
+/// highlight queries, from the most to the least specific.
+const SCOPES: &[(&str, &str)] = &[
+    ("attribute", "entity.other.attribute-name"),
+    ("comment", "comment"),
+    ("constant.builtin", "constant.language"),
+    ("constant", "constant"),
+    ("constructor", "entity.name.type"),
+    ("escape", "constant.character.escape"),
+    ("function.builtin", "support.function"),
+    ("function.macro", "entity.name.function.macro"),
+    ("function", "entity.name.function"),
+    ("keyword", "keyword"),
+    ("label", "entity.name.label"),
+    ("number", "constant.numeric"),
+    ("operator", "keyword.operator"),
+    ("property", "variable.other.member"),
+    ("punctuation", "punctuation"),
+    ("string", "string"),
+    ("tag", "entity.name.tag"),
+    ("type.builtin", "storage.type"),
+    ("type", "entity.name.type"),
+    ("variable.builtin", "variable.language"),
+    ("variable.parameter", "variable.parameter"),
+    ("variable", "variable"),
+];
+
+/// A `tree-sitter` language and its highlights query.
+struct Grammar {
+    language: tree_sitter::Language,
+    query: tree_sitter::Query,
+}
+
+impl Grammar {
+    fn new(language: impl Into<tree_sitter::Language>, queries: &[&str]) -> Self {
+        let language = language.into();
+        let query = tree_sitter::Query::new(&language, &queries.concat())
+            .expect("Highlights query must be valid");
+
+        Self { language, query }
+    }
+
+    fn find(token: &str) -> Option<&'static Self> {
+        static RUST: LazyLock<Grammar> = LazyLock::new(|| {
+            Grammar::new(
+                tree_sitter_rust::LANGUAGE,
+                &[tree_sitter_rust::HIGHLIGHTS_QUERY],
+            )
+        });
+
+        static JAVASCRIPT: LazyLock<Grammar> = LazyLock::new(|| {
+            Grammar::new(
+                tree_sitter_javascript::LANGUAGE,
+                &[
+                    tree_sitter_javascript::HIGHLIGHT_QUERY,
+                    tree_sitter_javascript::JSX_HIGHLIGHT_QUERY,
+                ],
+            )
+        });
+
+        static TYPESCRIPT: LazyLock<Grammar> = LazyLock::new(|| {
+            Grammar::new(
+                tree_sitter_typescript::LANGUAGE_TYPESCRIPT,
+                &[
+                    tree_sitter_javascript::HIGHLIGHT_QUERY,
+                    tree_sitter_typescript::HIGHLIGHTS_QUERY,
+                ],
+            )
+        });
+
+        static TSX: LazyLock<Grammar> = LazyLock::new(|| {
+            Grammar::new(
+                tree_sitter_typescript::LANGUAGE_TSX,
+                &[
+                    tree_sitter_javascript::HIGHLIGHT_QUERY,
+                    tree_sitter_javascript::JSX_HIGHLIGHT_QUERY,
+                    tree_sitter_typescript::HIGHLIGHTS_QUERY,
+                ],
+            )
+        });
+
+        static PYTHON: LazyLock<Grammar> = LazyLock::new(|| {
+            Grammar::new(
+                tree_sitter_python::LANGUAGE,
+                &[tree_sitter_python::HIGHLIGHTS_QUERY],
+            )
+        });
+
+        match token.to_ascii_lowercase().as_str() {
+            "rs" | "rust" => Some(&RUST),
+            "js" | "mjs" | "cjs" | "jsx" | "javascript" => Some(&JAVASCRIPT),
+            "ts" | "mts" | "cts" | "typescript" => Some(&TYPESCRIPT),
+            "tsx" => Some(&TSX),
+            "py" | "pyi" | "python" => Some(&PYTHON),
+            _ => None,
+        }
+    }
+}
+
+/// A syntax highlighter using the incremental parsers of `tree-sitter`.
+///
+/// It keeps a copy of the lines it has been fed and edits its syntax tree
+/// as they change, reparsing only what an edit touched.
+pub struct SyntaxTree {
+    grammar: &'static Grammar,
+    parser: tree_sitter::Parser,
+    tree: Option<tree_sitter::Tree>,
+    styles: Vec<Option<highlighting::StyleModifier>>,
+    source: String,
+    offsets: Vec<usize>,
+    known: Vec<bool>,
+    current_line: usize,
+    provisional: Option<usize>,
+    is_dirty: bool,
+    is_stale: bool,
+}
+
+impl SyntaxTree {
+    /// Creates a new [`SyntaxTree`] highlighter, if there is a grammar for
+    /// the language of the [`Settings`].
+    pub fn new(settings: &Settings) -> Option<Self> {
+        let grammar = Grammar::find(&settings.token)?;
+
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&grammar.language)
+            .expect("Grammar must be compatible with tree-sitter");
+
+        Some(Self {
+            grammar,
+            parser,
+            tree: None,
+            styles: styles(grammar, settings),
+            source: String::new(),
+            offsets: vec![0],
+            known: Vec::new(),
+            current_line: 0,
+            provisional: None,
+            is_dirty: false,
+            is_stale: true,
+        })
+    }
+
+    /// Returns `true` if the [`SyntaxTree`] highlighter can be updated with
+    /// the given [`Settings`].
+    pub fn supports(&self, settings: &Settings) -> bool {
+        settings.backend == crate::Backend::TreeSitter
+            && Grammar::find(&settings.token)
+                .is_some_and(|grammar| std::ptr::eq(grammar, self.grammar))
+    }
+
+    pub fn update(&mut self, new_settings: &Settings) {
+        self.styles = styles(self.grammar, new_settings);
+        self.change_line(0);
+    }
+
+    pub fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line).min(self.known.len());
+        self.is_stale = true;
+    }
+
+    pub fn replace_lines(&mut self, lines: Range<usize>, count: usize) {
+        let len = self.known.len();
+
+        if lines.start >= len {
+            return;
+        }
+
+        if lines.end > len {
+            // The lines after the edit are unknown
+            self.splice(lines.start..len, &[]);
+        } else {
+            self.splice(lines, &vec![None; count]);
+        }
+    }
+
+    pub fn highlight_line(
+        &mut self,
+        text: &str,
+    ) -> impl Iterator<Item = (Range<usize>, Highlight)> + use<> {
+        let line = self.current_line;
+        let is_appended = line == self.known.len();
+
+        self.current_line += 1;
+
+        if is_appended {
+            self.splice(line..line, &[Some(text)]);
+        } else if !self.known[line] || self.line(line) != text {
+            self.splice(line..line + 1, &[Some(text)]);
+        }
+
+        if self.is_dirty {
+            let is_edit_over = self.known.get(line + 1).is_none_or(|is_known| *is_known);
+
+            if is_appended || !is_edit_over || self.tree.is_none() {
+                // The line is highlighted once the rest of the edit is known
+                self.provisional = Some(self.provisional.map_or(line, |first| first.min(line)));
+
+                return Vec::new().into_iter();
+            }
+
+            self.parse();
+        }
+
+        self.highlights(line).into_iter()
+    }
+
+    pub fn current_line(&self) -> usize {
+        self.current_line
+    }
+
+    pub fn poll(&mut self) -> highlighter::Status {
+        let is_stale = std::mem::take(&mut self.is_stale);
+
+        if self.is_dirty {
+            self.parse();
+        }
+
+        if let Some(line) = self.provisional.take() {
+            self.current_line = self.current_line.min(line);
+
+            highlighter::Status::Updated
+        } else if is_stale {
+            highlighter::Status::Busy
+        } else {
+            highlighter::Status::Idle
+        }
+    }
+
+    fn line(&self, line: usize) -> &str {
+        &self.source[self.offsets[line]..self.offsets[line + 1] - 1]
+    }
+
+    /// Replaces the given lines of the source with some new ones, where
+    /// `None` is a line that has not been fed yet.
+    fn splice(&mut self, lines: Range<usize>, replacement: &[Option<&str>]) {
+        let start_byte = self.offsets[lines.start];
+        let old_end_byte = self.offsets[lines.end];
+
+        let text: String = replacement
+            .iter()
+            .flat_map(|line| [line.unwrap_or_default(), "\n"])
+            .collect();
+
+        let new_end_byte = start_byte + text.len();
+
+        if let Some(tree) = &mut self.tree {
+            tree.edit(&tree_sitter::InputEdit {
+                start_byte,
+                old_end_byte,
+                new_end_byte,
+                start_position: tree_sitter::Point::new(lines.start, 0),
+                old_end_position: tree_sitter::Point::new(lines.end, 0),
+                new_end_position: tree_sitter::Point::new(lines.start + replacement.len(), 0),
+            });
+        }
+
+        self.source.replace_range(start_byte..old_end_byte, &text);
+
+        let offsets: Vec<usize> = replacement
+            .iter()
+            .scan(start_byte, |offset, line| {
+                let start = *offset;
+                *offset += line.map_or(0, str::len) + 1;
+
+                Some(start)
+            })
+            .collect();
+
+        let _ = self.offsets.splice(lines.clone(), offsets);
+
+        for offset in &mut self.offsets[lines.start + replacement.len()..] {
+            *offset = *offset - old_end_byte + new_end_byte;
+        }
+
+        let _ = self
+            .known
+            .splice(lines, replacement.iter().map(Option::is_some));
+
+        self.is_dirty = true;
+    }
+
+    fn parse(&mut self) {
+        self.tree = self.parser.parse(&self.source, self.tree.as_ref());
+        self.is_dirty = false;
+    }
+
+    /// Returns the highlights of the given line from the captures of the
+    /// syntax tree; the innermost node winning over its ancestors, and the
+    /// first pattern winning over the rest.
+    fn highlights(&self, line: usize) -> Vec<(Range<usize>, Highlight)> {
+        let Some(tree) = &self.tree else {
+            return Vec::new();
+        };
+
+        let start = self.offsets[line];
+        let end = self.offsets[line + 1] - 1;
+
+        let mut winners: Vec<Option<(usize, usize, u32)>> = vec![None; end - start];
+
+        let mut cursor = tree_sitter::QueryCursor::new();
+        let _ = cursor.set_byte_range(start..end);
+
+        let mut captures = cursor.captures(
+            &self.grammar.query,
+            tree.root_node(),
+            self.source.as_bytes(),
+        );
+
+        while let Some((match_, index)) = captures.next() {
+            let capture = match_.captures[*index];
+
+            if self.styles[capture.index as usize].is_none() {
+                continue;
+            }
+
+            let node = capture.node.byte_range();
+            let size = node.len();
+
+            for winner in &mut winners[node.start.max(start) - start..node.end.min(end) - start] {
+                if winner.is_none_or(|(winner_size, pattern, _)| {
+                    size < winner_size || size == winner_size && match_.pattern_index < pattern
+                }) {
+                    *winner = Some((size, match_.pattern_index, capture.index));
+                }
+            }
+        }
+
+        let mut highlights = Vec::new();
+        let mut offset = 0;
+
+        for run in winners
+            .chunk_by(|a, b| a.map(|(.., capture)| capture) == b.map(|(.., capture)| capture))
+        {
+            if let Some((.., capture)) = run[0]
+                && let Some(style) = self.styles[capture as usize]
+            {
+                highlights.push((offset..offset + run.len(), Highlight(style)));
+            }
+
+            offset += run.len();
+        }
+
+        highlights
+    }
+}
+
+impl std::fmt::Debug for SyntaxTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyntaxTree")
+            .field("tree", &self.tree)
+            .field("current_line", &self.current_line)
+            .finish()
+    }
+}
+
+/// Returns the style of every capture of the highlights query of a
+/// [`Grammar`] in the theme of the [`Settings`].
+fn styles(grammar: &Grammar, settings: &Settings) -> Vec<Option<highlighting::StyleModifier>> {
+    let highlighter = highlighting::Highlighter::new(&THEMES.themes[settings.theme.key()]);
+
+    grammar
+        .query
+        .capture_names()
+        .iter()
+        .map(|name| {
+            let (_, scope) = SCOPES.iter().find(|(capture, _)| {
+                name.strip_prefix(capture)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            })?;
+
+            let scope = parsing::Scope::new(scope).ok()?;
+
+            Some(highlighter.style_mod_for_stack(&[scope]))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Backend, Theme};
+
+    fn settings(token: &str) -> Settings {
+        Settings::new(token, Theme::Base16Ocean).backend(Backend::TreeSitter)
+    }
+
+    fn feed(highlighter: &mut SyntaxTree, lines: &[&str]) -> Vec<Vec<Range<usize>>> {
+        let start = highlighter.current_line();
+
+        lines[start..]
+            .iter()
+            .map(|line| {
+                highlighter
+                    .highlight_line(line)
+                    .filter(|(_, highlight)| highlight.color().is_some())
+                    .map(|(range, _)| range)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn rust(lines: &[&str]) -> SyntaxTree {
+        let mut highlighter = SyntaxTree::new(&settings("rs")).expect("Rust grammar");
+
+        assert!(feed(&mut highlighter, lines).iter().all(Vec::is_empty));
+        assert_eq!(highlighter.poll(), highlighter::Status::Updated);
+        assert_eq!(highlighter.current_line(), 0);
+
+        highlighter
+    }
+
+    #[test]
+    fn grammars_are_valid() {
+        for token in ["rs", "js", "ts", "tsx", "py"] {
+            assert!(SyntaxTree::new(&settings(token)).is_some(), "{token}");
+        }
+
+        assert!(SyntaxTree::new(&settings("txt")).is_none());
+    }
+
+    #[test]
+    fn captures_are_highlighted() {
+        let lines = ["fn main() {", "    let x = 42;", "}"];
+        let mut highlighter = rust(&lines);
+
+        let highlights = feed(&mut highlighter, &lines);
+
+        assert!(highlights[0].contains(&(0..2)));
+        assert!(highlights[1].contains(&(4..7)));
+        assert!(highlights[1].contains(&(12..14)));
+        assert_eq!(highlighter.poll(), highlighter::Status::Idle);
+    }
+
+    #[test]
+    fn edits_reparse_incrementally() {
+        let lines = ["fn main() {", "    let x = 42;", "}"];
+        let mut highlighter = rust(&lines);
+        let _ = feed(&mut highlighter, &lines);
+
+        // An edit within a line is highlighted right away
+        let lines = ["fn main() {", "    // let x = 42;", "}"];
+
+        highlighter.replace_lines(1..2, 1);
+        highlighter.change_line(1);
+
+        assert_eq!(feed(&mut highlighter, &lines), vec![vec![4..18], vec![]]);
+        assert_eq!(highlighter.source, "fn main() {\n    // let x = 42;\n}\n");
+
+        // A line break is highlighted once both halves are known
+        let lines = ["fn main() {", "    // let x", "    = 42;", "}"];
+
+        highlighter.replace_lines(1..2, 2);
+        highlighter.change_line(1);
+
+        let highlights = feed(&mut highlighter, &lines);
+
+        assert!(highlights[0].is_empty());
+        assert!(highlights[1].contains(&(6..8)));
+        assert_eq!(highlighter.poll(), highlighter::Status::Updated);
+        assert_eq!(highlighter.current_line(), 1);
+        assert_eq!(feed(&mut highlighter, &lines)[0], vec![4..12]);
+        assert_eq!(
+            highlighter.source,
+            "fn main() {\n    // let x\n    = 42;\n}\n"
+        );
+    }
+}
@@ -180,6 +180,17 @@ where
         delegate!(self, renderer, renderer.measure_image(handle))
     }
 
+    fn load_frames(&self, handle: &Self::Handle) -> Result<image::Frames<A::Handle>, image::Error> {
+        delegate!(self, renderer, renderer.load_frames(handle))
+    }
+
+    fn frames(
+        &self,
+        handle: &Self::Handle,
+    ) -> Option<Result<image::Frames<A::Handle>, image::Error>> {
+        delegate!(self, renderer, renderer.frames(handle))
+    }
+
     fn draw_image(&mut self, image: Image<A::Handle>, bounds: Rectangle, clip_bounds: Rectangle) {
         delegate!(
             self,
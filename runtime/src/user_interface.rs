@@ -4,9 +4,10 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::touch;
 use crate::core::widget;
 use crate::core::window;
-use crate::core::{Clipboard, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector};
+use crate::core::{Clipboard, Drag, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector};
 
 /// A set of interactive graphical elements with a specific [`Layout`].
 ///
@@ -26,6 +27,7 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<Overlay>,
     bounds: Size,
+    drag: Option<Drag>,
 }
 
 struct Overlay {
@@ -96,7 +98,7 @@ where
     ) -> Self {
         let mut root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache { mut state, drag } = cache;
         state.diff(root.as_widget());
 
         let base = root.as_widget_mut().layout(
@@ -111,6 +113,7 @@ where
             state,
             overlay: None,
             bounds,
+            drag,
         }
     }
 
@@ -210,7 +213,7 @@ where
             let mut event_statuses = Vec::new();
 
             for event in events {
-                let mut shell = Shell::new(messages);
+                let mut shell = Shell::new(messages).with_drag(self.drag.clone());
 
                 overlay.update(event, Layout::new(&layout), cursor, renderer, &mut shell);
 
@@ -219,6 +222,11 @@ where
                 input_method.merge(shell.input_method());
                 clipboard.merge(shell.clipboard_mut());
 
+                if shell.is_drag_changed() {
+                    self.drag = shell.drag().cloned();
+                    redraw_request = window::RedrawRequest::NextFrame;
+                }
+
                 if shell.is_layout_invalid() {
                     drop(maybe_overlay);
 
@@ -298,11 +306,24 @@ where
             .iter()
             .zip(overlay_statuses)
             .map(|(event, overlay_status)| {
+                // Drags end when the pointer is released, after every widget has seen it
+                let drag = if is_pointer_released(event) {
+                    let drag = self.drag.take();
+
+                    if drag.is_some() {
+                        redraw_request = window::RedrawRequest::NextFrame;
+                    }
+
+                    drag
+                } else {
+                    self.drag.clone()
+                };
+
                 if matches!(overlay_status, event::Status::Captured) {
                     return overlay_status;
                 }
 
-                let mut shell = Shell::new(messages);
+                let mut shell = Shell::new(messages).with_drag(drag.clone());
 
                 self.root.as_widget_mut().update(
                     &mut self.state,
@@ -322,6 +343,11 @@ where
                 input_method.merge(shell.input_method());
                 clipboard.merge(shell.clipboard_mut());
 
+                if shell.is_drag_changed() {
+                    self.drag = shell.drag().cloned();
+                    redraw_request = window::RedrawRequest::NextFrame;
+                }
+
                 shell.revalidate_layout(|| {
                     has_layout_changed = true;
 
@@ -557,13 +583,24 @@ where
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                drag: self.drag,
+            },
+            renderer,
+        )
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            drag: self.drag,
+        }
     }
 }
 
@@ -571,6 +608,7 @@ where
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    drag: Option<Drag>,
 }
 
 impl Cache {
@@ -581,6 +619,7 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            drag: None,
         }
     }
 }
@@ -624,3 +663,11 @@ impl State {
         }
     }
 }
+
+fn is_pointer_released(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+    )
+}
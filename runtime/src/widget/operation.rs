@@ -53,6 +53,13 @@ pub fn is_focused(id: impl Into<Id>) -> Task<bool> {
     task::widget(operation::focusable::is_focused(id.into()))
 }
 
+/// Returns the [`Id`] of the focused widget, if any.
+pub fn find_focused() -> Task<Option<Id>> {
+    task::widget(operation::focusable::find_focused())
+        .collect()
+        .map(|focused| focused.into_iter().next())
+}
+
 /// Focuses the widget with the given [`Id`].
 pub fn focus<T>(id: impl Into<Id>) -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus(id.into())))
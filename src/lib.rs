@@ -590,8 +590,9 @@ pub mod event {
 pub mod keyboard {
     //! Listen and react to keyboard events.
     pub use crate::core::keyboard::key;
-    pub use crate::core::keyboard::{Event, Key, Location, Modifiers};
-    pub use iced_futures::keyboard::listen;
+    pub use crate::core::keyboard::shortcut;
+    pub use crate::core::keyboard::{Event, Key, Location, Modifiers, Shortcut};
+    pub use iced_futures::keyboard::{Binding, Scope, Shortcuts, listen, shortcuts};
 }
 
 pub mod mouse {
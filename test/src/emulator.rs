@@ -2,6 +2,7 @@
 use crate::core;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::theme;
 use crate::core::time::Instant;
 use crate::core::widget;
 use crate::core::window;
@@ -48,7 +49,12 @@ pub enum Event<P: Program> {
     /// An action that must be [performed](Emulator::perform) by the [`Emulator`].
     Action(Action<P>),
     /// An [`Instruction`] failed to be executed.
-    Failed(Instruction),
+    Failed {
+        /// The [`Instruction`] that failed.
+        instruction: Instruction,
+        /// A description of what was found instead.
+        found: String,
+    },
     /// The [`Emulator`] is ready.
     Ready,
 }
@@ -279,17 +285,15 @@ impl<P: Program + 'static> Emulator<P> {
     ///
     /// Otherwise, an [`Event::Failed`] will be triggered.
     pub fn run(&mut self, program: &P, instruction: &Instruction) {
-        let mut user_interface = UserInterface::build(
-            program.view(&self.state, self.window),
-            self.size,
-            self.cache.take().unwrap(),
-            &mut self.renderer,
-        );
-
-        let mut messages = Vec::new();
-
         match instruction {
             Instruction::Interact(interaction) => {
+                let mut user_interface = UserInterface::build(
+                    program.view(&self.state, self.window),
+                    self.size,
+                    self.cache.take().unwrap(),
+                    &mut self.renderer,
+                );
+
                 let Some(events) = interaction.events(|target| match target {
                     instruction::Target::Id(id) => {
                         use widget::Operation;
@@ -327,7 +331,10 @@ impl<P: Program + 'static> Emulator<P> {
                     }
                     instruction::Target::Point(position) => Some(*position),
                 }) else {
-                    self.runtime.send(Event::Failed(instruction.clone()));
+                    self.runtime.send(Event::Failed {
+                        instruction: instruction.clone(),
+                        found: "no matching target".to_owned(),
+                    });
                     self.cache = Some(user_interface.into_cache());
                     return;
                 };
@@ -338,6 +345,8 @@ impl<P: Program + 'static> Emulator<P> {
                     }
                 }
 
+                let mut messages = Vec::new();
+
                 let (_state, _status) =
                     user_interface.update(&events, self.cursor, &mut self.renderer, &mut messages);
 
@@ -354,30 +363,141 @@ impl<P: Program + 'static> Emulator<P> {
                 self.resubscribe(program);
                 self.wait_for(task);
             }
-            Instruction::Expect(expectation) => match expectation {
-                instruction::Expectation::Text(text) => {
-                    use widget::Operation;
+            Instruction::Expect(expectation) => match self.expect(program, expectation) {
+                Ok(()) => {
+                    self.runtime.send(Event::Ready);
+                }
+                Err(found) => {
+                    self.runtime.send(Event::Failed {
+                        instruction: instruction.clone(),
+                        found,
+                    });
+                }
+            },
+        }
+    }
 
-                    let mut operation = Selector::find(text.as_str());
+    /// Checks an [`Expectation`](instruction::Expectation), returning a description
+    /// of what was found instead if it is not met.
+    fn expect(
+        &mut self,
+        program: &P,
+        expectation: &instruction::Expectation,
+    ) -> Result<(), String> {
+        use widget::operation::semantics;
 
-                    user_interface.operate(
-                        &self.renderer,
-                        &mut widget::operation::black_box(&mut operation),
-                    );
+        if let instruction::Expectation::Screenshot(hash) = expectation {
+            use sha2::{Digest, Sha256};
 
-                    match operation.finish() {
-                        widget::operation::Outcome::Some(Some(_text)) => {
-                            self.runtime.send(Event::Ready);
-                        }
-                        _ => {
-                            self.runtime.send(Event::Failed(instruction.clone()));
-                        }
-                    }
+            let theme = self
+                .theme(program)
+                .unwrap_or_else(|| <P::Theme as theme::Base>::default(theme::Mode::None));
 
-                    self.cache = Some(user_interface.into_cache());
-                }
-            },
+            let screenshot = self.screenshot(program, &theme, 1.0);
+
+            let found = {
+                let mut hasher = Sha256::new();
+                hasher.update(&screenshot.rgba);
+                format!("{:x}", hasher.finalize())
+            };
+
+            return if found == *hash {
+                Ok(())
+            } else {
+                Err(format!("the hash {found}"))
+            };
         }
+
+        let mut user_interface = UserInterface::build(
+            program.view(&self.state, self.window),
+            self.size,
+            self.cache.take().unwrap(),
+            &mut self.renderer,
+        );
+
+        let mut count_text = |text: &str| {
+            use widget::Operation;
+
+            let mut operation = Selector::find_all(text);
+
+            user_interface.operate(
+                &self.renderer,
+                &mut widget::operation::black_box(&mut operation),
+            );
+
+            match operation.finish() {
+                widget::operation::Outcome::Some(texts) => texts.len(),
+                _ => 0,
+            }
+        };
+
+        let result = match expectation {
+            instruction::Expectation::Text(text) => match count_text(text) {
+                0 => Err("no matching text".to_owned()),
+                _ => Ok(()),
+            },
+            instruction::Expectation::NoText(text) => match count_text(text) {
+                0 => Ok(()),
+                count => Err(format!("{count} matching text(s)")),
+            },
+            instruction::Expectation::TextCount { text, count } => match count_text(text) {
+                found if found == *count => Ok(()),
+                found => Err(format!("{found} matching text(s)")),
+            },
+            instruction::Expectation::Focused { id, is_focused } => self
+                .semantics(&mut user_interface, id)
+                .and_then(|semantics| match semantics.is_focused {
+                    Some(found) if found == *is_focused => Ok(()),
+                    Some(true) => Err("a focused widget".to_owned()),
+                    Some(false) => Err("an unfocused widget".to_owned()),
+                    None => Err("a widget that cannot be focused".to_owned()),
+                }),
+            instruction::Expectation::Toggled { id, is_toggled } => self
+                .semantics(&mut user_interface, id)
+                .and_then(|semantics| match semantics.is_toggled {
+                    Some(found) if found == *is_toggled => Ok(()),
+                    Some(true) => Err("a toggled widget".to_owned()),
+                    Some(false) => Err("an untoggled widget".to_owned()),
+                    None => Err("a widget that cannot be toggled".to_owned()),
+                }),
+            instruction::Expectation::Value { id, value } => self
+                .semantics(&mut user_interface, id)
+                .and_then(|semantics| match semantics.value {
+                    Some(semantics::Value::Text(text)) if text == *value => Ok(()),
+                    Some(found) => Err(format!("the value {found}")),
+                    None => Err("a widget without a value".to_owned()),
+                }),
+            instruction::Expectation::Screenshot(_) => Ok(()),
+        };
+
+        self.cache = Some(user_interface.into_cache());
+
+        result
+    }
+
+    /// Returns the [`Semantics`](widget::operation::semantics::Semantics) of the
+    /// widget with the given id, or a description of the failure.
+    fn semantics(
+        &self,
+        user_interface: &mut UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+        id: &str,
+    ) -> Result<widget::operation::semantics::Semantics<'static>, String> {
+        use widget::Operation;
+
+        let mut operation = widget::operation::semantics::tree();
+
+        user_interface.operate(
+            &self.renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        let widget::operation::Outcome::Some(tree) = operation.finish() else {
+            return Err("no semantics".to_owned());
+        };
+
+        tree.find(&widget::Id::from(id.to_owned()))
+            .map(|node| node.semantics.clone())
+            .ok_or_else(|| "no widget with a matching id".to_owned())
     }
 
     fn wait_for(&mut self, task: Task<P::Message>) {
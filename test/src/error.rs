@@ -38,12 +38,14 @@ pub enum Error {
         error: ice::ParseError,
     },
     /// The execution of an [`Ice`](crate::Ice) test failed.
-    #[error("the ice test ({file}) failed")]
+    #[error("the ice test ({file}) failed at `{instruction}` (found {found})")]
     IceTestingFailed {
         /// The path of the test.
         file: PathBuf,
         /// The [`Instruction`] that failed.
         instruction: Instruction,
+        /// A description of what was found instead.
+        found: String,
     },
     /// The [`Preset`](crate::program::Preset) of a program could not be found.
     #[error("the preset \"{name}\" does not exist (available presets: {available:?})")]
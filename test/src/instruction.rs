@@ -445,6 +445,14 @@ mod format {
     pub fn id(id: &str) -> String {
         format!("#{id}")
     }
+
+    pub fn flag(name: &str, is_set: bool) -> String {
+        if is_set {
+            name.to_owned()
+        } else {
+            format!("not {name}")
+        }
+    }
 }
 
 /// A testing assertion.
@@ -455,6 +463,55 @@ mod format {
 pub enum Expectation {
     /// Expect some element to contain some text.
     Text(String),
+    /// Expect no element to contain some text.
+    NoText(String),
+    /// Expect an exact amount of elements to contain some text.
+    TextCount {
+        /// The text to look for.
+        text: String,
+        /// The expected amount of elements containing the text.
+        count: usize,
+    },
+    /// Expect the widget with the given id to be focused, or not.
+    Focused {
+        /// The id of the widget.
+        id: String,
+        /// Whether the widget is expected to be focused.
+        is_focused: bool,
+    },
+    /// Expect the widget with the given id (e.g. a checkbox or a toggler)
+    /// to be toggled, or not.
+    Toggled {
+        /// The id of the widget.
+        id: String,
+        /// Whether the widget is expected to be toggled.
+        is_toggled: bool,
+    },
+    /// Expect the widget with the given id (e.g. a text input) to have
+    /// some value.
+    Value {
+        /// The id of the widget.
+        id: String,
+        /// The expected value of the widget.
+        value: String,
+    },
+    /// Expect the SHA-256 hash of a screenshot of the user interface to
+    /// match the given lowercase hexadecimal digest.
+    ///
+    /// Use [`Expectation::screenshot`] to validate and normalize a digest.
+    Screenshot(String),
+}
+
+impl Expectation {
+    /// Creates an [`Expectation::Screenshot`] from the given SHA-256
+    /// hexadecimal digest, normalized to lowercase.
+    ///
+    /// Returns `None` if the digest is not made of exactly 64 hexadecimal
+    /// digits.
+    pub fn screenshot(hash: &str) -> Option<Self> {
+        (hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+            .then(|| Self::Screenshot(hash.to_ascii_lowercase()))
+    }
 }
 
 impl fmt::Display for Expectation {
@@ -463,6 +520,44 @@ impl fmt::Display for Expectation {
             Expectation::Text(text) => {
                 write!(f, "expect {}", format::string(text))
             }
+            Expectation::NoText(text) => {
+                write!(f, "expect no {}", format::string(text))
+            }
+            Expectation::TextCount { text, count } => {
+                write!(
+                    f,
+                    "expect {} {count} {}",
+                    format::string(text),
+                    if *count == 1 { "time" } else { "times" }
+                )
+            }
+            Expectation::Focused { id, is_focused } => {
+                write!(
+                    f,
+                    "expect {} {}",
+                    format::id(id),
+                    format::flag("focused", *is_focused)
+                )
+            }
+            Expectation::Toggled { id, is_toggled } => {
+                write!(
+                    f,
+                    "expect {} {}",
+                    format::id(id),
+                    format::flag("toggled", *is_toggled)
+                )
+            }
+            Expectation::Value { id, value } => {
+                write!(
+                    f,
+                    "expect {} value {}",
+                    format::id(id),
+                    format::string(value)
+                )
+            }
+            Expectation::Screenshot(hash) => {
+                write!(f, "expect screenshot {hash}")
+            }
         }
     }
 }
@@ -475,7 +570,9 @@ mod parser {
     use nom::branch::alt;
     use nom::bytes::complete::tag;
     use nom::bytes::{is_not, take_while_m_n};
-    use nom::character::complete::{alphanumeric1, char, multispace0, multispace1};
+    use nom::character::complete::{
+        alphanumeric1, char, hex_digit1, multispace0, multispace1, usize,
+    };
     use nom::combinator::{map, map_opt, map_res, opt, recognize, success, value, verify};
     use nom::error::ParseError;
     use nom::multi::{fold, many1_count};
@@ -571,12 +668,79 @@ mod parser {
     }
 
     fn expectation(input: &str) -> IResult<&str, Expectation> {
-        map(preceded(tag("expect "), string), |text| {
-            Expectation::Text(text)
-        })
+        preceded(
+            tag("expect "),
+            alt((
+                expect_no_text,
+                expect_text_count,
+                expect_focused,
+                expect_toggled,
+                expect_value,
+                expect_screenshot,
+                string.map(Expectation::Text),
+            )),
+        )
+        .parse(input)
+    }
+
+    fn expect_no_text(input: &str) -> IResult<&str, Expectation> {
+        preceded(tag("no "), string)
+            .map(Expectation::NoText)
+            .parse(input)
+    }
+
+    fn expect_text_count(input: &str) -> IResult<&str, Expectation> {
+        let (input, text) = string(input)?;
+        let (input, count) = preceded(char(' '), usize).parse(input)?;
+        let (input, _) = alt((tag(" times"), tag(" time"))).parse(input)?;
+
+        Ok((input, Expectation::TextCount { text, count }))
+    }
+
+    fn expect_focused(input: &str) -> IResult<&str, Expectation> {
+        separated_pair(id, char(' '), flag("focused"))
+            .map(|(id, is_focused)| Expectation::Focused {
+                id: id.to_owned(),
+                is_focused,
+            })
+            .parse(input)
+    }
+
+    fn expect_toggled(input: &str) -> IResult<&str, Expectation> {
+        separated_pair(id, char(' '), flag("toggled"))
+            .map(|(id, is_toggled)| Expectation::Toggled {
+                id: id.to_owned(),
+                is_toggled,
+            })
+            .parse(input)
+    }
+
+    fn expect_value(input: &str) -> IResult<&str, Expectation> {
+        separated_pair(id, tag(" value "), string)
+            .map(|(id, value)| Expectation::Value {
+                id: id.to_owned(),
+                value,
+            })
+            .parse(input)
+    }
+
+    fn expect_screenshot(input: &str) -> IResult<&str, Expectation> {
+        map_opt(
+            preceded(tag("screenshot "), hex_digit1),
+            Expectation::screenshot,
+        )
         .parse(input)
     }
 
+    fn flag<'a>(
+        name: &'static str,
+    ) -> impl Parser<&'a str, Output = bool, Error = nom::error::Error<&'a str>> {
+        alt((
+            value(true, tag(name)),
+            value(false, preceded(tag("not "), tag(name))),
+        ))
+    }
+
     fn key(input: &str) -> IResult<&str, Key> {
         alt((
             map(tag("enter"), |_| Key::Enter),
@@ -710,3 +874,79 @@ mod parser {
         delimited(char('"'), build_string, char('"')).parse(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    fn round_trip(expectation: Expectation) {
+        let line = expectation.to_string();
+
+        assert_eq!(
+            Instruction::parse(&line).ok(),
+            Some(Instruction::Expect(expectation)),
+            "{line}"
+        );
+    }
+
+    #[test]
+    fn expectations_round_trip() {
+        round_trip(Expectation::Text("Hello, \"world\"!".to_owned()));
+        round_trip(Expectation::NoText("Goodbye".to_owned()));
+
+        for count in [0, 1, 2] {
+            round_trip(Expectation::TextCount {
+                text: "Item".to_owned(),
+                count,
+            });
+        }
+
+        for is_set in [true, false] {
+            round_trip(Expectation::Focused {
+                id: "name-input".to_owned(),
+                is_focused: is_set,
+            });
+
+            round_trip(Expectation::Toggled {
+                id: "dark_mode".to_owned(),
+                is_toggled: is_set,
+            });
+        }
+
+        round_trip(Expectation::Value {
+            id: "email".to_owned(),
+            value: "ferris@iced.rs".to_owned(),
+        });
+
+        round_trip(Expectation::screenshot(HASH).unwrap());
+    }
+
+    #[test]
+    fn screenshot_hash_is_normalized() {
+        let uppercase = HASH.to_ascii_uppercase();
+
+        assert_eq!(
+            Expectation::screenshot(&uppercase),
+            Some(Expectation::Screenshot(HASH.to_owned()))
+        );
+
+        assert_eq!(
+            Instruction::parse(&format!("expect screenshot {uppercase}")).ok(),
+            Some(Instruction::Expect(Expectation::Screenshot(
+                HASH.to_owned()
+            )))
+        );
+    }
+
+    #[test]
+    fn screenshot_hash_must_have_64_digits() {
+        assert_eq!(Expectation::screenshot(&HASH[1..]), None);
+        assert_eq!(Expectation::screenshot(&format!("{HASH}0")), None);
+        assert_eq!(Expectation::screenshot(&HASH.replace('f', "g")), None);
+
+        assert!(Instruction::parse("expect screenshot abc123").is_err());
+        assert!(Instruction::parse(&format!("expect screenshot {HASH}00")).is_err());
+    }
+}
@@ -201,7 +201,7 @@ pub fn run<P: program::Program + 'static>(
                 emulator::Event::Action(action) => {
                     emulator.perform(&program, action);
                 }
-                emulator::Event::Failed(instruction) => {
+                emulator::Event::Failed { instruction, found } => {
                     fs::create_dir_all(&errors_dir)?;
 
                     let theme = emulator
@@ -239,6 +239,7 @@ pub fn run<P: program::Program + 'static>(
                     return Err(Error::IceTestingFailed {
                         file: file.path().to_path_buf(),
                         instruction,
+                        found,
                     });
                 }
                 emulator::Event::Ready => {
@@ -279,7 +280,7 @@ pub fn screenshot<P: program::Program + 'static>(
                 emulator::Event::Action(action) => {
                     emulator.perform(program, action);
                 }
-                emulator::Event::Failed(_) => {
+                emulator::Event::Failed { .. } => {
                     unreachable!("no instructions should be executed during a screenshot");
                 }
                 emulator::Event::Ready => {}
@@ -1,5 +1,6 @@
 //! Run a simulation of your application without side effects.
 use crate::core;
+use crate::core::clipboard;
 use crate::core::event;
 use crate::core::keyboard;
 use crate::core::mouse;
@@ -7,7 +8,7 @@ use crate::core::theme;
 use crate::core::time;
 use crate::core::widget;
 use crate::core::window;
-use crate::core::{Element, Event, Point, Settings, Size, SmolStr};
+use crate::core::{Element, Event, Point, Rectangle, Settings, Size, SmolStr};
 use crate::renderer;
 use crate::runtime::UserInterface;
 use crate::runtime::user_interface;
@@ -28,6 +29,7 @@ pub struct Simulator<'a, Message, Theme = core::Theme, Renderer = renderer::Rend
     size: Size,
     cursor: mouse::Cursor,
     messages: Vec<Message>,
+    clipboard: Option<clipboard::Content>,
 }
 
 impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
@@ -86,6 +88,7 @@ where
             size,
             cursor: mouse::Cursor::Unavailable,
             messages: Vec::new(),
+            clipboard: None,
         }
     }
 
@@ -115,6 +118,55 @@ where
         }
     }
 
+    /// Finds the [`Bounded`] target of the given widget [`Selector`] in the
+    /// [`Simulator`] and returns its layout bounds.
+    pub fn bounds_of<S>(&mut self, selector: S) -> Result<Rectangle, Error>
+    where
+        S: Selector + Send,
+        S::Output: Bounded + Clone + Send,
+    {
+        self.find(selector).map(|target| target.bounds())
+    }
+
+    /// Runs the given [`widget::Operation`] in the [`Simulator`], together with
+    /// any operations chained to it.
+    ///
+    /// This is how the runtime runs the operations of a [`Task`].
+    ///
+    /// [`Task`]: crate::runtime::Task
+    pub fn operate(&mut self, operation: impl widget::Operation + 'static) {
+        let mut current: Option<Box<dyn widget::Operation>> = Some(Box::new(operation));
+
+        while let Some(mut operation) = current.take() {
+            self.raw.operate(&self.renderer, operation.as_mut());
+
+            if let widget::operation::Outcome::Chain(next) = operation.finish() {
+                current = Some(next);
+            }
+        }
+    }
+
+    /// Builds the semantic [`Tree`] of the interface in the [`Simulator`].
+    ///
+    /// This is the same tree that is exposed to assistive technologies.
+    ///
+    /// [`Tree`]: widget::operation::semantics::Tree
+    pub fn semantics(&mut self) -> widget::operation::semantics::Tree {
+        use widget::Operation;
+
+        let mut operation = widget::operation::semantics::tree();
+
+        self.raw.operate(
+            &self.renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(tree) => tree,
+            _ => widget::operation::semantics::Tree::default(),
+        }
+    }
+
     /// Points the mouse cursor at the given position in the [`Simulator`].
     ///
     /// This does _not_ produce mouse movement events!
@@ -155,6 +207,28 @@ where
             .unwrap_or(event::Status::Ignored)
     }
 
+    /// Simulates a key press while holding the given [`keyboard::Modifiers`]
+    /// in the [`Simulator`].
+    pub fn press_key(
+        &mut self,
+        key: impl Into<keyboard::Key>,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        let mut event = press_key(key, None);
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            modifiers: pressed, ..
+        }) = &mut event
+        {
+            *pressed = modifiers;
+        }
+
+        self.simulate([event])
+            .first()
+            .copied()
+            .unwrap_or(event::Status::Ignored)
+    }
+
     /// Simulates a user typing in the keyboard the given text in the [`Simulator`].
     pub fn typewrite(&mut self, text: &str) -> event::Status {
         let statuses = self.simulate(typewrite(text));
@@ -168,13 +242,31 @@ where
     pub fn simulate(&mut self, events: impl IntoIterator<Item = Event>) -> Vec<event::Status> {
         let events: Vec<Event> = events.into_iter().collect();
 
-        let (_state, statuses) =
+        let (state, statuses) =
             self.raw
                 .update(&events, self.cursor, &mut self.renderer, &mut self.messages);
 
+        if let user_interface::State::Updated {
+            clipboard:
+                clipboard::Clipboard {
+                    write: Some(content),
+                    ..
+                },
+            ..
+        } = state
+        {
+            self.clipboard = Some(content);
+        }
+
         statuses
     }
 
+    /// Returns the latest [`clipboard::Content`] written to the clipboard by the
+    /// interactions in the [`Simulator`], if any.
+    pub fn clipboard(&self) -> Option<&clipboard::Content> {
+        self.clipboard.as_ref()
+    }
+
     /// Draws and takes a [`Snapshot`] of the interface in the [`Simulator`].
     pub fn snapshot(&mut self, theme: &Theme) -> Result<Snapshot, Error> {
         let base = theme.base();
@@ -228,6 +320,11 @@ pub struct Snapshot {
 }
 
 impl Snapshot {
+    /// Returns the [`window::Screenshot`] of the [`Snapshot`].
+    pub fn screenshot(&self) -> &window::Screenshot {
+        &self.screenshot
+    }
+
     /// Compares the [`Snapshot`] with the PNG image found in the given path, returning
     /// `true` if they are identical.
     ///
@@ -0,0 +1,96 @@
+//! Tests the [`Animated`](iced_widget::image::Animated) image widget with the
+//! [`Simulator`](iced_test::Simulator).
+use iced_test::Simulator;
+use iced_test::core::time::{Duration, Instant};
+use iced_test::core::{Event, Size, Theme, window};
+use iced_widget::image;
+
+/// A 1x1 GIF looping over a red and a blue frame of 100ms each.
+const GIF: &[u8] = &[
+    b'G', b'I', b'F', b'8', b'9', b'a', 0x01, 0x00, 0x01, 0x00, 0x80, 0x00, 0x00, // Header
+    0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, // Global color table
+    0x21, 0xFF, 0x0B, b'N', b'E', b'T', b'S', b'C', b'A', b'P', b'E', b'2', b'.', b'0', 0x03, 0x01,
+    0x00, 0x00, 0x00, // Loop forever
+    0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00, 0x00, // 100ms
+    0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, // Frame
+    0x02, 0x02, 0x44, 0x01, 0x00, // Red
+    0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00, 0x00, // 100ms
+    0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, // Frame
+    0x02, 0x02, 0x4C, 0x01, 0x00, // Blue
+    0x3B,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Blue,
+}
+
+fn simulator(animated: image::Animated) -> Simulator<'static, ()> {
+    Simulator::with_size(
+        iced_test::core::Settings::default(),
+        Size::new(10.0, 10.0),
+        animated.width(10).height(10),
+    )
+}
+
+fn color(ui: &mut Simulator<'_, ()>) -> Color {
+    let snapshot = ui.snapshot(&Theme::Light).expect("Take snapshot");
+    let screenshot = snapshot.screenshot();
+
+    let center = (screenshot.size.height / 2 * screenshot.size.width + screenshot.size.width / 2)
+        as usize
+        * 4;
+
+    match &screenshot.rgba[center..center + 3] {
+        [255, 0, 0] => Color::Red,
+        [0, 0, 255] => Color::Blue,
+        pixel => panic!("unexpected pixel: {pixel:?}"),
+    }
+}
+
+fn tick(ui: &mut Simulator<'_, ()>, at: Instant) {
+    let _ = ui.simulate([Event::Window(window::Event::RedrawRequested(at))]);
+}
+
+fn animated() -> image::Animated {
+    image::animated(image::Handle::from_bytes(GIF))
+}
+
+#[test]
+fn it_plays_and_loops_frames() {
+    let mut ui = simulator(animated());
+    let start = Instant::now();
+
+    tick(&mut ui, start);
+    assert_eq!(color(&mut ui), Color::Red);
+
+    tick(&mut ui, start + Duration::from_millis(150));
+    assert_eq!(color(&mut ui), Color::Blue);
+
+    tick(&mut ui, start + Duration::from_millis(250));
+    assert_eq!(color(&mut ui), Color::Red);
+}
+
+#[test]
+fn it_stops_at_the_last_frame_without_looping() {
+    let mut ui = simulator(animated().looping(false));
+    let start = Instant::now();
+
+    tick(&mut ui, start);
+    tick(&mut ui, start + Duration::from_millis(250));
+    assert_eq!(color(&mut ui), Color::Blue);
+
+    tick(&mut ui, start + Duration::from_millis(1000));
+    assert_eq!(color(&mut ui), Color::Blue);
+}
+
+#[test]
+fn it_does_not_advance_while_paused() {
+    let mut ui = simulator(animated().playing(false));
+    let start = Instant::now();
+
+    tick(&mut ui, start);
+    tick(&mut ui, start + Duration::from_millis(150));
+    assert_eq!(color(&mut ui), Color::Red);
+}
@@ -0,0 +1,144 @@
+//! Tests editing the attributes of the text in a [`Content`].
+use iced_widget::core::Color;
+use iced_widget::text_editor::{
+    Action, Attribute, Attributes, Content, Cursor, Edit, Motion, Position,
+};
+
+use std::sync::Arc;
+
+type Renderer = iced_renderer::Renderer;
+
+fn at(line: usize, column: usize) -> Position {
+    Position { line, column }
+}
+
+fn select(content: &mut Content<Renderer>, from: Position, to: Position) {
+    content.move_to(Cursor {
+        position: to,
+        selection: Some(from),
+    });
+}
+
+fn bold() -> Attributes {
+    Attributes {
+        weight: Some(iced_widget::core::font::Weight::Bold),
+        ..Attributes::default()
+    }
+}
+
+fn run(text: &str, attributes: Attributes) -> (String, Attributes) {
+    (text.to_owned(), attributes)
+}
+
+#[test]
+fn it_toggles_attributes_on_the_selection() {
+    let mut content =
+        Content::<Renderer>::with_attributed_text([("one two three", Attributes::default())]);
+
+    select(&mut content, at(0, 4), at(0, 7));
+    content.toggle(Attribute::Bold);
+
+    assert!(content.attributes().is_bold());
+    assert_eq!(
+        content.attributed_text(),
+        vec![
+            run("one ", Attributes::default()),
+            run("two", bold()),
+            run(" three", Attributes::default()),
+        ]
+    );
+
+    // Partially bold selections become fully bold first
+    select(&mut content, at(0, 0), at(0, 7));
+    content.toggle(Attribute::Bold);
+    assert_eq!(
+        content.attributed_text(),
+        vec![run("one two", bold()), run(" three", Attributes::default())]
+    );
+
+    content.toggle(Attribute::Bold);
+    assert_eq!(
+        content.attributed_text(),
+        vec![run("one two three", Attributes::default())]
+    );
+}
+
+#[test]
+fn it_keeps_attributes_while_editing() {
+    let mut content = Content::<Renderer>::with_attributed_text([
+        ("plain ", Attributes::default()),
+        ("bold", bold()),
+    ]);
+
+    content.perform(Action::Move(Motion::DocumentEnd));
+    content.perform(Action::Edit(Edit::Insert('!')));
+
+    content.move_to(Cursor {
+        position: at(0, 0),
+        selection: None,
+    });
+    content.perform(Action::Edit(Edit::Enter));
+
+    assert_eq!(
+        content.attributed_text(),
+        vec![run("\nplain ", Attributes::default()), run("bold!", bold())]
+    );
+
+    content.undo();
+    content.undo();
+
+    assert_eq!(
+        content.attributed_text(),
+        vec![run("plain ", Attributes::default()), run("bold", bold())]
+    );
+}
+
+#[test]
+fn it_applies_toggled_attributes_to_typed_text() {
+    let red = Color::from_rgb(1.0, 0.0, 0.0);
+    let mut content = Content::<Renderer>::with_attributed_text([("a", Attributes::default())]);
+
+    content.perform(Action::Move(Motion::DocumentEnd));
+    content.toggle(Attribute::Color(red));
+    assert_eq!(content.attributes().color, Some(red));
+
+    content.perform(Action::Edit(Edit::Paste(Arc::new(String::from("bc")))));
+    content.perform(Action::Edit(Edit::Insert('d')));
+
+    assert_eq!(
+        content.attributed_text(),
+        vec![
+            run("a", Attributes::default()),
+            run(
+                "bcd",
+                Attributes {
+                    color: Some(red),
+                    ..Attributes::default()
+                }
+            ),
+        ]
+    );
+
+    // Moving the cursor forgets toggled attributes
+    content.toggle(Attribute::Color(red));
+    content.perform(Action::Move(Motion::Left));
+    assert_eq!(content.attributes().color, Some(red));
+}
+
+#[test]
+fn it_round_trips_html() {
+    let content = Content::<Renderer>::with_html(
+        "<p>A <b>bold</b> <a href=\"https://iced.rs\">link</a></p><p><i>italic</i></p>",
+    );
+
+    assert_eq!(content.text(), "A bold link\nitalic");
+    assert_eq!(
+        content.to_markdown(),
+        "A **bold** [link](https://iced.rs)\\\n*italic*"
+    );
+
+    let clipboard = content.to_clipboard();
+    let pasted = Content::<Renderer>::from_clipboard(&clipboard).expect("Parse clipboard");
+
+    assert_eq!(pasted.attributed_text(), content.attributed_text());
+}
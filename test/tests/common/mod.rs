@@ -0,0 +1,7 @@
+//! Helpers shared by the tests of the [`Simulator`](iced_test::Simulator).
+
+/// Asserts that two logical coordinates are equal, up to rounding errors.
+#[track_caller]
+pub fn assert_near(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.01, "{a} is not near {b}");
+}
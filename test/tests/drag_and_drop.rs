@@ -0,0 +1,209 @@
+//! Tests the [`Draggable`](iced_widget::Draggable) and [`DropZone`](iced_widget::DropZone)
+//! widgets with the [`Simulator`].
+use iced_test::core::keyboard::{self, key};
+use iced_test::core::time::{Duration, Instant};
+use iced_test::core::{Element, Event, Length, Point, Settings, Size, Theme, mouse, window};
+use iced_test::{Simulator, simulator};
+use iced_widget::{column, draggable, drop_zone, scrollable, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Drag,
+    Enter(u32),
+    Leave,
+    Drop(u32),
+    Cancel,
+}
+
+fn board<'a>() -> Element<'a, Message, Theme, iced_widget::Renderer> {
+    column![
+        draggable(text("Card"), 7_u32)
+            .on_drag(Message::Drag)
+            .on_cancel(Message::Cancel),
+        text("Gap"),
+        drop_zone(text("Done"))
+            .on_enter(Message::Enter)
+            .on_leave(Message::Leave)
+            .on_drop(Message::Drop),
+    ]
+    .spacing(20)
+    .into()
+}
+
+fn center(ui: &mut Simulator<'_, Message>, label: &str) -> Point {
+    ui.find(label)
+        .expect("Label must be visible")
+        .bounds()
+        .center()
+}
+
+fn press(ui: &mut Simulator<'_, Message>, position: Point) {
+    ui.point_at(position);
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+}
+
+fn move_to(ui: &mut Simulator<'_, Message>, position: Point) {
+    ui.point_at(position);
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+}
+
+fn release(ui: &mut Simulator<'_, Message>) {
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    ))]);
+}
+
+#[test]
+fn it_drops_payloads() {
+    let mut ui = simulator(board());
+
+    let card = center(&mut ui, "Card");
+    let gap = center(&mut ui, "Gap");
+    let done = center(&mut ui, "Done");
+
+    press(&mut ui, card);
+    move_to(&mut ui, gap);
+    move_to(&mut ui, done);
+    move_to(&mut ui, gap);
+    move_to(&mut ui, done);
+    release(&mut ui);
+
+    // The drag is over once released
+    move_to(&mut ui, gap);
+    move_to(&mut ui, done);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [
+            Message::Drag,
+            Message::Enter(7),
+            Message::Leave,
+            Message::Enter(7),
+            Message::Drop(7),
+        ]
+    );
+}
+
+#[test]
+fn it_waits_for_the_pointer_to_move() {
+    let mut ui = simulator(board());
+
+    let card = center(&mut ui, "Card");
+    let done = center(&mut ui, "Done");
+
+    // A click does not start a drag
+    let _ = ui.click("Card").expect("Card must be visible");
+
+    press(&mut ui, card);
+    move_to(&mut ui, Point::new(card.x + 2.0, card.y));
+    release(&mut ui);
+
+    // Releasing outside of the drop zone does not drop
+    press(&mut ui, card);
+    move_to(&mut ui, done);
+    move_to(&mut ui, card);
+    release(&mut ui);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Drag, Message::Enter(7), Message::Leave]
+    );
+}
+
+#[test]
+fn it_ignores_other_payloads() {
+    let mut ui: Simulator<'_, Message> = simulator(column![
+        draggable(text("Card"), "card"),
+        text("Gap"),
+        drop_zone(text("Done")).on_drop(Message::Drop),
+    ]);
+
+    let card = center(&mut ui, "Card");
+    let done = center(&mut ui, "Done");
+
+    press(&mut ui, card);
+    move_to(&mut ui, done);
+    release(&mut ui);
+
+    assert_eq!(ui.into_messages().count(), 0);
+}
+
+#[test]
+fn it_cancels_drags_with_escape() {
+    let mut ui = simulator(board());
+
+    let card = center(&mut ui, "Card");
+    let done = center(&mut ui, "Done");
+
+    press(&mut ui, card);
+    move_to(&mut ui, done);
+
+    let key = keyboard::Key::Named(key::Named::Escape);
+
+    let _ = ui.simulate([Event::Keyboard(keyboard::Event::KeyPressed {
+        key: key.clone(),
+        modified_key: key,
+        physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+        location: keyboard::Location::Standard,
+        modifiers: keyboard::Modifiers::empty(),
+        text: None,
+        repeat: false,
+    })]);
+
+    move_to(&mut ui, done);
+    release(&mut ui);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [
+            Message::Drag,
+            Message::Enter(7),
+            Message::Cancel,
+            Message::Leave
+        ]
+    );
+}
+
+#[test]
+fn it_scrolls_near_the_edges_while_dragging() {
+    let mut ui: Simulator<'_, Message> = Simulator::with_size(
+        Settings::default(),
+        Size::new(300.0, 200.0),
+        scrollable(column(
+            std::iter::once(draggable(text("Card"), 7_u32).into())
+                .chain((0..50).map(|i| text!("Task {i}").into())),
+        ))
+        .height(Length::Fixed(200.0)),
+    );
+
+    let card = center(&mut ui, "Card");
+    let before = ui
+        .find("Task 10")
+        .expect("Task 10 must be visible")
+        .bounds();
+
+    press(&mut ui, card);
+    move_to(&mut ui, Point::new(card.x, 195.0));
+
+    let now = Instant::now();
+
+    for frame in [now, now + Duration::from_millis(500)] {
+        let _ = ui.simulate([Event::Window(window::Event::RedrawRequested(frame))]);
+    }
+
+    let after = ui
+        .semantics()
+        .iter()
+        .find(|node| node.semantics.label.as_deref() == Some("Task 10"))
+        .map(|node| node.bounds)
+        .expect("Task 10 must be described");
+
+    assert!(
+        after.y < before.y - 100.0,
+        "Task 10 moved from {before:?} to {after:?}"
+    );
+}
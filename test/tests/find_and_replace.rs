@@ -0,0 +1,68 @@
+//! Tests searching and replacing text in a [`Content`].
+use iced_widget::text_editor::{Content, Cursor, Position, Search};
+
+fn at(line: usize, column: usize) -> Position {
+    Position { line, column }
+}
+
+#[test]
+fn it_finds_matches_across_lines() {
+    let content = Content::<iced_renderer::Renderer>::with_text("let a = 1;\nlet b = a;\n");
+
+    assert_eq!(
+        content.find(&Search::new("a")),
+        vec![at(0, 4)..at(0, 5), at(1, 8)..at(1, 9)]
+    );
+
+    assert_eq!(
+        content.find(&Search::new(";\nlet")),
+        vec![at(0, 9)..at(1, 3)]
+    );
+}
+
+#[test]
+fn it_selects_the_next_match_and_wraps_around() {
+    let mut content = Content::<iced_renderer::Renderer>::with_text("one two one two");
+    let search = Search::new("two");
+
+    content.select_next_match(&search);
+    assert_eq!(content.selection().as_deref(), Some("two"));
+    assert_eq!(content.cursor().position, at(0, 7));
+
+    content.select_next_match(&search);
+    assert_eq!(content.cursor().position, at(0, 15));
+
+    content.select_next_match(&search);
+    assert_eq!(content.cursor().position, at(0, 7));
+}
+
+#[test]
+fn it_replaces_matches_with_history() {
+    let mut content = Content::<iced_renderer::Renderer>::with_text("one two\nthree two\ntwo");
+    let search = Search::new("two");
+
+    // The first replace only selects a match
+    content.replace(&search, "2");
+    assert_eq!(content.text(), "one two\nthree two\ntwo");
+
+    content.replace(&search, "2");
+    assert_eq!(content.text(), "one 2\nthree two\ntwo");
+    assert_eq!(
+        content.cursor(),
+        Cursor {
+            position: at(1, 9),
+            selection: Some(at(1, 6)),
+        }
+    );
+
+    content.replace_all(&search, "TWO");
+    assert_eq!(content.text(), "one 2\nthree TWO\nTWO");
+    assert_eq!(content.cursors().len(), 1);
+    assert!(content.find(&search).is_empty());
+
+    content.undo();
+    assert_eq!(content.text(), "one 2\nthree two\ntwo");
+
+    content.undo();
+    assert_eq!(content.text(), "one two\nthree two\ntwo");
+}
@@ -0,0 +1,104 @@
+//! Tests the wrapping [`Row`](iced_widget::Row) layout with the [`Simulator`].
+mod common;
+
+use common::assert_near;
+use iced_test::Simulator;
+use iced_test::core::alignment::Horizontal;
+use iced_test::core::{Length, Settings, Size, Theme};
+use iced_widget::{container, flow, row, scrollable, text};
+
+const TAGS: [&str; 5] = ["Alpha", "Beta", "Gamma", "Delta", "Epsilon"];
+
+fn tags<'a>() -> row::Wrapping<'a, (), Theme, iced_widget::Renderer> {
+    flow(
+        TAGS.into_iter()
+            .map(|tag| container(text(tag)).width(90).into()),
+    )
+    .spacing(10)
+    .vertical_spacing(20)
+    .width(Length::Fill)
+}
+
+#[test]
+fn it_wraps_lines_with_cross_axis_spacing() {
+    let mut ui = Simulator::with_size(Settings::default(), Size::new(300.0, 200.0), tags());
+
+    let alpha = ui.bounds_of("Alpha").expect("Label must be visible");
+    let gamma = ui.bounds_of("Gamma").expect("Label must be visible");
+    let delta = ui.bounds_of("Delta").expect("Label must be visible");
+    let epsilon = ui.bounds_of("Epsilon").expect("Label must be visible");
+
+    // Three tags fit in the first line
+    assert_eq!(alpha.y, gamma.y);
+    assert_near(gamma.x, 200.0);
+
+    // The rest wrap, separated by the vertical spacing
+    assert_eq!(delta.x, 0.0);
+    assert_near(delta.y, alpha.y + alpha.height + 20.0);
+    assert_near(epsilon.x, 100.0);
+}
+
+#[test]
+fn it_justifies_every_line() {
+    let mut ui = Simulator::with_size(
+        Settings::default(),
+        Size::new(350.0, 200.0),
+        tags().justify(row::Justify::SpaceBetween),
+    );
+
+    let alpha = ui.bounds_of("Alpha").expect("Label must be visible");
+    let beta = ui.bounds_of("Beta").expect("Label must be visible");
+    let gamma = ui.bounds_of("Gamma").expect("Label must be visible");
+    let delta = ui.bounds_of("Delta").expect("Label must be visible");
+    let epsilon = ui.bounds_of("Epsilon").expect("Label must be visible");
+
+    assert_eq!(alpha.x, 0.0);
+    assert_near(beta.x, 130.0);
+    assert_near(gamma.x, 260.0);
+
+    assert_eq!(delta.x, 0.0);
+    assert_near(epsilon.x, 260.0);
+
+    let mut ui = Simulator::with_size(
+        Settings::default(),
+        Size::new(350.0, 200.0),
+        tags().justify(row::Justify::Center),
+    );
+
+    let delta = ui.bounds_of("Delta").expect("Label must be visible");
+    let epsilon = ui.bounds_of("Epsilon").expect("Label must be visible");
+
+    assert_near(delta.x, 80.0);
+    assert_near(epsilon.x, 180.0);
+}
+
+#[test]
+fn it_aligns_lines_within_the_widest_one() {
+    let mut ui = Simulator::with_size(
+        Settings::default(),
+        Size::new(350.0, 200.0),
+        tags().align_x(Horizontal::Center),
+    );
+
+    let alpha = ui.bounds_of("Alpha").expect("Label must be visible");
+    let delta = ui.bounds_of("Delta").expect("Label must be visible");
+    let epsilon = ui.bounds_of("Epsilon").expect("Label must be visible");
+
+    assert_eq!(alpha.x, 0.0);
+    assert_near(delta.x, 50.0);
+    assert_near(epsilon.x, 150.0);
+}
+
+#[test]
+fn it_shrinks_to_its_lines_inside_scrollable() {
+    let mut ui = Simulator::with_size(
+        Settings::default(),
+        Size::new(300.0, 200.0),
+        scrollable(iced_widget::column![tags(), text("Below")].width(Length::Fill)),
+    );
+
+    let delta = ui.bounds_of("Delta").expect("Label must be visible");
+    let below = ui.bounds_of("Below").expect("Label must be visible");
+
+    assert_near(below.y, delta.y + delta.height);
+}
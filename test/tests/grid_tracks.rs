@@ -0,0 +1,56 @@
+//! Tests the tracks of the [`Grid`](iced_widget::Grid) widget with the [`Simulator`].
+mod common;
+
+use common::assert_near;
+use iced_test::Simulator;
+use iced_test::core::{Element, Length, Rectangle, Settings, Size, Theme};
+use iced_widget::grid::Cell;
+use iced_widget::{Grid, text};
+
+fn dashboard<'a>() -> Element<'a, (), Theme, iced_widget::Renderer> {
+    Grid::new()
+        .spacing(10)
+        .height(Length::Fill)
+        .template_columns([Length::Fixed(100.0), Length::Fill, Length::Shrink])
+        .template_rows([Length::Fixed(40.0), Length::Fill])
+        .template_areas(["header header header", "side main ."])
+        .push_at("header", text("Header"))
+        .push_at("side", text("Side"))
+        .push_at(Cell::area("main").center(), text("Main"))
+        .push_at((1, 2), text("Tag"))
+        .push(text("Footer"))
+        .into()
+}
+
+#[test]
+fn it_places_cells_on_tracks() {
+    let mut ui = Simulator::with_size(Settings::default(), Size::new(400.0, 300.0), dashboard());
+
+    let header = ui.bounds_of("Header").expect("Label must be visible");
+    let side = ui.bounds_of("Side").expect("Label must be visible");
+    let main = ui.bounds_of("Main").expect("Label must be visible");
+    let tag = ui.bounds_of("Tag").expect("Label must be visible");
+    let footer = ui.bounds_of("Footer").expect("Label must be visible");
+
+    assert_eq!((header.x, header.y), (0.0, 0.0));
+    assert_eq!((side.x, side.y), (0.0, 50.0));
+
+    // The shrinking column fits its cell at the end
+    assert_near(tag.x + tag.width, 400.0);
+    assert_eq!(tag.y, 50.0);
+
+    // Automatic cells create an implicit row at the bottom
+    assert_eq!(footer.x, 0.0);
+    assert_near(footer.y + footer.height, 300.0);
+
+    // The filling tracks take the rest, with the main cell centered in them
+    let area = Rectangle {
+        x: 110.0,
+        y: 50.0,
+        width: tag.x - 10.0 - 110.0,
+        height: footer.y - 10.0 - 50.0,
+    };
+
+    assert_near(main.center().x, area.center().x);
+    assert_near(main.center().y, area.center().y);
+}
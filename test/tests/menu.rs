@@ -0,0 +1,231 @@
+//! Tests the [`MenuBar`](iced_widget::MenuBar) and [`ContextMenu`](iced_widget::ContextMenu)
+//! widgets with the [`Simulator`].
+use iced_test::core::keyboard::{self, key};
+use iced_test::core::time::Instant;
+use iced_test::core::{Element, Event, Theme, mouse, window};
+use iced_test::{Simulator, simulator};
+use iced_widget::menu_bar::{Item, Menu};
+use iced_widget::{column, container, context_menu, menu_bar, text};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Open,
+    Recent(&'static str),
+    Wrap,
+    Undo,
+    Cut,
+}
+
+fn view<'a>() -> Element<'a, Message, Theme, iced_widget::Renderer> {
+    column![
+        menu_bar([
+            Menu::new(
+                "&File",
+                [
+                    Item::new("&Open...")
+                        .on_select(Message::Open)
+                        .shortcut("Ctrl+O"),
+                    Item::new("&Save").shortcut("Ctrl+S"),
+                    Item::separator(),
+                    Item::submenu(
+                        "Open &Recent",
+                        [
+                            Item::new("&a.txt").on_select(Message::Recent("a")),
+                            Item::new("&b.txt").on_select(Message::Recent("b")),
+                        ],
+                    ),
+                    Item::new("&Word Wrap")
+                        .on_select(Message::Wrap)
+                        .checked(true),
+                ],
+            ),
+            Menu::new("&Edit", [Item::new("&Undo").on_select(Message::Undo)]),
+        ]),
+        context_menu(
+            container(text("Right-click me")).width(400).height(300),
+            [
+                Item::new("Cu&t").on_select(Message::Cut),
+                Item::submenu("&More", [Item::new("&Undo").on_select(Message::Undo)]),
+            ],
+        ),
+    ]
+    .into()
+}
+
+fn redraw(ui: &mut Simulator<'_, Message>) {
+    for _ in 0..3 {
+        let _ = ui.simulate([Event::Window(
+            window::Event::RedrawRequested(Instant::now()),
+        )]);
+    }
+}
+
+fn press(ui: &mut Simulator<'_, Message>, key: impl Into<keyboard::Key>) {
+    press_with(ui, key, keyboard::Modifiers::empty());
+}
+
+fn press_with(
+    ui: &mut Simulator<'_, Message>,
+    key: impl Into<keyboard::Key>,
+    modifiers: keyboard::Modifiers,
+) {
+    let _ = ui.press_key(key, modifiers);
+
+    redraw(ui);
+}
+
+fn hover(ui: &mut Simulator<'_, Message>, label: &str) {
+    let position = ui
+        .find(label)
+        .expect("Label must be visible")
+        .bounds()
+        .center();
+
+    ui.point_at(position);
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    redraw(ui);
+}
+
+fn right_click(ui: &mut Simulator<'_, Message>) {
+    let _ = ui.simulate([
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Right)),
+    ]);
+
+    redraw(ui);
+}
+
+#[test]
+fn it_opens_a_menu() {
+    let mut ui = simulator(view());
+
+    assert!(ui.find("Open...").is_err());
+
+    let _ = ui.click("File").expect("File must be visible");
+    redraw(&mut ui);
+
+    assert!(ui.find("Open...").is_ok());
+
+    assert_eq!(
+        ui.semantics().to_string(),
+        "\
+MenuBar
+  MenuItem \"File\" [expanded]
+    Label \"File\"
+  MenuItem \"Edit\"
+    Label \"Edit\"
+Label \"Right-click me\"
+Menu
+  MenuItem \"Open...\"
+    Label \"Open...\"
+  MenuItem \"Save\" [disabled]
+    Label \"Save\"
+  MenuItem \"Open Recent\"
+    Label \"Open Recent\"
+  MenuItem \"Word Wrap\" [toggled]
+    Label \"Word Wrap\"
+"
+    );
+}
+
+#[test]
+fn it_navigates_with_the_keyboard() {
+    let mut ui = simulator(view());
+
+    press_with(&mut ui, character("f"), keyboard::Modifiers::ALT);
+    assert!(ui.find("Open...").is_ok());
+
+    // Disabled items and separators are skipped
+    press(&mut ui, key::Named::ArrowDown);
+    press(&mut ui, key::Named::ArrowDown);
+    press(&mut ui, key::Named::Enter);
+
+    assert!(ui.find("Open...").is_err());
+
+    press(&mut ui, key::Named::F10);
+    press(&mut ui, key::Named::ArrowRight);
+    assert!(ui.find("Undo").is_ok());
+
+    press(&mut ui, key::Named::Enter);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Wrap, Message::Undo]
+    );
+}
+
+#[test]
+fn it_opens_and_closes_submenus() {
+    let mut ui = simulator(view());
+
+    let _ = ui.click("File").expect("File must be visible");
+    redraw(&mut ui);
+
+    hover(&mut ui, "Open Recent");
+    assert!(ui.find("a.txt").is_ok());
+
+    press(&mut ui, key::Named::ArrowRight);
+    press(&mut ui, key::Named::ArrowLeft);
+    assert!(ui.find("a.txt").is_err());
+    assert!(ui.find("Open...").is_ok());
+
+    press(&mut ui, key::Named::ArrowRight);
+    assert!(ui.find("a.txt").is_ok());
+
+    let _ = ui.click("b.txt").expect("b.txt must be visible");
+    redraw(&mut ui);
+
+    assert!(ui.find("Open...").is_err());
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Recent("b")]
+    );
+}
+
+#[test]
+fn it_ignores_disabled_items() {
+    let mut ui = simulator(view());
+
+    let _ = ui.click("File").expect("File must be visible");
+    redraw(&mut ui);
+
+    let _ = ui.click("Save").expect("Save must be visible");
+    redraw(&mut ui);
+
+    assert!(ui.find("Open...").is_ok());
+    assert_eq!(ui.into_messages().count(), 0);
+}
+
+#[test]
+fn it_opens_a_context_menu() {
+    let mut ui = simulator(view());
+
+    hover(&mut ui, "Right-click me");
+    right_click(&mut ui);
+    assert!(ui.find("More").is_ok());
+
+    let _ = ui.click("Cut").expect("Cut must be visible");
+    redraw(&mut ui);
+    assert!(ui.find("More").is_err());
+
+    press(&mut ui, key::Named::ContextMenu);
+    assert!(ui.find("More").is_ok());
+
+    press(&mut ui, key::Named::Escape);
+    assert!(ui.find("More").is_err());
+
+    press_with(&mut ui, key::Named::F10, keyboard::Modifiers::SHIFT);
+    assert!(ui.find("More").is_ok());
+
+    press(&mut ui, character("t"));
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Cut, Message::Cut]
+    );
+}
+
+fn character(c: &str) -> keyboard::Key {
+    keyboard::Key::Character(c.into())
+}
@@ -0,0 +1,111 @@
+//! Tests the [`NumberInput`](iced_widget::NumberInput) widget with the [`Simulator`].
+use iced_test::core::keyboard::{self, key};
+use iced_test::core::{Element, Theme, widget};
+use iced_test::{Simulator, simulator};
+use iced_widget::number_input::Locale;
+use iced_widget::{column, number_input, text_input};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Message {
+    Changed(u32),
+    Typed,
+}
+
+fn form<'a>(value: u32) -> Element<'a, Message, Theme, iced_widget::Renderer> {
+    column![
+        number_input("Quantity", value)
+            .id(widget::Id::new("quantity"))
+            .max(5000)
+            .step(10)
+            .locale(Locale::ENGLISH)
+            .on_change(Message::Changed),
+        text_input("Other", "")
+            .id(widget::Id::new("other"))
+            .on_input(|_| Message::Typed),
+    ]
+    .into()
+}
+
+fn messages(ui: Simulator<'_, Message>) -> Vec<Message> {
+    ui.into_messages().collect()
+}
+
+#[test]
+fn it_groups_typed_digits() {
+    let mut ui = simulator(form(0));
+
+    let _ = ui.click(widget::Id::new("quantity")).expect("Focus input");
+    let _ = ui.press_key(key::Named::Backspace, keyboard::Modifiers::default());
+    let _ = ui.typewrite("12a34");
+
+    assert!(ui.find("1,234").is_ok());
+    assert_eq!(
+        messages(ui),
+        vec![
+            Message::Changed(1),
+            Message::Changed(12),
+            Message::Changed(123),
+            Message::Changed(1234),
+        ]
+    );
+}
+
+#[test]
+fn it_steps_with_arrow_keys() {
+    let mut ui = simulator(form(4990));
+
+    let _ = ui.click(widget::Id::new("quantity")).expect("Focus input");
+    let _ = ui.press_key(key::Named::ArrowDown, keyboard::Modifiers::default());
+    assert!(ui.find("4,980").is_ok());
+
+    let _ = ui.press_key(key::Named::ArrowUp, keyboard::Modifiers::SHIFT);
+    assert!(ui.find("5,000").is_ok());
+
+    assert_eq!(
+        messages(ui),
+        vec![Message::Changed(4980), Message::Changed(5000)]
+    );
+}
+
+#[test]
+fn it_clamps_out_of_range_numbers_when_unfocused() {
+    let mut ui = simulator(form(5));
+
+    let _ = ui.click(widget::Id::new("quantity")).expect("Focus input");
+    let _ = ui.typewrite("0000");
+    assert!(ui.find("50,000").is_ok());
+
+    let _ = ui
+        .click(widget::Id::new("other"))
+        .expect("Focus other input");
+    assert!(ui.find("5").is_ok());
+
+    assert_eq!(
+        messages(ui),
+        vec![
+            Message::Changed(50),
+            Message::Changed(500),
+            Message::Changed(5000),
+            Message::Changed(5000)
+        ]
+    );
+}
+
+#[test]
+fn it_steps_the_largest_integers_exactly() {
+    let mut ui = simulator(
+        number_input("Quantity", u32::MAX - 1)
+            .id(widget::Id::new("quantity"))
+            .on_change(Message::Changed),
+    );
+
+    let _ = ui.click(widget::Id::new("quantity")).expect("Focus input");
+    let _ = ui.press_key(key::Named::ArrowUp, keyboard::Modifiers::default());
+    assert!(ui.find("4294967295").is_ok());
+
+    // Steps past the bounds of the type are ignored
+    let _ = ui.press_key(key::Named::ArrowUp, keyboard::Modifiers::default());
+    assert!(ui.find("4294967295").is_ok());
+
+    assert_eq!(messages(ui), vec![Message::Changed(u32::MAX)]);
+}
@@ -0,0 +1,143 @@
+//! Tests selectable [`Text`](iced_widget::Text) and [`Rich`](iced_widget::text::Rich)
+//! text with the [`Simulator`].
+use iced_test::core::clipboard;
+use iced_test::core::keyboard::{self, key};
+use iced_test::core::{Event, Point, Rectangle, mouse};
+use iced_test::{Simulator, simulator};
+use iced_widget::{column, rich_text, span, text};
+
+fn press(ui: &mut Simulator<'_, ()>, position: Point) {
+    ui.point_at(position);
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+}
+
+fn release(ui: &mut Simulator<'_, ()>) {
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    ))]);
+}
+
+fn select(ui: &mut Simulator<'_, ()>, from: Point, to: Point) {
+    press(ui, from);
+
+    ui.point_at(to);
+    let _ = ui.simulate([Event::Mouse(mouse::Event::CursorMoved { position: to })]);
+
+    release(ui);
+}
+
+fn click(ui: &mut Simulator<'_, ()>, position: Point, times: usize) {
+    for _ in 0..times {
+        press(ui, position);
+        release(ui);
+    }
+}
+
+fn copy(ui: &mut Simulator<'_, ()>) -> Option<String> {
+    let key = keyboard::Key::Character("c".into());
+
+    let _ = ui.simulate([Event::Keyboard(keyboard::Event::KeyPressed {
+        key: key.clone(),
+        modified_key: key,
+        physical_key: key::Physical::Code(key::Code::KeyC),
+        location: keyboard::Location::Standard,
+        modifiers: keyboard::Modifiers::COMMAND,
+        text: None,
+        repeat: false,
+    })]);
+
+    match ui.clipboard()? {
+        clipboard::Content::Text(text) => Some(text.clone()),
+        _ => None,
+    }
+}
+
+fn start(bounds: Rectangle) -> Point {
+    Point::new(bounds.x + 1.0, bounds.center_y())
+}
+
+fn end(bounds: Rectangle) -> Point {
+    Point::new(bounds.x + bounds.width + 50.0, bounds.center_y())
+}
+
+#[test]
+fn it_copies_dragged_selections() {
+    let mut ui = simulator(text("Hello, world!").selectable(true));
+
+    let hello = ui
+        .bounds_of("Hello, world!")
+        .expect("Label must be visible");
+
+    // Nothing is copied without a selection
+    assert_eq!(copy(&mut ui), None);
+
+    // A click does not select anything
+    click(&mut ui, hello.center(), 1);
+    assert_eq!(copy(&mut ui), None);
+
+    select(&mut ui, start(hello), end(hello));
+    assert_eq!(copy(&mut ui).as_deref(), Some("Hello, world!"));
+}
+
+#[test]
+fn it_selects_words_and_lines_with_clicks() {
+    let mut ui = simulator(text("alpha beta_gamma\nsecond line").selectable(true));
+
+    let bounds = ui
+        .bounds_of("alpha beta_gamma\nsecond line")
+        .expect("Label must be visible");
+    let first_line = Point::new(bounds.x + bounds.width * 0.6, bounds.y + 5.0);
+
+    click(&mut ui, first_line, 2);
+    assert_eq!(copy(&mut ui).as_deref(), Some("beta_gamma"));
+
+    click(&mut ui, first_line, 3);
+    assert_eq!(copy(&mut ui).as_deref(), Some("alpha beta_gamma"));
+}
+
+#[test]
+fn it_selects_across_sibling_texts() {
+    let mut ui = simulator(column![
+        text("First").selectable(true),
+        text("Plain"),
+        text("Second").selectable(true),
+        text("Third").selectable(true),
+    ]);
+
+    let first = ui.bounds_of("First").expect("Label must be visible");
+    let third = ui.bounds_of("Third").expect("Label must be visible");
+
+    select(
+        &mut ui,
+        Point::new(third.x + third.width - 1.0, third.center_y()),
+        start(first),
+    );
+    assert_eq!(copy(&mut ui).as_deref(), Some("First\nSecond\nThird"));
+
+    // A new selection replaces the previous one
+    let second = ui.bounds_of("Second").expect("Label must be visible");
+
+    select(&mut ui, start(second), end(second));
+    assert_eq!(copy(&mut ui).as_deref(), Some("Second"));
+}
+
+#[test]
+fn it_selects_across_rich_text_spans() {
+    let mut ui = simulator(
+        column![
+            rich_text([
+                span::<(), _>("Hello, "),
+                span("rich").underline(true),
+                span(" world!"),
+            ])
+            .selectable(true),
+        ]
+        .padding(10),
+    );
+
+    select(&mut ui, Point::new(11.0, 15.0), Point::new(500.0, 15.0));
+    assert_eq!(copy(&mut ui).as_deref(), Some("Hello, rich world!"));
+}
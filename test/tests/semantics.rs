@@ -0,0 +1,84 @@
+//! Tests the semantic trees built by the [`Simulator`].
+use iced_test::core::widget::operation::semantics::Role;
+use iced_test::core::{Event, Length, Point, Settings, Size, mouse};
+use iced_test::{Simulator, simulator};
+use iced_widget::{button, checkbox, column, scrollable, slider, space, text, text_input};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Press,
+    Toggle,
+    Edit,
+    Slide,
+}
+
+#[test]
+fn it_describes_interactive_widgets() {
+    let mut ui: Simulator<'_, Message> = simulator(column![
+        button("Save").on_press(Message::Press),
+        button("Disabled"),
+        checkbox(true)
+            .label("Autosave")
+            .on_toggle(|_| Message::Toggle),
+        text_input("Name", "Ferris").on_input(|_| Message::Edit),
+        slider(0.0..=10.0, 5.0, |_| Message::Slide),
+    ]);
+
+    assert_eq!(
+        ui.semantics().to_string(),
+        "Button \"Save\"\n\
+         \x20 Label \"Save\"\n\
+         Button \"Disabled\" [disabled]\n\
+         \x20 Label \"Disabled\"\n\
+         CheckBox \"Autosave\" [toggled]\n\
+         \x20 Label \"Autosave\"\n\
+         TextInput = \"Ferris\"\n\
+         Slider = 5 in 0..=10\n"
+    );
+}
+
+#[test]
+fn it_translates_scrollable_contents() {
+    let mut ui: Simulator<'_, Message> = Simulator::with_size(
+        Settings::default(),
+        Size::new(200.0, 100.0),
+        scrollable(column![
+            space().height(300),
+            button("Bottom").on_press(Message::Press)
+        ])
+        .height(Length::Fill),
+    );
+
+    let button_y = |ui: &mut Simulator<'_, Message>| {
+        ui.semantics()
+            .iter()
+            .find(|node| node.semantics.role == Role::Button)
+            .map(|node| node.bounds.y)
+    };
+
+    assert_eq!(button_y(&mut ui), Some(300.0));
+
+    ui.point_at(Point::new(20.0, 50.0));
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::WheelScrolled {
+        delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -220.0 },
+    })]);
+
+    assert_eq!(button_y(&mut ui), Some(80.0));
+}
+
+#[test]
+fn it_does_not_name_containers_by_their_contents() {
+    let mut ui: Simulator<'_, Message> = simulator(scrollable(column![
+        text("Introduction"),
+        button("Next").on_press(Message::Press),
+    ]));
+
+    assert_eq!(
+        ui.semantics().to_string(),
+        "ScrollView\n\
+         \x20 Label \"Introduction\"\n\
+         \x20 Button \"Next\"\n\
+         \x20   Label \"Next\"\n"
+    );
+}
@@ -0,0 +1,143 @@
+//! Tests the [`Table`](iced_widget::Table) widget with the [`Simulator`].
+use iced_test::core::{Element, Event, Length, Point, Settings, Size, Theme, mouse};
+use iced_test::{Simulator, simulator};
+use iced_widget::table::{self, Direction};
+use iced_widget::{scrollable, text};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Message {
+    Sort(&'static str, Direction),
+    Resize(f32),
+    Click(usize),
+}
+
+const FRUITS: &[(&str, u32)] = &[("Apple", 3), ("Banana", 1), ("Cherry", 2)];
+
+fn fruits<'a>(
+    rows: &'a [(&'static str, u32)],
+) -> table::Table<'a, Message, Theme, iced_widget::Renderer> {
+    let columns = [
+        table::column(text("Name"), |(name, _): &(&str, u32)| text(*name))
+            .sorted(Direction::Ascending)
+            .on_sort(|direction| Message::Sort("name", direction)),
+        table::column(text("Price"), |(_, price): &(&str, u32)| text(*price))
+            .width(100)
+            .on_sort(|direction| Message::Sort("price", direction))
+            .on_resize(Message::Resize),
+    ];
+
+    table::table(columns, rows)
+}
+
+fn press_at(ui: &mut Simulator<'_, Message>, position: Point) {
+    ui.point_at(position);
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+}
+
+fn move_to(ui: &mut Simulator<'_, Message>, position: Point) {
+    ui.point_at(position);
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+}
+
+#[test]
+fn it_sorts_by_column() {
+    let mut ui = simulator(Element::from(fruits(FRUITS)));
+
+    let _ = ui.click("Name").expect("Name must be visible");
+    let _ = ui.click("Price").expect("Price must be visible");
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [
+            Message::Sort("name", Direction::Descending),
+            Message::Sort("price", Direction::Ascending),
+        ]
+    );
+}
+
+#[test]
+fn it_resizes_columns() {
+    let mut ui = simulator(Element::from(fruits(FRUITS)));
+
+    let header = ui.find("Price").expect("Price must be visible").bounds();
+
+    // The resize handle is the separator after the padding of the column
+    let handle = Point::new(header.x + 100.0 + 10.0 + 0.5, header.center_y());
+
+    press_at(&mut ui, handle);
+    move_to(&mut ui, handle + iced_test::core::Vector::new(30.0, 0.0));
+    move_to(&mut ui, handle + iced_test::core::Vector::new(-200.0, 0.0));
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    ))]);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Resize(130.0), Message::Resize(10.0)]
+    );
+}
+
+#[test]
+fn it_clicks_rows() {
+    let mut ui = simulator(Element::from(
+        fruits(FRUITS)
+            .on_row_click(Message::Click)
+            .selected(|row| row == 1),
+    ));
+
+    let _ = ui.click("Banana").expect("Banana must be visible");
+    let _ = ui.click("2").expect("Cherry must be visible");
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Click(1), Message::Click(2)]
+    );
+}
+
+#[test]
+fn it_keeps_the_header_sticky() {
+    let rows: Vec<(&'static str, u32)> = (0..50).map(|i| ("Fruit", i)).collect();
+
+    let view = |sticky_header| -> Element<'_, Message, Theme, iced_widget::Renderer> {
+        scrollable(
+            fruits(&rows)
+                .sticky_header(sticky_header)
+                .on_row_click(Message::Click),
+        )
+        .height(Length::Fixed(200.0))
+        .into()
+    };
+
+    for sticky_header in [false, true] {
+        let mut ui = Simulator::with_size(
+            Settings::default(),
+            Size::new(400.0, 200.0),
+            view(sticky_header),
+        );
+
+        let header = ui.find("Name").expect("Name must be visible").bounds();
+
+        ui.point_at(header.center());
+        let _ = ui.simulate([Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -500.0 },
+        })]);
+
+        let _ = ui.simulate(iced_test::simulator::click());
+
+        let messages: Vec<_> = ui.into_messages().collect();
+
+        if sticky_header {
+            assert_eq!(messages, [Message::Sort("name", Direction::Descending)]);
+        } else {
+            assert!(
+                matches!(messages.as_slice(), [Message::Click(row)] if *row > 10),
+                "Unexpected messages: {messages:?}"
+            );
+        }
+    }
+}
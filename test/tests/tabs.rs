@@ -0,0 +1,181 @@
+//! Tests the [`Tabs`](iced_widget::Tabs) widget with the [`Simulator`].
+use iced_test::core::keyboard::{self, key};
+use iced_test::core::time::Instant;
+use iced_test::core::{Element, Event, Point, Settings, Size, Theme, mouse, window};
+use iced_test::{Simulator, simulator};
+use iced_widget::tabs::Tab;
+use iced_widget::{tabs, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Select(usize),
+    Close(usize),
+    Reorder(usize, usize),
+}
+
+fn documents<'a>(
+    names: &[&'a str],
+    selected: usize,
+) -> Element<'a, Message, Theme, iced_widget::Renderer> {
+    tabs(
+        names.iter().map(|name| Tab::new(*name)),
+        Some(selected),
+        text(names[selected]),
+    )
+    .on_select(Message::Select)
+    .on_close(Message::Close)
+    .on_reorder(Message::Reorder)
+    .into()
+}
+
+fn redraw(ui: &mut Simulator<'_, Message>) {
+    for _ in 0..3 {
+        let _ = ui.simulate([Event::Window(
+            window::Event::RedrawRequested(Instant::now()),
+        )]);
+    }
+}
+
+fn center(ui: &mut Simulator<'_, Message>, label: &str) -> Point {
+    ui.find(label)
+        .expect("Label must be visible")
+        .bounds()
+        .center()
+}
+
+#[test]
+fn it_describes_tabs() {
+    let mut ui = simulator(documents(&["main.rs", "lib.rs"], 1));
+
+    assert_eq!(
+        ui.semantics().to_string(),
+        "\
+ScrollView
+  TabList
+    Tab \"main.rs\"
+      Label \"main.rs\"
+    Tab \"lib.rs\" [selected]
+      Label \"lib.rs\"
+TabPanel
+  Label \"lib.rs\"
+"
+    );
+}
+
+#[test]
+fn it_selects_and_closes_tabs() {
+    let mut ui = simulator(documents(&["main.rs", "lib.rs", "build.rs"], 0));
+
+    let _ = ui.click("lib.rs").expect("lib.rs must be visible");
+
+    // The close button follows the label
+    let label = ui
+        .find("build.rs")
+        .expect("build.rs must be visible")
+        .bounds();
+    ui.point_at(Point::new(
+        label.x + label.width + 6.0 + label.height / 2.0,
+        label.center_y(),
+    ));
+    let _ = ui.simulate(iced_test::simulator::click());
+
+    let position = center(&mut ui, "main.rs");
+    ui.point_at(position);
+    let _ = ui.simulate([
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Middle)),
+    ]);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Select(1), Message::Close(2), Message::Close(0)]
+    );
+}
+
+#[test]
+fn it_navigates_with_the_keyboard() {
+    let mut ui = simulator(documents(&["main.rs", "lib.rs", "build.rs"], 0));
+
+    // Unfocused tabs ignore the keyboard
+    let _ = ui.press_key(key::Named::ArrowRight, keyboard::Modifiers::empty());
+
+    let _ = ui.click("main.rs").expect("main.rs must be visible");
+
+    let _ = ui.press_key(key::Named::ArrowRight, keyboard::Modifiers::empty());
+    let _ = ui.press_key(key::Named::ArrowLeft, keyboard::Modifiers::empty());
+    let _ = ui.press_key(key::Named::End, keyboard::Modifiers::empty());
+    let _ = ui.press_key(key::Named::ArrowRight, keyboard::Modifiers::CTRL);
+    let _ = ui.press_key(key::Named::Delete, keyboard::Modifiers::empty());
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [
+            Message::Select(1),
+            Message::Select(2),
+            Message::Select(2),
+            Message::Reorder(0, 1),
+            Message::Close(0),
+        ]
+    );
+}
+
+#[test]
+fn it_reorders_tabs_by_dragging() {
+    let mut ui = simulator(documents(&["main.rs", "lib.rs", "build.rs"], 0));
+
+    let origin = center(&mut ui, "main.rs");
+    let target = center(&mut ui, "build.rs");
+
+    ui.point_at(origin);
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+
+    for position in [
+        Point::new(origin.x + 2.0, origin.y),
+        Point::new(target.x + 10.0, origin.y),
+    ] {
+        ui.point_at(position);
+        let _ = ui.simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    redraw(&mut ui);
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    ))]);
+
+    // A click without dragging does not reorder
+    let _ = ui.click("lib.rs").expect("lib.rs must be visible");
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Reorder(0, 2), Message::Select(1)]
+    );
+}
+
+#[test]
+fn it_scrolls_the_selected_tab_into_view() {
+    let names: Vec<String> = (0..20).map(|i| format!("Document {i}")).collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+
+    let mut ui = Simulator::with_size(
+        Settings::default(),
+        Size::new(300.0, 200.0),
+        documents(&names, 15),
+    );
+
+    redraw(&mut ui);
+
+    let tab = ui
+        .semantics()
+        .iter()
+        .find(|node| node.semantics.is_selected == Some(true))
+        .map(|node| node.bounds)
+        .expect("Selected tab must be described");
+
+    assert!(
+        tab.x >= -1.0 && tab.x + tab.width <= 301.0,
+        "Selected tab is at {tab:?}"
+    );
+}
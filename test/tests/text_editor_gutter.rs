@@ -0,0 +1,93 @@
+//! Tests the gutter of a [`TextEditor`](iced_widget::TextEditor) with the
+//! [`Simulator`](iced_test::Simulator).
+use iced_test::Simulator;
+use iced_test::core::text::{LineHeight, highlighter};
+use iced_test::core::{Event, Point, Settings, Size, mouse};
+use iced_widget::text_editor::{self, Action, Content};
+
+const TEXT: &str = "fn main() {\n    println!(\"Hello\");\n}\nmain();";
+
+type TextEditor<'a> = text_editor::TextEditor<'a, highlighter::PlainText, Action>;
+
+fn text_editor(content: &Content) -> TextEditor<'_> {
+    iced_widget::text_editor(content)
+}
+
+fn click(editor: TextEditor<'_>, position: Point) -> Vec<Action> {
+    let mut ui = Simulator::with_size(
+        Settings::default(),
+        Size::new(400.0, 200.0),
+        editor
+            .size(20)
+            .line_height(LineHeight::Absolute(20.into()))
+            .padding(0)
+            .on_action(std::convert::identity),
+    );
+
+    ui.point_at(position);
+
+    let _ = ui.simulate([
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+    ]);
+
+    ui.into_messages().collect()
+}
+
+fn perform(content: &mut Content, actions: Vec<Action>) {
+    for action in actions {
+        content.perform(action);
+    }
+}
+
+#[test]
+fn clicking_a_line_number_selects_the_line() {
+    let mut content = Content::with_text(TEXT);
+
+    let actions = click(
+        text_editor(&content).line_numbers(true),
+        Point::new(1.0, 25.0),
+    );
+
+    perform(&mut content, actions);
+
+    assert_eq!(content.cursor().position.line, 1);
+    assert_eq!(
+        content.selection().as_deref(),
+        Some("    println!(\"Hello\");")
+    );
+}
+
+#[test]
+fn clicking_a_fold_marker_toggles_the_region() {
+    let mut content = Content::with_text(TEXT);
+    let region = 0..3;
+    let foldable = std::slice::from_ref(&region);
+
+    let actions = click(
+        text_editor(&content).foldable(foldable),
+        Point::new(10.0, 10.0),
+    );
+
+    assert_eq!(actions, vec![Action::Fold(0..3)]);
+    perform(&mut content, actions);
+    assert_eq!(content.folds(), vec![0..3]);
+
+    // The line after the fold is laid out right below its first line
+    let actions = click(
+        text_editor(&content).foldable(foldable),
+        Point::new(25.0, 25.0),
+    );
+
+    perform(&mut content, actions);
+    assert_eq!(content.selection().as_deref(), Some("main();"));
+
+    let actions = click(
+        text_editor(&content).foldable(foldable),
+        Point::new(10.0, 10.0),
+    );
+
+    assert_eq!(actions, vec![Action::Unfold(0)]);
+    perform(&mut content, actions);
+    assert!(content.folds().is_empty());
+}
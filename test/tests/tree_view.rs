@@ -0,0 +1,246 @@
+//! Tests the [`TreeView`](iced_widget::TreeView) widget with the [`Simulator`].
+use iced_test::core::keyboard::{self, key};
+use iced_test::core::time::Instant;
+use iced_test::core::widget::operation::semantics;
+use iced_test::core::{Element, Event, Point, Settings, Size, Theme, window};
+use iced_test::{Simulator, simulator};
+use iced_widget::tree_view::{Node, Selection};
+use iced_widget::{text, tree_view};
+
+use std::cell::Cell;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Message {
+    Select(&'static str),
+    SelectMany(Selection<&'static str>),
+    Toggle(&'static str, bool),
+}
+
+fn node<'a>(name: &&'static str) -> Node<'a, &'static str, Message, Theme, iced_widget::Renderer> {
+    match *name {
+        "src" => Node::new(text("src")).children(|| ["main.rs", "widget"]),
+        "widget" => Node::new(text("widget")).children(|| ["button.rs"]),
+        file => Node::new(text(file)),
+    }
+}
+
+fn files<'a>() -> Element<'a, Message, Theme, iced_widget::Renderer> {
+    tree_view(["src", "Cargo.toml"], node)
+        .selected(|name| *name == "Cargo.toml")
+        .on_select(Message::Select)
+        .on_toggle(Message::Toggle)
+        .into()
+}
+
+fn redraw(ui: &mut Simulator<'_, Message>) {
+    for _ in 0..3 {
+        let _ = ui.simulate([Event::Window(
+            window::Event::RedrawRequested(Instant::now()),
+        )]);
+    }
+}
+
+fn hold(ui: &mut Simulator<'_, Message>, modifiers: keyboard::Modifiers) {
+    let _ = ui.simulate([Event::Keyboard(keyboard::Event::ModifiersChanged(
+        modifiers,
+    ))]);
+}
+
+fn toggle(ui: &mut Simulator<'_, Message>, label: &str) {
+    // The disclosure arrow precedes the label
+    let label = ui.find(label).expect("Label must be visible").bounds();
+
+    ui.point_at(Point::new(label.x - 8.0, label.center_y()));
+    let _ = ui.simulate(iced_test::simulator::click());
+}
+
+#[test]
+fn it_describes_the_tree() {
+    let mut ui = simulator(files());
+
+    toggle(&mut ui, "src");
+
+    assert_eq!(
+        ui.semantics().to_string(),
+        "\
+ScrollView
+  Tree [focused]
+    TreeItem \"src\" [expanded]
+      Label \"src\"
+    TreeItem \"main.rs\"
+      Label \"main.rs\"
+    TreeItem \"widget\"
+      Label \"widget\"
+    TreeItem \"Cargo.toml\" [selected]
+      Label \"Cargo.toml\"
+"
+    );
+}
+
+#[test]
+fn it_loads_children_lazily() {
+    let loads = Cell::new(0);
+
+    let mut ui: Simulator<'_, Message> = simulator(tree_view(["src"], |name: &&'static str| {
+        if *name == "src" {
+            Node::new(text("src")).children(|| {
+                loads.set(loads.get() + 1);
+
+                ["main.rs"]
+            })
+        } else {
+            node(name)
+        }
+    }));
+
+    assert_eq!(loads.get(), 0);
+    assert!(ui.find("main.rs").is_err());
+
+    toggle(&mut ui, "src");
+
+    assert!(loads.get() > 0);
+    assert!(ui.find("main.rs").is_ok());
+}
+
+#[test]
+fn it_expands_and_collapses_nodes() {
+    let mut ui = simulator(files());
+
+    toggle(&mut ui, "src");
+    assert!(ui.find("main.rs").is_ok());
+
+    // Double clicking a node toggles it
+    let _ = ui.click("src").expect("src must be visible");
+    let _ = ui.click("src").expect("src must be visible");
+    assert!(ui.find("main.rs").is_err());
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [
+            Message::Toggle("src", true),
+            Message::Select("src"),
+            Message::Select("src"),
+            Message::Toggle("src", false),
+        ]
+    );
+}
+
+#[test]
+fn it_navigates_with_the_keyboard() {
+    let mut ui = simulator(files());
+
+    // Unfocused trees ignore the keyboard
+    let _ = ui.press_key(key::Named::ArrowDown, keyboard::Modifiers::empty());
+
+    let _ = ui.click("src").expect("src must be visible");
+
+    for key in [
+        key::Named::ArrowRight,
+        key::Named::ArrowDown,
+        key::Named::ArrowDown,
+        key::Named::ArrowRight,
+        key::Named::ArrowRight,
+        key::Named::ArrowLeft,
+        key::Named::ArrowLeft,
+        key::Named::End,
+    ] {
+        let _ = ui.press_key(key, keyboard::Modifiers::empty());
+    }
+
+    assert!(ui.find("main.rs").is_ok());
+    assert!(ui.find("button.rs").is_err());
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [
+            Message::Select("src"),
+            Message::Toggle("src", true),
+            Message::Select("main.rs"),
+            Message::Select("widget"),
+            Message::Toggle("widget", true),
+            Message::Select("button.rs"),
+            Message::Select("widget"),
+            Message::Toggle("widget", false),
+        ]
+    );
+}
+
+#[test]
+fn it_selects_multiple_nodes() {
+    let mut ui: Simulator<'_, Message> = simulator(
+        tree_view(["src", "Cargo.toml"], node)
+            .expanded(|name| *name == "src")
+            .on_select_many(Message::SelectMany),
+    );
+
+    let _ = ui.click("main.rs").expect("main.rs must be visible");
+
+    hold(&mut ui, keyboard::Modifiers::SHIFT);
+    let _ = ui.click("Cargo.toml").expect("Cargo.toml must be visible");
+
+    hold(&mut ui, keyboard::Modifiers::CTRL);
+    let _ = ui.click("widget").expect("widget must be visible");
+
+    // The command key moves the cursor without selecting
+    let _ = ui.press_key(key::Named::ArrowUp, keyboard::Modifiers::CTRL);
+    let _ = ui.press_key(key::Named::Space, keyboard::Modifiers::CTRL);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [
+            Message::SelectMany(Selection::Only("main.rs")),
+            Message::SelectMany(Selection::Range(vec!["main.rs", "widget", "Cargo.toml"])),
+            Message::SelectMany(Selection::Toggle("widget")),
+            Message::SelectMany(Selection::Toggle("main.rs")),
+        ]
+    );
+}
+
+#[test]
+fn it_can_be_controlled_externally() {
+    let mut ui: Simulator<'_, Message> = simulator(
+        tree_view(["src", "Cargo.toml"], node)
+            .expanded(|name| *name == "src")
+            .on_toggle(Message::Toggle),
+    );
+
+    assert!(ui.find("main.rs").is_ok());
+
+    toggle(&mut ui, "src");
+    assert!(ui.find("main.rs").is_ok());
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Toggle("src", false)]
+    );
+}
+
+#[test]
+fn it_scrolls_the_cursor_into_view() {
+    let names: Vec<String> = (0..50).map(|i| format!("File {i}")).collect();
+
+    let mut ui: Simulator<'_, Message> = Simulator::with_size(
+        Settings::default(),
+        Size::new(300.0, 200.0),
+        tree_view(0..names.len(), |i: &usize| Node::new(text(&names[*i])))
+            .height(200)
+            .on_select(|_| Message::Select("file")),
+    );
+
+    let _ = ui.click("File 0").expect("File 0 must be visible");
+    let _ = ui.press_key(key::Named::End, keyboard::Modifiers::empty());
+    redraw(&mut ui);
+
+    let last = ui
+        .semantics()
+        .iter()
+        .filter(|node| node.semantics.role == semantics::Role::TreeItem)
+        .last()
+        .map(|node| node.bounds)
+        .expect("Last file must be described");
+
+    assert!(
+        last.y >= -1.0 && last.y + last.height <= 201.0,
+        "Last file is at {last:?}"
+    );
+}
@@ -0,0 +1,90 @@
+//! Tests the [`VirtualList`](iced_widget::VirtualList) widget with the [`Simulator`].
+use iced_test::core::time::Instant;
+use iced_test::core::{Element, Event, Settings, Size, Theme, window};
+use iced_test::{Simulator, simulator};
+use iced_widget::{column, scrollable, text, virtual_list};
+
+const ROWS: usize = 100_000;
+
+fn list<'a>(is_estimated: bool) -> Element<'a, (), Theme, iced_widget::Renderer> {
+    let list = virtual_list(ROWS, move |i| {
+        // Even rows are twice as tall as estimated
+        if is_estimated && i % 2 == 0 {
+            column![text!("Row {i}"), text("Details")].into()
+        } else {
+            text!("Row {i}").into()
+        }
+    })
+    .id("list");
+
+    let list = if is_estimated {
+        list.estimated_row_height(20)
+    } else {
+        list.row_height(20)
+    };
+
+    scrollable(list).id("scrollable").into()
+}
+
+/// Returns the vertical position of the given row in the viewport, if built.
+fn position(ui: &mut Simulator<'_, ()>, index: usize) -> Option<f32> {
+    let label = format!("Row {index}");
+
+    ui.semantics()
+        .iter()
+        .find(|node| node.semantics.label.as_deref() == Some(label.as_str()))
+        .map(|node| node.bounds.y)
+}
+
+fn redraw(ui: &mut Simulator<'_, ()>) {
+    for _ in 0..3 {
+        let _ = ui.simulate([Event::Window(
+            window::Event::RedrawRequested(Instant::now()),
+        )]);
+    }
+}
+
+#[test]
+fn it_builds_visible_rows_inside_a_scrollable() {
+    let mut ui = simulator(list(true));
+
+    assert!(ui.find("Row 0").is_ok());
+    assert!(ui.find("Row 20").is_ok());
+    assert!(ui.find("Row 500").is_err());
+}
+
+#[test]
+fn it_scrolls_to_a_row_with_estimated_heights() {
+    let mut ui = Simulator::with_size(Settings::default(), Size::new(400.0, 300.0), list(true));
+
+    for index in [50_001, 500, 20] {
+        ui.operate(virtual_list::scroll_to("scrollable", "list", index));
+        redraw(&mut ui);
+
+        let y = position(&mut ui, index).expect("Row must be built");
+
+        // The scrollable snaps its translation to the pixel grid
+        assert!(y.abs() < 1.0, "Row {index} is at {y}");
+    }
+
+    // The last rows cannot reach the top, but they must be visible
+    ui.operate(virtual_list::scroll_to("scrollable", "list", ROWS - 1));
+    redraw(&mut ui);
+
+    let y = position(&mut ui, ROWS - 1).expect("Row must be built");
+    assert!((0.0..300.0).contains(&y), "Row {} is at {y}", ROWS - 1);
+}
+
+#[test]
+fn it_snaps_to_a_row() {
+    let mut ui = Simulator::with_size(Settings::default(), Size::new(400.0, 300.0), list(false));
+
+    for index in [1_000, 75_000] {
+        ui.operate(virtual_list::snap_to("scrollable", "list", index));
+        redraw(&mut ui);
+
+        let y = position(&mut ui, index).expect("Row must be built");
+
+        assert!(y.abs() < 1.0, "Row {index} is at {y}");
+    }
+}
@@ -446,7 +446,7 @@ impl<P: Program + 'static> Tester<P> {
                         emulator::Event::Action(action) => {
                             emulator.perform(program, action);
                         }
-                        emulator::Event::Failed(_instruction) => {
+                        emulator::Event::Failed { .. } => {
                             *outcome = Outcome::Failed;
                         }
                         emulator::Event::Ready => {
@@ -717,6 +717,7 @@ impl<P: Program + 'static> Tester<P> {
                                             }
                                             _ => None,
                                         },
+                                        selection: None,
                                     })
                                     .into()
                             }),
@@ -882,6 +883,7 @@ where
             text(label).size(14).style(|theme: &core::Theme| {
                 text::Style {
                     color: Some(theme.palette().background.weak.text),
+                    selection: None,
                 }
             }),
             space::horizontal(),
@@ -1,7 +1,10 @@
 use crate::Primitive;
+use crate::core::gradient::ColorStop;
 use crate::core::renderer::Quad;
-use crate::core::{Background, Color, Gradient, Rectangle, Size, Transformation, Vector};
-use crate::graphics::{Image, Text};
+use crate::core::{
+    Background, Color, Gradient, Point, Radians, Rectangle, Size, Transformation, Vector,
+};
+use crate::graphics::{Image, Text, gradient};
 use crate::text;
 
 #[derive(Debug)]
@@ -130,56 +133,63 @@ impl Engine {
 
         let clip_mask = (!physical_bounds.is_within(&clip_bounds)).then_some(clip_mask as &_);
 
-        pixels.fill_path(
-            &path,
-            &tiny_skia::Paint {
-                shader: match background {
-                    Background::Color(color) => tiny_skia::Shader::SolidColor(into_color(*color)),
-                    Background::Gradient(Gradient::Linear(linear)) => {
-                        let (start, end) = linear.angle.to_distance(&quad.bounds);
-
-                        let stops: Vec<tiny_skia::GradientStop> = linear
-                            .stops
-                            .into_iter()
-                            .flatten()
-                            .map(|stop| {
-                                tiny_skia::GradientStop::new(
-                                    stop.offset,
-                                    tiny_skia::Color::from_rgba(
-                                        stop.color.b,
-                                        stop.color.g,
-                                        stop.color.r,
-                                        stop.color.a,
-                                    )
-                                    .expect("Create color"),
-                                )
-                            })
-                            .collect();
-
-                        tiny_skia::LinearGradient::new(
-                            tiny_skia::Point {
-                                x: start.x,
-                                y: start.y,
-                            },
-                            tiny_skia::Point { x: end.x, y: end.y },
-                            if stops.is_empty() {
-                                vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
-                            } else {
-                                stops
-                            },
-                            tiny_skia::SpreadMode::Pad,
-                            tiny_skia::Transform::identity(),
-                        )
-                        .expect("Create linear gradient")
-                    }
+        let shader = match background {
+            Background::Color(color) => Some(tiny_skia::Shader::SolidColor(into_color(*color))),
+            Background::Gradient(Gradient::Linear(linear)) => {
+                let (start, end) = linear.angle.to_distance(&quad.bounds);
+
+                Some(
+                    tiny_skia::LinearGradient::new(
+                        tiny_skia::Point {
+                            x: start.x,
+                            y: start.y,
+                        },
+                        tiny_skia::Point { x: end.x, y: end.y },
+                        into_gradient_stops(&linear.stops),
+                        tiny_skia::SpreadMode::Pad,
+                        tiny_skia::Transform::identity(),
+                    )
+                    .expect("Create linear gradient"),
+                )
+            }
+            Background::Gradient(Gradient::Radial(radial)) => {
+                let (center, radii, focal) = radial.to_absolute(&quad.bounds);
+
+                Some(into_radial_gradient(center, radii, focal, &radial.stops))
+            }
+            Background::Gradient(Gradient::Conic(conic)) => {
+                let gradient = gradient::Conic {
+                    center: conic.to_absolute(&quad.bounds),
+                    angle: conic.angle,
+                    stops: conic.stops,
+                };
+
+                draw_conic(
+                    &path,
+                    tiny_skia::FillRule::EvenOdd,
+                    &gradient,
+                    transform,
+                    pixels,
+                    clip_mask,
+                );
+
+                None
+            }
+        };
+
+        if let Some(shader) = shader {
+            pixels.fill_path(
+                &path,
+                &tiny_skia::Paint {
+                    shader,
+                    anti_alias: true,
+                    ..tiny_skia::Paint::default()
                 },
-                anti_alias: true,
-                ..tiny_skia::Paint::default()
-            },
-            tiny_skia::FillRule::EvenOdd,
-            transform,
-            clip_mask,
-        );
+                tiny_skia::FillRule::EvenOdd,
+                transform,
+                clip_mask,
+            );
+        }
 
         if border_width > 0.0 {
             // Border path is offset by half the border width
@@ -474,6 +484,39 @@ impl Engine {
                     clip_mask,
                 );
             }
+            Primitive::Conic {
+                path,
+                gradient,
+                transform,
+                rule,
+            } => {
+                let physical_bounds = {
+                    let bounds = path.bounds();
+
+                    Rectangle {
+                        x: bounds.x(),
+                        y: bounds.y(),
+                        width: bounds.width(),
+                        height: bounds.height(),
+                    } * transformation
+                };
+
+                if !clip_bounds.intersects(&physical_bounds) {
+                    return;
+                }
+
+                let clip_mask =
+                    (!physical_bounds.is_within(&clip_bounds)).then_some(clip_mask as &_);
+
+                draw_conic(
+                    path,
+                    *rule,
+                    gradient,
+                    into_transform(transformation).pre_concat(*transform),
+                    pixels,
+                    clip_mask,
+                );
+            }
             Primitive::Stroke {
                 path,
                 paint,
@@ -608,6 +651,181 @@ pub fn into_color(color: Color) -> tiny_skia::Color {
         .expect("Convert color from iced to tiny_skia")
 }
 
+pub fn into_gradient_stops(stops: &[Option<ColorStop>; 8]) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<tiny_skia::GradientStop> = stops
+        .iter()
+        .flatten()
+        .map(|stop| tiny_skia::GradientStop::new(stop.offset, into_color(stop.color)))
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+pub fn into_radial_gradient(
+    center: Point,
+    radii: Size,
+    focal: Point,
+    stops: &[Option<ColorStop>; 8],
+) -> tiny_skia::Shader<'static> {
+    // The gradient is drawn as a unit circle scaled into the ending ellipse
+    let focal = tiny_skia::Point {
+        x: (focal.x - center.x) / radii.width,
+        y: (focal.y - center.y) / radii.height,
+    };
+
+    let fallback = stops
+        .iter()
+        .flatten()
+        .next_back()
+        .map_or(tiny_skia::Color::BLACK, |stop| into_color(stop.color));
+
+    tiny_skia::RadialGradient::new(
+        focal,
+        tiny_skia::Point::zero(),
+        1.0,
+        into_gradient_stops(stops),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::Transform::from_row(radii.width, 0.0, 0.0, radii.height, center.x, center.y),
+    )
+    .unwrap_or(tiny_skia::Shader::SolidColor(fallback))
+}
+
+/// Fills the given path with a rasterized conic gradient.
+///
+/// The `transform` maps both the path and the gradient into the pixels.
+fn draw_conic(
+    path: &tiny_skia::Path,
+    rule: tiny_skia::FillRule,
+    gradient: &gradient::Conic,
+    transform: tiny_skia::Transform,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let Some(bounds) = path.bounds().transform(transform) else {
+        return;
+    };
+
+    let Some(inverse) = transform.invert() else {
+        return;
+    };
+
+    let left = bounds.left().floor().max(0.0) as u32;
+    let top = bounds.top().floor().max(0.0) as u32;
+    let right = (bounds.right().ceil().max(0.0) as u32).min(pixels.width());
+    let bottom = (bounds.bottom().ceil().max(0.0) as u32).min(pixels.height());
+
+    if left >= right || top >= bottom {
+        return;
+    }
+
+    let colors: Vec<tiny_skia::PremultipliedColorU8> = (top..bottom)
+        .flat_map(|y| {
+            (left..right).map(move |x| {
+                let mut point = tiny_skia::Point {
+                    x: x as f32 + 0.5,
+                    y: y as f32 + 0.5,
+                };
+
+                inverse.map_point(&mut point);
+
+                let color = conic_color(gradient, Point::new(point.x, point.y));
+
+                into_color(color).to_color_u8().premultiply()
+            })
+        })
+        .collect();
+
+    let Some(size) = tiny_skia::IntSize::from_wh(right - left, bottom - top) else {
+        return;
+    };
+
+    let Some(mut pixmap) = tiny_skia::Pixmap::from_vec(bytemuck::cast_vec(colors), size) else {
+        return;
+    };
+
+    // The mask only covers the bounds of the path, not the whole target
+    let transform = transform.post_translate(-(left as f32), -(top as f32));
+
+    let mask = match clip_mask {
+        Some(clip_mask) => {
+            let width = (right - left) as usize;
+
+            let data = (top..bottom)
+                .flat_map(|y| {
+                    let start = (y * clip_mask.width() + left) as usize;
+
+                    &clip_mask.data()[start..start + width]
+                })
+                .copied()
+                .collect();
+
+            tiny_skia::Mask::from_vec(data, size).map(|mut mask| {
+                mask.intersect_path(path, rule, true, transform);
+
+                mask
+            })
+        }
+        None => tiny_skia::Mask::new(size.width(), size.height()).map(|mut mask| {
+            mask.fill_path(path, rule, true, transform);
+
+            mask
+        }),
+    };
+
+    let Some(mask) = mask else {
+        return;
+    };
+
+    pixmap.apply_mask(&mask);
+
+    pixels.draw_pixmap(
+        left as i32,
+        top as i32,
+        pixmap.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::identity(),
+        None,
+    );
+}
+
+fn conic_color(gradient: &gradient::Conic, point: Point) -> Color {
+    let Radians(start) = gradient.angle;
+
+    let angle = (point.y - gradient.center.y).atan2(point.x - gradient.center.x)
+        + std::f32::consts::FRAC_PI_2
+        - start;
+
+    let offset = (angle / std::f32::consts::TAU).rem_euclid(1.0);
+
+    let mut stops = gradient.stops.iter().flatten();
+
+    let Some(first) = stops.next() else {
+        return Color::BLACK;
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    let mut previous = first;
+
+    for stop in stops {
+        if offset <= stop.offset {
+            let factor = (offset - previous.offset) / (stop.offset - previous.offset);
+
+            return previous.color.mix(stop.color, factor);
+        }
+
+        previous = stop;
+    }
+
+    previous.color
+}
+
 fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     let translation = transformation.translation();
 
@@ -788,3 +1006,60 @@ pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
         tiny_skia::Transform::default(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
+    const BLUE: Color = Color::from_rgb(0.0, 0.0, 1.0);
+
+    fn conic(angle: f32) -> gradient::Conic {
+        gradient::Conic::new(Point::ORIGIN, Radians(angle))
+            .add_stop(0.0, RED)
+            .add_stop(1.0, BLUE)
+    }
+
+    #[test]
+    fn conic_color_starts_upwards() {
+        let gradient = conic(0.0);
+
+        assert_eq!(conic_color(&gradient, Point::new(0.0, -1.0)), RED);
+        assert_eq!(
+            conic_color(&gradient, Point::new(1.0, 0.0)),
+            RED.mix(BLUE, 0.25)
+        );
+        assert_eq!(
+            conic_color(&gradient, Point::new(0.0, 1.0)),
+            RED.mix(BLUE, 0.5)
+        );
+    }
+
+    #[test]
+    fn conic_color_is_rotated_by_angle() {
+        let gradient = conic(FRAC_PI_2);
+
+        assert_eq!(conic_color(&gradient, Point::new(1.0, 0.0)), RED);
+        assert_eq!(
+            conic_color(&gradient, Point::new(0.0, 1.0)),
+            RED.mix(BLUE, 0.25)
+        );
+    }
+
+    #[test]
+    fn conic_color_wraps_around() {
+        let gradient = conic(PI);
+
+        // Right before the start angle, the offset wraps around to the end
+        let color = conic_color(&gradient, Point::new(0.01, 1.0));
+
+        assert!(color.b > 0.99, "{color:?}");
+        assert_eq!(conic_color(&gradient, Point::new(0.0, 1.0)), RED);
+        assert_eq!(
+            conic_color(&gradient, Point::new(0.0, -1.0)),
+            RED.mix(BLUE, 0.5)
+        );
+    }
+}
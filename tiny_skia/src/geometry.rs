@@ -1,6 +1,7 @@
 use crate::Primitive;
 use crate::core::text::LineHeight;
 use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector};
+use crate::engine;
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
@@ -102,6 +103,17 @@ impl geometry::frame::Backend for Frame {
 
         let fill = fill.into();
 
+        if let Style::Gradient(Gradient::Conic(gradient)) = fill.style {
+            self.primitives.push(Primitive::Conic {
+                path,
+                gradient,
+                transform: self.transform,
+                rule: into_fill_rule(fill.rule),
+            });
+
+            return;
+        }
+
         let mut paint = into_paint(fill.style);
         paint.shader.transform(self.transform);
 
@@ -121,6 +133,17 @@ impl geometry::frame::Backend for Frame {
 
         let fill = fill.into();
 
+        if let Style::Gradient(Gradient::Conic(gradient)) = fill.style {
+            self.primitives.push(Primitive::Conic {
+                path,
+                gradient,
+                transform: self.transform,
+                rule: into_fill_rule(fill.rule),
+            });
+
+            return;
+        }
+
         let mut paint = tiny_skia::Paint {
             anti_alias: false,
             ..into_paint(fill.style)
@@ -142,6 +165,26 @@ impl geometry::frame::Backend for Frame {
         let stroke = stroke.into();
         let skia_stroke = into_stroke(&stroke);
 
+        if let Style::Gradient(Gradient::Conic(gradient)) = stroke.style {
+            let path = match &skia_stroke.dash {
+                Some(dash) => path.dash(dash, 1.0),
+                None => Some(path),
+            };
+
+            let Some(path) = path.and_then(|path| path.stroke(&skia_stroke, 1.0)) else {
+                return;
+            };
+
+            self.primitives.push(Primitive::Conic {
+                path,
+                gradient,
+                transform: self.transform,
+                rule: tiny_skia::FillRule::Winding,
+            });
+
+            return;
+        }
+
         let mut paint = into_paint(stroke.style);
         paint.shader.transform(self.transform);
 
@@ -401,44 +444,28 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
                     .expect("Create color"),
             ),
             Style::Gradient(gradient) => match gradient {
-                Gradient::Linear(linear) => {
-                    let stops: Vec<tiny_skia::GradientStop> = linear
-                        .stops
-                        .into_iter()
-                        .flatten()
-                        .map(|stop| {
-                            tiny_skia::GradientStop::new(
-                                stop.offset,
-                                tiny_skia::Color::from_rgba(
-                                    stop.color.b,
-                                    stop.color.g,
-                                    stop.color.r,
-                                    stop.color.a,
-                                )
-                                .expect("Create color"),
-                            )
-                        })
-                        .collect();
-
-                    tiny_skia::LinearGradient::new(
-                        tiny_skia::Point {
-                            x: linear.start.x,
-                            y: linear.start.y,
-                        },
-                        tiny_skia::Point {
-                            x: linear.end.x,
-                            y: linear.end.y,
-                        },
-                        if stops.is_empty() {
-                            vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
-                        } else {
-                            stops
-                        },
-                        tiny_skia::SpreadMode::Pad,
-                        tiny_skia::Transform::identity(),
-                    )
-                    .expect("Create linear gradient")
-                }
+                Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+                    tiny_skia::Point {
+                        x: linear.start.x,
+                        y: linear.start.y,
+                    },
+                    tiny_skia::Point {
+                        x: linear.end.x,
+                        y: linear.end.y,
+                    },
+                    engine::into_gradient_stops(&linear.stops),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                )
+                .expect("Create linear gradient"),
+                Gradient::Radial(radial) => engine::into_radial_gradient(
+                    radial.center,
+                    radial.radii,
+                    radial.focal,
+                    &radial.stops,
+                ),
+                // Conic gradients are drawn as a `Primitive::Conic` instead
+                Gradient::Conic(_) => tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT),
             },
         },
         anti_alias: true,
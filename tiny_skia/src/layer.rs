@@ -57,6 +57,22 @@ impl Layer {
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
+        for (bounds, decoration) in editor.decorations(color) {
+            let Some(bounds) = (bounds + (position - Point::ORIGIN)).intersection(&clip_bounds)
+            else {
+                continue;
+            };
+
+            self.draw_quad(
+                Quad {
+                    bounds,
+                    ..Quad::default()
+                },
+                Background::Color(decoration),
+                transformation,
+            );
+        }
+
         let editor = Text::Editor {
             editor: editor.downgrade(),
             position,
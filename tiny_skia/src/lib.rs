@@ -365,6 +365,13 @@ impl core::image::Renderer for Renderer {
         self.engine.raster_pipeline.dimensions(handle)
     }
 
+    fn load_frames(
+        &self,
+        handle: &Self::Handle,
+    ) -> Result<core::image::Frames, core::image::Error> {
+        self.engine.raster_pipeline.load_frames(handle)
+    }
+
     fn draw_image(&mut self, image: core::Image, bounds: Rectangle, clip_bounds: Rectangle) {
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, clip_bounds, transformation);
@@ -1,4 +1,5 @@
 use crate::core::Rectangle;
+use crate::graphics::gradient;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Primitive {
@@ -20,6 +21,20 @@ pub enum Primitive {
         /// The stroke settings.
         stroke: tiny_skia::Stroke,
     },
+    /// A path filled with a conic gradient.
+    ///
+    /// `tiny_skia` does not support conic gradients, so they are rasterized
+    /// when drawn.
+    Conic {
+        /// The path to fill.
+        path: tiny_skia::Path,
+        /// The conic gradient to fill the path with.
+        gradient: gradient::Conic,
+        /// The transform of the gradient.
+        transform: tiny_skia::Transform,
+        /// The fill rule to follow.
+        rule: tiny_skia::FillRule,
+    },
 }
 
 impl Primitive {
@@ -28,6 +43,7 @@ impl Primitive {
         let bounds = match self {
             Primitive::Fill { path, .. } => path.bounds(),
             Primitive::Stroke { path, .. } => path.bounds(),
+            Primitive::Conic { path, .. } => path.bounds(),
         };
 
         Rectangle {
@@ -26,6 +26,10 @@ impl Pipeline {
         Ok(unsafe { raster::allocate(handle, Size::new(image.width(), image.height())) })
     }
 
+    pub fn load_frames(&self, handle: &raster::Handle) -> Result<raster::Frames, raster::Error> {
+        self.cache.borrow_mut().load_frames(handle)
+    }
+
     pub fn dimensions(&self, handle: &raster::Handle) -> Option<Size<u32>> {
         let mut cache = self.cache.borrow_mut();
         let image = cache.allocate(handle).ok()?;
@@ -81,6 +85,8 @@ impl Pipeline {
 #[derive(Debug, Default)]
 struct Cache {
     entries: FxHashMap<raster::Id, Option<Entry>>,
+    animations: FxHashMap<raster::Id, raster::Frames>,
+    frames: FxHashMap<raster::Id, raster::Id>,
     hits: FxHashSet<raster::Id>,
 }
 
@@ -138,8 +144,52 @@ impl Cache {
             .expect("Image should be allocated"))
     }
 
+    pub fn load_frames(
+        &mut self,
+        handle: &raster::Handle,
+    ) -> Result<raster::Frames, raster::Error> {
+        let id = handle.id();
+        let _ = self.hits.insert(id);
+
+        if let Some(frames) = self.animations.get(&id) {
+            return Ok(frames.clone());
+        }
+
+        let frames = graphics::image::load_frames(handle)?;
+
+        if frames.is_animated() {
+            for frame in frames.iter() {
+                let _ = self.frames.insert(frame.handle.id(), id);
+            }
+
+            let _ = self.animations.insert(id, frames.clone());
+        }
+
+        Ok(frames)
+    }
+
     fn trim(&mut self) {
-        self.entries.retain(|key, _| self.hits.contains(key));
+        // Animations stay alive as long as any of their frames is drawn
+        let animations: FxHashSet<_> = self
+            .hits
+            .iter()
+            .filter_map(|id| self.frames.get(id).copied())
+            .chain(self.hits.iter().copied())
+            .collect();
+
+        self.entries.retain(|key, _| {
+            self.hits.contains(key)
+                || self
+                    .frames
+                    .get(key)
+                    .is_some_and(|animation| animations.contains(animation))
+        });
+
+        self.animations.retain(|key, _| animations.contains(key));
+
+        self.frames
+            .retain(|_, animation| self.animations.contains_key(animation));
+
         self.hits.clear();
     }
 }
@@ -567,6 +567,18 @@ impl Transform {
                 linear.start = self.transform_point(linear.start);
                 linear.end = self.transform_point(linear.end);
             }
+            Gradient::Radial(radial) => {
+                radial.center = self.transform_point(radial.center);
+                radial.focal = self.transform_point(radial.focal);
+                radial.radii = Size::new(
+                    radial.radii.width * self.0.m11.hypot(self.0.m12),
+                    radial.radii.height * self.0.m21.hypot(self.0.m22),
+                );
+            }
+            Gradient::Conic(conic) => {
+                conic.center = self.transform_point(conic.center);
+                conic.angle = Radians(conic.angle.0 + self.0.m12.atan2(self.0.m11));
+            }
         }
 
         gradient
@@ -37,6 +37,8 @@ impl Cache {
             raster: Raster {
                 cache: crate::image::raster::Cache::default(),
                 pending: HashMap::new(),
+                #[cfg(not(target_arch = "wasm32"))]
+                decoding: HashMap::new(),
                 belt: wgpu::util::StagingBelt::new(device.clone(), 2 * 1024 * 1024),
             },
             #[cfg(feature = "svg")]
@@ -165,6 +167,47 @@ impl Cache {
         }
     }
 
+    #[cfg(feature = "image")]
+    pub fn load_frames(
+        &mut self,
+        handle: &core::image::Handle,
+    ) -> Result<core::image::Frames, core::image::Error> {
+        self.raster.cache.load_frames(handle)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn frames(
+        &mut self,
+        handle: &core::image::Handle,
+    ) -> Option<Result<core::image::Frames, core::image::Error>> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            // TODO: Concurrent support for Wasm
+            return Some(self.load_frames(handle));
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.receive();
+
+            if let Some(Some(_)) = self.raster.decoding.get(&handle.id()) {
+                return self.raster.decoding.remove(&handle.id()).flatten();
+            }
+
+            if let Some(frames) = self.raster.cache.animation(handle) {
+                return Some(Ok(frames));
+            }
+
+            if !self.raster.decoding.contains_key(&handle.id()) {
+                let _ = self.raster.decoding.insert(handle.id(), None);
+
+                self.worker.decode(handle);
+            }
+
+            None
+        }
+    }
+
     #[cfg(feature = "image")]
     pub fn measure_image(&mut self, handle: &core::image::Handle) -> Option<Size<u32>> {
         self.receive();
@@ -328,6 +371,13 @@ impl Cache {
 
                     self.raster.cache.insert(&handle, Memory::Error(error));
                 }
+                worker::Work::Frames { handle, result } => {
+                    if let Ok(frames) = &result {
+                        self.raster.cache.insert_animation(&handle, frames);
+                    }
+
+                    let _ = self.raster.decoding.insert(handle.id(), Some(result));
+                }
             }
         }
     }
@@ -344,6 +394,8 @@ impl Drop for Cache {
 struct Raster {
     cache: crate::image::raster::Cache,
     pending: HashMap<core::image::Id, Vec<Callback>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    decoding: HashMap<core::image::Id, Option<Result<core::image::Frames, core::image::Error>>>,
     belt: wgpu::util::StagingBelt,
 }
 
@@ -447,6 +499,12 @@ mod worker {
             });
         }
 
+        pub fn decode(&self, handle: &image::Handle) {
+            let _ = self.jobs.send(Job::Decode {
+                handle: handle.clone(),
+            });
+        }
+
         pub fn drop(&self, bind_group: Arc<wgpu::BindGroup>) {
             let _ = self.jobs.send(Job::Drop(bind_group));
         }
@@ -486,6 +544,9 @@ mod worker {
             width: u32,
             height: u32,
         },
+        Decode {
+            handle: image::Handle,
+        },
         Drop(Arc<wgpu::BindGroup>),
         Quit,
     }
@@ -500,6 +561,10 @@ mod worker {
             handle: image::Handle,
             error: image::Error,
         },
+        Frames {
+            handle: image::Handle,
+            result: Result<image::Frames, image::Error>,
+        },
     }
 
     impl Instance {
@@ -541,6 +606,12 @@ mod worker {
                     } => {
                         self.upload(handle, width, height, rgba, Shell::request_redraw);
                     }
+                    Job::Decode { handle } => {
+                        let result = crate::graphics::image::load_frames(&handle);
+                        let _ = self.output.send(Work::Frames { handle, result });
+
+                        self.shell.request_redraw();
+                    }
                     Job::Drop(bind_group) => {
                         drop(bind_group);
                     }
@@ -53,6 +53,8 @@ impl Memory {
 #[derive(Debug, Default)]
 pub struct Cache {
     map: FxHashMap<image::Id, Memory>,
+    animations: FxHashMap<image::Id, image::Frames>,
+    frames: FxHashMap<image::Id, image::Id>,
     hits: FxHashSet<image::Id>,
     should_trim: bool,
 }
@@ -75,6 +77,40 @@ impl Cache {
         self.map.contains_key(&handle.id())
     }
 
+    pub fn load_frames(&mut self, handle: &image::Handle) -> Result<image::Frames, image::Error> {
+        if let Some(frames) = self.animation(handle) {
+            return Ok(frames);
+        }
+
+        let frames = graphics::image::load_frames(handle)?;
+        self.insert_animation(handle, &frames);
+
+        Ok(frames)
+    }
+
+    pub fn animation(&mut self, handle: &image::Handle) -> Option<image::Frames> {
+        let id = handle.id();
+        let _ = self.hits.insert(id);
+
+        self.animations.get(&id).cloned()
+    }
+
+    pub fn insert_animation(&mut self, handle: &image::Handle, frames: &image::Frames) {
+        if !frames.is_animated() {
+            return;
+        }
+
+        let id = handle.id();
+
+        for frame in frames.iter() {
+            let _ = self.frames.insert(frame.handle.id(), id);
+        }
+
+        let _ = self.animations.insert(id, frames.clone());
+        let _ = self.hits.insert(id);
+        self.should_trim = true;
+    }
+
     pub fn trim(&mut self, atlas: &mut Atlas, on_drop: impl Fn(Arc<wgpu::BindGroup>)) {
         // Only trim if new entries have landed in the `Cache`
         if !self.should_trim {
@@ -82,6 +118,14 @@ impl Cache {
         }
 
         let hits = &self.hits;
+        let frames = &self.frames;
+
+        // Animations stay alive as long as any of their frames is drawn
+        let animations: FxHashSet<_> = hits
+            .iter()
+            .filter_map(|id| frames.get(id).copied())
+            .chain(hits.iter().copied())
+            .collect();
 
         self.map.retain(|id, memory| {
             // Retain active allocations
@@ -93,7 +137,10 @@ impl Cache {
                 return true;
             }
 
-            let retain = hits.contains(id);
+            let retain = hits.contains(id)
+                || frames
+                    .get(id)
+                    .is_some_and(|animation| animations.contains(animation));
 
             if !retain {
                 log::debug!("Dropping image allocation: {id:?}");
@@ -113,6 +160,13 @@ impl Cache {
             retain
         });
 
+        self.animations.retain(|id, _| animations.contains(id));
+
+        let animations = &self.animations;
+
+        self.frames
+            .retain(|_, animation| animations.contains_key(animation));
+
         self.hits.clear();
         self.should_trim = false;
     }
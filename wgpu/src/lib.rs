@@ -794,6 +794,20 @@ impl core::image::Renderer for Renderer {
         self.image_cache.borrow_mut().measure_image(handle)
     }
 
+    fn load_frames(
+        &self,
+        handle: &Self::Handle,
+    ) -> Result<core::image::Frames, core::image::Error> {
+        self.image_cache.borrow_mut().load_frames(handle)
+    }
+
+    fn frames(
+        &self,
+        handle: &Self::Handle,
+    ) -> Option<Result<core::image::Frames, core::image::Error>> {
+        self.image_cache.borrow_mut().frames(handle)
+    }
+
     fn draw_image(&mut self, image: core::Image, bounds: Rectangle, clip_bounds: Rectangle) {
         let (layer, transformation) = self.layers.current_mut();
         layer.draw_raster(image, bounds, clip_bounds, transformation);
@@ -87,6 +87,8 @@ impl Pipeline {
                     "\n",
                     include_str!("../shader/quad/gradient.wgsl"),
                     "\n",
+                    include_str!("../shader/gradient.wgsl"),
+                    "\n",
                     include_str!("../shader/color.wgsl"),
                     "\n",
                     include_str!("../shader/color/linear_rgb.wgsl")
@@ -115,16 +117,18 @@ impl Pipeline {
                             4 => Uint32x4,
                             // Direction
                             5 => Float32x4,
-                            // Position & Scale
+                            // Parameters
                             6 => Float32x4,
-                            // Border color
+                            // Position & Scale
                             7 => Float32x4,
-                            // Border radius
+                            // Border color
                             8 => Float32x4,
+                            // Border radius
+                            9 => Float32x4,
                             // Border width
-                            9 => Float32,
+                            10 => Float32,
                             // Snap
-                            10 => Uint32,
+                            11 => Uint32,
                         ),
                     }],
                     compilation_options: wgpu::PipelineCompilationOptions::default(),
@@ -0,0 +1,49 @@
+/// Returns the offset of the given position along a gradient.
+///
+/// The kind of the gradient is stored in `parameters.x`:
+/// - `0`: linear, from `direction.xy` to `direction.zw`.
+/// - `1`: radial, from the focal point at `direction.zw` to the ellipse
+///   centered at `direction.xy` with radii `parameters.yz`.
+/// - `2`: conic, around `direction.xy` starting at the angle `parameters.y`.
+fn gradient_offset(position: vec2<f32>, direction: vec4<f32>, parameters: vec4<f32>) -> f32 {
+    switch u32(parameters.x) {
+        case 1u: {
+            // Map the ellipse to a unit circle
+            let radii = parameters.yz;
+            let point = (position - direction.xy) / radii;
+            let focal = (direction.zw - direction.xy) / radii;
+
+            let ray = point - focal;
+            let distance = length(ray);
+
+            if (distance == 0.0) {
+                return 0.0;
+            }
+
+            // Find where the ray from the focal point hits the circle
+            let unit = ray / distance;
+            let projection = dot(focal, unit);
+            let reach = sqrt(max(projection * projection - dot(focal, focal) + 1.0, 0.0)) - projection;
+
+            return distance / reach;
+        }
+        case 2u: {
+            let v = position - direction.xy;
+
+            // Angles start upwards and grow clockwise, like linear gradients
+            let angle = atan2(v.y, v.x) + 1.5707964 - parameters.y;
+
+            return fract(angle / 6.2831855);
+        }
+        default: {
+            let start = direction.xy;
+            let end = direction.zw;
+
+            let v1 = end - start;
+            let v2 = position - start;
+            let unit = normalize(v1);
+
+            return dot(unit, v2) / length(v1);
+        }
+    }
+}
@@ -6,11 +6,12 @@ struct GradientVertexInput {
     @location(3) @interpolate(flat) colors_4: vec4<u32>,
     @location(4) @interpolate(flat) offsets: vec4<u32>,
     @location(5) direction: vec4<f32>,
-    @location(6) position_and_scale: vec4<f32>,
-    @location(7) border_color: vec4<f32>,
-    @location(8) border_radius: vec4<f32>,
-    @location(9) border_width: f32,
-    @location(10) snap: u32,
+    @location(6) parameters: vec4<f32>,
+    @location(7) position_and_scale: vec4<f32>,
+    @location(8) border_color: vec4<f32>,
+    @location(9) border_radius: vec4<f32>,
+    @location(10) border_width: f32,
+    @location(11) snap: u32,
 }
 
 struct GradientVertexOutput {
@@ -21,10 +22,11 @@ struct GradientVertexOutput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
-    @location(7) position_and_scale: vec4<f32>,
-    @location(8) border_color: vec4<f32>,
-    @location(9) border_radius: vec4<f32>,
-    @location(10) border_width: f32,
+    @location(7) @interpolate(flat) parameters: vec4<f32>,
+    @location(8) position_and_scale: vec4<f32>,
+    @location(9) border_color: vec4<f32>,
+    @location(10) border_radius: vec4<f32>,
+    @location(11) border_width: f32,
 }
 
 @vertex
@@ -64,6 +66,13 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.colors_4 = input.colors_4;
     out.offsets = input.offsets;
     out.direction = input.direction * globals.scale;
+    out.parameters = input.parameters;
+
+    // Radii of radial gradients are distances, too
+    if (u32(input.parameters.x) == 1u) {
+        out.parameters = vec4<f32>(input.parameters.x, input.parameters.yz * globals.scale, input.parameters.w);
+    }
+
     out.position_and_scale = vec4<f32>(pos + pos_snap, scale + scale_snap);
     out.border_color = premultiply(input.border_color);
     out.border_radius = border_radius * globals.scale;
@@ -80,17 +89,12 @@ fn random(coords: vec2<f32>) -> f32 {
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
+    parameters: vec4<f32>,
     colors: array<vec4<f32>, 8>,
     offsets: array<f32, 8>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
-
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+    let coord_offset = gradient_offset(raw_position, direction, parameters);
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -161,7 +165,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
+    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, input.parameters, colors, offsets, last_index);
 
     let pos = input.position_and_scale.xy;
     let scale = input.position_and_scale.zw;
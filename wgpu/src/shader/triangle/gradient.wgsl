@@ -6,6 +6,7 @@ struct GradientVertexInput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
+    @location(7) parameters: vec4<f32>,
 }
 
 struct GradientVertexOutput {
@@ -17,6 +18,7 @@ struct GradientVertexOutput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
+    @location(7) @interpolate(flat) parameters: vec4<f32>,
 }
 
 @vertex
@@ -31,6 +33,7 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     output.colors_4 = input.colors_4;
     output.offsets = input.offsets;
     output.direction = input.direction;
+    output.parameters = input.parameters;
 
     return output;
 }
@@ -39,17 +42,12 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
+    parameters: vec4<f32>,
     colors: array<vec4<f32>, 8>,
     offsets: array<f32, 8>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
-
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+    let coord_offset = gradient_offset(raw_position, direction, parameters);
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -119,7 +117,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    return gradient(input.raw_position, input.direction, colors, offsets, last_index);
+    return gradient(input.raw_position, input.direction, input.parameters, colors, offsets, last_index);
 }
 
 fn random(coords: vec2<f32>) -> f32 {
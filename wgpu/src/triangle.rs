@@ -831,6 +831,8 @@ mod gradient {
                     "\n",
                     include_str!("shader/triangle/gradient.wgsl"),
                     "\n",
+                    include_str!("shader/gradient.wgsl"),
+                    "\n",
                     include_str!("shader/color.wgsl"),
                     "\n",
                     include_str!("shader/color/linear_rgb.wgsl")
@@ -860,7 +862,9 @@ mod gradient {
                             // Offsets
                             5 => Uint32x4,
                             // Direction
-                            6 => Float32x4
+                            6 => Float32x4,
+                            // Parameters
+                            7 => Float32x4
                         ),
                     }],
                     compilation_options: wgpu::PipelineCompilationOptions::default(),
@@ -24,6 +24,7 @@ use crate::core::renderer;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget::Operation;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -249,6 +250,11 @@ where
         operation: &mut dyn Operation,
     ) {
         operation.container(None, layout.bounds());
+        operation.semantics(
+            None,
+            layout.bounds(),
+            &Semantics::new(semantics::Role::Button).disabled(self.on_press.is_none()),
+        );
         operation.traverse(&mut |operation| {
             self.content.as_widget_mut().operate(
                 &mut tree.children[0],
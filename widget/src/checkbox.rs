@@ -39,6 +39,7 @@ use crate::core::text;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -84,6 +85,7 @@ where
     Renderer: text::Renderer,
     Theme: Catalog,
 {
+    id: Option<widget::Id>,
     is_checked: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: Option<text::Fragment<'a>>,
@@ -114,6 +116,7 @@ where
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     pub fn new(is_checked: bool) -> Self {
         Checkbox {
+            id: None,
             is_checked,
             on_toggle: None,
             label: None,
@@ -137,6 +140,12 @@ where
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Checkbox`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the label of the [`Checkbox`].
     pub fn label(mut self, label: impl text::IntoFragment<'a>) -> Self {
         self.label = Some(label.into_fragment());
@@ -449,6 +458,7 @@ where
                 state.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -462,8 +472,18 @@ where
         _renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        operation.semantics(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Semantics::new(semantics::Role::CheckBox)
+                .toggled(self.is_checked)
+                .disabled(self.on_toggle.is_none()),
+        );
+
         if let Some(label) = self.label.as_deref() {
-            operation.text(None, layout.bounds(), label);
+            operation.traverse(&mut |operation| {
+                operation.text(None, layout.bounds(), label);
+            });
         }
     }
 }
@@ -562,7 +562,7 @@ where
 
         // Create a new list of local messages
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell = Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         // Provide it to the widget
         self.text_input.update(
@@ -579,6 +579,13 @@ where
             shell.capture_event();
         }
 
+        if local_shell.is_drag_changed() {
+            match local_shell.drag() {
+                Some(drag) => shell.start_drag(drag.clone()),
+                None => shell.end_drag(),
+            }
+        }
+
         shell.request_redraw_at(local_shell.redraw_request());
         shell.request_input_method(local_shell.input_method());
         shell.clipboard_mut().merge(local_shell.clipboard_mut());
@@ -718,7 +725,8 @@ where
 
                 // Unfocus the input
                 let mut local_messages = Vec::new();
-                let mut local_shell = Shell::new(&mut local_messages);
+                let mut local_shell =
+                    Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
                 self.text_input.update(
                     &mut tree.children[0],
                     &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
@@ -729,6 +737,13 @@ where
                     viewport,
                 );
                 shell.request_input_method(local_shell.input_method());
+
+                if local_shell.is_drag_changed() {
+                    match local_shell.drag() {
+                        Some(drag) => shell.start_drag(drag.clone()),
+                        None => shell.end_drag(),
+                    }
+                }
             }
         });
 
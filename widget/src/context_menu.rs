@@ -0,0 +1,396 @@
+//! Context menus display a menu at the cursor when some content is right-clicked.
+//!
+//! A context menu can also be opened with the keyboard, by pressing the
+//! <kbd>Menu</kbd> key or <kbd>Shift</kbd>+<kbd>F10</kbd> while the cursor is
+//! over the content or while some widget inside of it is focused.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::context_menu;
+//! use iced::widget::menu_bar::Item;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Cut,
+//!     Copy,
+//!     Paste,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     context_menu(
+//!         "Right-click me!",
+//!         [
+//!             Item::new("Cu&t").on_select(Message::Cut).shortcut("Ctrl+X"),
+//!             Item::new("&Copy").on_select(Message::Copy).shortcut("Ctrl+C"),
+//!             Item::new("&Paste").on_select(Message::Paste).shortcut("Ctrl+V"),
+//!         ],
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::Focusable;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::menu::{self, Metrics};
+use crate::menu_bar::{Catalog, Item, Style, StyleFn};
+
+/// Some content that displays a menu of [`Item`]s when right-clicked.
+///
+/// The menu can also be opened with the <kbd>Menu</kbd> key or
+/// <kbd>Shift</kbd>+<kbd>F10</kbd>.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::context_menu;
+/// use iced::widget::menu_bar::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Delete,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     context_menu("Right-click me!", [Item::new("&Delete").on_select(Message::Delete)]).into()
+/// }
+/// ```
+pub struct ContextMenu<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<'a, Message>>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ContextMenu`] for the given content with the given [`Item`]s.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<'a, Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            padding: menu::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ContextMenu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    menu: menu::State,
+    /// The position of the menu, relative to the content.
+    position: Vector,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.menu.is_open()
+            && let Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            // Event wasn't processed by overlay, so cursor was clicked outside its bounds
+            state.menu.close();
+
+            shell.capture_event();
+            shell.invalidate_layout();
+            shell.request_redraw();
+
+            if !matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+            ) {
+                return;
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) = event
+            && let Some(position) = cursor.position_over(layout.bounds())
+            && !self.items.is_empty()
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.menu.open(0, &self.items, false);
+            state.position = position - layout.position();
+
+            shell.capture_event();
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event
+            && !shell.is_event_captured()
+            && !self.items.is_empty()
+            && match key.as_ref() {
+                keyboard::Key::Named(key::Named::ContextMenu) => true,
+                keyboard::Key::Named(key::Named::F10) => *modifiers == keyboard::Modifiers::SHIFT,
+                _ => false,
+            }
+        {
+            // Open at the cursor or, otherwise, below the focused widget
+            let position = cursor.position_over(layout.bounds()).or_else(|| {
+                let mut operation = FindFocused(None);
+
+                self.content.as_widget_mut().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    &mut operation,
+                );
+
+                operation
+                    .0
+                    .map(|bounds| Point::new(bounds.x, bounds.y + bounds.height))
+            });
+
+            if let Some(position) = position {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.menu.open(0, &self.items, true);
+                state.position = position - layout.position();
+
+                shell.capture_event();
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.menu.is_open() {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                viewport,
+                translation,
+            );
+        }
+
+        let anchor = Rectangle::new(layout.position() + state.position + translation, Size::ZERO);
+
+        Some(menu::overlay(
+            &mut state.menu,
+            vec![(self.items.as_slice(), anchor)],
+            Metrics::new(renderer, self.text_size, self.font, self.padding),
+            &self.class,
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(context_menu)
+    }
+}
+
+/// An [`Operation`](widget::Operation) that finds the bounds of the focused widget.
+struct FindFocused(Option<Rectangle>);
+
+impl widget::Operation for FindFocused {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn widget::Operation)) {
+        operate(self);
+    }
+
+    fn focusable(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        state: &mut dyn Focusable,
+    ) {
+        if state.is_focused() {
+            self.0 = Some(bounds);
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! Draggables let users pick up some content and drop it into a [`DropZone`].
+//!
+//! A [`Draggable`] carries a typed payload. Once the pointer moves far enough
+//! while pressing it, a drag starts and a preview of the content follows the
+//! pointer until it is released. Any [`DropZone`] accepting the type of the
+//! payload will be notified as the drag enters, leaves, or is dropped into it.
+//!
+//! Pressing the escape key cancels the drag in progress.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, draggable, drop_zone, row, text};
+//!
+//! struct State {
+//!     todo: Vec<String>,
+//!     done: Vec<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Complete(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let todo = column(
+//!         state
+//!             .todo
+//!             .iter()
+//!             .enumerate()
+//!             .map(|(index, task)| draggable(text(task), index).into()),
+//!     );
+//!
+//!     let done = column(state.done.iter().map(|task| text(task).into()));
+//!
+//!     row![todo, drop_zone(done).on_drop(Message::Complete)].into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Complete(index) => {
+//!             let task = state.todo.remove(index);
+//!             state.done.push(task);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`DropZone`]: crate::DropZone
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{Operation, Tree, tree};
+use crate::core::{
+    Drag, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::any::Any;
+
+/// The distance the pointer needs to travel before a drag starts.
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+/// Some content that can be dragged into a [`DropZone`].
+///
+/// [`DropZone`]: crate::DropZone
+pub struct Draggable<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
+    drag: Drag,
+    on_drag: Option<Message>,
+    on_cancel: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Draggable<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Draggable`] with the given content and payload.
+    ///
+    /// The payload will be handed to the [`DropZone`] the content is dropped into.
+    ///
+    /// [`DropZone`]: crate::DropZone
+    pub fn new<T>(content: impl Into<Element<'a, Message, Theme, Renderer>>, payload: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        Self {
+            content: content.into(),
+            preview: None,
+            drag: Drag::new(payload),
+            on_drag: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets the content to display under the pointer while the [`Draggable`] is dragged.
+    ///
+    /// By default, the content of the [`Draggable`] itself is displayed.
+    #[must_use]
+    pub fn preview(mut self, preview: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+
+    /// Sets the message that will be produced when a drag of the [`Draggable`] starts.
+    #[must_use]
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when a drag of the [`Draggable`] is
+    /// cancelled with the escape key.
+    #[must_use]
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`Draggable`].
+#[derive(Debug, Clone, Copy, Default)]
+enum State {
+    #[default]
+    Idle,
+    Pressed {
+        origin: Point,
+    },
+    Dragging {
+        grab: Vector,
+    },
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Draggable<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.preview)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(&self.preview)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if shell.is_event_captured() {
+                    return;
+                }
+
+                if let Some(origin) = cursor.position_over(bounds) {
+                    *state = State::Pressed { origin };
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => match *state {
+                State::Pressed { origin } => {
+                    let Some(position) = cursor.land().position() else {
+                        return;
+                    };
+
+                    if position.distance(origin) > DRAG_DEADBAND_DISTANCE {
+                        *state = State::Dragging {
+                            grab: origin - bounds.position(),
+                        };
+
+                        shell.start_drag(self.drag.clone());
+                        shell.invalidate_layout();
+                        shell.request_redraw();
+
+                        if let Some(on_drag) = &self.on_drag {
+                            shell.publish(on_drag.clone());
+                        }
+                    }
+                }
+                State::Dragging { .. } => {
+                    shell.request_redraw();
+                }
+                State::Idle => {}
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let State::Dragging { .. } = state {
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+
+                *state = State::Idle;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if matches!(state, State::Dragging { .. }) => {
+                *state = State::Idle;
+
+                shell.end_drag();
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+
+                if let Some(on_cancel) = &self.on_cancel {
+                    shell.publish(on_cancel.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let State::Dragging { .. } = state {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let content_interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if content_interaction == mouse::Interaction::None && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            content_interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let State::Dragging { grab } = *tree.state.downcast_ref::<State>() else {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                viewport,
+                translation,
+            );
+        };
+
+        let preview = match &mut self.preview {
+            Some(preview) => Preview {
+                element: preview,
+                tree: &mut tree.children[1],
+                layout: None,
+                grab,
+            },
+            None => Preview {
+                element: &mut self.content,
+                tree: &mut tree.children[0],
+                layout: Some(layout),
+                grab,
+            },
+        };
+
+        Some(overlay::Element::new(Box::new(preview)))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Draggable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        draggable: Draggable<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(draggable)
+    }
+}
+
+/// The content displayed under the pointer while dragging.
+struct Preview<'a, 'b, Message, Theme, Renderer> {
+    element: &'a mut Element<'b, Message, Theme, Renderer>,
+    tree: &'a mut Tree,
+    layout: Option<Layout<'a>>,
+    grab: Vector,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        match self.layout {
+            Some(layout) => layout::Node::new(layout.bounds().size()),
+            None => self.element.as_widget_mut().layout(
+                self.tree,
+                renderer,
+                &layout::Limits::new(Size::ZERO, bounds),
+            ),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        // The original content keeps its own layout, wherever it may be
+        let layout = self.layout.unwrap_or(layout);
+
+        let bounds = layout.bounds();
+        let translation = position - self.grab - bounds.position();
+
+        renderer.with_layer(bounds + translation, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                self.element.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    &bounds,
+                );
+            });
+        });
+    }
+}
@@ -0,0 +1,405 @@
+//! Drop zones receive the payload of a [`Draggable`] dropped into them.
+//!
+//! A [`DropZone`] only reacts to drags carrying a payload of its type, so multiple
+//! kinds of drags can coexist in the same user interface.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{column, draggable, drop_zone, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Hover(&'static str),
+//!     Leave,
+//!     Drop(&'static str),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         draggable(text("Apple"), "Apple"),
+//!         draggable(text("Banana"), "Banana"),
+//!         drop_zone(text("Basket"))
+//!             .on_enter(Message::Hover)
+//!             .on_leave(Message::Leave)
+//!             .on_drop(Message::Drop),
+//!     ]
+//!     .into()
+//! }
+//! ```
+//!
+//! [`Draggable`]: crate::Draggable
+use crate::core::border;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{Operation, Tree, tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Color, Drag, Element, Event, Layout, Length, Rectangle, Shell, Size, Theme,
+    Vector, Widget,
+};
+
+use std::any::Any;
+
+/// A container that receives the payload of a [`Draggable`] dropped into it.
+///
+/// [`Draggable`]: crate::Draggable
+pub struct DropZone<'a, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_enter: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    on_drop: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+impl<'a, T, Message, Theme, Renderer> DropZone<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`DropZone`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_enter: None,
+            on_leave: None,
+            on_drop: None,
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the message that will be produced when a drag enters the [`DropZone`].
+    #[must_use]
+    pub fn on_enter(mut self, on_enter: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Sets the message that will be produced when a drag leaves the [`DropZone`]
+    /// without being dropped.
+    #[must_use]
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when a drag is dropped into the [`DropZone`].
+    #[must_use]
+    pub fn on_drop(mut self, on_drop: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the style of the [`DropZone`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DropZone`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The local state of a [`DropZone`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_hovered: bool,
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropZone<'_, T, Message, Theme, Renderer>
+where
+    T: Any + Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let payload = shell.drag().and_then(|drag| drag.payload::<T>()).cloned();
+
+        let is_over = payload.is_some() && cursor.is_over(layout.bounds());
+
+        let is_dropped = is_over
+            && !shell.is_event_captured()
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerLifted { .. })
+            );
+
+        match payload {
+            Some(payload) if is_dropped => {
+                state.is_hovered = false;
+
+                if let Some(on_drop) = &self.on_drop {
+                    shell.publish(on_drop(payload));
+                    shell.capture_event();
+                }
+            }
+            Some(payload) if is_over && !state.is_hovered => {
+                state.is_hovered = true;
+
+                if let Some(on_enter) = &self.on_enter {
+                    shell.publish(on_enter(payload));
+                }
+            }
+            _ if !is_over && state.is_hovered => {
+                state.is_hovered = false;
+
+                if let Some(on_leave) = &self.on_leave {
+                    shell.publish(on_leave.clone());
+                }
+            }
+            _ => {}
+        }
+
+        let current_status = if state.is_hovered {
+            Status::Hovered
+        } else if shell.drag().is_some_and(Drag::is::<T>) {
+            Status::Dragging
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event {
+            self.status = Some(current_status);
+        } else if self.status.is_some_and(|status| status != current_status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(&self.class, self.status.unwrap_or(Status::Active));
+
+        if appearance.background.is_some() || appearance.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    ..renderer::Quad::default()
+                },
+                appearance
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<DropZone<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Any + Clone + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        drop_zone: DropZone<'a, T, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(drop_zone)
+    }
+}
+
+/// The possible status of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// No drag the [`DropZone`] accepts is in progress.
+    Active,
+    /// A drag the [`DropZone`] accepts is in progress.
+    Dragging,
+    /// A drag the [`DropZone`] accepts is over it.
+    Hovered,
+}
+
+/// The appearance of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] of the [`DropZone`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`DropZone`].
+    pub border: Border,
+}
+
+/// The theme catalog of a [`DropZone`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DropZone`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DropZone`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.palette();
+
+    match status {
+        Status::Active => Style::default(),
+        Status::Dragging => Style {
+            background: None,
+            border: border::rounded(2)
+                .width(1)
+                .color(palette.primary.weak.color),
+        },
+        Status::Hovered => Style {
+            background: Some(palette.primary.weak.color.scale_alpha(0.3).into()),
+            border: border::rounded(2)
+                .width(2)
+                .color(palette.primary.base.color),
+        },
+    }
+}
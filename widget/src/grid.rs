@@ -4,17 +4,34 @@ use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::{Operation, Tree};
-use crate::core::{Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget};
+use crate::core::{
+    Alignment, Element, Event, Length, Padding, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
 
 /// A container that distributes its contents on a responsive grid.
+///
+/// By default, every cell of a [`Grid`] has the same size. Setting some
+/// [`template_columns`](Self::template_columns) turns it into a grid of
+/// tracks instead, where cells can be placed anywhere with
+/// [`push_at`](Self::push_at), span multiple tracks and be aligned
+/// individually.
 pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     spacing: f32,
     columns: Constraint,
     width: Option<Pixels>,
     height: Sizing,
+    template: Option<Template>,
+    cells: Vec<Cell>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
 }
 
+#[derive(Debug, Default)]
+struct Template {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    areas: Vec<Vec<String>>,
+}
+
 enum Constraint {
     MaxWidth(Pixels),
     Amount(usize),
@@ -50,6 +67,8 @@ where
             columns: Constraint::Amount(3),
             width: None,
             height: Sizing::AspectRatio(1.0),
+            template: None,
+            cells: vec![Cell::default(); children.len()],
             children,
         }
     }
@@ -91,8 +110,66 @@ where
         self
     }
 
+    /// Sets the tracks of the columns of the [`Grid`].
+    ///
+    /// A [`Length::Fixed`] column has the given width, a [`Length::Shrink`]
+    /// column fits its cells, and [`Length::Fill`] or [`Length::FillPortion`]
+    /// columns share the remaining space.
+    ///
+    /// Once set, the cells of the [`Grid`] are placed on its tracks instead
+    /// of being sized evenly.
+    pub fn template_columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        self.template.get_or_insert_with(Template::default).columns =
+            columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the tracks of the rows of the [`Grid`].
+    ///
+    /// Rows are sized just like [`template_columns`](Self::template_columns).
+    /// Any rows needed beyond these [`Length::Shrink`] to fit their cells.
+    pub fn template_rows(mut self, rows: impl IntoIterator<Item = impl Into<Length>>) -> Self {
+        self.template.get_or_insert_with(Template::default).rows =
+            rows.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Names the areas of the [`Grid`], so cells can be placed in them with
+    /// [`Cell::area`].
+    ///
+    /// Each row is described by the whitespace-separated names of its
+    /// columns, using `.` for unnamed cells. A name repeated in adjacent
+    /// columns or rows spans all of them:
+    ///
+    /// ```text
+    /// header  header
+    /// sidebar main
+    /// ```
+    pub fn template_areas<'b>(mut self, rows: impl IntoIterator<Item = &'b str>) -> Self {
+        self.template.get_or_insert_with(Template::default).areas = rows
+            .into_iter()
+            .map(|row| row.split_whitespace().map(str::to_owned).collect())
+            .collect();
+        self
+    }
+
     /// Adds an [`Element`] to the [`Grid`].
-    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_at(Cell::default(), child)
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] in the given [`Cell`].
+    ///
+    /// The [`Cell`] is only taken into account when the [`Grid`] has tracks.
+    pub fn push_at(
+        mut self,
+        cell: impl Into<Cell>,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.cells.push(cell.into());
         self.children.push(child.into());
         self
     }
@@ -168,6 +245,23 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let size = self.size();
+
+        if let Some(template) = &self.template {
+            return layout::grid::resolve(
+                renderer,
+                limits,
+                size.width,
+                size.height,
+                Padding::ZERO,
+                Size::new(self.spacing, self.spacing),
+                &template.columns,
+                &template.rows,
+                &template.place(&self.cells),
+                &mut self.children,
+                &mut tree.children,
+            );
+        }
+
         let limits = limits.width(size.width).height(size.height);
         let available = limits.max();
 
@@ -391,3 +485,206 @@ impl From<Length> for Sizing {
 pub fn aspect_ratio(width: impl Into<Pixels>, height: impl Into<Pixels>) -> Sizing {
     Sizing::AspectRatio(width.into().0 / height.into().0)
 }
+
+/// The placement of an element in a [`Grid`] with tracks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cell {
+    position: Position,
+    rows: usize,
+    columns: usize,
+    align_x: Alignment,
+    align_y: Alignment,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Position {
+    Auto,
+    At { row: usize, column: usize },
+    Area(String),
+}
+
+impl Cell {
+    /// Creates a [`Cell`] placed in the next free track of the [`Grid`],
+    /// filling rows first.
+    pub fn auto() -> Self {
+        Self {
+            position: Position::Auto,
+            rows: 1,
+            columns: 1,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+        }
+    }
+
+    /// Creates a [`Cell`] placed at the given row and column of the [`Grid`].
+    pub fn at(row: usize, column: usize) -> Self {
+        Self {
+            position: Position::At { row, column },
+            ..Self::auto()
+        }
+    }
+
+    /// Creates a [`Cell`] covering the named area of the [`Grid`].
+    ///
+    /// If the area does not exist, the [`Cell`] is placed automatically.
+    ///
+    /// See [`Grid::template_areas`].
+    pub fn area(name: impl Into<String>) -> Self {
+        Self {
+            position: Position::Area(name.into()),
+            ..Self::auto()
+        }
+    }
+
+    /// Sets the amount of rows and columns spanned by the [`Cell`].
+    ///
+    /// The span of a named area is given by its template.
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.rows = rows.max(1);
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Cell`].
+    pub fn align_x(mut self, alignment: impl Into<Alignment>) -> Self {
+        self.align_x = alignment.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Cell`].
+    pub fn align_y(mut self, alignment: impl Into<Alignment>) -> Self {
+        self.align_y = alignment.into();
+        self
+    }
+
+    /// Centers the contents of the [`Cell`] in both axes.
+    pub fn center(self) -> Self {
+        self.align_x(Alignment::Center).align_y(Alignment::Center)
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::auto()
+    }
+}
+
+impl From<(usize, usize)> for Cell {
+    fn from((row, column): (usize, usize)) -> Self {
+        Self::at(row, column)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(area: &str) -> Self {
+        Self::area(area)
+    }
+}
+
+impl Template {
+    /// Places the given cells on the tracks of the [`Template`].
+    ///
+    /// Automatic cells take the free tracks left by the others, in order.
+    fn place(&self, cells: &[Cell]) -> Vec<layout::grid::Cell> {
+        let columns = self
+            .areas
+            .iter()
+            .map(Vec::len)
+            .fold(self.columns.len(), usize::max)
+            .max(1);
+
+        let mut areas: Vec<Option<layout::grid::Area>> = cells
+            .iter()
+            .map(|cell| match &cell.position {
+                Position::Auto => None,
+                Position::At { row, column } => Some(layout::grid::Area {
+                    row: *row,
+                    column: *column,
+                    rows: cell.rows,
+                    columns: cell.columns,
+                }),
+                Position::Area(name) => self.area(name),
+            })
+            .collect();
+
+        let mut next = 0;
+
+        for (i, cell) in cells.iter().enumerate() {
+            if areas[i].is_some() {
+                continue;
+            }
+
+            let span = cell.columns.min(columns);
+
+            let area = loop {
+                let area = layout::grid::Area {
+                    row: next / columns,
+                    column: next % columns,
+                    rows: cell.rows,
+                    columns: span,
+                };
+
+                let fits = area.column + span <= columns
+                    && !areas.iter().flatten().any(|other| overlap(&area, other));
+
+                if fits {
+                    break area;
+                }
+
+                next += 1;
+            };
+
+            next += span;
+            areas[i] = Some(area);
+        }
+
+        areas
+            .into_iter()
+            .zip(cells)
+            .map(|(area, cell)| layout::grid::Cell {
+                area: area.unwrap_or(layout::grid::Area::new(0, 0)),
+                align_x: cell.align_x,
+                align_y: cell.align_y,
+            })
+            .collect()
+    }
+
+    /// Returns the [`Area`](layout::grid::Area) covered by the given name.
+    fn area(&self, name: &str) -> Option<layout::grid::Area> {
+        let mut tracks = self.areas.iter().enumerate().flat_map(|(row, columns)| {
+            columns
+                .iter()
+                .enumerate()
+                .filter(|(_, area)| *area == name)
+                .map(move |(column, _)| (row, column))
+        });
+
+        let first = tracks.next()?;
+
+        let (row, column, last_row, last_column) = tracks.fold(
+            (first.0, first.1, first.0, first.1),
+            |(row, column, last_row, last_column), (r, c)| {
+                (
+                    row.min(r),
+                    column.min(c),
+                    last_row.max(r),
+                    last_column.max(c),
+                )
+            },
+        );
+
+        Some(layout::grid::Area {
+            row,
+            column,
+            rows: last_row - row + 1,
+            columns: last_column - column + 1,
+        })
+    }
+}
+
+fn overlap(a: &layout::grid::Area, b: &layout::grid::Area) -> bool {
+    a.row < b.row + b.rows
+        && b.row < a.row + a.rows
+        && a.column < b.column + b.columns
+        && b.column < a.column + a.columns
+}
@@ -3,13 +3,17 @@ use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::ContextMenu;
 use crate::core;
 use crate::core::theme;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Size, Widget};
+use crate::drop_zone::{self, DropZone};
 use crate::float::{self, Float};
 use crate::keyed;
+use crate::menu_bar::{self, Item, Menu, MenuBar};
+use crate::number_input::NumberInput;
 use crate::overlay;
 use crate::pane_grid::{self, PaneGrid};
 use crate::pick_list::{self, PickList};
@@ -17,13 +21,18 @@ use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::tabs::{self, Tab, Tabs};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::tree_view::{self, TreeView};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer};
+use crate::virtual_list::VirtualList;
+use crate::{
+    Column, Draggable, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer,
+};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -543,6 +552,39 @@ where
     Row::with_children(children)
 }
 
+/// Creates a new wrapping [`Row`] from an iterator.
+///
+/// Flows distribute their children horizontally, wrapping them into new
+/// lines when they run out of space.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{flow, row, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     flow((0..20).map(|i| text!("Tag {i}").into()))
+///         .spacing(10)
+///         .vertical_spacing(5)
+///         .justify(row::Justify::SpaceBetween)
+///         .into()
+/// }
+/// ```
+pub fn flow<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> crate::row::Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Row::with_children(children).wrap()
+}
+
 /// Creates a new [`Grid`] from an iterator.
 pub fn grid<'a, Message, Theme, Renderer>(
     children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
@@ -1419,6 +1461,46 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`].
+///
+/// Number inputs display fields that can be filled with a number.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::number_input;
+///
+/// struct State {
+///    quantity: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     number_input("Quantity", state.quantity)
+///         .max(99)
+///         .on_change(Message::QuantityChanged)
+///         .into()
+/// }
+/// ```
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: T,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: crate::number_input::Number,
+    Message: Clone,
+    Theme: text_input::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(placeholder, value)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// Text editors display a multi-line text input for text editing.
@@ -1973,6 +2055,73 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`Draggable`] with the given content and payload.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, draggable, drop_zone, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Move(u32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     column![
+///         draggable(text("Task #1"), 1),
+///         drop_zone(text("Done")).on_drop(Message::Move),
+///     ]
+///     .into()
+/// }
+/// ```
+pub fn draggable<'a, T, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    payload: T,
+) -> Draggable<'a, Message, Theme, Renderer>
+where
+    T: std::any::Any + Send + Sync,
+    Renderer: core::Renderer,
+{
+    Draggable::new(content, payload)
+}
+
+/// Creates a new [`DropZone`] with the given content.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{drop_zone, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Hover(u32),
+///     Leave,
+///     Drop(u32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     drop_zone(text("Done"))
+///         .on_enter(Message::Hover)
+///         .on_leave(Message::Leave)
+///         .on_drop(Message::Drop)
+///         .into()
+/// }
+/// ```
+pub fn drop_zone<'a, T, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropZone<'a, T, Message, Theme, Renderer>
+where
+    Theme: drop_zone::Catalog,
+    Renderer: core::Renderer,
+{
+    DropZone::new(content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, Theme, Renderer>(
     theme: Option<Theme>,
@@ -2058,3 +2207,167 @@ where
 {
     Responsive::new(f)
 }
+
+/// Creates a new [`VirtualList`] with the given amount of rows and a closure
+/// that produces the row at a given index.
+///
+/// Only the rows that are visible will be built.
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    count: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    VirtualList::new(count, view)
+}
+
+/// Creates a new [`MenuBar`] with the given [`Menu`]s.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu_bar;
+/// use iced::widget::menu_bar::{Item, Menu};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     Undo,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     menu_bar([
+///         Menu::new("&File", [Item::new("&Save").on_select(Message::Save).shortcut("Ctrl+S")]),
+///         Menu::new("&Edit", [Item::new("&Undo").on_select(Message::Undo).shortcut("Ctrl+Z")]),
+///     ])
+///     .into()
+/// }
+/// ```
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = Menu<'a, Message>>,
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: menu_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`ContextMenu`] that displays the given [`Item`]s when the
+/// content is right-clicked.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::context_menu;
+/// use iced::widget::menu_bar::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Rename,
+///     Delete,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     context_menu(
+///         "Right-click me!",
+///         [
+///             Item::new("&Rename").on_select(Message::Rename).shortcut("F2"),
+///             Item::separator(),
+///             Item::new("&Delete").on_select(Message::Delete),
+///         ],
+///     )
+///     .into()
+/// }
+/// ```
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = Item<'a, Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: menu_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates new [`Tabs`] with the given [`Tab`]s, the index of the selected one, and the
+/// content to display under them.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{tabs, text};
+/// use iced::widget::tabs::Tab;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+///     Close(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tabs(
+///         [Tab::new("main.rs"), Tab::new("lib.rs")],
+///         Some(0),
+///         text("fn main() {}"),
+///     )
+///     .on_select(Message::Select)
+///     .on_close(Message::Close)
+///     .into()
+/// }
+/// ```
+pub fn tabs<'a, Message, Theme, Renderer>(
+    tabs: impl IntoIterator<Item = Tab<'a, Message, Theme, Renderer>>,
+    selected: Option<usize>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: tabs::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(tabs, selected).content(content)
+}
+
+/// Creates a new [`TreeView`] with the given root keys and a closure that
+/// produces the [`Node`](tree_view::Node) of a key.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{text, tree_view};
+/// use iced::widget::tree_view::Node;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle(&'static str, bool),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tree_view(["src"], |name: &&'static str| match *name {
+///         "src" => Node::new(text("src")).children(|| ["main.rs", "lib.rs"]),
+///         file => Node::new(text(file)),
+///     })
+///     .on_toggle(Message::Toggle)
+///     .into()
+/// }
+/// ```
+pub fn tree_view<'a, Key, Message, Theme, Renderer>(
+    roots: impl IntoIterator<Item = Key>,
+    view: impl Fn(&Key) -> tree_view::Node<'a, Key, Message, Theme, Renderer> + 'a,
+) -> TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Theme: tree_view::Catalog,
+    Renderer: core::text::Renderer,
+{
+    TreeView::new(roots, view)
+}
@@ -16,7 +16,10 @@
 //! }
 //! ```
 //! <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+pub mod animated;
 pub mod viewer;
+
+pub use animated::Animated;
 pub use viewer::Viewer;
 
 use crate::core::border;
@@ -31,6 +34,11 @@ use crate::core::{
 
 pub use image::{FilterMethod, Handle};
 
+/// Creates a new [`Animated`] image with the given image `Handle`.
+pub fn animated<Handle>(handle: impl Into<Handle>) -> Animated<Handle> {
+    Animated::new(handle)
+}
+
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
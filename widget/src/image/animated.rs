@@ -0,0 +1,358 @@
+//! Animated images play the frames of GIF, APNG, and WebP animations.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::image;
+//!
+//! struct State {
+//!     is_playing: bool,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     image::animated("ferris.gif")
+//!         .playing(state.is_playing)
+//!         .into()
+//! }
+//! ```
+use crate::core::border;
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    ContentFit, Element, Event, Layout, Length, Rectangle, Rotation, Shell, Size, Widget,
+};
+use crate::image::FilterMethod;
+
+/// The minimum amount of time a frame is displayed for.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(10);
+
+/// A frame that plays an animated image while keeping aspect ratio.
+///
+/// Images that are not animated are displayed just like an [`Image`]. So
+/// are animated images until their frames are decoded in the background.
+///
+/// [`Image`]: crate::Image
+pub struct Animated<Handle = image::Handle> {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    border_radius: border::Radius,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    rotation: Rotation,
+    opacity: f32,
+    is_playing: bool,
+    is_looping: bool,
+}
+
+impl<Handle> Animated<Handle> {
+    /// Creates a new [`Animated`] image with the given handle.
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            border_radius: border::Radius::default(),
+            content_fit: ContentFit::default(),
+            filter_method: FilterMethod::default(),
+            rotation: Rotation::default(),
+            opacity: 1.0,
+            is_playing: true,
+            is_looping: true,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] image boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] image boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Animated`] image.
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Applies the given [`Rotation`] to the [`Animated`] image.
+    pub fn rotation(mut self, rotation: impl Into<Rotation>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Animated`] image.
+    ///
+    /// It should be in the [0.0, 1.0] range—`0.0` meaning completely transparent,
+    /// and `1.0` meaning completely opaque.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+
+    /// Sets the [`border::Radius`] of the [`Animated`] image.
+    pub fn border_radius(mut self, border_radius: impl Into<border::Radius>) -> Self {
+        self.border_radius = border_radius.into();
+        self
+    }
+
+    /// Sets whether the [`Animated`] image is playing.
+    ///
+    /// A paused animation keeps displaying its current frame and resumes
+    /// from it once played again. Animations play by default.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the [`Animated`] image starts over once its last frame
+    /// is displayed.
+    ///
+    /// Otherwise, the animation stops at its last frame. Animations loop
+    /// by default.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+}
+
+/// The local state of an [`Animated`] image.
+#[derive(Debug)]
+struct State<Handle> {
+    handle: Option<Handle>,
+    frames: Option<image::Frames<Handle>>,
+    current: usize,
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+    is_finished: bool,
+}
+
+impl<Handle> State<Handle>
+where
+    Handle: Clone + PartialEq,
+{
+    fn reset(&mut self, handle: &Handle) {
+        if self.handle.as_ref() == Some(handle) {
+            return;
+        }
+
+        *self = Self {
+            handle: Some(handle.clone()),
+            ..Self::default()
+        };
+    }
+
+    fn frame<'a>(&'a self, handle: &'a Handle) -> &'a Handle {
+        if self.handle.as_ref() != Some(handle) {
+            return handle;
+        }
+
+        self.frames
+            .as_ref()
+            .and_then(|frames| frames.get(self.current))
+            .map_or(handle, |frame| &frame.handle)
+    }
+}
+
+impl<Handle> Default for State<Handle> {
+    fn default() -> Self {
+        Self {
+            handle: None,
+            frames: None,
+            current: 0,
+            elapsed: Duration::ZERO,
+            last_tick: None,
+            is_finished: false,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer> for Animated<Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + PartialEq + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Handle>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Handle>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Handle>>();
+        state.reset(&self.handle);
+
+        super::layout(
+            renderer,
+            limits,
+            state.frame(&self.handle),
+            self.width,
+            self.height,
+            None,
+            self.content_fit,
+            self.rotation,
+            false,
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State<Handle>>();
+        state.reset(&self.handle);
+
+        // Stills are never queried again; animations are queried on every
+        // frame to keep them cached in the renderer while alive
+        if state.frames.as_ref().is_none_or(image::Frames::is_animated) {
+            match renderer.frames(&self.handle) {
+                Some(Ok(frames)) => {
+                    state.current %= frames.len().max(1);
+                    state.frames = Some(frames);
+                }
+                Some(Err(_)) => {
+                    state.frames = Some(image::Frames::still(self.handle.clone()));
+                }
+                // The renderer will redraw once the frames are decoded
+                None => return,
+            }
+        }
+
+        let Some(frames) = state.frames.as_ref().filter(|frames| frames.is_animated()) else {
+            return;
+        };
+
+        if state.is_finished && !self.is_looping {
+            return;
+        }
+
+        state.is_finished = false;
+
+        if !self.is_playing {
+            state.last_tick = None;
+            return;
+        }
+
+        state.last_tick = Some(match state.last_tick {
+            Some(last_tick) => {
+                state.elapsed += now.saturating_duration_since(last_tick);
+                last_tick.max(*now)
+            }
+            None => *now,
+        });
+
+        let delay = |index| {
+            frames
+                .get(index)
+                .map_or(Duration::ZERO, |frame: &image::Frame<Handle>| frame.delay)
+                .max(MIN_FRAME_DELAY)
+        };
+
+        if self.is_looping {
+            let duration: Duration = (0..frames.len()).map(delay).sum();
+
+            if state.elapsed >= duration {
+                state.elapsed =
+                    Duration::from_nanos((state.elapsed.as_nanos() % duration.as_nanos()) as u64);
+            }
+        }
+
+        while state.elapsed >= delay(state.current) {
+            if state.current + 1 == frames.len() && !self.is_looping {
+                state.elapsed = Duration::ZERO;
+                state.last_tick = None;
+                state.is_finished = true;
+                return;
+            }
+
+            state.elapsed -= delay(state.current);
+            state.current = (state.current + 1) % frames.len();
+        }
+
+        shell.request_redraw_at(*now + (delay(state.current) - state.elapsed));
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Handle>>();
+
+        super::draw(
+            renderer,
+            layout,
+            state.frame(&self.handle),
+            None,
+            self.border_radius,
+            self.content_fit,
+            self.filter_method,
+            self.rotation,
+            self.opacity,
+            1.0,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle> From<Animated<Handle>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + PartialEq + 'static,
+{
+    fn from(animated: Animated<Handle>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(animated)
+    }
+}
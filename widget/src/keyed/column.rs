@@ -1,15 +1,21 @@
 //! Keyed columns distribute content vertically while keeping continuity.
+use crate::core::animation::Easing;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::Operation;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Alignment, Element, Event, Layout, Length, Padding, Pixels, Rectangle, Shell, Size, Vector,
     Widget,
 };
 
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
 /// A container that distributes its contents vertically while keeping continuity.
 ///
 /// # Example
@@ -41,6 +47,9 @@ where
     align_items: Alignment,
     keys: Vec<Key>,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
+    exiting: Vec<bool>,
+    transition: Option<Transition>,
+    on_exit: Option<Box<dyn Fn(Key) -> Message + 'a>>,
 }
 
 impl<'a, Key, Message, Theme, Renderer> Column<'a, Key, Message, Theme, Renderer>
@@ -68,8 +77,11 @@ where
             height: Length::Shrink,
             max_width: f32::INFINITY,
             align_items: Alignment::Start,
+            exiting: vec![false; children.len()],
             keys,
             children,
+            transition: None,
+            on_exit: None,
         }
     }
 
@@ -127,11 +139,50 @@ where
         self
     }
 
+    /// Animates the layout changes of the [`Column`] with the given [`Transition`].
+    ///
+    /// Children moving or resizing between layouts will tween from their
+    /// previous bounds, while inserted and exiting children will play the
+    /// enter and exit effects of the [`Transition`].
+    pub fn animate(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Sets the message that will be produced when an exiting child of the
+    /// [`Column`] finishes its exit transition.
+    ///
+    /// This is the right time to stop pushing the child with
+    /// [`push_exiting`](Self::push_exiting).
+    pub fn on_exit(mut self, on_exit: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_exit = Some(Box::new(on_exit));
+        self
+    }
+
     /// Adds an element to the [`Column`].
-    pub fn push(
+    pub fn push(self, key: Key, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_with(key, child, false)
+    }
+
+    /// Adds an element that is leaving the [`Column`].
+    ///
+    /// Exiting elements play the exit effect of the [`Transition`] of an
+    /// animated [`Column`] and ignore any user interaction. Once the exit
+    /// transition finishes, the [`Column`] will produce the
+    /// [`on_exit`](Self::on_exit) message.
+    pub fn push_exiting(
+        self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push_with(key, child, true)
+    }
+
+    fn push_with(
         mut self,
         key: Key,
         child: impl Into<Element<'a, Message, Theme, Renderer>>,
+        is_exiting: bool,
     ) -> Self {
         let child = child.into();
         let child_size = child.as_widget().size_hint();
@@ -141,6 +192,7 @@ where
 
         self.keys.push(key);
         self.children.push(child);
+        self.exiting.push(is_exiting);
         self
     }
 
@@ -178,18 +230,257 @@ where
     }
 }
 
+/// The animation of the layout changes of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    /// The duration of the [`Transition`].
+    pub duration: Duration,
+    /// The [`Easing`] of the [`Transition`].
+    pub easing: Easing,
+    /// The [`Effect`] played by children entering the [`Column`].
+    pub enter: Effect,
+    /// The [`Effect`] played by children exiting the [`Column`].
+    pub exit: Effect,
+}
+
+impl Transition {
+    /// Sets the duration of the [`Transition`].
+    pub fn duration(self, duration: Duration) -> Self {
+        Self { duration, ..self }
+    }
+
+    /// Sets the [`Easing`] of the [`Transition`].
+    pub fn easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// Sets the [`Effect`] played by children entering the [`Column`].
+    pub fn enter(self, enter: Effect) -> Self {
+        Self { enter, ..self }
+    }
+
+    /// Sets the [`Effect`] played by children exiting the [`Column`].
+    pub fn exit(self, exit: Effect) -> Self {
+        Self { exit, ..self }
+    }
+
+    fn progress(&self, started: Option<Instant>, now: Option<Instant>) -> f32 {
+        let (Some(started), Some(now)) = (started, now) else {
+            return 0.0;
+        };
+
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+
+        let elapsed = now.saturating_duration_since(started);
+
+        self.easing
+            .value((elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0))
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(200),
+            easing: Easing::EaseOut,
+            enter: Effect::Grow,
+            exit: Effect::Grow,
+        }
+    }
+}
+
+/// The effect played by a child entering or exiting an animated [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    /// The child appears or disappears instantly.
+    None,
+    /// The child grows from or shrinks to an empty height, pushing its
+    /// siblings along.
+    Grow,
+}
+
+/// Collapses the given bounds of a child to an empty height.
+fn collapse(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        height: 0.0,
+        ..bounds
+    }
+}
+
 struct State<Key>
 where
     Key: Copy + PartialEq,
 {
     keys: Vec<Key>,
+    motions: HashMap<Key, Motion>,
+    frames: Vec<Rectangle>,
+    now: Option<Instant>,
+    is_initialized: bool,
+}
+
+impl<Key> State<Key>
+where
+    Key: Copy + Eq + Hash,
+{
+    /// Tweens the given layout from the previous one, producing the nodes
+    /// of the current frame.
+    fn animate(
+        &mut self,
+        transition: &Transition,
+        keys: &[Key],
+        exiting: &[bool],
+        node: layout::Node,
+        spacing: f32,
+        padding: Padding,
+        height: Length,
+    ) -> layout::Node {
+        let mut shift = 0.0;
+        let mut nodes = Vec::with_capacity(keys.len());
+
+        self.frames.clear();
+
+        for ((key, is_exiting), node) in keys.iter().zip(exiting).zip(node.children()) {
+            let node = node.clone().translate([0.0, -shift]);
+
+            let target = if *is_exiting {
+                if transition.exit == Effect::Grow {
+                    shift += node.size().height + spacing;
+                }
+
+                collapse(node.bounds())
+            } else {
+                node.bounds()
+            };
+
+            let motion = self.motions.entry(*key).or_insert_with(|| {
+                let from = if self.is_initialized && transition.enter == Effect::Grow {
+                    collapse(target)
+                } else {
+                    target
+                };
+
+                Motion {
+                    from,
+                    to: target,
+                    started: None,
+                    is_exiting: *is_exiting,
+                    is_settled: from == target && !*is_exiting,
+                }
+            });
+
+            if motion.to != target {
+                motion.from = if *is_exiting && transition.exit == Effect::None {
+                    target
+                } else {
+                    motion.current(transition, self.now)
+                };
+                motion.to = target;
+                motion.started = None;
+                motion.is_exiting = *is_exiting;
+                motion.is_settled = false;
+            }
+
+            let frame = motion.current(transition, self.now);
+
+            nodes.push(node.move_to(frame.position()));
+            self.frames.push(frame);
+        }
+
+        if self.motions.len() > keys.len() {
+            let keys: HashSet<_> = keys.iter().collect();
+
+            self.motions.retain(|key, _| keys.contains(key));
+        }
+
+        self.is_initialized = true;
+
+        let size = if height == Length::Shrink {
+            let bottom = self
+                .frames
+                .iter()
+                .map(|frame| frame.y + frame.height)
+                .fold(padding.top, f32::max);
+
+            Size::new(node.size().width, bottom + padding.bottom)
+        } else {
+            node.size()
+        };
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    /// Advances the motions to the given instant, returning whether any of
+    /// them changed and any keys that finished exiting.
+    fn tick(&mut self, transition: &Transition, now: Instant) -> (bool, Vec<Key>) {
+        let mut has_changed = false;
+        let mut exited = Vec::new();
+
+        self.now = Some(now);
+
+        for key in &self.keys {
+            let Some(motion) = self
+                .motions
+                .get_mut(key)
+                .filter(|motion| !motion.is_settled)
+            else {
+                continue;
+            };
+
+            let started = *motion.started.get_or_insert(now);
+
+            has_changed = true;
+
+            if now.saturating_duration_since(started) >= transition.duration {
+                motion.is_settled = true;
+
+                if motion.is_exiting {
+                    exited.push(*key);
+                }
+            }
+        }
+
+        (has_changed, exited)
+    }
+
+    fn is_animating(&self) -> bool {
+        self.motions.values().any(|motion| !motion.is_settled)
+    }
+}
+
+/// The transition of a keyed child between two bounds.
+struct Motion {
+    from: Rectangle,
+    to: Rectangle,
+    started: Option<Instant>,
+    is_exiting: bool,
+    is_settled: bool,
+}
+
+impl Motion {
+    fn current(&self, transition: &Transition, now: Option<Instant>) -> Rectangle {
+        if self.is_settled {
+            return self.to;
+        }
+
+        let progress = transition.progress(self.started, now);
+        let mix = |from: f32, to: f32| from + (to - from) * progress;
+
+        Rectangle {
+            x: mix(self.from.x, self.to.x),
+            y: mix(self.from.y, self.to.y),
+            width: mix(self.from.width, self.to.width),
+            height: mix(self.from.height, self.to.height),
+        }
+    }
 }
 
 impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Column<'_, Key, Message, Theme, Renderer>
 where
     Renderer: crate::core::Renderer,
-    Key: Copy + PartialEq + 'static,
+    Key: Copy + Eq + Hash + 'static,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State<Key>>()
@@ -198,6 +489,10 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             keys: self.keys.clone(),
+            motions: HashMap::new(),
+            frames: Vec::new(),
+            now: None,
+            is_initialized: false,
         })
     }
 
@@ -246,7 +541,7 @@ where
             .width(self.width)
             .height(self.height);
 
-        layout::flex::resolve(
+        let node = layout::flex::resolve(
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
@@ -257,6 +552,20 @@ where
             self.align_items,
             &mut self.children,
             &mut tree.children,
+        );
+
+        let Some(transition) = &self.transition else {
+            return node;
+        };
+
+        tree.state.downcast_mut::<State<Key>>().animate(
+            transition,
+            &self.keys,
+            &self.exiting,
+            node,
+            self.spacing,
+            self.padding,
+            self.height,
         )
     }
 
@@ -273,7 +582,9 @@ where
                 .iter_mut()
                 .zip(&mut tree.children)
                 .zip(layout.children())
-                .for_each(|((child, state), layout)| {
+                .zip(&self.exiting)
+                .filter(|(_, is_exiting)| !**is_exiting)
+                .for_each(|(((child, state), layout), _)| {
                     child
                         .as_widget_mut()
                         .operate(state, layout, renderer, operation);
@@ -291,12 +602,38 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        for ((child, tree), layout) in self
+        if let Some(transition) = &self.transition
+            && let Event::Window(window::Event::RedrawRequested(now)) = event
+        {
+            let state = tree.state.downcast_mut::<State<Key>>();
+            let (has_changed, exited) = state.tick(transition, *now);
+
+            if has_changed {
+                shell.invalidate_layout();
+            }
+
+            if state.is_animating() {
+                shell.request_redraw();
+            }
+
+            if let Some(on_exit) = &self.on_exit {
+                for key in exited {
+                    shell.publish(on_exit(key));
+                }
+            }
+        }
+
+        for (((child, tree), layout), is_exiting) in self
             .children
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
+            .zip(&self.exiting)
         {
+            if *is_exiting {
+                continue;
+            }
+
             child
                 .as_widget_mut()
                 .update(tree, event, layout, cursor, renderer, shell, viewport);
@@ -315,7 +652,9 @@ where
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
-            .map(|((child, tree), layout)| {
+            .zip(&self.exiting)
+            .filter(|(_, is_exiting)| !**is_exiting)
+            .map(|(((child, tree), layout), _)| {
                 child
                     .as_widget()
                     .mouse_interaction(tree, layout, cursor, viewport, renderer)
@@ -334,15 +673,43 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((child, state), layout) in self
+        let frames = if self.transition.is_some() {
+            tree.state.downcast_ref::<State<Key>>().frames.as_slice()
+        } else {
+            &[]
+        };
+
+        for (index, ((child, state), layout)) in self
             .children
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
+            .enumerate()
         {
-            child
-                .as_widget()
-                .draw(state, renderer, theme, style, layout, cursor, viewport);
+            let Some(frame) = frames.get(index) else {
+                child
+                    .as_widget()
+                    .draw(state, renderer, theme, style, layout, cursor, viewport);
+
+                continue;
+            };
+
+            let bounds = layout.bounds();
+
+            if frame.size() == bounds.size() {
+                child
+                    .as_widget()
+                    .draw(state, renderer, theme, style, layout, cursor, viewport);
+            } else if frame.height > 0.0 {
+                renderer.with_layer(
+                    Rectangle::new(bounds.position(), frame.size()),
+                    |renderer| {
+                        child
+                            .as_widget()
+                            .draw(state, renderer, theme, style, layout, cursor, viewport);
+                    },
+                );
+            }
         }
     }
 
@@ -354,21 +721,28 @@ where
         viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        overlay::from_children(
-            &mut self.children,
-            tree,
-            layout,
-            renderer,
-            viewport,
-            translation,
-        )
+        let children = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(&self.exiting)
+            .filter(|(_, is_exiting)| !**is_exiting)
+            .filter_map(|(((child, state), layout), _)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
     }
 }
 
 impl<'a, Key, Message, Theme, Renderer> From<Column<'a, Key, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Key: Copy + PartialEq + 'static,
+    Key: Copy + Eq + Hash + 'static,
     Message: 'a,
     Theme: 'a,
     Renderer: crate::core::Renderer + 'a,
@@ -377,3 +751,119 @@ where
         Self::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    type Column<'a> = super::Column<'a, u32, u32, crate::Theme, ()>;
+
+    const LIMITS: layout::Limits = layout::Limits::new(Size::ZERO, Size::new(100.0, 1000.0));
+
+    fn list<'a>(keys: &[u32], exiting: Option<u32>) -> Column<'a> {
+        keys.iter()
+            .fold(Column::new(), |column, &key| {
+                let item = Space::new().width(100).height(10);
+
+                if Some(key) == exiting {
+                    column.push_exiting(key, item)
+                } else {
+                    column.push(key, item)
+                }
+            })
+            .spacing(10)
+            .animate(Transition::default().easing(Easing::Linear))
+            .on_exit(|key| key)
+    }
+
+    fn frame(
+        column: &mut Column<'_>,
+        tree: &mut Tree,
+        now: Instant,
+    ) -> (layout::Node, Vec<u32>, bool) {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let node = column.layout(tree, &(), &LIMITS);
+
+        column.update(
+            tree,
+            &Event::Window(window::Event::RedrawRequested(now)),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut shell,
+            &Rectangle::with_size(LIMITS.max()),
+        );
+
+        let is_animating = shell.redraw_request() == window::RedrawRequest::NextFrame;
+
+        (column.layout(tree, &(), &LIMITS), messages, is_animating)
+    }
+
+    fn positions(node: &layout::Node) -> Vec<f32> {
+        node.children().iter().map(|node| node.bounds().y).collect()
+    }
+
+    #[test]
+    fn children_tween_between_layouts() {
+        let start = Instant::now();
+        let mut column = list(&[1, 2], None);
+        let mut tree = Tree::new(&column as &dyn Widget<_, _, _>);
+
+        let (node, _, is_animating) = frame(&mut column, &mut tree, start);
+        assert!(!is_animating);
+        assert_eq!(positions(&node), vec![0.0, 20.0]);
+
+        // A new child is inserted at the top
+        let mut column = list(&[0, 1, 2], None);
+        tree.diff(&column as &dyn Widget<_, _, _>);
+
+        let (node, _, is_animating) = frame(&mut column, &mut tree, start);
+        assert!(is_animating);
+        assert_eq!(positions(&node), vec![0.0, 0.0, 20.0]);
+        assert_eq!(node.size().height, 30.0);
+
+        let (node, _, is_animating) =
+            frame(&mut column, &mut tree, start + Duration::from_millis(100));
+        assert!(is_animating);
+        assert_eq!(positions(&node), vec![0.0, 10.0, 30.0]);
+        assert_eq!(node.size().height, 40.0);
+
+        let (node, _, is_animating) =
+            frame(&mut column, &mut tree, start + Duration::from_millis(200));
+        assert!(!is_animating);
+        assert_eq!(positions(&node), vec![0.0, 20.0, 40.0]);
+        assert_eq!(node.size().height, 50.0);
+    }
+
+    #[test]
+    fn exiting_children_collapse_and_notify() {
+        let start = Instant::now();
+        let mut column = list(&[0, 1, 2], None);
+        let mut tree = Tree::new(&column as &dyn Widget<_, _, _>);
+
+        let _ = frame(&mut column, &mut tree, start);
+
+        let mut column = list(&[0, 1, 2], Some(1));
+        tree.diff(&column as &dyn Widget<_, _, _>);
+
+        let (node, messages, is_animating) = frame(&mut column, &mut tree, start);
+        assert!(is_animating);
+        assert_eq!(positions(&node), vec![0.0, 20.0, 40.0]);
+        assert!(messages.is_empty());
+
+        let (node, messages, is_animating) =
+            frame(&mut column, &mut tree, start + Duration::from_millis(100));
+        assert!(is_animating);
+        assert_eq!(positions(&node), vec![0.0, 20.0, 30.0]);
+        assert!(messages.is_empty());
+
+        let (node, messages, is_animating) =
+            frame(&mut column, &mut tree, start + Duration::from_millis(200));
+        assert!(!is_animating);
+        assert_eq!(positions(&node), vec![0.0, 20.0, 20.0]);
+        assert_eq!(node.size().height, 30.0);
+        assert_eq!(messages, vec![1]);
+    }
+}
@@ -308,7 +308,7 @@ where
         viewport: &Rectangle,
     ) {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell = Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         let t = tree.state.downcast_mut::<Rc<RefCell<Option<Tree>>>>();
         self.with_element_mut(|element| {
@@ -332,6 +332,13 @@ where
         shell.request_input_method(local_shell.input_method());
         shell.clipboard_mut().merge(local_shell.clipboard_mut());
 
+        if local_shell.is_drag_changed() {
+            match local_shell.drag() {
+                Some(drag) => shell.start_drag(drag.clone()),
+                None => shell.end_drag(),
+            }
+        }
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -584,7 +591,7 @@ where
         shell: &mut Shell<'_, Message>,
     ) {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell = Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
 
         let _ = self.with_overlay_mut_maybe(|overlay| {
             overlay.update(event, layout, cursor, renderer, &mut local_shell);
@@ -599,6 +606,13 @@ where
         shell.request_input_method(local_shell.input_method());
         shell.clipboard_mut().merge(local_shell.clipboard_mut());
 
+        if local_shell.is_drag_changed() {
+            match local_shell.drag() {
+                Some(drag) => shell.start_drag(drag.clone()),
+                None => shell.end_drag(),
+            }
+        }
+
         if !local_messages.is_empty() {
             let mut inner = self.overlay.take().unwrap().0.take().unwrap().into_heads();
             let mut heads = inner.instance.state.take().unwrap().into_heads();
@@ -11,6 +11,7 @@ pub use core::widget::Id;
 
 mod action;
 mod column;
+mod menu;
 mod mouse_area;
 mod pin;
 mod responsive;
@@ -21,9 +22,14 @@ pub mod button;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod draggable;
+pub mod drop_zone;
 pub mod float;
 pub mod grid;
 pub mod keyed;
+pub mod menu_bar;
+pub mod number_input;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
@@ -36,12 +42,15 @@ pub mod sensor;
 pub mod slider;
 pub mod space;
 pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod tree_view;
 pub mod vertical_slider;
+pub mod virtual_list;
 
 mod helpers;
 
@@ -64,12 +73,22 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_zone::DropZone;
+#[doc(no_inline)]
 pub use float::Float;
 #[doc(no_inline)]
 pub use grid::Grid;
 #[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -96,6 +115,8 @@ pub use space::Space;
 #[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -108,7 +129,11 @@ pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
@@ -49,7 +49,9 @@ use crate::core::font::{self, Font};
 use crate::core::padding;
 use crate::core::theme::palette;
 use crate::core::{self, Color, Element, Length, Padding, Pixels, Theme, color};
-use crate::{checkbox, column, container, rich_text, row, rule, scrollable, span, text};
+use crate::{
+    checkbox, column, container, rich_text, row, rule, scrollable, span, text, text_editor,
+};
 
 use std::borrow::BorrowMut;
 use std::cell::{Cell, RefCell};
@@ -277,6 +279,12 @@ impl Text {
 
         self.last_styled_spans.borrow().clone()
     }
+
+    /// Returns the text of the spans of the [`Text`] along with their
+    /// [`Attributes`](text_editor::Attributes).
+    pub(crate) fn attributed(&self) -> impl Iterator<Item = (&str, text_editor::Attributes)> + '_ {
+        self.spans.iter().map(Span::attributed)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -298,6 +306,36 @@ enum Span {
 }
 
 impl Span {
+    fn attributed(&self) -> (&str, text_editor::Attributes) {
+        match self {
+            Span::Standard {
+                text,
+                link,
+                strong,
+                emphasis,
+                ..
+            } => (
+                text,
+                text_editor::Attributes {
+                    weight: strong.then_some(font::Weight::Bold),
+                    style: emphasis.then_some(font::Style::Italic),
+                    color: None,
+                    link: link.clone(),
+                },
+            ),
+            #[cfg(feature = "highlighter")]
+            Span::Highlight { text, color, font } => (
+                text,
+                text_editor::Attributes {
+                    weight: font.map(|font| font.weight),
+                    style: font.map(|font| font.style),
+                    color: *color,
+                    link: None,
+                },
+            ),
+        }
+    }
+
     fn view(&self, style: &Style) -> text::Span<'static, Uri> {
         match self {
             Span::Standard {
@@ -446,10 +484,10 @@ impl Highlighter {
     pub fn new(language: &str) -> Self {
         Self {
             lines: Vec::new(),
-            parser: iced_highlighter::Stream::new(&iced_highlighter::Settings {
-                theme: iced_highlighter::Theme::Base16Ocean,
-                token: language.to_owned(),
-            }),
+            parser: iced_highlighter::Stream::new(&iced_highlighter::Settings::new(
+                language,
+                iced_highlighter::Theme::Base16Ocean,
+            )),
             language: language.to_owned(),
             current: 0,
         }
@@ -1013,6 +1051,8 @@ pub struct Settings {
     pub spacing: Pixels,
     /// The styling of the Markdown.
     pub style: Style,
+    /// Whether the text of the Markdown can be selected and copied.
+    pub selectable: bool,
 }
 
 impl Settings {
@@ -1040,6 +1080,7 @@ impl Settings {
             code_size: text_size * 0.75,
             spacing: text_size * 0.875,
             style: style.into(),
+            selectable: false,
         }
     }
 }
@@ -1251,6 +1292,7 @@ where
     container(
         rich_text(text.spans(settings.style))
             .on_link_click(on_link_click)
+            .selectable(settings.selectable)
             .size(match level {
                 pulldown_cmark::HeadingLevel::H1 => h1_size,
                 pulldown_cmark::HeadingLevel::H2 => h2_size,
@@ -1282,6 +1324,7 @@ where
     rich_text(text.spans(settings.style))
         .size(settings.text_size)
         .on_link_click(on_link_click)
+        .selectable(settings.selectable)
         .into()
 }
 
@@ -1380,6 +1423,7 @@ where
             container(column(lines.iter().map(|line| {
                 rich_text(line.spans(settings.style))
                     .on_link_click(on_link_click.clone())
+                    .selectable(settings.selectable)
                     .font(settings.style.code_block_font)
                     .size(settings.code_size)
                     .into()
@@ -0,0 +1,1149 @@
+//! The menus shared by [`MenuBar`] and [`ContextMenu`].
+//!
+//! [`MenuBar`]: crate::MenuBar
+//! [`ContextMenu`]: crate::ContextMenu
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
+use crate::core::{
+    Background, Color, Event, Padding, Pixels, Point, Rectangle, Shadow, Shell, Size, Theme,
+};
+
+use std::borrow::Cow;
+
+/// The default padding of the titles and items of a menu.
+pub(crate) const DEFAULT_PADDING: Padding = Padding {
+    top: 4.0,
+    bottom: 4.0,
+    right: 10.0,
+    left: 10.0,
+};
+
+/// The space around the items of an open menu.
+const PANEL_PADDING: f32 = 4.0;
+
+/// The height of a separator.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// The minimum space between the columns of an item.
+const SPACING: f32 = 24.0;
+
+/// The minimum width of an open menu.
+const MIN_WIDTH: f32 = 120.0;
+
+/// A titled list of [`Item`]s.
+pub struct Menu<'a, Message> {
+    pub(crate) label: Label<'a>,
+    pub(crate) items: Vec<Item<'a, Message>>,
+}
+
+impl<'a, Message> Menu<'a, Message> {
+    /// Creates a new [`Menu`] with the given label and [`Item`]s.
+    pub fn new(
+        label: impl Into<Cow<'a, str>>,
+        items: impl IntoIterator<Item = Item<'a, Message>>,
+    ) -> Self {
+        Self {
+            label: Label::parse(label.into()),
+            items: items.into_iter().collect(),
+        }
+    }
+
+    /// Adds an [`Item`] to the [`Menu`].
+    pub fn push(mut self, item: Item<'a, Message>) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// An entry of a [`Menu`].
+pub struct Item<'a, Message> {
+    label: Label<'a>,
+    kind: Kind<'a, Message>,
+}
+
+enum Kind<'a, Message> {
+    Action {
+        on_select: Option<Message>,
+        shortcut: Option<Cow<'a, str>>,
+        is_checked: Option<bool>,
+    },
+    Submenu(Vec<Item<'a, Message>>),
+    Separator,
+}
+
+impl<'a, Message> Item<'a, Message> {
+    /// Creates a new [`Item`] with the given label.
+    ///
+    /// The [`Item`] is disabled until [`on_select`](Self::on_select) is set.
+    pub fn new(label: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: Label::parse(label.into()),
+            kind: Kind::Action {
+                on_select: None,
+                shortcut: None,
+                is_checked: None,
+            },
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    ///
+    /// A submenu without items is disabled.
+    pub fn submenu(
+        label: impl Into<Cow<'a, str>>,
+        items: impl IntoIterator<Item = Item<'a, Message>>,
+    ) -> Self {
+        Self {
+            label: Label::parse(label.into()),
+            kind: Kind::Submenu(items.into_iter().collect()),
+        }
+    }
+
+    /// Creates a new separator [`Item`].
+    pub fn separator() -> Self {
+        Self {
+            label: Label::parse(Cow::Borrowed("")),
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected.
+    ///
+    /// It has no effect on submenus and separators.
+    pub fn on_select(self, message: Message) -> Self {
+        self.on_select_maybe(Some(message))
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected,
+    /// if `Some`.
+    ///
+    /// If `None`, the [`Item`] will be disabled.
+    pub fn on_select_maybe(mut self, message: Option<Message>) -> Self {
+        if let Kind::Action { on_select, .. } = &mut self.kind {
+            *on_select = message;
+        }
+
+        self
+    }
+
+    /// Sets the shortcut hint displayed next to the label of the [`Item`].
+    ///
+    /// The hint is only displayed; the shortcut itself must be handled by
+    /// the application.
+    pub fn shortcut(mut self, hint: impl Into<Cow<'a, str>>) -> Self {
+        if let Kind::Action { shortcut, .. } = &mut self.kind {
+            *shortcut = Some(hint.into());
+        }
+
+        self
+    }
+
+    /// Makes the [`Item`] checkable and sets whether it is checked.
+    pub fn checked(mut self, checked: bool) -> Self {
+        if let Kind::Action { is_checked, .. } = &mut self.kind {
+            *is_checked = Some(checked);
+        }
+
+        self
+    }
+
+    fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action { on_select, .. } => on_select.is_some(),
+            Kind::Submenu(items) => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+
+    fn submenu_items(&self) -> Option<&[Item<'a, Message>]> {
+        match &self.kind {
+            Kind::Submenu(items) if !items.is_empty() => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// A label with an optional mnemonic.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Label<'a> {
+    pub content: Cow<'a, str>,
+    /// The byte offset of the mnemonic in the content.
+    pub mnemonic: Option<usize>,
+}
+
+impl<'a> Label<'a> {
+    fn parse(label: Cow<'a, str>) -> Self {
+        if !label.contains('&') {
+            return Self {
+                content: label,
+                mnemonic: None,
+            };
+        }
+
+        let mut content = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                content.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('&') | None => content.push('&'),
+                Some(c) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some(content.len());
+                    }
+
+                    content.push(c);
+                }
+            }
+        }
+
+        Self {
+            content: Cow::Owned(content),
+            mnemonic,
+        }
+    }
+
+    /// Returns true if the mnemonic of the [`Label`] is the given character,
+    /// ignoring case.
+    pub fn matches(&self, c: char) -> bool {
+        self.mnemonic
+            .and_then(|index| self.content[index..].chars().next())
+            .is_some_and(|mnemonic| mnemonic.to_lowercase().eq(c.to_lowercase()))
+    }
+}
+
+/// The local state of an open menu.
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The index of the open [`Menu`], if any.
+    pub open: Option<usize>,
+    /// The highlighted item of every open panel, from the root to the deepest submenu.
+    pub levels: Vec<Option<usize>>,
+    /// Whether mnemonics are displayed.
+    pub show_mnemonics: bool,
+}
+
+impl State {
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Opens the menu with the given index. If `from_keyboard` is true, its first
+    /// enabled item is highlighted and mnemonics are displayed.
+    pub fn open<Message>(
+        &mut self,
+        index: usize,
+        items: &[Item<'_, Message>],
+        from_keyboard: bool,
+    ) {
+        self.open = Some(index);
+        self.levels = vec![if from_keyboard {
+            step(items, None, true)
+        } else {
+            None
+        }];
+        self.show_mnemonics = from_keyboard;
+    }
+
+    pub fn close(&mut self) {
+        self.open = None;
+        self.levels.clear();
+        self.show_mnemonics = false;
+    }
+}
+
+/// The text settings shared by the parts of a menu.
+pub(crate) struct Metrics<Font> {
+    pub size: Pixels,
+    pub line_height: text::LineHeight,
+    pub font: Font,
+    pub padding: Padding,
+}
+
+impl<Font: Copy> Metrics<Font> {
+    pub fn new<Renderer>(
+        renderer: &Renderer,
+        size: Option<Pixels>,
+        font: Option<Font>,
+        padding: Padding,
+    ) -> Self
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        Self {
+            size: size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: font.unwrap_or_else(|| renderer.default_font()),
+            padding,
+        }
+    }
+
+    /// The height of an item or a title.
+    pub fn height(&self) -> f32 {
+        self.line_height.to_absolute(self.size).0 + self.padding.y()
+    }
+
+    pub fn text<T>(&self, content: T, bounds: Size) -> Text<T, Font> {
+        Text {
+            content,
+            bounds,
+            size: self.size,
+            line_height: self.line_height,
+            font: self.font,
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            ellipsis: text::Ellipsis::default(),
+            hint_factor: None,
+        }
+    }
+
+    /// Measures the given [`Label`], returning its width and the node of the
+    /// underline of its mnemonic, relative to the start of the label.
+    pub fn label<P>(&self, label: &Label<'_>) -> (f32, Option<layout::Node>)
+    where
+        P: Paragraph<Font = Font>,
+    {
+        let paragraph = P::with_text(self.text(&label.content, Size::INFINITE));
+        let width = paragraph.min_width();
+
+        let underline = label.mnemonic.and_then(|index| {
+            let grapheme = label.content[..index].chars().count();
+
+            let start = paragraph.grapheme_position(0, grapheme)?.x;
+            let end = paragraph
+                .grapheme_position(0, grapheme + 1)
+                .map_or(width, |position| position.x);
+
+            Some(
+                layout::Node::new(Size::new(end - start, 1.0))
+                    .move_to(Point::new(start, (self.height() + self.size.0) / 2.0)),
+            )
+        });
+
+        (width, underline)
+    }
+
+    pub fn width<P>(&self, content: &str) -> f32
+    where
+        P: Paragraph<Font = Font>,
+    {
+        P::with_text(self.text(content, Size::INFINITE)).min_width()
+    }
+}
+
+/// Creates the overlay of an open menu.
+///
+/// Every entry of `menus` contains the items of a [`Menu`] and the bounds its
+/// panel is anchored to.
+pub(crate) fn overlay<'a, 'b, Message, Theme, Renderer>(
+    state: &'a mut State,
+    menus: Vec<(&'a [Item<'b, Message>], Rectangle)>,
+    metrics: Metrics<Renderer::Font>,
+    class: &'a Theme::Class<'b>,
+) -> overlay::Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    overlay::Element::new(Box::new(Overlay {
+        state,
+        menus,
+        metrics,
+        class,
+    }))
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'a mut State,
+    menus: Vec<(&'a [Item<'b, Message>], Rectangle)>,
+    metrics: Metrics<Renderer::Font>,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the items of every open panel.
+    fn panels(&self) -> Vec<&'a [Item<'b, Message>]> {
+        let Some(&(items, _)) = self.state.open.and_then(|index| self.menus.get(index)) else {
+            return Vec::new();
+        };
+
+        let mut panels = vec![items];
+
+        for level in &self.state.levels[..self.state.levels.len().saturating_sub(1)] {
+            let Some(items) = level
+                .and_then(|index| panels.last()?.get(index))
+                .and_then(Item::submenu_items)
+            else {
+                break;
+            };
+
+            panels.push(items);
+        }
+
+        panels
+    }
+
+    fn panel(&self, items: &[Item<'_, Message>]) -> layout::Node {
+        let metrics = &self.metrics;
+        let gutter = if items.iter().any(|item| {
+            matches!(
+                item.kind,
+                Kind::Action {
+                    is_checked: Some(_),
+                    ..
+                }
+            )
+        }) {
+            metrics.size.0 + metrics.padding.left
+        } else {
+            0.0
+        };
+
+        let arrow = if items
+            .iter()
+            .any(|item| matches!(item.kind, Kind::Submenu(_)))
+        {
+            metrics.size.0 + SPACING
+        } else {
+            0.0
+        };
+
+        let mut label_width: f32 = 0.0;
+        let mut shortcut_width: f32 = 0.0;
+
+        let underlines: Vec<_> = items
+            .iter()
+            .map(|item| {
+                if let Kind::Action {
+                    shortcut: Some(shortcut),
+                    ..
+                } = &item.kind
+                {
+                    shortcut_width =
+                        shortcut_width.max(metrics.width::<Renderer::Paragraph>(shortcut));
+                }
+
+                let (width, underline) = metrics.label::<Renderer::Paragraph>(&item.label);
+                label_width = label_width.max(width);
+
+                underline.map(|underline| underline.translate([metrics.padding.left + gutter, 0.0]))
+            })
+            .collect();
+
+        let shortcut_width = if shortcut_width > 0.0 {
+            shortcut_width + SPACING
+        } else {
+            0.0
+        };
+
+        let width = (gutter + label_width + shortcut_width + arrow + metrics.padding.x())
+            .max(MIN_WIDTH - PANEL_PADDING * 2.0);
+
+        let mut y = PANEL_PADDING;
+
+        let children = items
+            .iter()
+            .zip(underlines)
+            .map(|(item, underline)| {
+                let height = if matches!(item.kind, Kind::Separator) {
+                    SEPARATOR_HEIGHT
+                } else {
+                    metrics.height()
+                };
+
+                let node = layout::Node::with_children(
+                    Size::new(width, height),
+                    underline.into_iter().collect(),
+                )
+                .move_to(Point::new(PANEL_PADDING, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(
+            Size::new(width + PANEL_PADDING * 2.0, y + PANEL_PADDING),
+            children,
+        )
+    }
+
+    /// Returns the panel under the cursor and the item under the cursor in that
+    /// panel, if any.
+    fn hit(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<(usize, Option<usize>)> {
+        let (level, panel) = layout
+            .children()
+            .enumerate()
+            .rev()
+            .find(|(_, panel)| cursor.is_over(panel.bounds()))?;
+
+        let item = panel
+            .children()
+            .position(|item| cursor.is_over(item.bounds()));
+
+        Some((level, item))
+    }
+
+    /// Highlights the given item, opening it if it is a submenu.
+    ///
+    /// Returns true if the state changed.
+    fn highlight(
+        &mut self,
+        panels: &[&[Item<'b, Message>]],
+        level: usize,
+        index: Option<usize>,
+        from_keyboard: bool,
+    ) -> bool {
+        let item = index.and_then(|index| panels[level].get(index));
+
+        let mut levels = self.state.levels[..=level].to_vec();
+        levels[level] = index.filter(|_| item.is_some_and(Item::is_enabled));
+
+        if let Some(items) = item.and_then(Item::submenu_items) {
+            levels.push(if from_keyboard {
+                step(items, None, true)
+            } else {
+                None
+            });
+        }
+
+        if levels == self.state.levels {
+            return false;
+        }
+
+        self.state.levels = levels;
+
+        true
+    }
+
+    /// Activates the given item. Returns true if the state changed.
+    fn activate(
+        &mut self,
+        panels: &[&[Item<'b, Message>]],
+        level: usize,
+        index: usize,
+        from_keyboard: bool,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let Some(item) = panels[level].get(index) else {
+            return false;
+        };
+
+        match &item.kind {
+            Kind::Action {
+                on_select: Some(on_select),
+                ..
+            } => {
+                shell.publish(on_select.clone());
+                self.state.close();
+
+                true
+            }
+            Kind::Submenu(_) => self.highlight(panels, level, Some(index), from_keyboard),
+            _ => false,
+        }
+    }
+
+    /// Moves to the next or previous [`Menu`], if there are any.
+    fn switch(&mut self, forward: bool) -> bool {
+        let Some(open) = self.state.open else {
+            return false;
+        };
+
+        let count = self.menus.len();
+
+        if count < 2 {
+            return false;
+        }
+
+        let index = if forward {
+            (open + 1) % count
+        } else {
+            (open + count - 1) % count
+        };
+
+        self.state.open(index, self.menus[index].0, true);
+
+        true
+    }
+
+    fn key(
+        &mut self,
+        panels: &[&[Item<'b, Message>]],
+        key: &keyboard::Key,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        // A submenu opened by hovering or stepping onto its item has nothing
+        // highlighted yet, so the keyboard keeps navigating its parent.
+        let level = self
+            .state
+            .levels
+            .iter()
+            .rposition(Option::is_some)
+            .unwrap_or(0)
+            .min(panels.len() - 1);
+
+        let items = panels[level];
+        let current = self.state.levels[level];
+
+        match key.as_ref() {
+            keyboard::Key::Named(key::Named::ArrowDown) => {
+                self.highlight(panels, level, step(items, current, true), false)
+            }
+            keyboard::Key::Named(key::Named::ArrowUp) => {
+                self.highlight(panels, level, step(items, current, false), false)
+            }
+            keyboard::Key::Named(key::Named::Home) => {
+                self.highlight(panels, level, step(items, None, true), false)
+            }
+            keyboard::Key::Named(key::Named::End) => {
+                self.highlight(panels, level, step(items, None, false), false)
+            }
+            keyboard::Key::Named(key::Named::ArrowRight) => {
+                match current.filter(|&index| items[index].submenu_items().is_some()) {
+                    Some(index) => self.activate(panels, level, index, true, shell),
+                    None => self.switch(true),
+                }
+            }
+            keyboard::Key::Named(key::Named::ArrowLeft) => {
+                if level > 0 {
+                    self.state.levels.truncate(level);
+
+                    true
+                } else {
+                    self.switch(false)
+                }
+            }
+            keyboard::Key::Named(key::Named::Enter | key::Named::Space) => {
+                current.is_some_and(|index| self.activate(panels, level, index, true, shell))
+            }
+            keyboard::Key::Named(key::Named::Escape) => {
+                if level > 0 {
+                    self.state.levels.truncate(level);
+                } else {
+                    self.state.close();
+                }
+
+                true
+            }
+            keyboard::Key::Character(c) => {
+                let Some(c) = c.chars().next() else {
+                    return false;
+                };
+
+                items
+                    .iter()
+                    .position(|item| item.is_enabled() && item.label.matches(c))
+                    .is_some_and(|index| self.activate(panels, level, index, true, shell))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> crate::core::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let Some(&(_, anchor)) = self.state.open.and_then(|index| self.menus.get(index)) else {
+            return layout::Node::new(Size::ZERO);
+        };
+
+        let mut panels: Vec<layout::Node> = Vec::new();
+
+        for (level, items) in self.panels().into_iter().enumerate() {
+            let panel = self.panel(items);
+            let size = panel.size();
+
+            let position = if let Some(parent) = panels.last() {
+                let parent = parent.bounds();
+                let item = self.state.levels[level - 1]
+                    .and_then(|index| panels.last()?.children().get(index))
+                    .map(layout::Node::bounds)
+                    .unwrap_or_default();
+
+                let x = if parent.x + parent.width + size.width <= bounds.width {
+                    parent.x + parent.width
+                } else {
+                    parent.x - size.width
+                };
+
+                Point::new(
+                    x.max(0.0),
+                    (parent.y + item.y - PANEL_PADDING)
+                        .min(bounds.height - size.height)
+                        .max(0.0),
+                )
+            } else {
+                let below = anchor.y + anchor.height;
+
+                let y = if below + size.height <= bounds.height || anchor.y < size.height {
+                    below.min(bounds.height - size.height)
+                } else {
+                    anchor.y - size.height
+                };
+
+                Point::new(anchor.x.min(bounds.width - size.width).max(0.0), y.max(0.0))
+            };
+
+            panels.push(panel.move_to(position));
+        }
+
+        layout::Node::with_children(bounds, panels)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let panels = self.panels();
+
+        if panels.is_empty() {
+            return;
+        }
+
+        let is_changed = match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => match self.hit(layout, cursor) {
+                Some((level, index)) => self.highlight(&panels, level, index, false),
+                None => false,
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some((level, index)) = self.hit(layout, cursor) else {
+                    // Clicks outside of the menu are left to its owner
+                    return;
+                };
+
+                shell.capture_event();
+
+                index.is_some_and(|index| self.activate(&panels, level, index, false, shell))
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                shell.capture_event();
+
+                let is_changed = self.key(&panels, key, shell);
+
+                if is_changed && !self.state.show_mnemonics {
+                    self.state.show_mnemonics = true;
+                }
+
+                is_changed
+            }
+            _ => false,
+        };
+
+        if is_changed {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.hit(layout, cursor) {
+            Some((level, Some(index)))
+                if self
+                    .panels()
+                    .get(level)
+                    .and_then(|items| items.get(index))
+                    .is_some_and(Item::is_enabled) =>
+            {
+                mouse::Interaction::Pointer
+            }
+            Some(_) => mouse::Interaction::Idle,
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let panels = self.panels();
+
+        for (level, (items, panel)) in panels.iter().zip(layout.children()).enumerate() {
+            operation.semantics(None, panel.bounds(), &Semantics::new(semantics::Role::Menu));
+
+            operation.traverse(&mut |operation| {
+                for (index, (item, layout)) in items.iter().zip(panel.children()).enumerate() {
+                    if matches!(item.kind, Kind::Separator) {
+                        continue;
+                    }
+
+                    let bounds = layout.bounds();
+                    let mut semantics = Semantics::new(semantics::Role::MenuItem)
+                        .label(item.label.content.as_ref())
+                        .disabled(!item.is_enabled());
+
+                    match &item.kind {
+                        Kind::Action {
+                            shortcut,
+                            is_checked,
+                            ..
+                        } => {
+                            if let Some(shortcut) = shortcut {
+                                semantics = semantics.description(shortcut.as_ref());
+                            }
+
+                            if let Some(is_checked) = is_checked {
+                                semantics = semantics.toggled(*is_checked);
+                            }
+                        }
+                        Kind::Submenu(_) => {
+                            semantics = semantics.expanded(
+                                self.state.levels.len() > level + 1
+                                    && self.state.levels[level] == Some(index),
+                            );
+                        }
+                        Kind::Separator => {}
+                    }
+
+                    operation.semantics(None, bounds, &semantics);
+                    operation.traverse(&mut |operation| {
+                        operation.text(None, bounds, &item.label.content);
+                    });
+                }
+            });
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let metrics = &self.metrics;
+        let viewport = layout.bounds();
+        let hint_factor = renderer.scale_factor();
+
+        for (level, (items, panel)) in self.panels().into_iter().zip(layout.children()).enumerate()
+        {
+            // Every panel needs its own layer, so submenus are drawn on top of the
+            // text of their parents.
+            renderer.with_layer(viewport, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: panel.bounds(),
+                        border: style.border,
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                let gutter = if items.iter().any(|item| {
+                    matches!(
+                        item.kind,
+                        Kind::Action {
+                            is_checked: Some(_),
+                            ..
+                        }
+                    )
+                }) {
+                    metrics.size.0 + metrics.padding.left
+                } else {
+                    0.0
+                };
+
+                for (index, (item, layout)) in items.iter().zip(panel.children()).enumerate() {
+                    let bounds = layout.bounds();
+
+                    if matches!(item.kind, Kind::Separator) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x,
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width,
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.separator,
+                        );
+
+                        continue;
+                    }
+
+                    let is_highlighted = self.state.levels.get(level) == Some(&Some(index));
+
+                    if is_highlighted {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                border: border::rounded(style.border.radius),
+                                ..renderer::Quad::default()
+                            },
+                            style.selected_background,
+                        );
+                    }
+
+                    let (color, hint_color) = if !item.is_enabled() {
+                        (style.disabled_text_color, style.disabled_text_color)
+                    } else if is_highlighted {
+                        (style.selected_text_color, style.selected_text_color)
+                    } else {
+                        (style.text_color, style.shortcut_color)
+                    };
+
+                    let text = |content: &str, font| Text {
+                        font,
+                        hint_factor,
+                        ..metrics.text(content.to_owned(), Size::new(f32::INFINITY, bounds.height))
+                    };
+
+                    renderer.fill_text(
+                        text(&item.label.content, metrics.font),
+                        Point::new(bounds.x + metrics.padding.left + gutter, bounds.center_y()),
+                        color,
+                        viewport,
+                    );
+
+                    if self.state.show_mnemonics
+                        && let Some(underline) = layout.children().next()
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: underline.bounds(),
+                                ..renderer::Quad::default()
+                            },
+                            color,
+                        );
+                    }
+
+                    match &item.kind {
+                        Kind::Action {
+                            shortcut,
+                            is_checked,
+                            ..
+                        } => {
+                            if *is_checked == Some(true) {
+                                renderer.fill_text(
+                                    text(
+                                        &Renderer::CHECKMARK_ICON.to_string(),
+                                        Renderer::ICON_FONT,
+                                    ),
+                                    Point::new(bounds.x + metrics.padding.left, bounds.center_y()),
+                                    color,
+                                    viewport,
+                                );
+                            }
+
+                            if let Some(shortcut) = shortcut {
+                                renderer.fill_text(
+                                    Text {
+                                        align_x: text::Alignment::Right,
+                                        ..text(shortcut, metrics.font)
+                                    },
+                                    Point::new(
+                                        bounds.x + bounds.width - metrics.padding.right,
+                                        bounds.center_y(),
+                                    ),
+                                    hint_color,
+                                    viewport,
+                                );
+                            }
+                        }
+                        Kind::Submenu(_) => {
+                            renderer.fill_text(
+                                Text {
+                                    align_x: text::Alignment::Right,
+                                    ..text(
+                                        &Renderer::SCROLL_RIGHT_ICON.to_string(),
+                                        Renderer::ICON_FONT,
+                                    )
+                                },
+                                Point::new(
+                                    bounds.x + bounds.width - metrics.padding.right,
+                                    bounds.center_y(),
+                                ),
+                                color,
+                                viewport,
+                            );
+                        }
+                        Kind::Separator => {}
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Returns the next enabled item after `current`, wrapping around.
+///
+/// If `current` is `None`, the search starts from the first or the last item,
+/// depending on the direction.
+fn step<Message>(
+    items: &[Item<'_, Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    if count == 0 {
+        return None;
+    }
+
+    let start = current.unwrap_or(if forward { count - 1 } else { 0 });
+
+    (1..=count)
+        .map(|offset| {
+            if forward {
+                (start + offset) % count
+            } else {
+                (start + count - offset) % count
+            }
+        })
+        .find(|&index| items[index].is_enabled())
+}
+
+/// The appearance of a menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of a menu bar.
+    pub bar_background: Option<Background>,
+    /// The [`Background`] of an open menu.
+    pub background: Background,
+    /// The [`Border`] of an open menu.
+    pub border: Border,
+    /// The [`Shadow`] of an open menu.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the menu.
+    pub text_color: Color,
+    /// The text [`Color`] of a highlighted item.
+    pub selected_text_color: Color,
+    /// The [`Background`] of a highlighted item.
+    pub selected_background: Background,
+    /// The text [`Color`] of a disabled item.
+    pub disabled_text_color: Color,
+    /// The [`Color`] of the shortcut hints.
+    pub shortcut_color: Color,
+    /// The [`Color`] of the separators.
+    pub separator: Color,
+}
+
+/// The theme catalog of a menu.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a menu.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a menu.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.palette();
+
+    Style {
+        bar_background: Some(palette.background.weak.color.into()),
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: [0.0, 2.0].into(),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+        disabled_text_color: palette.background.strong.color,
+        shortcut_color: palette.background.strong.text.scale_alpha(0.7),
+        separator: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mnemonics() {
+        let label = Label::parse(Cow::Borrowed("Save &As..."));
+
+        assert_eq!(label.content, "Save As...");
+        assert_eq!(label.mnemonic, Some(5));
+        assert!(label.matches('a'));
+        assert!(!label.matches('s'));
+
+        let label = Label::parse(Cow::Borrowed("Copy && &Paste"));
+
+        assert_eq!(label.content, "Copy & Paste");
+        assert_eq!(label.mnemonic, Some(7));
+        assert!(label.matches('P'));
+
+        let label = Label::parse(Cow::Borrowed("Trailing &"));
+
+        assert_eq!(label.content, "Trailing &");
+        assert_eq!(label.mnemonic, None);
+    }
+}
@@ -0,0 +1,497 @@
+//! Menu bars display a row of menus, like the File and Edit menus of a desktop application.
+//!
+//! A [`Menu`] is a titled list of [`Item`]s. Items can also be displayed without
+//! a title, as a [`ContextMenu`].
+//!
+//! # Mnemonics
+//! An `&` in a label marks the next character as the mnemonic of the [`Menu`] or
+//! [`Item`]. A mnemonic is displayed underlined when the menu is navigated with the
+//! keyboard, and pressing it activates its entry. Use `&&` for a literal `&`.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type State = ();
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::menu_bar;
+//! use iced::widget::menu_bar::{Item, Menu};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     New,
+//!     Quit,
+//!     Undo,
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     menu_bar([
+//!         Menu::new("&File", [
+//!             Item::new("&New").on_select(Message::New).shortcut("Ctrl+N"),
+//!             Item::separator(),
+//!             Item::new("&Quit").on_select(Message::Quit),
+//!         ]),
+//!         Menu::new("&Edit", [
+//!             Item::new("&Undo").on_select(Message::Undo).shortcut("Ctrl+Z"),
+//!         ]),
+//!     ])
+//!     .into()
+//! }
+//! ```
+//!
+//! [`ContextMenu`]: crate::ContextMenu
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::menu::{self, Metrics};
+
+pub use crate::menu::{Catalog, Item, Menu, Style, StyleFn, default};
+
+/// A horizontal bar of [`Menu`]s.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::menu_bar;
+/// use iced::widget::menu_bar::{Item, Menu};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Open,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     menu_bar([Menu::new("&File", [Item::new("&Open...").on_select(Message::Open)])]).into()
+/// }
+/// ```
+pub struct MenuBar<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    menus: Vec<Menu<'a, Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`MenuBar`] with the given [`Menu`]s.
+    pub fn new(menus: impl IntoIterator<Item = Menu<'a, Message>>) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: menu::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Menu`] to the [`MenuBar`].
+    pub fn push(mut self, menu: Menu<'a, Message>) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles and items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MenuBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer) -> Metrics<Renderer::Font> {
+        Metrics::new(renderer, self.text_size, self.font, self.padding)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    menu: menu::State,
+    hovered: Option<usize>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    fn show_mnemonics(&self) -> bool {
+        self.modifiers.alt() || self.menu.show_mnemonics
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = self.metrics(renderer);
+        let height = metrics.height();
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let (width, underline) = metrics.label::<Renderer::Paragraph>(&menu.label);
+                let width = width + metrics.padding.x();
+
+                let title = layout::Node::with_children(
+                    Size::new(width, height),
+                    underline
+                        .map(|underline| underline.translate([metrics.padding.left, 0.0]))
+                        .into_iter()
+                        .collect(),
+                )
+                .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                title
+            })
+            .collect();
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        operation.semantics(
+            None,
+            layout.bounds(),
+            &Semantics::new(semantics::Role::MenuBar),
+        );
+
+        operation.traverse(&mut |operation| {
+            for (index, (menu, layout)) in self.menus.iter().zip(layout.children()).enumerate() {
+                let bounds = layout.bounds();
+
+                operation.semantics(
+                    None,
+                    bounds,
+                    &Semantics::new(semantics::Role::MenuItem)
+                        .label(menu.label.content.as_ref())
+                        .expanded(state.menu.open == Some(index)),
+                );
+
+                operation.traverse(&mut |operation| {
+                    operation.text(None, bounds, &menu.label.content);
+                });
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let title = layout
+            .children()
+            .position(|title| cursor.is_over(title.bounds()));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Only the primary button opens menus, but any button closes them
+                let title = title.filter(|_| {
+                    !matches!(
+                        event,
+                        Event::Mouse(mouse::Event::ButtonPressed(button))
+                            if *button != mouse::Button::Left
+                    )
+                });
+
+                if state.menu.is_open() {
+                    // Event wasn't processed by overlay, so cursor was clicked either outside its
+                    // bounds or on the bar, either way we close the overlay unless a different
+                    // menu was clicked.
+                    match title.filter(|&index| state.menu.open != Some(index)) {
+                        Some(index) => state.menu.open(index, &self.menus[index].items, false),
+                        None => state.menu.close(),
+                    }
+                } else if let Some(index) = title {
+                    state.menu.open(index, &self.menus[index].items, false);
+                } else {
+                    return;
+                }
+
+                shell.capture_event();
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if state.hovered != title {
+                    state.hovered = title;
+                    shell.request_redraw();
+                }
+
+                if let Some(index) = title
+                    && state.menu.is_open()
+                    && state.menu.open != Some(index)
+                {
+                    state.menu.open(index, &self.menus[index].items, false);
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) => {
+                let index = if modifiers.alt() {
+                    key.to_latin(*physical_key)
+                        .and_then(|c| self.menus.iter().position(|menu| menu.label.matches(c)))
+                } else if key == &keyboard::Key::Named(key::Named::F10) && modifiers.is_empty() {
+                    Some(0).filter(|_| !self.menus.is_empty())
+                } else {
+                    None
+                };
+
+                if let Some(index) = index {
+                    state.menu.open(index, &self.menus[index].items, true);
+
+                    shell.capture_event();
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                if state.modifiers.alt() != modifiers.alt() {
+                    shell.request_redraw();
+                }
+
+                state.modifiers = *modifiers;
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|title| cursor.is_over(title.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let metrics = self.metrics(renderer);
+
+        if let Some(background) = style.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for (index, (menu, layout)) in self.menus.iter().zip(layout.children()).enumerate() {
+            let bounds = layout.bounds();
+
+            let is_highlighted = match state.menu.open {
+                Some(open) => open == index,
+                None => state.hovered == Some(index),
+            };
+
+            if is_highlighted {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            let color = if is_highlighted {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            renderer.fill_text(
+                Text {
+                    hint_factor: renderer.scale_factor(),
+                    ..metrics.text(menu.label.content.to_string(), bounds.size())
+                },
+                Point::new(bounds.x + metrics.padding.left, bounds.center_y()),
+                color,
+                *viewport,
+            );
+
+            if state.show_mnemonics()
+                && let Some(underline) = layout.children().next()
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: underline.bounds(),
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.menu.is_open() {
+            return None;
+        }
+
+        let menus = self
+            .menus
+            .iter()
+            .zip(layout.children())
+            .map(|(menu, layout)| (menu.items.as_slice(), layout.bounds() + translation))
+            .collect();
+
+        Some(menu::overlay(
+            &mut state.menu,
+            menus,
+            self.metrics(renderer),
+            &self.class,
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
@@ -0,0 +1,772 @@
+//! Number inputs let users type and step through numeric values.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::number_input;
+//!
+//! struct State {
+//!    quantity: u32,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     QuantityChanged(u32),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     number_input("Quantity", state.quantity)
+//!         .min(1)
+//!         .max(99)
+//!         .on_change(Message::QuantityChanged)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::QuantityChanged(quantity) => {
+//!             state.quantity = quantity;
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::{self, Widget};
+use crate::core::{Element, Event, Length, Padding, Pixels, Rectangle, Shell, Size};
+use crate::text_input::{self, TextInput};
+
+use num_traits::FromPrimitive;
+use std::fmt::Display;
+
+/// A field that can be filled with a number.
+///
+/// The number can be typed, or stepped with the arrow keys. Holding shift
+/// while stepping multiplies the step by 10.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::number_input;
+/// use iced::widget::number_input::Locale;
+///
+/// struct State {
+///    price: f64,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PriceChanged(f64),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     number_input("Price", state.price)
+///         .step(0.5)
+///         .precision(2)
+///         .locale(Locale::EUROPEAN)
+///         .on_change(Message::PriceChanged)
+///         .into()
+/// }
+/// ```
+pub struct NumberInput<'a, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    text_input: TextInput<'a, Input, Theme, Renderer>,
+    value: T,
+    step: T,
+    min: Option<T>,
+    max: Option<T>,
+    precision: Option<usize>,
+    locale: Locale,
+    on_change: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_submit: Option<Message>,
+}
+
+/// A type of number that can be edited in a [`NumberInput`].
+///
+/// Numbers are parsed and stepped as [`f64`]; so only the types it represents
+/// exactly (i.e. those implementing `Into<f64>`, like `u32`, `i32` or `f32`)
+/// are supported. Wider integers like `i64` would lose precision.
+pub trait Number: Copy + PartialOrd + Display + Into<f64> + FromPrimitive {}
+
+impl<T> Number for T where T: Copy + PartialOrd + Display + Into<f64> + FromPrimitive {}
+
+#[derive(Debug, Clone)]
+enum Input {
+    Changed(String),
+    Submit,
+}
+
+impl<'a, T, Message, Theme, Renderer> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Number,
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`NumberInput`] with the given placeholder and
+    /// its current value.
+    pub fn new(placeholder: &str, value: T) -> Self {
+        let locale = Locale::default();
+
+        let text_input = TextInput::new(placeholder, &format(value, None, locale))
+            .on_submit(Input::Submit)
+            .filter(filter(locale, is_integer::<T>()));
+
+        Self {
+            text_input,
+            value,
+            step: T::from_u8(1).unwrap_or(value),
+            min: None,
+            max: None,
+            precision: None,
+            locale,
+            on_change: None,
+            on_submit: None,
+        }
+    }
+
+    /// Sets the message that should be produced when a new number is typed
+    /// or stepped into the [`NumberInput`].
+    ///
+    /// If this method is not called, the [`NumberInput`] will be disabled.
+    pub fn on_change(mut self, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        self.text_input = self.text_input.on_input(Input::Changed);
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`NumberInput`] is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the step of the [`NumberInput`], used by the arrow keys.
+    ///
+    /// By default, it is 1.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount of decimal digits the [`NumberInput`] displays.
+    ///
+    /// By default, numbers are displayed as short as possible.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the [`Locale`] used to format the numbers of the [`NumberInput`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self.text_input = self.text_input.filter(filter(locale, is_integer::<T>()));
+        self
+    }
+
+    /// Sets the [`widget::Id`] of the [`NumberInput`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`NumberInput`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.text_input = self.text_input.align_x(alignment);
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    #[must_use]
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>: From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`NumberInput`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as text_input::Catalog>::Class<'a>>) -> Self {
+        self.text_input = self.text_input.class(class);
+        self
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let value = self.min.map_or(value, |min| value.max(min.into()));
+
+        self.max.map_or(value, |max| value.min(max.into()))
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        self.clamp(value) == value
+    }
+
+    /// Synchronizes the text of the [`NumberInput`] with its value, unless
+    /// the user is typing a representation of it.
+    fn sync(&mut self, tree: &mut widget::Tree) {
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let buffer = tree.state.downcast_mut::<Buffer>();
+        let value = self.value.into();
+
+        if !is_focused
+            || (buffer.value != Some(value) && parse(&buffer.text, self.locale) != Some(value))
+        {
+            buffer.text = format(self.value, self.precision, self.locale);
+        }
+
+        buffer.value = Some(value);
+
+        self.text_input.set_value(&buffer.text);
+    }
+}
+
+/// The text being edited in a [`NumberInput`], along with the last value
+/// it was given.
+#[derive(Debug, Default)]
+struct Buffer {
+    text: String,
+    value: Option<f64>,
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'_, T, Message, Theme, Renderer>
+where
+    T: Number,
+    Message: Clone,
+    Theme: text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<Buffer>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(Buffer::default())
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        vec![widget::Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        Widget::<Input, Theme, Renderer>::diff(&self.text_input, &mut tree.children[0]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<Input, Theme, Renderer>::size(&self.text_input)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.sync(tree);
+
+        self.text_input
+            .layout(&mut tree.children[0], renderer, limits, None)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.text_input
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.sync(tree);
+
+        let Some(on_change) = &self.on_change else {
+            return;
+        };
+
+        let started_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        if started_focused
+            && let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named @ (key::Named::ArrowUp | key::Named::ArrowDown)),
+                modifiers,
+                ..
+            }) = event
+        {
+            let buffer = tree.state.downcast_mut::<Buffer>();
+
+            let current = parse(&buffer.text, self.locale).unwrap_or(self.value.into());
+            let step = self.step.into() * if modifiers.shift() { 10.0 } else { 1.0 };
+            let stepped = if *named == key::Named::ArrowUp {
+                current + step
+            } else {
+                current - step
+            };
+
+            let decimals = decimals(self.step).max(decimals(self.value));
+            let rounded = {
+                let scale = 10f64.powi(decimals as i32);
+
+                (stepped * scale).round() / scale
+            };
+
+            if let Some(value) = T::from_f64(self.clamp(rounded)) {
+                buffer.text = format(value, self.precision, self.locale);
+
+                self.text_input.set_value(&buffer.text);
+
+                tree.children[0]
+                    .state
+                    .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+                    .move_cursor_to_end();
+
+                shell.publish(on_change(value));
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+
+            shell.capture_event();
+            return;
+        }
+
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages).with_drag(shell.drag().cloned());
+
+        self.text_input.update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_event_captured() {
+            shell.capture_event();
+        }
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        if local_shell.is_drag_changed() {
+            match local_shell.drag() {
+                Some(drag) => shell.start_drag(drag.clone()),
+                None => shell.end_drag(),
+            }
+        }
+
+        shell.request_redraw_at(local_shell.redraw_request());
+        shell.request_input_method(local_shell.input_method());
+        shell.clipboard_mut().merge(local_shell.clipboard_mut());
+
+        for message in local_messages {
+            match message {
+                Input::Changed(text) => {
+                    let text_input_state = tree.children[0]
+                        .state
+                        .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+                    let cursor = text_input_state
+                        .cursor()
+                        .end(&text_input::Value::new(&text));
+
+                    let (text, cursor) = regroup(&text, cursor, self.locale);
+
+                    self.text_input.set_value(&text);
+                    text_input_state.move_cursor_to(cursor);
+
+                    let buffer = tree.state.downcast_mut::<Buffer>();
+
+                    // Edits rejected by the filter produce the same text again
+                    if let Some(value) = parse(&text, self.locale)
+                        && parse(&buffer.text, self.locale) != Some(value)
+                        && self.contains(value)
+                        && let Some(number) = T::from_f64(value)
+                    {
+                        shell.publish(on_change(number));
+                    }
+
+                    buffer.text = text;
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+                Input::Submit => {
+                    if let Some(on_submit) = self.on_submit.clone() {
+                        shell.publish(on_submit);
+                    }
+                }
+            }
+        }
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        if started_focused && !is_focused {
+            let buffer = tree.state.downcast_mut::<Buffer>();
+
+            // Out of range numbers are clamped once the user is done typing
+            if let Some(value) = parse(&buffer.text, self.locale)
+                && !self.contains(value)
+                && let Some(number) = T::from_f64(self.clamp(value))
+            {
+                shell.publish(on_change(number));
+            }
+
+            buffer.text = format(self.value, self.precision, self.locale);
+
+            self.text_input.set_value(&buffer.text);
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.text_input
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            layout,
+            cursor,
+            None,
+            viewport,
+        );
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Number + 'a,
+    Message: Clone + 'a,
+    Theme: text_input::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(number_input: NumberInput<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(number_input)
+    }
+}
+
+/// The separators used to format the numbers of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// The separator between the integer and the fractional parts.
+    pub decimal: char,
+    /// The separator between groups of thousands, if any.
+    pub group: Option<char>,
+}
+
+impl Locale {
+    /// Numbers like `1234.5`.
+    pub const PLAIN: Self = Self {
+        decimal: '.',
+        group: None,
+    };
+
+    /// Numbers like `1,234.5`.
+    pub const ENGLISH: Self = Self {
+        decimal: '.',
+        group: Some(','),
+    };
+
+    /// Numbers like `1.234,5`.
+    pub const EUROPEAN: Self = Self {
+        decimal: ',',
+        group: Some('.'),
+    };
+
+    /// Numbers like `1 234,5`.
+    pub const INTERNATIONAL: Self = Self {
+        decimal: ',',
+        group: Some(' '),
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+/// Returns whether `T` can only represent integers.
+fn is_integer<T>() -> bool
+where
+    T: Into<f64> + FromPrimitive,
+{
+    T::from_f64(0.5).map(Into::into) != Some(0.5)
+}
+
+/// Returns the characters that can be typed in a [`NumberInput`].
+fn filter(locale: Locale, is_integer: bool) -> impl Fn(char) -> bool {
+    move |c| {
+        c.is_ascii_digit()
+            || c == '-'
+            || Some(c) == locale.group
+            || (c == locale.decimal && !is_integer)
+    }
+}
+
+fn format<T: Display>(value: T, precision: Option<usize>, locale: Locale) -> String {
+    let text = match precision {
+        Some(precision) => {
+            let value: String = value.to_string();
+
+            value
+                .parse::<f64>()
+                .map(|value| format!("{value:.precision$}"))
+                .unwrap_or(value)
+        }
+        None => value.to_string(),
+    };
+
+    localize(&text, locale)
+}
+
+/// Returns the amount of decimal digits of a number.
+fn decimals<T: Display>(value: T) -> usize {
+    value
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+/// Formats a plain number, like `-1234.5`, with the given [`Locale`].
+fn localize(number: &str, locale: Locale) -> String {
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", number),
+    };
+
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+
+    let mut output = String::from(sign);
+    let digits = integer.chars().count();
+
+    for (i, digit) in integer.chars().enumerate() {
+        if let Some(group) = locale.group
+            && i > 0
+            && (digits - i) % 3 == 0
+        {
+            output.push(group);
+        }
+
+        output.push(digit);
+    }
+
+    if let Some(fraction) = fraction {
+        output.push(locale.decimal);
+        output.push_str(fraction);
+    }
+
+    output
+}
+
+/// Turns localized text into a plain number, like `-1234.5`.
+///
+/// Returns the number along with the amount of its characters that came
+/// before the given cursor.
+fn delocalize(text: &str, cursor: usize, locale: Locale) -> (String, usize) {
+    let mut number = String::new();
+    let mut before = 0;
+
+    for (i, c) in text.chars().enumerate() {
+        let c = match c {
+            '-' if number.is_empty() => '-',
+            c if c == locale.decimal && !number.contains('.') => '.',
+            c if c.is_ascii_digit() => c,
+            _ => continue,
+        };
+
+        number.push(c);
+
+        if i < cursor {
+            before += 1;
+        }
+    }
+
+    (number, before)
+}
+
+/// Regroups the text typed in a [`NumberInput`], keeping the cursor
+/// right after the same significant characters.
+fn regroup(text: &str, cursor: usize, locale: Locale) -> (String, usize) {
+    let (number, before) = delocalize(text, cursor, locale);
+    let text = localize(&number, locale);
+
+    let cursor = if before == 0 {
+        0
+    } else {
+        text.chars()
+            .enumerate()
+            .filter(|(_, c)| Some(*c) != locale.group)
+            .nth(before - 1)
+            .map_or(text.chars().count(), |(i, _)| i + 1)
+    };
+
+    (text, cursor)
+}
+
+fn parse(text: &str, locale: Locale) -> Option<f64> {
+    delocalize(text, 0, locale).0.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_formats_with_locales() {
+        assert_eq!(format(1234567.5, None, Locale::PLAIN), "1234567.5");
+        assert_eq!(format(1234567.5, None, Locale::ENGLISH), "1,234,567.5");
+        assert_eq!(format(-1234.5, Some(2), Locale::EUROPEAN), "-1.234,50");
+        assert_eq!(format(123, None, Locale::INTERNATIONAL), "123");
+
+        assert_eq!(parse("-1.234,5", Locale::EUROPEAN), Some(-1234.5));
+        assert_eq!(parse("1,", Locale::EUROPEAN), Some(1.0));
+        assert_eq!(parse("-", Locale::ENGLISH), None);
+    }
+
+    #[test]
+    fn it_regroups_typed_text() {
+        // Typing a digit at the end
+        assert_eq!(
+            regroup("1,2345", 6, Locale::ENGLISH),
+            ("12,345".to_owned(), 6)
+        );
+
+        // Typing a digit in the middle
+        assert_eq!(
+            regroup("1,2934", 4, Locale::ENGLISH),
+            ("12,934".to_owned(), 4)
+        );
+
+        // Removing a digit
+        assert_eq!(
+            regroup("12,45", 3, Locale::ENGLISH),
+            ("1,245".to_owned(), 3)
+        );
+
+        // Unfinished fractions are kept
+        assert_eq!(
+            regroup("1000,", 5, Locale::EUROPEAN),
+            ("1.000,".to_owned(), 6)
+        );
+    }
+
+    #[test]
+    fn it_filters_decimals_of_integers() {
+        assert!(is_integer::<u32>());
+        assert!(!is_integer::<f32>());
+
+        assert!(!filter(Locale::ENGLISH, true)('.'));
+        assert!(filter(Locale::ENGLISH, true)(','));
+        assert!(filter(Locale::ENGLISH, false)('.'));
+    }
+}
@@ -70,6 +70,8 @@ use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -427,6 +429,28 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let mut semantics = Semantics::new(semantics::Role::ComboBox).expanded(state.is_open);
+
+        if let Some(placeholder) = &self.placeholder {
+            semantics = semantics.description(placeholder.as_str());
+        }
+
+        if let Some(selected) = &self.selected {
+            semantics = semantics.value((self.to_string)(selected.borrow()));
+        }
+
+        operation.semantics(None, layout.bounds(), &semantics);
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
@@ -64,6 +64,7 @@ use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -317,6 +318,24 @@ where
         )
     }
 
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.semantics(
+            None,
+            layout.bounds(),
+            &Semantics::new(semantics::Role::RadioButton).toggled(self.is_selected),
+        );
+
+        operation.traverse(&mut |operation| {
+            operation.text(None, layout.bounds(), &self.label);
+        });
+    }
+
     fn update(
         &mut self,
         _tree: &mut Tree,
@@ -441,6 +460,7 @@ where
                 state.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -9,6 +9,8 @@ use crate::core::{
     Element, Event, Length, Padding, Pixels, Rectangle, Shell, Size, Vector, Widget,
 };
 
+pub use crate::core::layout::flow::Justify;
+
 /// A container that distributes its contents horizontally.
 ///
 /// # Example
@@ -154,6 +156,7 @@ where
             row: self,
             vertical_spacing: None,
             align_x: alignment::Horizontal::Left,
+            justify: None,
         }
     }
 }
@@ -351,9 +354,28 @@ pub struct Wrapping<'a, Message, Theme = crate::Theme, Renderer = crate::Rendere
     row: Row<'a, Message, Theme, Renderer>,
     vertical_spacing: Option<f32>,
     align_x: alignment::Horizontal,
+    justify: Option<Justify>,
 }
 
 impl<Message, Theme, Renderer> Wrapping<'_, Message, Theme, Renderer> {
+    /// Sets the horizontal spacing _between_ elements of a line.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the wrapping [`Row`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.row.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the wrapping [`Row`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.row.width = width.into();
+        self
+    }
+
     /// Sets the vertical spacing _between_ lines.
     pub fn vertical_spacing(mut self, amount: impl Into<Pixels>) -> Self {
         self.vertical_spacing = Some(amount.into().0);
@@ -361,10 +383,22 @@ impl<Message, Theme, Renderer> Wrapping<'_, Message, Theme, Renderer> {
     }
 
     /// Sets the horizontal alignment of the wrapping [`Row`].
+    ///
+    /// Each line is aligned within the widest line.
     pub fn align_x(mut self, align_x: impl Into<alignment::Horizontal>) -> Self {
         self.align_x = align_x.into();
         self
     }
+
+    /// Sets the horizontal distribution of the contents of each line of the
+    /// wrapping [`Row`].
+    ///
+    /// Unlike [`align_x`](Self::align_x), the contents are distributed in
+    /// the whole width of the wrapping [`Row`].
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = Some(justify);
+        self
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -390,108 +424,21 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits = limits
-            .width(self.row.width)
-            .height(self.row.height)
-            .shrink(self.row.padding);
-
-        let child_limits = limits.loose();
-        let spacing = self.row.spacing;
-        let vertical_spacing = self.vertical_spacing.unwrap_or(spacing);
-        let max_width = limits.max().width;
-
-        let mut children: Vec<layout::Node> = Vec::new();
-        let mut intrinsic_size = Size::ZERO;
-        let mut row_start = 0;
-        let mut row_height = 0.0;
-        let mut x = 0.0;
-        let mut y = 0.0;
-
-        let align_factor = match self.row.align {
-            Alignment::Start => 0.0,
-            Alignment::Center => 2.0,
-            Alignment::End => 1.0,
-        };
-
-        let align_y = |row_start: std::ops::Range<usize>,
-                       row_height: f32,
-                       children: &mut Vec<layout::Node>| {
-            if align_factor != 0.0 {
-                for node in &mut children[row_start] {
-                    let height = node.size().height;
-
-                    node.translate_mut(Vector::new(0.0, (row_height - height) / align_factor));
-                }
-            }
-        };
-
-        for (i, child) in self.row.children.iter_mut().enumerate() {
-            let node = child
-                .as_widget_mut()
-                .layout(&mut tree.children[i], renderer, &child_limits);
-
-            let child_size = node.size();
-
-            if x != 0.0 && x + child_size.width > max_width {
-                intrinsic_size.width = intrinsic_size.width.max(x - spacing);
-
-                align_y(row_start..i, row_height, &mut children);
-
-                y += row_height + vertical_spacing;
-                x = 0.0;
-                row_start = i;
-                row_height = 0.0;
-            }
-
-            row_height = row_height.max(child_size.height);
-
-            children.push(node.move_to((x + self.row.padding.left, y + self.row.padding.top)));
-
-            x += child_size.width + spacing;
-        }
-
-        if x != 0.0 {
-            intrinsic_size.width = intrinsic_size.width.max(x - spacing);
-        }
-
-        intrinsic_size.height = y + row_height;
-        align_y(row_start..children.len(), row_height, &mut children);
-
-        let align_factor = match self.align_x {
-            alignment::Horizontal::Left => 0.0,
-            alignment::Horizontal::Center => 2.0,
-            alignment::Horizontal::Right => 1.0,
-        };
-
-        if align_factor != 0.0 {
-            let total_width = intrinsic_size.width;
-
-            let mut row_start = 0;
-
-            for i in 0..children.len() {
-                let bounds = children[i].bounds();
-                let row_width = bounds.x + bounds.width;
-
-                let next_x = children
-                    .get(i + 1)
-                    .map(|node| node.bounds().x)
-                    .unwrap_or_default();
-
-                if next_x == 0.0 {
-                    let translation = Vector::new((total_width - row_width) / align_factor, 0.0);
-
-                    for node in &mut children[row_start..=i] {
-                        node.translate_mut(translation);
-                    }
-
-                    row_start = i + 1;
-                }
-            }
-        }
-
-        let size = limits.resolve(self.row.width, self.row.height, intrinsic_size);
-
-        layout::Node::with_children(size.expand(self.row.padding), children)
+        layout::flow::resolve(
+            layout::flex::Axis::Horizontal,
+            renderer,
+            limits,
+            self.row.width,
+            self.row.height,
+            self.row.padding,
+            self.row.spacing,
+            self.vertical_spacing.unwrap_or(self.row.spacing),
+            self.row.align,
+            self.align_x.into(),
+            self.justify,
+            &mut self.row.children,
+            &mut tree.children,
+        )
     }
 
     fn operate(
@@ -31,6 +31,7 @@ use crate::core::text;
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -535,6 +536,11 @@ where
         let content_bounds = content_layout.bounds();
         let translation = state.translation(self.direction, bounds, content_bounds);
 
+        operation.semantics(
+            self.id.as_ref(),
+            bounds,
+            &Semantics::new(semantics::Role::ScrollView),
+        );
         operation.scrollable(self.id.as_ref(), bounds, content_bounds, translation, state);
 
         operation.traverse(&mut |operation| {
@@ -559,11 +565,25 @@ where
     ) {
         const AUTOSCROLL_DEADZONE: f32 = 20.0;
         const AUTOSCROLL_SMOOTHNESS: f32 = 1.5;
+        const DRAG_SCROLL_MARGIN: f32 = 30.0;
+        const DRAG_SCROLL_SPEED: f32 = 600.0;
 
         let state = tree.state.downcast_mut::<State>();
         let bounds = layout.bounds();
         let cursor_over_scrollable = cursor.position_over(bounds);
 
+        // Scrolls faster the closer the dragging pointer is to an edge
+        let drag_scroll_velocity = |position: Point| {
+            let speed = |distance: f32| {
+                (1.0 - distance / DRAG_SCROLL_MARGIN).clamp(0.0, 1.0) * DRAG_SCROLL_SPEED
+            };
+
+            Vector::new(
+                speed(bounds.x + bounds.width - position.x) - speed(position.x - bounds.x),
+                speed(bounds.y + bounds.height - position.y) - speed(position.y - bounds.y),
+            )
+        };
+
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
@@ -842,8 +862,9 @@ where
                     shell.request_redraw();
                 }
                 Event::Touch(event)
-                    if matches!(state.interaction, Interaction::TouchScrolling(_))
-                        || (!mouse_over_y_scrollbar && !mouse_over_x_scrollbar) =>
+                    if shell.drag().is_none()
+                        && (matches!(state.interaction, Interaction::TouchScrolling(_))
+                            || (!mouse_over_y_scrollbar && !mouse_over_x_scrollbar)) =>
                 {
                     match event {
                         touch::Event::FingerPressed { .. } => {
@@ -907,6 +928,13 @@ where
                             shell.request_redraw();
                         }
                     }
+
+                    if shell.drag().is_some()
+                        && cursor_over_scrollable
+                            .is_some_and(|position| drag_scroll_velocity(position) != Vector::ZERO)
+                    {
+                        shell.request_redraw();
+                    }
                 }
                 Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                     state.keyboard_modifiers = *modifiers;
@@ -983,6 +1011,34 @@ where
                         }
                     }
 
+                    let velocity = cursor_over_scrollable
+                        .filter(|_| shell.drag().is_some())
+                        .map(drag_scroll_velocity)
+                        .unwrap_or(Vector::ZERO);
+
+                    if velocity == Vector::ZERO {
+                        state.last_drag_scroll = None;
+                    } else {
+                        let time_delta = state
+                            .last_drag_scroll
+                            .map(|last_frame| *now - last_frame)
+                            .unwrap_or_default();
+
+                        state.scroll(
+                            self.direction.align(velocity * time_delta.as_secs_f32()),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        state.last_drag_scroll = Some(*now);
+
+                        let _ =
+                            notify_scroll(state, &self.on_scroll, bounds, content_bounds, shell);
+
+                        shell.request_redraw();
+                        return;
+                    }
+
                     let _ = notify_viewport(state, &self.on_scroll, bounds, content_bounds, shell);
                 }
                 _ => {}
@@ -1506,6 +1562,7 @@ struct State {
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
     last_scrolled: Option<Instant>,
+    last_drag_scroll: Option<Instant>,
     is_scrollbar_visible: bool,
 }
 
@@ -1531,6 +1588,7 @@ impl Default for State {
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
             last_scrolled: None,
+            last_drag_scroll: None,
             is_scrollbar_visible: true,
         }
     }
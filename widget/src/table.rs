@@ -1,15 +1,31 @@
 //! Display tables.
+//!
+//! Tables can be sorted by clicking the header of a [`Column`] with a
+//! [`Column::on_sort`] handler, and resized by dragging the separator to
+//! the right of its header.
 use crate::core;
 use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
 use crate::core::widget;
 use crate::core::{
-    Alignment, Background, Element, Layout, Length, Pixels, Rectangle, Size, Widget,
+    Alignment, Background, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Size,
+    Vector, Widget,
 };
 
+/// The horizontal space reserved for the sort indicator of a [`Column`].
+const SORT_INDICATOR_WIDTH: f32 = 16.0;
+
+/// The width of the area around a separator that can be dragged to resize a [`Column`].
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+
+/// The minimum width a [`Column`] can be resized to.
+const MIN_COLUMN_WIDTH: f32 = 10.0;
+
 /// Creates a new [`Table`] with the given columns and rows.
 ///
 /// Columns can be created using the [`column()`] function, while rows can be any
@@ -21,7 +37,7 @@ pub fn table<'a, 'b, T, Message, Theme, Renderer>(
 where
     T: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     Table::new(columns, rows)
 }
@@ -44,6 +60,9 @@ where
         width: Length::Shrink,
         align_x: alignment::Horizontal::Left,
         align_y: alignment::Vertical::Top,
+        sorted: None,
+        on_sort: None,
+        on_resize: None,
     }
 }
 
@@ -52,7 +71,7 @@ pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
     Theme: Catalog,
 {
-    columns: Vec<Column_>,
+    columns: Vec<Column_<'a, Message>>,
     cells: Vec<Element<'a, Message, Theme, Renderer>>,
     width: Length,
     height: Length,
@@ -60,19 +79,25 @@ where
     padding_y: f32,
     separator_x: f32,
     separator_y: f32,
+    sticky_header: bool,
+    on_row_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    is_selected: Option<Box<dyn Fn(usize) -> bool + 'a>>,
     class: Theme::Class<'a>,
 }
 
-struct Column_ {
+struct Column_<'a, Message> {
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    sorted: Option<Direction>,
+    on_sort: Option<Box<dyn Fn(Direction) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     /// Creates a new [`Table`] with the given columns and rows.
     ///
@@ -104,6 +129,9 @@ where
                         width: column.width,
                         align_x: column.align_x,
                         align_y: column.align_y,
+                        sorted: column.sorted,
+                        on_sort: column.on_sort,
+                        on_resize: column.on_resize,
                     },
                     column.view,
                 )
@@ -136,6 +164,9 @@ where
             padding_y: 5.0,
             separator_x: 1.0,
             separator_y: 1.0,
+            sticky_header: false,
+            on_row_click: None,
+            is_selected: None,
             class: Theme::default(),
         }
     }
@@ -183,18 +214,181 @@ where
         self.separator_y = separator.into().0;
         self
     }
+
+    /// Sets whether the header of the [`Table`] should stay visible while
+    /// scrolling its rows inside a [`Scrollable`](crate::Scrollable).
+    pub fn sticky_header(mut self, sticky_header: bool) -> Self {
+        self.sticky_header = sticky_header;
+        self
+    }
+
+    /// Sets the message that should be produced when a row of the [`Table`]
+    /// is clicked.
+    ///
+    /// The closure receives the index of the row, starting from the first
+    /// one after the header.
+    pub fn on_row_click(mut self, on_row_click: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_row_click = Some(Box::new(on_row_click));
+        self
+    }
+
+    /// Sets the closure that decides whether the row with the given index
+    /// is selected.
+    pub fn selected(mut self, is_selected: impl Fn(usize) -> bool + 'a) -> Self {
+        self.is_selected = Some(Box::new(is_selected));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the horizontal position of the separator to the right of the
+    /// given column, relative to the [`Table`].
+    fn separator_position(&self, metrics: &Metrics, column: usize) -> f32 {
+        metrics.columns[..=column].iter().sum::<f32>()
+            + column as f32 * (self.padding_x * 2.0 + self.separator_x)
+            + self.padding_x * 2.0
+    }
+
+    /// Returns the bounds of the given row, including its padding.
+    ///
+    /// The header is the first row.
+    fn row_bounds(&self, metrics: &Metrics, bounds: Rectangle, row: usize) -> Rectangle {
+        let spacing_y = self.padding_y * 2.0 + self.separator_y;
+
+        Rectangle {
+            x: bounds.x,
+            y: bounds.y
+                + metrics.rows[..row]
+                    .iter()
+                    .map(|height| height + spacing_y)
+                    .sum::<f32>(),
+            width: bounds.width,
+            height: metrics.rows[row] + self.padding_y * 2.0,
+        }
+    }
+
+    /// Returns the vertical offset of the header, which follows the
+    /// viewport when it is sticky.
+    fn header_offset(&self, metrics: &Metrics, bounds: Rectangle, viewport: &Rectangle) -> f32 {
+        if !self.sticky_header || metrics.rows.is_empty() {
+            return 0.0;
+        }
+
+        let header = metrics.rows[0] + self.padding_y * 2.0 + self.separator_y;
+
+        (viewport.y - bounds.y).clamp(0.0, (bounds.height - header).max(0.0))
+    }
+
+    /// Returns the bounds of the header, at its current offset.
+    fn header_bounds(
+        &self,
+        metrics: &Metrics,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Rectangle {
+        if metrics.rows.is_empty() {
+            return Rectangle::new(bounds.position(), Size::ZERO);
+        }
+
+        self.row_bounds(metrics, bounds, 0)
+            + Vector::new(0.0, self.header_offset(metrics, bounds, viewport))
+    }
+
+    fn hover(
+        &self,
+        metrics: &Metrics,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Option<Hover> {
+        let bounds = layout.bounds();
+        let position = cursor.position()?;
+        let header = self.header_bounds(metrics, bounds, viewport);
+
+        // The handle of the last column may stick out of the table
+        if header
+            .expand([0.0, RESIZE_HANDLE_WIDTH / 2.0])
+            .contains(position)
+        {
+            let resize = self.columns.iter().enumerate().find_map(|(column, data)| {
+                let x =
+                    bounds.x + self.separator_position(metrics, column) + self.separator_x / 2.0;
+
+                (data.on_resize.is_some() && (position.x - x).abs() <= RESIZE_HANDLE_WIDTH / 2.0)
+                    .then_some(column)
+            });
+
+            if let Some(column) = resize {
+                return Some(Hover::Resize(column));
+            }
+        }
+
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        if header.contains(position) {
+            let column = (0..self.columns.len()).find(|&column| {
+                position.x - bounds.x < self.separator_position(metrics, column) + self.separator_x
+            })?;
+
+            return Some(Hover::Header(column));
+        }
+
+        (1..metrics.rows.len())
+            .find(|&row| self.row_bounds(metrics, bounds, row).contains(position))
+            .map(|row| Hover::Row(row - 1))
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    metrics: Metrics,
+    hovered: Option<Hover>,
+    resizing: Option<Resizing>,
 }
 
+#[derive(Debug, Default)]
 struct Metrics {
     columns: Vec<f32>,
     rows: Vec<f32>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hover {
+    Header(usize),
+    Resize(usize),
+    Row(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Table<'a, Message, Theme, Renderer>
 where
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn size(&self) -> Size<Length> {
         Size {
@@ -204,14 +398,11 @@ where
     }
 
     fn tag(&self) -> widget::tree::Tag {
-        widget::tree::Tag::of::<Metrics>()
+        widget::tree::Tag::of::<State>()
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(Metrics {
-            columns: Vec::new(),
-            rows: Vec::new(),
-        })
+        widget::tree::State::new(State::default())
     }
 
     fn children(&self) -> Vec<widget::Tree> {
@@ -231,7 +422,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let metrics = tree.state.downcast_mut::<Metrics>();
+        let metrics = &mut tree.state.downcast_mut::<State>().metrics;
         let columns = self.columns.len();
         let rows = self.cells.len() / columns;
 
@@ -298,7 +489,12 @@ where
             .width(width);
 
             let layout = cell.as_widget_mut().layout(state, renderer, &limits);
-            let size = limits.resolve(width, Length::Shrink, layout.size());
+            let mut size = limits.resolve(width, Length::Shrink, layout.size());
+
+            // Make room for the sort indicator of shrinking columns
+            if row == 0 && width == Length::Shrink && self.columns[column].on_sort.is_some() {
+                size.width += SORT_INDICATOR_WIDTH;
+            }
 
             metrics.columns[column] = metrics.columns[column].max(size.width);
             metrics.rows[row] = metrics.rows[row].max(size.height);
@@ -415,14 +611,23 @@ where
             }
 
             let Column_ {
-                align_x, align_y, ..
+                align_x,
+                align_y,
+                on_sort,
+                ..
             } = &self.columns[column];
 
+            let width = if row == 0 && on_sort.is_some() {
+                (metrics.columns[column] - SORT_INDICATOR_WIDTH).max(0.0)
+            } else {
+                metrics.columns[column]
+            };
+
             cell.move_to_mut((x, y));
             cell.align_mut(
                 Alignment::from(*align_x),
                 Alignment::from(*align_y),
-                Size::new(metrics.columns[column], metrics.rows[row]),
+                Size::new(width, metrics.rows[row]),
             );
 
             x += metrics.columns[column] + spacing_x;
@@ -455,15 +660,117 @@ where
         shell: &mut core::Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        for ((cell, tree), layout) in self
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+        let offset = self.header_offset(&state.metrics, bounds, viewport);
+        let header = self.header_bounds(&state.metrics, bounds, viewport);
+
+        // The sticky header covers the rows under it
+        let rows_cursor = if offset > 0.0 && cursor.is_over(header) {
+            cursor.levitate()
+        } else {
+            cursor
+        };
+
+        let columns = self.columns.len();
+
+        for (i, ((cell, tree), layout)) in self
             .cells
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
+            .enumerate()
         {
+            let cursor = if i < columns {
+                cursor - Vector::new(0.0, offset)
+            } else {
+                rows_cursor
+            };
+
             cell.as_widget_mut()
                 .update(tree, event, layout, cursor, renderer, shell, viewport);
         }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(resizing) = state.resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let Some(position) = cursor.land().position()
+                        && let Some(on_resize) = &self.columns[resizing.column].on_resize
+                    {
+                        let width =
+                            (resizing.width + position.x - resizing.origin).max(MIN_COLUMN_WIDTH);
+
+                        shell.publish(on_resize(width));
+                        shell.capture_event();
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    state.resizing = None;
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+                _ => {}
+            }
+
+            return;
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let hovered = self.hover(&state.metrics, layout, cursor, viewport);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match hovered {
+                Some(Hover::Resize(column)) => {
+                    if let Some(position) = cursor.position() {
+                        state.resizing = Some(Resizing {
+                            column,
+                            origin: position.x,
+                            width: state.metrics.columns[column],
+                        });
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+                }
+                Some(Hover::Header(column)) => {
+                    let column = &self.columns[column];
+
+                    if let Some(on_sort) = &column.on_sort {
+                        shell.publish(on_sort(match column.sorted {
+                            Some(Direction::Ascending) => Direction::Descending,
+                            Some(Direction::Descending) | None => Direction::Ascending,
+                        }));
+
+                        shell.capture_event();
+                    }
+                }
+                Some(Hover::Row(row)) => {
+                    if let Some(on_row_click) = &self.on_row_click {
+                        shell.publish(on_row_click(row));
+                        shell.capture_event();
+                    }
+                }
+                None => {}
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft)
+                if state.hovered != hovered =>
+            {
+                state.hovered = hovered;
+                shell.request_redraw();
+            }
+            _ => {}
+        }
     }
 
     fn draw(
@@ -476,26 +783,64 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        for ((cell, state), layout) in self.cells.iter().zip(&tree.children).zip(layout.children())
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let metrics = &state.metrics;
+        let appearance = theme.style(&self.class);
+        let columns = self.columns.len();
+        let offset = self.header_offset(metrics, bounds, viewport);
+
+        for row in 1..metrics.rows.len() {
+            let index = row - 1;
+
+            let background = if self
+                .is_selected
+                .as_ref()
+                .is_some_and(|is_selected| is_selected(index))
+            {
+                appearance.row_selected
+            } else if self.on_row_click.is_some()
+                && state.resizing.is_none()
+                && state.hovered == Some(Hover::Row(index))
+            {
+                appearance.row_hovered
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                let bounds = self.row_bounds(metrics, bounds, row);
+
+                if bounds.intersects(viewport) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+            }
+        }
+
+        for ((cell, state), layout) in self
+            .cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .skip(columns)
         {
             cell.as_widget()
                 .draw(state, renderer, theme, style, layout, cursor, viewport);
         }
 
-        let bounds = layout.bounds();
-        let metrics = tree.state.downcast_ref::<Metrics>();
-        let style = theme.style(&self.class);
-
         if self.separator_x > 0.0 {
-            let mut x = self.padding_x;
-
-            for width in &metrics.columns[..metrics.columns.len().saturating_sub(1)] {
-                x += width + self.padding_x;
-
+            for column in 0..metrics.columns.len().saturating_sub(1) {
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
-                            x: bounds.x + x,
+                            x: bounds.x + self.separator_position(metrics, column),
                             y: bounds.y,
                             width: self.separator_x,
                             height: bounds.height,
@@ -503,10 +848,8 @@ where
                         snap: true,
                         ..renderer::Quad::default()
                     },
-                    style.separator_x,
+                    appearance.separator_x,
                 );
-
-                x += self.separator_x + self.padding_x;
             }
         }
 
@@ -527,12 +870,147 @@ where
                         snap: true,
                         ..renderer::Quad::default()
                     },
-                    style.separator_y,
+                    appearance.separator_y,
                 );
 
                 y += self.separator_y + self.padding_y;
             }
         }
+
+        let draw_header = |renderer: &mut Renderer| {
+            if metrics.rows.is_empty() {
+                return;
+            }
+
+            let header = self.row_bounds(metrics, bounds, 0);
+
+            if let Some(background) = appearance.header_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: header,
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            for ((cell, state), layout) in self
+                .cells
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .take(columns)
+            {
+                cell.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor - Vector::new(0.0, offset),
+                    viewport,
+                );
+            }
+
+            for (column, data) in self.columns.iter().enumerate() {
+                let right = bounds.x + self.separator_position(metrics, column);
+
+                if self.separator_x > 0.0 && column + 1 < columns {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: right,
+                                width: self.separator_x,
+                                ..header
+                            },
+                            snap: true,
+                            ..renderer::Quad::default()
+                        },
+                        appearance.separator_x,
+                    );
+                }
+
+                if let Some(direction) = data.sorted {
+                    renderer.fill_text(
+                        Text {
+                            content: match direction {
+                                Direction::Ascending => Renderer::SCROLL_UP_ICON,
+                                Direction::Descending => Renderer::SCROLL_DOWN_ICON,
+                            }
+                            .to_string(),
+                            bounds: Size::new(SORT_INDICATOR_WIDTH, header.height),
+                            size: renderer.default_size() * 0.75,
+                            line_height: text::LineHeight::default(),
+                            font: Renderer::ICON_FONT,
+                            align_x: text::Alignment::Center,
+                            align_y: alignment::Vertical::Center,
+                            shaping: text::Shaping::Basic,
+                            wrapping: text::Wrapping::None,
+                            ellipsis: text::Ellipsis::default(),
+                            hint_factor: renderer.scale_factor(),
+                        },
+                        Point::new(
+                            right - self.padding_x - SORT_INDICATOR_WIDTH / 2.0,
+                            header.center_y(),
+                        ),
+                        appearance.indicator,
+                        header,
+                    );
+                }
+            }
+
+            if offset > 0.0 && self.separator_y > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: header.y + header.height,
+                            height: self.separator_y,
+                            ..header
+                        },
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.separator_y,
+                );
+            }
+        };
+
+        if offset > 0.0 {
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.with_translation(Vector::new(0.0, offset), draw_header);
+            });
+        } else {
+            draw_header(renderer);
+        }
+
+        let resize = match (state.resizing, state.hovered) {
+            (Some(resizing), _) => Some(resizing.column),
+            (None, Some(Hover::Resize(column))) => Some(column),
+            _ => None,
+        };
+
+        if let Some(column) = resize
+            && !metrics.rows.is_empty()
+        {
+            let header = self.header_bounds(metrics, bounds, viewport);
+            let x = bounds.x + self.separator_position(metrics, column) + self.separator_x / 2.0;
+
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x - 1.0,
+                            width: 2.0,
+                            ..header
+                        },
+                        snap: true,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.resize_handle,
+                );
+            });
+        }
     }
 
     fn mouse_interaction(
@@ -543,16 +1021,51 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.cells
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let hovered = self.hover(&state.metrics, layout, cursor, viewport);
+
+        if let Some(Hover::Resize(_)) = hovered {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let offset = self.header_offset(&state.metrics, layout.bounds(), viewport);
+        let columns = self.columns.len();
+
+        let interaction = self
+            .cells
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
-            .map(|((cell, tree), layout)| {
+            .enumerate()
+            .map(|(i, ((cell, tree), layout))| {
+                let cursor = if i < columns {
+                    cursor - Vector::new(0.0, offset)
+                } else if matches!(hovered, Some(Hover::Header(_))) {
+                    cursor.levitate()
+                } else {
+                    cursor
+                };
+
                 cell.as_widget()
                     .mouse_interaction(tree, layout, cursor, viewport, renderer)
             })
             .max()
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        match hovered {
+            Some(Hover::Header(column))
+                if interaction == mouse::Interaction::default()
+                    && self.columns[column].on_sort.is_some() =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => interaction,
+        }
     }
 
     fn operate(
@@ -597,7 +1110,7 @@ impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
         Element::new(table)
@@ -611,6 +1124,9 @@ pub struct Column<'a, 'b, T, Message, Theme = crate::Theme, Renderer = crate::Re
     width: Length,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    sorted: Option<Direction>,
+    on_sort: Option<Box<dyn Fn(Direction) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Renderer> {
@@ -631,6 +1147,44 @@ impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Rend
         self.align_y = alignment.into();
         self
     }
+
+    /// Sets the message that should be produced when the header of the
+    /// [`Column`] is clicked.
+    ///
+    /// The closure receives the [`Direction`] the rows should be sorted in,
+    /// which is the reverse of the [`sorted`](Self::sorted) one.
+    pub fn on_sort(mut self, on_sort: impl Fn(Direction) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the [`Direction`] the rows of the [`Table`] are sorted in by
+    /// the [`Column`], if any.
+    ///
+    /// An indicator will be displayed in its header.
+    pub fn sorted(mut self, direction: impl Into<Option<Direction>>) -> Self {
+        self.sorted = direction.into();
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Column`] is
+    /// resized by dragging the separator to the right of its header.
+    ///
+    /// The closure receives the new width of the [`Column`], which should
+    /// be used as its [fixed width](Self::width).
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+}
+
+/// The direction the rows of a [`Table`] are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From the smallest to the largest value.
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
 }
 
 /// The appearance of a [`Table`].
@@ -640,6 +1194,21 @@ pub struct Style {
     pub separator_x: Background,
     /// The background color of the vertical line separator between cells.
     pub separator_y: Background,
+    /// The [`Background`] of the header, if any.
+    ///
+    /// A sticky header should have an opaque background.
+    pub header_background: Option<Background>,
+    /// The [`Color`] of the sort indicator of a [`Column`].
+    pub indicator: Color,
+    /// The [`Background`] of a hovered row, if any.
+    ///
+    /// Rows are only hovered when [`Table::on_row_click`] is set.
+    pub row_hovered: Option<Background>,
+    /// The [`Background`] of a selected row, if any.
+    pub row_selected: Option<Background>,
+    /// The [`Background`] of the resize handle of a [`Column`] while
+    /// it is hovered or dragged.
+    pub resize_handle: Background,
 }
 
 /// The theme catalog of a [`Table`].
@@ -683,5 +1252,10 @@ pub fn default(theme: &crate::Theme) -> Style {
     Style {
         separator_x: separator,
         separator_y: separator,
+        header_background: Some(palette.background.base.color.into()),
+        indicator: palette.background.base.text,
+        row_hovered: Some(palette.background.weak.color.into()),
+        row_selected: Some(palette.primary.weak.color.into()),
+        resize_handle: palette.primary.base.color.into(),
     }
 }
@@ -0,0 +1,1385 @@
+//! Tabs let users switch between multiple views, like the open documents of an editor.
+//!
+//! Tabs can be selected with a click or with the arrow keys, closed with their close
+//! button or with a middle click, and reordered by dragging them. The tab bar scrolls
+//! horizontally once its tabs do not fit.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{tabs, text};
+//! use iced::widget::tabs::Tab;
+//!
+//! struct State {
+//!     documents: Vec<String>,
+//!     selected: Option<usize>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Select(usize),
+//!     Close(usize),
+//!     Reorder(usize, usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     let content = match state.selected {
+//!         Some(index) => text!("Editing {}", state.documents[index]),
+//!         None => text("Nothing is open"),
+//!     };
+//!
+//!     tabs(
+//!         state.documents.iter().map(|document| Tab::new(document.as_str())),
+//!         state.selected,
+//!         content,
+//!     )
+//!     .on_select(Message::Select)
+//!     .on_close(Message::Close)
+//!     .on_reorder(Message::Reorder)
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Select(index) => {
+//!             state.selected = Some(index);
+//!         }
+//!         Message::Close(index) => {
+//!             let _ = state.documents.remove(index);
+//!
+//!             state.selected = state
+//!                 .selected
+//!                 .filter(|_| !state.documents.is_empty())
+//!                 .map(|selected| selected.min(state.documents.len() - 1));
+//!         }
+//!         Message::Reorder(from, to) => {
+//!             let document = state.documents.remove(from);
+//!             state.documents.insert(to, document);
+//!
+//!             state.selected = Some(to);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::scrollable::AbsoluteOffset;
+use crate::core::widget::operation::semantics::{self, Semantics};
+use crate::core::widget::operation::{Focusable, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Color, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Theme, Vector, Widget,
+};
+use crate::scrollable::{self, Scrollable};
+
+const DEFAULT_PADDING: Padding = Padding {
+    top: 6.0,
+    bottom: 6.0,
+    right: 12.0,
+    left: 12.0,
+};
+
+/// The space between the label of a [`Tab`] and its close button.
+const CLOSE_SPACING: f32 = 6.0;
+
+/// The thickness of the indicator of a [`Tab`].
+const INDICATOR_WIDTH: f32 = 2.0;
+
+/// The thickness of the scrollbar of an overflowing tab bar.
+const SCROLLBAR_WIDTH: f32 = 3.0;
+
+/// The distance the cursor must travel before a [`Tab`] is dragged.
+const DRAG_DEADBAND_DISTANCE: f32 = 5.0;
+
+/// A bar of [`Tab`]s paired with the content of the selected one.
+///
+/// The tab bar can also be displayed on its own, like in the
+/// [`TitleBar`] of a [`PaneGrid`]. In that case, consider a
+/// [`Length::Shrink`] width, so the rest of the title bar can
+/// still be used to drag its pane.
+///
+/// [`TitleBar`]: crate::pane_grid::TitleBar
+/// [`PaneGrid`]: crate::PaneGrid
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{tabs, text};
+/// use iced::widget::tabs::Tab;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tabs([Tab::new("Overview"), Tab::new("Details")], Some(0), text("Overview"))
+///         .on_select(Message::Select)
+///         .into()
+/// }
+/// ```
+pub struct Tabs<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    tabs: Vec<Tab<'a, Message, Theme, Renderer>>,
+    selected: Option<usize>,
+    content: Option<Element<'a, Message, Theme, Renderer>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new tab bar with the given [`Tab`]s and the index of the
+    /// selected one, if any.
+    pub fn new(
+        tabs: impl IntoIterator<Item = Tab<'a, Message, Theme, Renderer>>,
+        selected: Option<usize>,
+    ) -> Self {
+        Self {
+            id: None,
+            tabs: tabs.into_iter().collect(),
+            selected,
+            content: None,
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: DEFAULT_PADDING,
+            spacing: 0.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the content displayed under the tab bar of the [`Tabs`].
+    pub fn content(mut self, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        let content = content.into();
+
+        self.height = self.height.enclose(content.as_widget().size_hint().height);
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the [`widget::Id`] of the tab bar of the [`Tabs`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Tab`] is selected.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Tab`] is closed.
+    ///
+    /// Closable tabs will display a close button.
+    pub fn on_close(mut self, on_close: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Tab`] is dragged
+    /// to a different position.
+    ///
+    /// The closure receives the current index of the [`Tab`] and its new index.
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each [`Tab`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the [`Tab`]s.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tabs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// A tab of some [`Tabs`].
+///
+/// Its label can be any widget. A [`rich_text`] with multiple spans can
+/// be used to display an icon next to some text.
+///
+/// [`rich_text`]: crate::rich_text()
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::*; }
+/// # pub type Tab<'a> = iced_widget::tabs::Tab<'a, ()>;
+/// use iced::widget::{rich_text, span};
+/// use iced::{Font, never};
+///
+/// const ICONS: Font = Font::new("Icons");
+///
+/// fn document(name: &str) -> Tab<'_> {
+///     Tab::new(rich_text![span("\u{e800} ").font(ICONS), span(name)].on_link_click(never))
+/// }
+/// ```
+pub struct Tab<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    label: Element<'a, Message, Theme, Renderer>,
+    is_closable: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Tab<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Tab`] with the given label.
+    pub fn new(label: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            label: label.into(),
+            is_closable: true,
+        }
+    }
+
+    /// Sets whether the [`Tab`] can be closed.
+    ///
+    /// Tabs can be closed by default, as long as [`Tabs::on_close`] is set.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+/// The tab bar of some [`Tabs`], displayed inside a [`Scrollable`].
+struct Bar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    tabs: Vec<Tab<'a, Message, Theme, Renderer>>,
+    selected: Option<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    padding: Padding,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_focused: bool,
+    hovered: Option<Hover>,
+    closing: Option<usize>,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hover {
+    index: usize,
+    is_over_close: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: Point,
+    position: Point,
+    is_active: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<Message, Theme, Renderer> Bar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn is_closable(&self, index: usize) -> bool {
+        self.on_close.is_some() && self.tabs.get(index).is_some_and(|tab| tab.is_closable)
+    }
+
+    fn hover(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<Hover> {
+        let (index, tab) = layout
+            .children()
+            .enumerate()
+            .find(|(_, tab)| cursor.is_over(tab.bounds()))?;
+
+        let is_over_close = self.is_closable(index)
+            && tab
+                .children()
+                .nth(1)
+                .is_some_and(|close| cursor.is_over(close.bounds()));
+
+        Some(Hover {
+            index,
+            is_over_close,
+        })
+    }
+
+    /// Returns the horizontal offset of the dragged [`Tab`] and the index
+    /// where it would be dropped.
+    fn drop_target(&self, layout: Layout<'_>, drag: &Drag) -> (f32, usize) {
+        let bounds = layout.bounds();
+        let dragged = layout.child(drag.index).bounds();
+
+        let offset = (drag.position.x - drag.origin.x).clamp(
+            bounds.x - dragged.x,
+            bounds.x + bounds.width - dragged.x - dragged.width,
+        );
+
+        let center = dragged.center_x() + offset;
+
+        let target = layout
+            .children()
+            .enumerate()
+            .filter(|(index, tab)| *index != drag.index && tab.bounds().center_x() < center)
+            .count();
+
+        (offset, target)
+    }
+
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if self.selected != Some(index)
+            && let Some(on_select) = &self.on_select
+        {
+            shell.publish(on_select(index));
+        }
+    }
+
+    fn draw_tab(
+        &self,
+        (index, tab): (usize, &Tab<'_, Message, Theme, Renderer>),
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let hovered = state.hovered.filter(|hover| hover.index == index);
+        let is_dragged = state
+            .drag
+            .is_some_and(|drag| drag.is_active && drag.index == index);
+
+        let status = if self.selected == Some(index) {
+            Status::Selected {
+                is_focused: state.is_focused,
+            }
+        } else if hovered.is_some() || is_dragged {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        if let Some(indicator) = style.indicator {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - INDICATOR_WIDTH,
+                        height: INDICATOR_WIDTH,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                indicator,
+            );
+        }
+
+        let mut children = layout.children();
+        let label = children.next().expect("Tab must have a label");
+
+        tab.label.as_widget().draw(
+            &tree.children[index],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style.text_color,
+            },
+            label,
+            cursor,
+            viewport,
+        );
+
+        if let Some(close) = children.next()
+            && self.is_closable(index)
+            && (status != Status::Active || is_dragged)
+        {
+            let close = close.bounds();
+
+            if hovered.is_some_and(|hover| hover.is_over_close) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: close,
+                        border: border::rounded(close.width / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.text_color.scale_alpha(0.15),
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: String::from("\u{00d7}"),
+                    bounds: close.size(),
+                    size: renderer.default_size(),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    align_x: text::Alignment::Center,
+                    align_y: crate::core::alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                    ellipsis: text::Ellipsis::default(),
+                    hint_factor: renderer.scale_factor(),
+                },
+                close.center(),
+                style.text_color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Bar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.tabs
+            .iter()
+            .map(|tab| Tree::new(tab.label.as_widget()))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &self.tabs,
+            |tree, tab| tree.diff(tab.label.as_widget()),
+            |tab| Tree::new(tab.label.as_widget()),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let label_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, limits.max().height - self.padding.y()),
+        );
+
+        let labels: Vec<_> = self
+            .tabs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(tab, tree)| {
+                tab.label
+                    .as_widget_mut()
+                    .layout(tree, renderer, &label_limits)
+            })
+            .collect();
+
+        let height = labels
+            .iter()
+            .map(|label| label.size().height)
+            .fold(0.0, f32::max);
+
+        let mut x = 0.0;
+
+        let tabs: Vec<_> = labels
+            .into_iter()
+            .enumerate()
+            .map(|(index, label)| {
+                let size = label.size();
+                let label = label.move_to(Point::new(
+                    self.padding.left,
+                    self.padding.top + (height - size.height) / 2.0,
+                ));
+
+                let mut children = vec![label];
+                let mut width = size.width;
+
+                if self.is_closable(index) {
+                    children.push(layout::Node::new(Size::new(height, height)).move_to(
+                        Point::new(self.padding.left + width + CLOSE_SPACING, self.padding.top),
+                    ));
+
+                    width += CLOSE_SPACING + height;
+                }
+
+                let tab = layout::Node::with_children(
+                    Size::new(width + self.padding.x(), height + self.padding.y()),
+                    children,
+                )
+                .move_to(Point::new(x, 0.0));
+
+                x += tab.size().width + self.spacing;
+
+                tab
+            })
+            .collect();
+
+        let width = if tabs.is_empty() {
+            0.0
+        } else {
+            x - self.spacing
+        };
+
+        layout::Node::with_children(Size::new(width, height + self.padding.y()), tabs)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.semantics(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Semantics::new(semantics::Role::TabList).focused(state.is_focused),
+        );
+
+        operation.traverse(&mut |operation| {
+            for (index, ((tab, tree), layout)) in self
+                .tabs
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                operation.semantics(
+                    None,
+                    layout.bounds(),
+                    &Semantics::new(semantics::Role::Tab).selected(self.selected == Some(index)),
+                );
+
+                operation.traverse(&mut |operation| {
+                    tab.label.as_widget_mut().operate(
+                        tree,
+                        layout.children().next().expect("Tab must have a label"),
+                        renderer,
+                        operation,
+                    );
+                });
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((tab, tree), layout) in self
+            .tabs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            tab.label.as_widget_mut().update(
+                tree,
+                event,
+                layout.children().next().expect("Tab must have a label"),
+                cursor,
+                renderer,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let hovered = self.hover(layout, cursor);
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(hover) = hovered else {
+                    if state.is_focused {
+                        state.is_focused = false;
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                state.is_focused = true;
+
+                if hover.is_over_close {
+                    state.closing = Some(hover.index);
+                } else {
+                    self.select(hover.index, shell);
+
+                    if self.on_reorder.is_some()
+                        && let Some(position) = cursor.position()
+                    {
+                        state.drag = Some(Drag {
+                            index: hover.index,
+                            origin: position,
+                            position,
+                            is_active: false,
+                        });
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                if let Some(hover) = hovered
+                    && self.is_closable(hover.index)
+                    && let Some(on_close) = &self.on_close
+                {
+                    shell.publish(on_close(hover.index));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft)
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut state.drag
+                    && let Some(position) = cursor.land().position()
+                {
+                    drag.position = position;
+
+                    if !drag.is_active && position.distance(drag.origin) > DRAG_DEADBAND_DISTANCE {
+                        drag.is_active = true;
+                    }
+
+                    if drag.is_active {
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+                }
+
+                if state.hovered != hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some(index) = state.closing.take() {
+                    if hovered.is_some_and(|hover| hover.index == index && hover.is_over_close)
+                        && let Some(on_close) = &self.on_close
+                    {
+                        shell.publish(on_close(index));
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+
+                if let Some(drag) = state.drag.take()
+                    && drag.is_active
+                {
+                    let (_, target) = self.drop_target(layout, &drag);
+
+                    if target != drag.index
+                        && let Some(on_reorder) = &self.on_reorder
+                    {
+                        shell.publish(on_reorder(drag.index, target));
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if state.is_focused =>
+            {
+                if state.drag.is_some() {
+                    if let keyboard::Key::Named(key::Named::Escape) = key {
+                        state.drag = None;
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                    }
+
+                    return;
+                }
+
+                let count = self.tabs.len();
+
+                if count == 0 {
+                    return;
+                }
+
+                let current = self.selected.filter(|&index| index < count);
+
+                let target = match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        current.map_or(count - 1, |index| (index + count - 1) % count)
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        current.map_or(0, |index| (index + 1) % count)
+                    }
+                    keyboard::Key::Named(key::Named::Home) => 0,
+                    keyboard::Key::Named(key::Named::End) => count - 1,
+                    keyboard::Key::Named(key::Named::Delete) => {
+                        if let Some(index) = current
+                            && self.is_closable(index)
+                            && let Some(on_close) = &self.on_close
+                        {
+                            shell.publish(on_close(index));
+                            shell.capture_event();
+                        }
+
+                        return;
+                    }
+                    _ => return,
+                };
+
+                // Holding the command key moves the selected tab instead
+                if modifiers.command() {
+                    if let Some(index) = current
+                        && index.abs_diff(target) == 1
+                        && let Some(on_reorder) = &self.on_reorder
+                    {
+                        shell.publish(on_reorder(index, target));
+                    }
+                } else {
+                    self.select(target, shell);
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_active) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self
+            .tabs
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((tab, tree), layout)| {
+                tab.label.as_widget().mouse_interaction(
+                    tree,
+                    layout.children().next().expect("Tab must have a label"),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default() && self.hover(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if let Some(background) = theme.style(&self.class, Status::Active).bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: viewport.x,
+                        width: viewport.width,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let drag = state
+            .drag
+            .filter(|drag| drag.is_active && drag.index < self.tabs.len())
+            .map(|drag| {
+                let (offset, target) = self.drop_target(layout, &drag);
+                let width = layout.child(drag.index).bounds().width + self.spacing;
+
+                (drag.index, offset, target, width)
+            });
+
+        for (index, (tab, layout)) in self.tabs.iter().zip(layout.children()).enumerate() {
+            // Make room for the dragged tab at its drop target
+            let shift = match drag {
+                Some((dragged, ..)) if dragged == index => continue,
+                Some((dragged, _, target, width)) if dragged < index && index <= target => -width,
+                Some((dragged, _, target, width)) if target <= index && index < dragged => width,
+                _ => 0.0,
+            };
+
+            renderer.with_translation(Vector::new(shift, 0.0), |renderer| {
+                self.draw_tab(
+                    (index, tab),
+                    tree,
+                    renderer,
+                    theme,
+                    layout,
+                    cursor,
+                    viewport,
+                );
+            });
+        }
+
+        if let Some((index, offset, ..)) = drag {
+            let layout = layout.child(index);
+
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.with_translation(Vector::new(offset, 0.0), |renderer| {
+                    self.draw_tab(
+                        (index, &self.tabs[index]),
+                        tree,
+                        renderer,
+                        theme,
+                        layout,
+                        cursor,
+                        viewport,
+                    );
+                });
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .tabs
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((tab, tree), layout)| {
+                tab.label.as_widget_mut().overlay(
+                    tree,
+                    layout.children().next().expect("Tab must have a label"),
+                    renderer,
+                    viewport,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+/// The tab bar of some [`Tabs`] stacked on top of their content.
+///
+/// It scrolls the selected [`Tab`] into view whenever the selection changes.
+struct Inner<'a, Message, Theme, Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    selected: Option<usize>,
+    width: Length,
+    height: Length,
+}
+
+#[derive(Debug, Default)]
+struct Memory {
+    revealed: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Inner<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn reveal(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, index: usize) {
+        struct Reveal {
+            target: Rectangle,
+        }
+
+        impl Operation for Reveal {
+            fn traverse(&mut self, _operate: &mut dyn FnMut(&mut dyn Operation)) {}
+
+            fn scrollable(
+                &mut self,
+                _id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+                translation: Vector,
+                state: &mut dyn widget::operation::Scrollable,
+            ) {
+                let left = bounds.x + translation.x;
+                let right = left + bounds.width;
+
+                let x = if self.target.x < left {
+                    self.target.x - left
+                } else if self.target.x + self.target.width > right {
+                    (self.target.x + self.target.width - right).min(self.target.x - left)
+                } else {
+                    return;
+                };
+
+                state.scroll_by(AbsoluteOffset { x, y: 0.0 }, bounds, content_bounds);
+            }
+        }
+
+        let bar = layout.child(0);
+
+        let Some(tab) = bar
+            .children()
+            .next()
+            .and_then(|tabs| tabs.children().nth(index))
+        else {
+            return;
+        };
+
+        self.children[0].as_widget_mut().operate(
+            &mut tree.children[0],
+            bar,
+            renderer,
+            &mut Reveal {
+                target: tab.bounds(),
+            },
+        );
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Inner<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Memory>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Memory::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            Padding::ZERO,
+            0.0,
+            crate::core::Alignment::Start,
+            &mut self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            let mut children = self
+                .children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children());
+
+            if let Some(((bar, tree), layout)) = children.next() {
+                bar.as_widget_mut()
+                    .operate(tree, layout, renderer, operation);
+            }
+
+            if let Some(((content, tree), layout)) = children.next() {
+                operation.semantics(
+                    None,
+                    layout.bounds(),
+                    &Semantics::new(semantics::Role::TabPanel),
+                );
+
+                operation.traverse(&mut |operation| {
+                    content
+                        .as_widget_mut()
+                        .operate(tree, layout, renderer, operation);
+                });
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, shell, viewport);
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let memory = tree.state.downcast_mut::<Memory>();
+
+            if memory.revealed != self.selected {
+                memory.revealed = self.selected;
+
+                if let Some(index) = self.selected {
+                    self.reveal(tree, layout, renderer, index);
+                    shell.request_redraw();
+                }
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tabs<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + scrollable::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Message, Theme, Renderer>) -> Self {
+        let bar = Scrollable::with_direction(
+            Element::new(Bar {
+                id: tabs.id,
+                tabs: tabs.tabs,
+                selected: tabs.selected,
+                on_select: tabs.on_select,
+                on_close: tabs.on_close,
+                on_reorder: tabs.on_reorder,
+                padding: tabs.padding,
+                spacing: tabs.spacing,
+                class: tabs.class,
+            }),
+            scrollable::Direction::Horizontal(
+                scrollable::Scrollbar::new()
+                    .width(SCROLLBAR_WIDTH)
+                    .scroller_width(SCROLLBAR_WIDTH),
+            ),
+        )
+        .width(tabs.width);
+
+        Element::new(Inner {
+            children: std::iter::once(bar.into()).chain(tabs.content).collect(),
+            selected: tabs.selected,
+            width: tabs.width,
+            height: tabs.height,
+        })
+    }
+}
+
+/// The possible status of a [`Tab`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Tab`] is not selected.
+    Active,
+    /// The [`Tab`] is not selected and it is being hovered.
+    Hovered,
+    /// The [`Tab`] is selected.
+    Selected {
+        /// Whether the tab bar is focused.
+        is_focused: bool,
+    },
+}
+
+/// The appearance of a [`Tab`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the tab bar.
+    ///
+    /// Only the [`Style`] of [`Status::Active`] is used to draw it.
+    pub bar_background: Option<Background>,
+    /// The [`Background`] of the [`Tab`].
+    pub background: Option<Background>,
+    /// The text [`Color`] of the [`Tab`].
+    pub text_color: Color,
+    /// The [`Border`] of the [`Tab`].
+    pub border: Border,
+    /// The [`Color`] of the line under the [`Tab`], if any.
+    pub indicator: Option<Color>,
+}
+
+/// The theme catalog of some [`Tabs`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for some [`Tabs`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of some [`Tabs`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.palette();
+
+    let base = Style {
+        bar_background: Some(palette.background.weak.color.into()),
+        background: None,
+        text_color: palette.background.weak.text.scale_alpha(0.7),
+        border: border::rounded(border::top(4)),
+        indicator: None,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            background: Some(palette.background.strong.color.into()),
+            text_color: palette.background.strong.text,
+            ..base
+        },
+        Status::Selected { is_focused } => Style {
+            background: Some(palette.background.base.color.into()),
+            text_color: palette.background.base.text,
+            indicator: Some(if is_focused {
+                palette.primary.strong.color
+            } else {
+                palette.primary.base.color
+            }),
+            ..base
+        },
+    }
+}
@@ -2,7 +2,7 @@ use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::text::{Paragraph, Span};
+use crate::core::text::{Paragraph, Selection, Span};
 use crate::core::widget::text::{
     self, Alignment, Catalog, Ellipsis, LineHeight, Shaping, Style, StyleFn, Wrapping,
 };
@@ -29,6 +29,7 @@ where
     align_y: alignment::Vertical,
     wrapping: Wrapping,
     ellipsis: Ellipsis,
+    selectable: bool,
     class: Theme::Class<'a>,
     hovered_link: Option<usize>,
     on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
@@ -54,6 +55,7 @@ where
             align_y: alignment::Vertical::Top,
             wrapping: Wrapping::default(),
             ellipsis: Ellipsis::default(),
+            selectable: false,
             class: Theme::default(),
             hovered_link: None,
             on_link_click: None,
@@ -128,6 +130,15 @@ where
         self
     }
 
+    /// Sets whether the contents of the [`Rich`] text can be selected and copied.
+    ///
+    /// The selection spans across all of its spans and may continue into any
+    /// other selectable text in the same user interface.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the message that will be produced when a link of the [`Rich`] text
     /// is clicked.
     ///
@@ -164,7 +175,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            selection: None,
+        })
     }
 
     /// Sets the default style class of the [`Rich`] text.
@@ -192,6 +206,8 @@ struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
     span_pressed: Option<usize>,
     paragraph: P,
+    content: String,
+    selection: Selection,
 }
 
 impl<Link, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -210,6 +226,8 @@ where
             spans: Vec::new(),
             span_pressed: None,
             paragraph: Renderer::Paragraph::default(),
+            content: String::new(),
+            selection: Selection::default(),
         })
     }
 
@@ -264,6 +282,15 @@ where
 
         let style = theme.style(&self.class);
 
+        if self.selectable {
+            state.selection.draw(
+                renderer,
+                &state.paragraph,
+                layout.bounds(),
+                style.selection_color(defaults.text_color),
+            );
+        }
+
         for (index, span) in self.spans.as_ref().as_ref().iter().enumerate() {
             let is_hovered_link = self.on_link_click.is_some() && Some(index) == self.hovered_link;
 
@@ -357,6 +384,21 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        if self.selectable {
+            let state = tree
+                .state
+                .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+            state.selection.update(
+                event,
+                layout.bounds(),
+                cursor,
+                &state.paragraph,
+                &state.content,
+                shell,
+            );
+        }
+
         let Some(on_link_clicked) = &self.on_link_click else {
             return;
         };
@@ -423,13 +465,15 @@ where
     fn mouse_interaction(
         &self,
         _tree: &Tree,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         if self.hovered_link.is_some() {
             mouse::Interaction::Pointer
+        } else if self.selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
         } else {
             mouse::Interaction::None
         }
@@ -478,6 +522,7 @@ where
         if state.spans != spans {
             state.paragraph = Renderer::Paragraph::with_spans(text_with_spans());
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
+            state.content = spans.iter().map(|span| span.text.as_ref()).collect();
         } else {
             match state.paragraph.compare(core::Text {
                 content: (),
@@ -31,6 +31,11 @@
 //!     }
 //! }
 //! ```
+mod attributed;
+mod gutter;
+mod history;
+mod search;
+
 use crate::core::alignment;
 use crate::core::clipboard;
 use crate::core::input_method;
@@ -59,6 +64,13 @@ use std::ops;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
+use attributed::Attributed;
+use gutter::Gutter;
+use history::History;
+
+pub use attributed::{Attribute, Attributes};
+pub use gutter::Decoration;
+pub use search::Search;
 pub use text::editor::{Action, Cursor, Edit, Line, LineEnding, Motion, Position, Selection};
 
 /// A multi-line text input.
@@ -112,6 +124,10 @@ where
     max_height: f32,
     padding: Padding,
     wrapping: Wrapping,
+    matches: &'a [ops::Range<Position>],
+    line_numbers: bool,
+    decorations: Option<&'a [Decoration<Renderer::Font>]>,
+    foldable: Option<&'a [ops::Range<usize>]>,
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
@@ -140,6 +156,10 @@ where
             max_height: f32::INFINITY,
             padding: Padding::new(5.0),
             wrapping: Wrapping::default(),
+            matches: &[],
+            line_numbers: false,
+            decorations: None,
+            foldable: None,
             class: <Theme as Catalog>::default(),
             key_binding: None,
             on_edit: None,
@@ -234,6 +254,38 @@ where
         self
     }
 
+    /// Highlights the given ranges of text in the [`TextEditor`]; like the
+    /// matches of a [`Search`] found with [`Content::find`].
+    pub fn highlight_matches(mut self, matches: &'a [ops::Range<Position>]) -> Self {
+        self.matches = matches;
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] shows the number of every line in
+    /// its gutter.
+    ///
+    /// Clicking a line number selects the whole line.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Shows the given [`Decoration`]s in the gutter of the [`TextEditor`].
+    pub fn decorations(mut self, decorations: &'a [Decoration<Renderer::Font>]) -> Self {
+        self.decorations = Some(decorations);
+        self
+    }
+
+    /// Sets the regions of lines that can be folded from the gutter of
+    /// the [`TextEditor`].
+    ///
+    /// Clicking the marker next to the first line of a region produces an
+    /// [`Action::Fold`] or an [`Action::Unfold`].
+    pub fn foldable(mut self, regions: &'a [ops::Range<usize>]) -> Self {
+        self.foldable = Some(regions);
+        self
+    }
+
     /// Highlights the [`TextEditor`] using the given syntax and theme.
     #[cfg(feature = "highlighter")]
     pub fn highlight(
@@ -245,10 +297,7 @@ where
         Renderer: text::Renderer<Font = crate::core::Font>,
     {
         self.highlight_with::<iced_highlighter::Highlighter>(
-            iced_highlighter::Settings {
-                theme,
-                token: syntax.to_owned(),
-            },
+            iced_highlighter::Settings::new(syntax, theme),
             |highlight, _theme| highlight.to_format(),
         )
     }
@@ -273,6 +322,10 @@ where
             max_height: self.max_height,
             padding: self.padding,
             wrapping: self.wrapping,
+            matches: self.matches,
+            line_numbers: self.line_numbers,
+            decorations: self.decorations,
+            foldable: self.foldable,
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
@@ -311,6 +364,125 @@ where
         self
     }
 
+    fn text_padding(&self, state: &State<Highlighter>) -> Padding {
+        Padding {
+            left: self.padding.left + state.gutter.width(),
+            ..self.padding
+        }
+    }
+
+    fn draw_gutter(
+        &self,
+        gutter: &Gutter,
+        editor: &Renderer::Editor,
+        renderer: &mut Renderer,
+        style: &Style,
+        text_bounds: Rectangle,
+    ) {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let hint_factor = renderer.scale_factor();
+        let row = f32::from(self.line_height.to_absolute(size));
+
+        let x = text_bounds.x - gutter.width();
+        let clip_bounds = Rectangle {
+            x,
+            width: gutter.width(),
+            ..text_bounds
+        };
+
+        let text = |content: String, font, width, align_x, align_y| Text {
+            content,
+            bounds: Size::new(width, row),
+            size,
+            line_height: self.line_height,
+            font,
+            align_x,
+            align_y,
+            shaping: text::Shaping::Advanced,
+            wrapping: Wrapping::None,
+            ellipsis: text::Ellipsis::None,
+            hint_factor,
+        };
+
+        let current_line = editor.cursor().position.line;
+        let folds = editor.folds();
+
+        for line in editor.visible_lines() {
+            let y = text_bounds.y + line.y;
+
+            if let Some(decoration) = self
+                .decorations
+                .unwrap_or_default()
+                .iter()
+                .find(|decoration| decoration.line == line.index)
+            {
+                renderer.fill_text(
+                    text(
+                        decoration.icon.to_string(),
+                        decoration.font.unwrap_or(font),
+                        gutter.decorations,
+                        text::Alignment::Center,
+                        alignment::Vertical::Center,
+                    ),
+                    Point::new(x + gutter.decorations / 2.0, y + row / 2.0),
+                    decoration.color.unwrap_or(style.line_number),
+                    clip_bounds,
+                );
+            }
+
+            if self.line_numbers {
+                renderer.fill_text(
+                    text(
+                        (line.index + 1).to_string(),
+                        font,
+                        gutter.line_numbers,
+                        text::Alignment::Right,
+                        alignment::Vertical::Top,
+                    ),
+                    Point::new(x + gutter.decorations + gutter.line_numbers, y),
+                    if line.index == current_line {
+                        style.current_line_number
+                    } else {
+                        style.line_number
+                    },
+                    clip_bounds,
+                );
+            }
+
+            let marker = if folds.iter().any(|fold| fold.start == line.index) {
+                Some('▸')
+            } else if self
+                .foldable
+                .unwrap_or_default()
+                .iter()
+                .any(|region| region.start == line.index)
+            {
+                Some('▾')
+            } else {
+                None
+            };
+
+            if let Some(marker) = marker {
+                renderer.fill_text(
+                    text(
+                        marker.to_string(),
+                        font,
+                        gutter.folding,
+                        text::Alignment::Center,
+                        alignment::Vertical::Center,
+                    ),
+                    Point::new(
+                        x + gutter.decorations + gutter.line_numbers + gutter.folding / 2.0,
+                        y + row / 2.0,
+                    ),
+                    style.line_number,
+                    clip_bounds,
+                );
+            }
+        }
+    }
+
     fn input_method<'b>(
         &self,
         state: &'b State<Highlighter>,
@@ -328,7 +500,7 @@ where
         let bounds = layout.bounds();
         let internal = self.content.0.borrow_mut();
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state));
         let translation = text_bounds.position() - Point::ORIGIN;
 
         let cursor = match internal.editor.selection() {
@@ -360,6 +532,8 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History,
+    attributed: Option<Attributed<R::Font>>,
 }
 
 impl<R> Content<R>
@@ -375,28 +549,96 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::default(),
+            attributed: None,
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Any [`Edit`] is recorded in the history of the [`Content`], so it
+    /// can be reverted with [`Action::Undo`].
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
 
-        internal.editor.perform(action);
+        match action {
+            Action::Edit(edit) => {
+                internal.history.edit(&mut internal.editor, edit);
+                internal.sync_attributes();
+            }
+            Action::Undo => {
+                internal.history.undo(&mut internal.editor);
+                internal.sync_attributes();
+            }
+            Action::Redo => {
+                internal.history.redo(&mut internal.editor);
+                internal.sync_attributes();
+            }
+            Action::Scroll { .. } | Action::Fold(_) | Action::Unfold(_) => {
+                internal.editor.perform(action);
+            }
+            _ => {
+                internal.history.seal();
+                internal.editor.perform(action);
+
+                if let Some(attributed) = &mut internal.attributed {
+                    attributed.reset_typing();
+                }
+            }
+        }
+    }
+
+    /// Reverts the last group of edits of the [`Content`], if any.
+    pub fn undo(&mut self) {
+        self.perform(Action::Undo);
+    }
+
+    /// Reapplies the last group of edits reverted by [`undo`](Self::undo), if any.
+    pub fn redo(&mut self) {
+        self.perform(Action::Redo);
+    }
+
+    /// Returns whether the [`Content`] has any edits that can be undone.
+    pub fn can_undo(&self) -> bool {
+        self.0.borrow().history.can_undo()
+    }
+
+    /// Returns whether the [`Content`] has any undone edits that can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.0.borrow().history.can_redo()
     }
 
     /// Moves the current cursor to reflect the given one.
     pub fn move_to(&mut self, cursor: Cursor) {
         let internal = self.0.get_mut();
 
+        internal.history.seal();
         internal.editor.move_to(cursor);
+
+        if let Some(attributed) = &mut internal.attributed {
+            attributed.reset_typing();
+        }
     }
 
     /// Returns the current cursor position of the [`Content`].
+    ///
+    /// This is the primary cursor when the [`Content`] has multiple cursors.
     pub fn cursor(&self) -> Cursor {
         self.0.borrow().editor.cursor()
     }
 
+    /// Returns all the cursors of the [`Content`], starting with the primary one.
+    pub fn cursors(&self) -> Vec<Cursor> {
+        self.0.borrow().editor.cursors()
+    }
+
+    /// Returns the folded regions of lines of the [`Content`].
+    ///
+    /// See [`Action::Fold`].
+    pub fn folds(&self) -> Vec<ops::Range<usize>> {
+        self.0.borrow().editor.folds()
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -442,10 +684,106 @@ where
     }
 
     /// Returns the selected text of the [`Content`].
+    ///
+    /// The selections of multiple cursors are joined by new lines.
     pub fn selection(&self) -> Option<String> {
         self.0.borrow().editor.copy()
     }
 
+    /// Returns the ranges of text in the [`Content`] matching the given [`Search`].
+    pub fn find(&self, search: &Search) -> Vec<ops::Range<Position>> {
+        let internal = self.0.borrow();
+
+        let mut text = String::new();
+        let mut starts = Vec::new();
+
+        for index in 0..internal.editor.line_count() {
+            let Some(line) = internal.editor.line(index) else {
+                break;
+            };
+
+            if index > 0 {
+                text.push('\n');
+            }
+
+            starts.push(text.len());
+            text.push_str(&line.text);
+        }
+
+        let position = |offset: usize| {
+            let line = starts.partition_point(|start| *start <= offset) - 1;
+
+            Position {
+                line,
+                column: offset - starts[line],
+            }
+        };
+
+        search
+            .matches(&text)
+            .into_iter()
+            .map(|range| position(range.start)..position(range.end))
+            .collect()
+    }
+
+    /// Selects the first match of the given [`Search`] after the current cursor,
+    /// wrapping around the [`Content`] if needed.
+    pub fn select_next_match(&mut self, search: &Search) {
+        let matches = self.find(search);
+        let cursor = self.cursor();
+
+        let after = cursor.selection.map_or(cursor.position, |selection| {
+            ordered(selection, cursor.position).end
+        });
+
+        let next = matches
+            .iter()
+            .find(|range| (range.start.line, range.start.column) >= (after.line, after.column))
+            .or(matches.first());
+
+        if let Some(next) = next {
+            self.move_to(Cursor {
+                position: next.end,
+                selection: Some(next.start),
+            });
+        }
+    }
+
+    /// Replaces the selected match of the given [`Search`] with some text and
+    /// selects the next match.
+    ///
+    /// If no match is selected, the next match is only selected.
+    pub fn replace(&mut self, search: &Search, text: impl Into<String>) {
+        let cursor = self.cursor();
+
+        if let Some(selection) = cursor.selection {
+            let range = ordered(selection, cursor.position);
+
+            if self.find(search).contains(&range) {
+                self.perform(Action::Edit(Edit::Replace {
+                    ranges: vec![range],
+                    text: Arc::new(text.into()),
+                }));
+            }
+        }
+
+        self.select_next_match(search);
+    }
+
+    /// Replaces every match of the given [`Search`] with some text.
+    pub fn replace_all(&mut self, search: &Search, text: impl Into<String>) {
+        let ranges = self.find(search);
+
+        if ranges.is_empty() {
+            return;
+        }
+
+        self.perform(Action::Edit(Edit::Replace {
+            ranges,
+            text: Arc::new(text.into()),
+        }));
+    }
+
     /// Returns the kind of [`LineEnding`] used for separating lines in the [`Content`].
     pub fn line_ending(&self) -> Option<LineEnding> {
         Some(self.line(0)?.ending)
@@ -455,6 +793,152 @@ where
     pub fn is_empty(&self) -> bool {
         self.0.borrow().editor.is_empty()
     }
+
+    /// Returns whether the [`Content`] keeps [`Attributes`] for its text.
+    ///
+    /// See [`Content::with_attributed_text`].
+    pub fn is_attributed(&self) -> bool {
+        self.0.borrow().attributed.is_some()
+    }
+
+    /// Toggles an [`Attribute`] on the selected text of the [`Content`].
+    ///
+    /// The [`Attribute`] is removed if all of the selected text has it
+    /// already, and added otherwise. If nothing is selected, the [`Attribute`]
+    /// is toggled for the text typed next at the cursor instead.
+    ///
+    /// Nothing happens if the [`Content`] is not attributed.
+    pub fn toggle(&mut self, attribute: Attribute) {
+        let cursors = self.cursors();
+        let internal = self.0.get_mut();
+
+        let Some(attributed) = &mut internal.attributed else {
+            return;
+        };
+
+        let selections: Vec<_> = cursors
+            .iter()
+            .filter_map(|cursor| Some(ordered(cursor.selection?, cursor.position)))
+            .collect();
+
+        attributed.toggle(&attribute, &selections, cursors[0].position);
+    }
+
+    /// Returns the [`Attributes`] of the text at the cursor of the [`Content`];
+    /// that is, the ones text typed next would have.
+    ///
+    /// If some text is selected, these are the [`Attributes`] of its start.
+    pub fn attributes(&self) -> Attributes {
+        let cursor = self.cursor();
+        let internal = self.0.borrow();
+
+        internal
+            .attributed
+            .as_ref()
+            .map(|attributed| match cursor.selection {
+                Some(selection) => {
+                    let start = ordered(selection, cursor.position).start;
+
+                    attributed.attributes(Position {
+                        column: start.column + 1,
+                        ..start
+                    })
+                }
+                None => attributed.attributes(cursor.position),
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the text of the [`Content`] split in runs with the same
+    /// [`Attributes`].
+    pub fn attributed_text(&self) -> Vec<(String, Attributes)> {
+        match &self.0.borrow().attributed {
+            Some(attributed) => attributed.runs(),
+            None => vec![(self.text(), Attributes::default())],
+        }
+    }
+
+    /// Writes the [`Content`] as Markdown.
+    ///
+    /// Colors cannot be represented in Markdown and are dropped.
+    pub fn to_markdown(&self) -> String {
+        attributed::to_markdown(&self.attributed_text())
+    }
+
+    /// Writes the [`Content`] as HTML, with a paragraph per line.
+    pub fn to_html(&self) -> String {
+        attributed::to_html(&self.attributed_text())
+    }
+
+    /// Returns the [`Content`] as HTML ready to be written to the clipboard.
+    pub fn to_clipboard(&self) -> clipboard::Content {
+        clipboard::Content::Html(self.to_html())
+    }
+}
+
+impl<R> Content<R>
+where
+    R: text::Renderer<Font = crate::core::Font>,
+{
+    /// Creates an attributed [`Content`] with the given runs of text.
+    ///
+    /// An attributed [`Content`] keeps the [`Attributes`] of its text as it
+    /// is edited. Inserted text takes the [`Attributes`] toggled for typing or,
+    /// otherwise, the ones of the text before it.
+    pub fn with_attributed_text(
+        runs: impl IntoIterator<Item = (impl AsRef<str>, Attributes)>,
+    ) -> Self {
+        let attributed = Attributed::new(runs, attributed::to_font);
+        let mut internal = Internal {
+            editor: R::Editor::with_text(&attributed.text()),
+            history: History::default(),
+            attributed: Some(attributed),
+        };
+
+        internal.sync_attributes();
+
+        Self(RefCell::new(internal))
+    }
+
+    /// Creates an attributed [`Content`] from some HTML.
+    ///
+    /// Only bold, italic, colored and linked text keep their style. Block
+    /// elements and line breaks start new lines.
+    pub fn with_html(html: &str) -> Self {
+        Self::with_attributed_text(attributed::from_html(html))
+    }
+
+    /// Creates an attributed [`Content`] from some parsed Markdown.
+    ///
+    /// Blocks are separated by empty lines.
+    #[cfg(feature = "markdown")]
+    pub fn with_markdown(markdown: &crate::markdown::Content) -> Self {
+        Self::with_attributed_text(attributed::from_markdown(markdown.items()))
+    }
+
+    /// Creates an attributed [`Content`] from some [`clipboard::Content`].
+    ///
+    /// Returns `None` if the [`clipboard::Content`] has no text.
+    pub fn from_clipboard(content: &clipboard::Content) -> Option<Self> {
+        match content {
+            clipboard::Content::Html(html) => Some(Self::with_html(html)),
+            clipboard::Content::Text(text) => {
+                Some(Self::with_attributed_text([(text, Attributes::default())]))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<R> Internal<R>
+where
+    R: text::Renderer,
+{
+    fn sync_attributes(&mut self) {
+        if let Some(attributed) = &mut self.attributed {
+            attributed.sync(&self.editor);
+        }
+    }
 }
 
 impl<Renderer> Clone for Content<Renderer>
@@ -462,7 +946,10 @@ where
     Renderer: text::Renderer,
 {
     fn clone(&self) -> Self {
-        Self::with_text(&self.text())
+        let content = Self::with_text(&self.text());
+
+        content.0.borrow_mut().attributed = self.0.borrow().attributed.clone();
+        content
     }
 }
 
@@ -496,6 +983,9 @@ pub struct State<Highlighter: text::Highlighter> {
     preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
+    box_origin: Option<Point>,
+    modifiers: keyboard::Modifiers,
+    gutter: Gutter,
     partial_scroll: f32,
     last_theme: RefCell<Option<String>>,
     highlighter: RefCell<Highlighter>,
@@ -568,6 +1058,9 @@ where
             preedit: None,
             last_click: None,
             drag_click: None,
+            box_origin: None,
+            modifiers: keyboard::Modifiers::default(),
+            gutter: Gutter::default(),
             partial_scroll: 0.0,
             last_theme: RefCell::default(),
             highlighter: RefCell::new(Highlighter::new(&self.highlighter_settings)),
@@ -613,10 +1106,22 @@ where
             .min_height(self.min_height)
             .max_height(self.max_height);
 
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        state.gutter = Gutter::new::<Renderer>(
+            self.line_numbers.then(|| internal.editor.line_count()),
+            self.decorations.is_some(),
+            self.foldable.is_some(),
+            font,
+            text_size,
+            self.line_height,
+        );
+
         internal.editor.update(
-            limits.shrink(self.padding).max(),
-            self.font.unwrap_or_else(|| renderer.default_font()),
-            self.text_size.unwrap_or_else(|| renderer.default_size()),
+            limits.shrink(self.text_padding(state)).max(),
+            font,
+            text_size,
             self.line_height,
             self.wrapping,
             renderer.scale_factor(),
@@ -650,11 +1155,18 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        if let Event::Window(window::Event::RedrawRequested(_now)) = event
+            && state.highlighter.get_mut().poll() == highlighter::Status::Updated
+        {
+            shell.request_redraw();
+        }
+
         let Some(on_edit) = self.on_edit.as_ref() else {
             return;
         };
 
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
         let is_redraw = matches!(event, Event::Window(window::Event::RedrawRequested(_now)),);
 
         match event {
@@ -686,6 +1198,9 @@ where
                     );
                 }
             }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
             Event::Clipboard(clipboard::Event::Read(Ok(content))) => {
                 if let clipboard::Content::Text(text) = content.as_ref()
                     && let Some(focus) = &mut state.focus
@@ -701,13 +1216,63 @@ where
             event,
             state,
             layout.bounds(),
-            self.padding,
+            self.text_padding(state),
             cursor,
             self.key_binding.as_deref(),
         ) {
             match update {
+                // Clicks on the left of the text land on the gutter
+                Update::Click(click) if click.position().x < 0.0 => {
+                    let position = click.position();
+                    let internal = self.content.0.borrow();
+
+                    let line = internal
+                        .editor
+                        .visible_lines()
+                        .into_iter()
+                        .find(|line| line.y <= position.y && position.y < line.y + line.height);
+
+                    if let Some(line) = line {
+                        let column = state.gutter.column_at(position.x + state.gutter.width());
+
+                        let is_folded = internal
+                            .editor
+                            .folds()
+                            .iter()
+                            .any(|fold| fold.start == line.index);
+
+                        let region = self
+                            .foldable
+                            .unwrap_or_default()
+                            .iter()
+                            .find(|region| region.start == line.index);
+
+                        if column == Some(gutter::Column::Folding) && is_folded {
+                            shell.publish(on_edit(Action::Unfold(line.index)));
+                        } else if column == Some(gutter::Column::Folding)
+                            && let Some(region) = region
+                        {
+                            shell.publish(on_edit(Action::Fold(region.clone())));
+                        } else {
+                            shell.publish(on_edit(Action::Click(Point::new(0.0, position.y))));
+                            shell.publish(on_edit(Action::SelectLine));
+                        }
+                    }
+
+                    state.focus = Some(Focus::now());
+                    state.last_click = None;
+                    state.drag_click = None;
+                    state.box_origin = None;
+
+                    shell.capture_event();
+                }
                 Update::Click(click) => {
+                    let is_adding_cursor = state.modifiers.command() && state.modifiers.alt();
+
                     let action = match click.kind() {
+                        mouse::click::Kind::Single if is_adding_cursor => {
+                            Action::AddCursor(click.position())
+                        }
                         mouse::click::Kind::Single => Action::Click(click.position()),
                         mouse::click::Kind::Double => Action::SelectWord,
                         mouse::click::Kind::Triple => Action::SelectLine,
@@ -715,16 +1280,24 @@ where
 
                     state.focus = Some(Focus::now());
                     state.last_click = Some(click);
-                    state.drag_click = Some(click.kind());
+                    state.drag_click = (!is_adding_cursor).then_some(click.kind());
+                    state.box_origin = (click.kind() == mouse::click::Kind::Single
+                        && state.modifiers.alt()
+                        && !is_adding_cursor)
+                        .then_some(click.position());
 
                     shell.publish(on_edit(action));
                     shell.capture_event();
                 }
                 Update::Drag(position) => {
-                    shell.publish(on_edit(Action::Drag(position)));
+                    shell.publish(on_edit(match state.box_origin {
+                        Some(from) => Action::SelectBox { from, to: position },
+                        None => Action::Drag(position),
+                    }));
                 }
                 Update::Release => {
                     state.drag_click = None;
+                    state.box_origin = None;
                 }
                 Update::Scroll(lines) => {
                     let bounds = self.content.0.borrow().editor.bounds();
@@ -805,6 +1378,9 @@ where
                             Binding::SelectAll => {
                                 publish(Action::SelectAll);
                             }
+                            Binding::SelectNextOccurrence => {
+                                publish(Action::SelectNextOccurrence);
+                            }
                             Binding::Insert(c) => {
                                 publish(Action::Edit(Edit::Insert(c)));
                             }
@@ -817,6 +1393,12 @@ where
                             Binding::Delete => {
                                 publish(Action::Edit(Edit::Delete));
                             }
+                            Binding::Undo => {
+                                publish(Action::Undo);
+                            }
+                            Binding::Redo => {
+                                publish(Action::Redo);
+                            }
                             Binding::Sequence(sequence) => {
                                 for binding in sequence {
                                     apply_binding(binding, content, state, on_edit, shell);
@@ -897,6 +1479,18 @@ where
             let _ = state.last_theme.borrow_mut().replace(theme_name.to_owned());
         }
 
+        let Internal {
+            editor, attributed, ..
+        } = &mut *internal;
+
+        if let Some(attributed) = attributed
+            && let Some(lines) = attributed.take_dirty(font)
+        {
+            for line in lines {
+                editor.format_line(line, attributed.formats(line, font));
+            }
+        }
+
         internal.editor.highlight(
             font,
             state.highlighter.borrow_mut().deref_mut(),
@@ -914,7 +1508,17 @@ where
             style.background,
         );
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state));
+
+        if state.gutter.width() > 0.0 {
+            self.draw_gutter(
+                &state.gutter,
+                &internal.editor,
+                renderer,
+                &style,
+                text_bounds,
+            );
+        }
 
         if internal.editor.is_empty() {
             if let Some(placeholder) = self.placeholder.clone() {
@@ -948,52 +1552,69 @@ where
 
         let translation = text_bounds.position() - Point::ORIGIN;
 
-        if let Some(focus) = state.focus.as_ref() {
-            match internal.editor.selection() {
-                Selection::Caret(position) if focus.is_cursor_visible() => {
-                    let cursor = Rectangle::new(
-                        position + translation,
-                        Size::new(
-                            if renderer::CRISP {
-                                (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
-                            } else {
-                                1.0
-                            },
-                            self.line_height
-                                .to_absolute(
-                                    self.text_size.unwrap_or_else(|| renderer.default_size()),
-                                )
-                                .into(),
-                        ),
-                    );
+        for bounds in self
+            .matches
+            .iter()
+            .flat_map(|range| internal.editor.range_bounds(range.clone()))
+            .filter_map(|bounds| text_bounds.intersection(&(bounds + translation)))
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                style.matches,
+            );
+        }
 
-                    if let Some(clipped_cursor) = text_bounds.intersection(&cursor) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: clipped_cursor,
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
+        if let Some(focus) = state.focus.as_ref() {
+            for selection in internal.editor.selections() {
+                match selection {
+                    Selection::Caret(position) if focus.is_cursor_visible() => {
+                        let cursor = Rectangle::new(
+                            position + translation,
+                            Size::new(
+                                if renderer::CRISP {
+                                    (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
+                                } else {
+                                    1.0
+                                },
+                                self.line_height
+                                    .to_absolute(
+                                        self.text_size.unwrap_or_else(|| renderer.default_size()),
+                                    )
+                                    .into(),
+                            ),
                         );
+
+                        if let Some(clipped_cursor) = text_bounds.intersection(&cursor) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: clipped_cursor,
+                                    ..renderer::Quad::default()
+                                },
+                                style.value,
+                            );
+                        }
                     }
-                }
-                Selection::Range(ranges) => {
-                    for range in ranges
-                        .into_iter()
-                        .filter_map(|range| text_bounds.intersection(&(range + translation)))
-                    {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            style.selection,
-                        );
+                    Selection::Range(ranges) => {
+                        for range in ranges
+                            .into_iter()
+                            .filter_map(|range| text_bounds.intersection(&(range + translation)))
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                style.selection,
+                            );
+                        }
+                    }
+                    Selection::Caret(_) => {
+                        // Drawing an empty quad helps some renderers to track the damage of the blinking cursor
+                        renderer.fill_quad(renderer::Quad::default(), Color::TRANSPARENT);
                     }
-                }
-                Selection::Caret(_) => {
-                    // Drawing an empty quad helps some renderers to track the damage of the blinking cursor
-                    renderer.fill_quad(renderer::Quad::default(), Color::TRANSPARENT);
                 }
             }
         }
@@ -1001,17 +1622,27 @@ where
 
     fn mouse_interaction(
         &self,
-        _tree: &widget::Tree,
+        tree: &widget::Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let is_disabled = self.on_edit.is_none();
+        let bounds = layout.bounds();
+
+        if cursor.is_over(bounds) {
+            let state = tree.state.downcast_ref::<State<Highlighter>>();
+
+            let gutter = Rectangle {
+                width: self.text_padding(state).left,
+                ..bounds
+            };
 
-        if cursor.is_over(layout.bounds()) {
             if is_disabled {
                 mouse::Interaction::NotAllowed
+            } else if state.gutter.width() > 0.0 && cursor.is_over(gutter) {
+                mouse::Interaction::Idle
             } else {
                 mouse::Interaction::Text
             }
@@ -1068,6 +1699,9 @@ pub enum Binding<Message> {
     SelectLine,
     /// Select the entire buffer.
     SelectAll,
+    /// Select the word at the current cursor or add a cursor selecting
+    /// the next occurrence of the current selection.
+    SelectNextOccurrence,
     /// Insert the given character.
     Insert(char),
     /// Break the current line.
@@ -1076,6 +1710,10 @@ pub enum Binding<Message> {
     Backspace,
     /// Delete the next character.
     Delete,
+    /// Undo the last group of edits.
+    Undo,
+    /// Redo the last group of undone edits.
+    Redo,
     /// A sequence of bindings to execute.
     Sequence(Vec<Self>),
     /// Produce the given message.
@@ -1126,6 +1764,10 @@ impl<Message> Binding<Message> {
             Some('x') if modifiers.command() => Some(Self::Cut),
             Some('v') if modifiers.command() && !modifiers.alt() => Some(Self::Paste),
             Some('a') if modifiers.command() => Some(Self::SelectAll),
+            Some('d') if modifiers.command() => Some(Self::SelectNextOccurrence),
+            Some('z') if modifiers.command() && modifiers.shift() => Some(Self::Redo),
+            Some('z') if modifiers.command() => Some(Self::Undo),
+            Some('y') if modifiers.command() => Some(Self::Redo),
             _ => None,
         };
 
@@ -1350,6 +1992,12 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the highlighted matches of the text input.
+    pub matches: Color,
+    /// The [`Color`] of the line numbers and fold markers in the gutter.
+    pub line_number: Color,
+    /// The [`Color`] of the line number of the line with the cursor.
+    pub current_line_number: Color,
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -1393,6 +2041,9 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.secondary.base.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        matches: palette.warning.weak.color,
+        line_number: palette.background.strongest.color,
+        current_line_number: palette.background.base.text,
     };
 
     match status {
@@ -1420,6 +2071,14 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     }
 }
 
+fn ordered(a: Position, b: Position) -> ops::Range<Position> {
+    if (a.line, a.column) <= (b.line, b.column) {
+        a..b
+    } else {
+        b..a
+    }
+}
+
 #[cfg(target_os = "macos")]
 pub(crate) fn convert_macos_shortcut(
     key: &keyboard::Key,
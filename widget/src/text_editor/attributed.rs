@@ -0,0 +1,1457 @@
+use crate::core::font;
+use crate::core::text::editor::{Editor, Position};
+use crate::core::text::highlighter;
+use crate::core::{Color, Font};
+
+#[cfg(feature = "markdown")]
+use crate::markdown;
+
+use std::ops::Range;
+
+/// The attributes of some text in an attributed [`Content`](super::Content).
+///
+/// Any attribute left as `None` is inherited from the
+/// [`TextEditor`](super::TextEditor).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Attributes {
+    /// The weight of the text.
+    pub weight: Option<font::Weight>,
+    /// The style of the text.
+    pub style: Option<font::Style>,
+    /// The color of the text.
+    pub color: Option<Color>,
+    /// The link of the text.
+    pub link: Option<String>,
+}
+
+impl Attributes {
+    /// Returns whether the [`Attributes`] leave the text unchanged.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns whether the text is bold.
+    pub fn is_bold(&self) -> bool {
+        matches!(
+            self.weight,
+            Some(
+                font::Weight::Semibold
+                    | font::Weight::Bold
+                    | font::Weight::ExtraBold
+                    | font::Weight::Black
+            )
+        )
+    }
+
+    /// Returns whether the text is italic.
+    pub fn is_italic(&self) -> bool {
+        matches!(self.style, Some(font::Style::Italic | font::Style::Oblique))
+    }
+
+    /// Returns whether the given [`Attribute`] is set.
+    pub fn has(&self, attribute: &Attribute) -> bool {
+        match attribute {
+            Attribute::Bold => self.is_bold(),
+            Attribute::Italic => self.is_italic(),
+            Attribute::Color(color) => self.color == Some(*color),
+            Attribute::Link(link) => self.link.as_ref() == Some(link),
+        }
+    }
+
+    fn set(&mut self, attribute: &Attribute, is_enabled: bool) {
+        match attribute {
+            Attribute::Bold => {
+                self.weight = is_enabled.then_some(font::Weight::Bold);
+            }
+            Attribute::Italic => {
+                self.style = is_enabled.then_some(font::Style::Italic);
+            }
+            Attribute::Color(color) => {
+                self.color = is_enabled.then_some(*color);
+            }
+            Attribute::Link(link) => {
+                self.link = is_enabled.then(|| link.clone());
+            }
+        }
+    }
+
+    fn format<F: Copy>(&self, font: F, to_font: fn(F, &Self) -> F) -> highlighter::Format<F> {
+        highlighter::Format::default()
+            .color(self.color)
+            .font((self.weight.is_some() || self.style.is_some()).then(|| to_font(font, self)))
+            .underline(
+                self.link
+                    .is_some()
+                    .then_some(highlighter::Underline::Straight),
+            )
+    }
+}
+
+/// An attribute that can be toggled on the selection of an attributed
+/// [`Content`](super::Content).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attribute {
+    /// Bold text.
+    Bold,
+    /// Italic text.
+    Italic,
+    /// Colored text.
+    Color(Color),
+    /// A link to the given URL.
+    Link(String),
+}
+
+/// Applies the weight and style of some [`Attributes`] to a [`Font`].
+pub(crate) fn to_font(font: Font, attributes: &Attributes) -> Font {
+    Font {
+        weight: attributes.weight.unwrap_or(font.weight),
+        style: attributes.style.unwrap_or(font.style),
+        ..font
+    }
+}
+
+/// The attributes of the lines of an editor, kept in sync with its text.
+#[derive(Debug)]
+pub(crate) struct Attributed<Font> {
+    lines: Vec<Line>,
+    typing: Option<Attributes>,
+    dirty: Option<Range<usize>>,
+    font: Option<Font>,
+    to_font: fn(Font, &Attributes) -> Font,
+}
+
+impl<F> Clone for Attributed<F> {
+    fn clone(&self) -> Self {
+        // A clone is laid out by a different editor, which needs every format
+        Self {
+            lines: self.lines.clone(),
+            typing: self.typing.clone(),
+            dirty: Some(0..self.lines.len()),
+            font: None,
+            to_font: self.to_font,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Line {
+    text: String,
+    spans: Vec<(Range<usize>, Attributes)>,
+}
+
+impl<F> Attributed<F>
+where
+    F: Copy + PartialEq,
+{
+    /// Creates the attributes of the given runs of text.
+    pub fn new(
+        runs: impl IntoIterator<Item = (impl AsRef<str>, Attributes)>,
+        to_font: fn(F, &Attributes) -> F,
+    ) -> Self {
+        let mut lines = vec![Line::default()];
+
+        for (text, attributes) in runs {
+            for (i, piece) in text.as_ref().split('\n').enumerate() {
+                if i > 0 {
+                    lines.push(Line::default());
+                }
+
+                let line = lines.last_mut().expect("Lines should not be empty");
+                let start = line.text.len();
+
+                line.text.push_str(piece);
+
+                if !attributes.is_empty() {
+                    line.spans
+                        .push((start..line.text.len(), attributes.clone()));
+                }
+            }
+        }
+
+        for line in &mut lines {
+            if line.text.ends_with('\r') {
+                let _ = line.text.pop();
+            }
+
+            line.normalize();
+        }
+
+        Self {
+            dirty: Some(0..lines.len()),
+            lines,
+            typing: None,
+            font: None,
+            to_font,
+        }
+    }
+
+    /// Returns the text of the attributed lines.
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the runs of text with the same attributes, new lines included.
+    pub fn runs(&self) -> Vec<(String, Attributes)> {
+        let mut runs = Runs::default();
+
+        for (i, line) in self.lines.iter().enumerate() {
+            if i > 0 {
+                runs.push("\n", &Attributes::default());
+            }
+
+            for (range, attributes) in line.runs() {
+                runs.push(&line.text[range], &attributes);
+            }
+        }
+
+        runs.0
+    }
+
+    /// Returns the [`Attributes`] new text would have at the given position.
+    pub fn attributes(&self, position: Position) -> Attributes {
+        if let Some(typing) = &self.typing {
+            return typing.clone();
+        }
+
+        let Some(line) = self.lines.get(position.line) else {
+            return Attributes::default();
+        };
+
+        position
+            .column
+            .checked_sub(1)
+            .and_then(|before| line.attributes_at(before))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Toggles an [`Attribute`] on the given selections.
+    ///
+    /// If nothing is selected, the [`Attribute`] is toggled for the text typed
+    /// next at the given cursor position instead.
+    pub fn toggle(
+        &mut self,
+        attribute: &Attribute,
+        selections: &[Range<Position>],
+        cursor: Position,
+    ) {
+        let selections: Vec<_> = selections
+            .iter()
+            .filter(|selection| selection.start != selection.end)
+            .collect();
+
+        if selections.is_empty() {
+            let mut typing = self.attributes(cursor);
+            let is_enabled = !typing.has(attribute);
+
+            typing.set(attribute, is_enabled);
+            self.typing = Some(typing);
+
+            return;
+        }
+
+        let is_enabled = !selections.iter().all(|selection| {
+            self.columns(selection).all(|(line, columns)| {
+                self.lines[line]
+                    .runs()
+                    .into_iter()
+                    .filter(|(range, _)| range.start < columns.end && columns.start < range.end)
+                    .all(|(_, attributes)| attributes.has(attribute))
+            })
+        });
+
+        for selection in selections {
+            let columns: Vec<_> = self.columns(selection).collect();
+
+            for (line, columns) in columns {
+                self.lines[line].update(columns, |attributes| {
+                    attributes.set(attribute, is_enabled);
+                });
+
+                self.invalidate(line..line + 1);
+            }
+        }
+    }
+
+    /// Forgets any attributes toggled for the text typed next.
+    pub fn reset_typing(&mut self) {
+        self.typing = None;
+    }
+
+    /// Updates the attributes to match the current text of the [`Editor`].
+    ///
+    /// Inserted text takes the attributes toggled for typing or, otherwise, the
+    /// ones of the text right before it.
+    pub fn sync(&mut self, editor: &impl Editor) {
+        let texts: Vec<String> = (0..editor.line_count())
+            .filter_map(|i| editor.line(i))
+            .map(|line| line.text.into_owned())
+            .collect();
+
+        let prefix = self
+            .lines
+            .iter()
+            .zip(&texts)
+            .take_while(|(line, text)| line.text == **text)
+            .count();
+
+        if prefix == self.lines.len() && prefix == texts.len() {
+            return;
+        }
+
+        let suffix = self
+            .lines
+            .iter()
+            .rev()
+            .zip(texts.iter().rev())
+            .take(self.lines.len().min(texts.len()) - prefix)
+            .take_while(|(line, text)| line.text == **text)
+            .count();
+
+        let mut old = prefix..self.lines.len() - suffix;
+        let mut new = prefix..texts.len() - suffix;
+
+        // Whole lines were inserted or removed; a neighbor keeps the new line
+        if old.is_empty() || new.is_empty() {
+            if suffix > 0 {
+                old.end += 1;
+                new.end += 1;
+            } else if prefix > 0 {
+                old.start -= 1;
+                new.start -= 1;
+            }
+        }
+
+        let typing = self.typing.take();
+        let mut is_typed = false;
+
+        let replaced: Vec<Line> = if old.len() == new.len() {
+            self.lines[old.clone()]
+                .iter()
+                .zip(&texts[new])
+                .map(|(line, text)| {
+                    let (line, has_insertion) = line.splice(text, typing.as_ref());
+                    is_typed |= has_insertion;
+
+                    line
+                })
+                .collect()
+        } else {
+            let (joined, has_insertion) = Line::join(&self.lines[old.clone()])
+                .splice(&texts[new].join("\n"), typing.as_ref());
+
+            is_typed = has_insertion;
+            joined.split()
+        };
+
+        let _ = self.lines.splice(old, replaced);
+
+        if !is_typed {
+            self.typing = typing;
+        }
+
+        // The editor drops the formats of every line touched by an edit,
+        // which may include unchanged lines around it
+        self.dirty = Some(0..self.lines.len());
+    }
+
+    /// Returns the lines whose formats need to be updated for the given font.
+    pub fn take_dirty(&mut self, font: F) -> Option<Range<usize>> {
+        if self.font != Some(font) {
+            self.font = Some(font);
+            self.dirty = Some(0..self.lines.len());
+        }
+
+        self.dirty
+            .take()
+            .map(|lines| lines.start..lines.end.min(self.lines.len()))
+    }
+
+    /// Returns the formats of the given line.
+    pub fn formats(&self, line: usize, font: F) -> Vec<(Range<usize>, highlighter::Format<F>)> {
+        self.lines.get(line).map_or_else(Vec::new, |line| {
+            line.spans
+                .iter()
+                .map(|(range, attributes)| (range.clone(), attributes.format(font, self.to_font)))
+                .collect()
+        })
+    }
+
+    fn columns(
+        &self,
+        selection: &Range<Position>,
+    ) -> impl Iterator<Item = (usize, Range<usize>)> + '_ {
+        let Range { start, end } = selection.clone();
+
+        (start.line..=end.line.min(self.lines.len().saturating_sub(1))).map(move |line| {
+            let length = self.lines[line].text.len();
+
+            let from = if line == start.line { start.column } else { 0 };
+            let to = if line == end.line { end.column } else { length };
+
+            (line, from.min(length)..to.min(length))
+        })
+    }
+
+    fn invalidate(&mut self, lines: Range<usize>) {
+        self.dirty = Some(match self.dirty.take() {
+            Some(dirty) => dirty.start.min(lines.start)..dirty.end.max(lines.end),
+            None => lines,
+        });
+    }
+}
+
+impl Line {
+    /// Returns the runs of the [`Line`], covering all of its text.
+    fn runs(&self) -> Vec<(Range<usize>, Attributes)> {
+        let mut runs = Vec::new();
+        let mut end = 0;
+
+        for (range, attributes) in &self.spans {
+            if range.start > end {
+                runs.push((end..range.start, Attributes::default()));
+            }
+
+            runs.push((range.clone(), attributes.clone()));
+            end = range.end;
+        }
+
+        if end < self.text.len() || runs.is_empty() {
+            runs.push((end..self.text.len(), Attributes::default()));
+        }
+
+        runs
+    }
+
+    fn attributes_at(&self, index: usize) -> Option<&Attributes> {
+        self.spans
+            .iter()
+            .find(|(range, _)| range.contains(&index))
+            .map(|(_, attributes)| attributes)
+    }
+
+    fn update(&mut self, columns: Range<usize>, f: impl Fn(&mut Attributes)) {
+        self.spans = self
+            .runs()
+            .into_iter()
+            .flat_map(|(range, attributes)| {
+                let start = range.start.clamp(columns.start, columns.end);
+                let end = range.end.clamp(columns.start, columns.end);
+
+                let mut updated = attributes.clone();
+                f(&mut updated);
+
+                [
+                    (range.start..start, attributes.clone()),
+                    (start..end, updated),
+                    (end..range.end, attributes),
+                ]
+            })
+            .collect();
+
+        self.normalize();
+    }
+
+    /// Replaces the text of the [`Line`], keeping the attributes of the text
+    /// left untouched.
+    ///
+    /// Returns whether any text was inserted.
+    fn splice(&self, text: &str, typing: Option<&Attributes>) -> (Self, bool) {
+        let prefix: usize = self
+            .text
+            .chars()
+            .zip(text.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+
+        let suffix: usize = self.text[prefix..]
+            .chars()
+            .rev()
+            .zip(text[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+
+        let removed = prefix..self.text.len() - suffix;
+        let inserted = prefix..text.len() - suffix;
+
+        let mut before = Vec::new();
+        let mut after = Vec::new();
+
+        for (range, attributes) in &self.spans {
+            if range.start < removed.start {
+                before.push((
+                    range.start..range.end.min(removed.start),
+                    attributes.clone(),
+                ));
+            }
+
+            if range.end > removed.end {
+                let start = range.start.max(removed.end);
+
+                after.push((
+                    start - removed.end + inserted.end..range.end - removed.end + inserted.end,
+                    attributes.clone(),
+                ));
+            }
+        }
+
+        let is_inserted = !inserted.is_empty();
+
+        if is_inserted {
+            let attributes = typing
+                .cloned()
+                .unwrap_or_else(|| self.inherited(removed.clone()));
+
+            before.push((inserted, attributes));
+        }
+
+        let mut line = Self {
+            text: text.to_owned(),
+            spans: before.into_iter().chain(after).collect(),
+        };
+
+        line.normalize();
+
+        (line, is_inserted)
+    }
+
+    /// Returns the attributes of the text right before the given range or,
+    /// at the start of a line, right after it.
+    ///
+    /// Links are only inherited inside of them.
+    fn inherited(&self, removed: Range<usize>) -> Attributes {
+        let index = match self.text[..removed.start].chars().next_back() {
+            Some(c) if c != '\n' => removed.start - c.len_utf8(),
+            _ => removed.end,
+        };
+
+        self.spans
+            .iter()
+            .find(|(range, _)| range.contains(&index))
+            .map(|(range, attributes)| {
+                let mut attributes = attributes.clone();
+
+                if !(range.start < removed.start && removed.end < range.end) {
+                    attributes.link = None;
+                }
+
+                attributes
+            })
+            .unwrap_or_default()
+    }
+
+    fn join(lines: &[Line]) -> Self {
+        let mut joined = Line::default();
+
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                joined.text.push('\n');
+            }
+
+            let offset = joined.text.len();
+
+            joined.text.push_str(&line.text);
+            joined
+                .spans
+                .extend(line.spans.iter().map(|(range, attributes)| {
+                    (range.start + offset..range.end + offset, attributes.clone())
+                }));
+        }
+
+        joined
+    }
+
+    fn split(self) -> Vec<Line> {
+        let mut offset = 0;
+
+        self.text
+            .split('\n')
+            .map(|text| {
+                let bounds = offset..offset + text.len();
+                offset = bounds.end + 1;
+
+                let mut line = Line {
+                    text: text.to_owned(),
+                    spans: self
+                        .spans
+                        .iter()
+                        .filter(|(range, _)| range.start < bounds.end && bounds.start < range.end)
+                        .map(|(range, attributes)| {
+                            (
+                                range.start.max(bounds.start) - bounds.start
+                                    ..range.end.min(bounds.end) - bounds.start,
+                                attributes.clone(),
+                            )
+                        })
+                        .collect(),
+                };
+
+                line.normalize();
+                line
+            })
+            .collect()
+    }
+
+    /// Drops empty spans and merges contiguous spans with equal attributes.
+    fn normalize(&mut self) {
+        let length = self.text.len();
+        let mut spans: Vec<(Range<usize>, Attributes)> = Vec::with_capacity(self.spans.len());
+
+        for (range, attributes) in self.spans.drain(..) {
+            let range = range.start.min(length)..range.end.min(length);
+
+            if range.is_empty() || attributes.is_empty() {
+                continue;
+            }
+
+            if let Some((last, last_attributes)) = spans.last_mut()
+                && last.end == range.start
+                && *last_attributes == attributes
+            {
+                last.end = range.end;
+                continue;
+            }
+
+            spans.push((range, attributes));
+        }
+
+        self.spans = spans;
+    }
+}
+
+/// Runs of text with the same attributes.
+#[derive(Debug, Default)]
+struct Runs(Vec<(String, Attributes)>);
+
+impl Runs {
+    fn push(&mut self, text: &str, attributes: &Attributes) {
+        if text.is_empty() {
+            return;
+        }
+
+        match self.0.last_mut() {
+            Some((last, last_attributes)) if last_attributes == attributes => {
+                last.push_str(text);
+            }
+            _ => {
+                self.0.push((text.to_owned(), attributes.clone()));
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|(text, _)| text.len()).sum()
+    }
+
+    fn last_char(&self) -> Option<char> {
+        self.0.last().and_then(|(text, _)| text.chars().next_back())
+    }
+
+    fn is_line_start(&self) -> bool {
+        self.0.last().is_none_or(|(text, _)| text.ends_with('\n'))
+    }
+
+    fn finish(mut self) -> Vec<(String, Attributes)> {
+        if let Some((text, _)) = self.0.last_mut()
+            && text.ends_with('\n')
+        {
+            let _ = text.pop();
+
+            if text.is_empty() {
+                let _ = self.0.pop();
+            }
+        }
+
+        self.0
+    }
+}
+
+/// Splits some runs of text into lines.
+fn lines(runs: &[(String, Attributes)]) -> Vec<Vec<(&str, &Attributes)>> {
+    let mut lines = vec![Vec::new()];
+
+    for (text, attributes) in runs {
+        for (i, piece) in text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Vec::new());
+            }
+
+            if !piece.is_empty() {
+                lines
+                    .last_mut()
+                    .expect("Lines should not be empty")
+                    .push((piece, attributes));
+            }
+        }
+    }
+
+    lines
+}
+
+/// Writes some runs of text as Markdown.
+///
+/// Colors cannot be represented in Markdown and are dropped.
+pub(crate) fn to_markdown(runs: &[(String, Attributes)]) -> String {
+    let lines: Vec<String> = lines(runs)
+        .into_iter()
+        .map(|line| {
+            let mut markdown = String::new();
+
+            for (text, attributes) in line {
+                let trimmed = text.trim();
+
+                if trimmed.is_empty() {
+                    markdown.push_str(text);
+                    continue;
+                }
+
+                let start = text.len() - text.trim_start().len();
+                let end = start + trimmed.len();
+
+                let marker = match (attributes.is_bold(), attributes.is_italic()) {
+                    (true, true) => "***",
+                    (true, false) => "**",
+                    (false, true) => "*",
+                    (false, false) => "",
+                };
+
+                let mut styled = format!("{marker}{}{marker}", escape_markdown(trimmed));
+
+                if let Some(link) = &attributes.link {
+                    styled = format!("[{styled}]({})", link.replace(' ', "%20"));
+                }
+
+                markdown.push_str(&text[..start]);
+
+                if markdown.is_empty() && (styled.starts_with('-') || styled.starts_with('+')) {
+                    markdown.push('\\');
+                }
+
+                markdown.push_str(&styled);
+                markdown.push_str(&text[end..]);
+            }
+
+            markdown
+        })
+        .collect();
+
+    let mut markdown = String::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            // Consecutive lines would be merged into the same paragraph
+            markdown.push_str(if lines[i - 1].is_empty() || line.is_empty() {
+                "\n"
+            } else {
+                "\\\n"
+            });
+        }
+
+        markdown.push_str(line);
+    }
+
+    markdown
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '~' | '|'
+        ) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Reads the runs of text of some parsed Markdown.
+///
+/// Blocks are separated by an empty line, while code blocks, images and
+/// tables are kept as plain text.
+#[cfg(feature = "markdown")]
+pub(crate) fn from_markdown(items: &[markdown::Item]) -> Vec<(String, Attributes)> {
+    let mut runs = Runs::default();
+
+    push_markdown(&mut runs, items, "\n\n");
+
+    runs.0
+}
+
+#[cfg(feature = "markdown")]
+fn push_markdown(runs: &mut Runs, items: &[markdown::Item], separator: &str) {
+    let plain = Attributes::default();
+
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            runs.push(separator, &plain);
+        }
+
+        match item {
+            markdown::Item::Heading(_, text) => {
+                for (text, attributes) in text.attributed() {
+                    runs.push(
+                        text,
+                        &Attributes {
+                            weight: Some(font::Weight::Bold),
+                            ..attributes
+                        },
+                    );
+                }
+            }
+            markdown::Item::Paragraph(text) | markdown::Item::Image { alt: text, .. } => {
+                for (text, attributes) in text.attributed() {
+                    runs.push(text, &attributes);
+                }
+            }
+            markdown::Item::CodeBlock { code, .. } => {
+                runs.push(code.trim_end_matches('\n'), &plain);
+            }
+            markdown::Item::List { start, bullets } => {
+                for (i, bullet) in bullets.iter().enumerate() {
+                    if i > 0 {
+                        runs.push("\n", &plain);
+                    }
+
+                    let (marker, items) = match bullet {
+                        markdown::Bullet::Point { items } => (String::from("- "), items),
+                        markdown::Bullet::Task { items, done } => {
+                            (format!("- [{}] ", if *done { 'x' } else { ' ' }), items)
+                        }
+                    };
+
+                    match start {
+                        Some(start) => runs.push(&format!("{}. ", start + i as u64), &plain),
+                        None => runs.push(&marker, &plain),
+                    }
+
+                    push_markdown(runs, items, "\n");
+                }
+            }
+            markdown::Item::Quote(items) => {
+                runs.push("> ", &plain);
+                push_markdown(runs, items, "\n");
+            }
+            markdown::Item::Rule => {
+                runs.push("---", &plain);
+            }
+            markdown::Item::Table { columns, .. } => {
+                for (i, column) in columns.iter().enumerate() {
+                    if i > 0 {
+                        runs.push(" | ", &plain);
+                    }
+
+                    push_markdown(runs, &column.header, " ");
+                }
+            }
+        }
+    }
+}
+
+/// Writes some runs of text as HTML, with a paragraph per line.
+pub(crate) fn to_html(runs: &[(String, Attributes)]) -> String {
+    let mut html = String::new();
+
+    for line in lines(runs) {
+        html.push_str("<p>");
+
+        if line.is_empty() {
+            html.push_str("<br>");
+        }
+
+        for (text, attributes) in line {
+            let mut closing = Vec::new();
+
+            if let Some(link) = &attributes.link {
+                html.push_str(&format!("<a href=\"{}\">", escape_html(link)));
+                closing.push("</a>");
+            }
+
+            if attributes.weight == Some(font::Weight::Bold) {
+                html.push_str("<b>");
+                closing.push("</b>");
+            }
+
+            if attributes.style == Some(font::Style::Italic) {
+                html.push_str("<i>");
+                closing.push("</i>");
+            }
+
+            let mut style = Vec::new();
+
+            if let Some(weight) = attributes.weight
+                && weight != font::Weight::Bold
+            {
+                style.push(format!("font-weight: {}", css_weight(weight)));
+            }
+
+            if let Some(font_style) = attributes.style
+                && font_style != font::Style::Italic
+            {
+                style.push(format!(
+                    "font-style: {}",
+                    match font_style {
+                        font::Style::Oblique => "oblique",
+                        font::Style::Normal | font::Style::Italic => "normal",
+                    }
+                ));
+            }
+
+            if let Some(color) = attributes.color {
+                style.push(format!("color: {color}"));
+            }
+
+            if !style.is_empty() {
+                html.push_str(&format!("<span style=\"{}\">", style.join("; ")));
+                closing.push("</span>");
+            }
+
+            html.push_str(&escape_html(text));
+
+            for tag in closing.into_iter().rev() {
+                html.push_str(tag);
+            }
+        }
+
+        html.push_str("</p>");
+    }
+
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn css_weight(weight: font::Weight) -> u16 {
+    match weight {
+        font::Weight::Thin => 100,
+        font::Weight::ExtraLight => 200,
+        font::Weight::Light => 300,
+        font::Weight::Normal => 400,
+        font::Weight::Medium => 500,
+        font::Weight::Semibold => 600,
+        font::Weight::Bold => 700,
+        font::Weight::ExtraBold => 800,
+        font::Weight::Black => 900,
+    }
+}
+
+/// Reads the runs of text of some HTML.
+///
+/// Only the inline styles for weight, style, color and links are kept. Block
+/// elements and line breaks start new lines, while any whitespace in between
+/// is collapsed like a browser would.
+pub(crate) fn from_html(html: &str) -> Vec<(String, Attributes)> {
+    const BLOCKS: &[&str] = &[
+        "p",
+        "div",
+        "li",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "tr",
+        "ul",
+        "ol",
+        "table",
+        "blockquote",
+        "pre",
+    ];
+
+    const VOID: &[&str] = &[
+        "br", "img", "hr", "meta", "link", "input", "wbr", "col", "area", "base", "source",
+    ];
+
+    const HIDDEN: &[&str] = &["head", "script", "style", "title"];
+
+    let mut runs = Runs::default();
+    let mut stack: Vec<(String, Attributes, usize)> = Vec::new();
+    let mut hidden: Option<String> = None;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if rest.starts_with('<')
+            && let Some(end) = rest.find('>')
+        {
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            if tag.starts_with('!') || tag.starts_with('?') {
+                continue;
+            }
+
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim().to_ascii_lowercase();
+
+                if hidden.as_ref() == Some(&name) {
+                    hidden = None;
+                    continue;
+                }
+
+                if hidden.is_some() {
+                    continue;
+                }
+
+                let start = match stack.iter().rposition(|(open, _, _)| *open == name) {
+                    Some(index) => stack.drain(index..).next().map(|(_, _, start)| start),
+                    None => None,
+                };
+
+                if BLOCKS.contains(&name.as_str())
+                    && (!runs.is_line_start() || start == Some(runs.len()))
+                {
+                    runs.push("\n", &Attributes::default());
+                }
+
+                continue;
+            }
+
+            if hidden.is_some() {
+                continue;
+            }
+
+            let is_self_closing = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+
+            let (name, source) = tag
+                .split_once(|c: char| c.is_ascii_whitespace())
+                .unwrap_or((tag, ""));
+
+            let name = name.to_ascii_lowercase();
+
+            if HIDDEN.contains(&name.as_str()) {
+                if !is_self_closing {
+                    hidden = Some(name);
+                }
+
+                continue;
+            }
+
+            if name == "br" {
+                runs.push("\n", &Attributes::default());
+            }
+
+            if BLOCKS.contains(&name.as_str()) && !runs.is_line_start() {
+                runs.push("\n", &Attributes::default());
+            }
+
+            if is_self_closing || VOID.contains(&name.as_str()) {
+                continue;
+            }
+
+            let mut attributes = stack
+                .last()
+                .map(|(_, attributes, _)| attributes.clone())
+                .unwrap_or_default();
+
+            match name.as_str() {
+                "b" | "strong" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    attributes.weight = Some(font::Weight::Bold);
+                }
+                "i" | "em" => {
+                    attributes.style = Some(font::Style::Italic);
+                }
+                _ => {}
+            }
+
+            for (key, value) in html_attributes(source) {
+                match key.as_str() {
+                    "href" if name == "a" => {
+                        attributes.link = Some(value);
+                    }
+                    "color" if name == "font" => {
+                        attributes.color = css_color(&value).or(attributes.color);
+                    }
+                    "style" => {
+                        apply_css(&value, &mut attributes);
+                    }
+                    _ => {}
+                }
+            }
+
+            stack.push((name, attributes, runs.len()));
+            continue;
+        }
+
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| *c == '<')
+            .map_or(rest.len(), |(i, _)| i);
+
+        let text = &rest[..end];
+        rest = &rest[end..];
+
+        if hidden.is_some() {
+            continue;
+        }
+
+        let mut collapsed = String::with_capacity(text.len());
+
+        for c in text.chars() {
+            if c.is_ascii_whitespace() {
+                let previous = collapsed.chars().next_back().or_else(|| runs.last_char());
+
+                if previous.is_some_and(|c| c != ' ' && c != '\n') {
+                    collapsed.push(' ');
+                }
+            } else {
+                collapsed.push(c);
+            }
+        }
+
+        let attributes = stack
+            .last()
+            .map(|(_, attributes, _)| attributes.clone())
+            .unwrap_or_default();
+
+        runs.push(&decode_html(&collapsed), &attributes);
+    }
+
+    runs.finish()
+}
+
+/// Parses the attributes of an HTML tag.
+fn html_attributes(source: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut rest = source.trim_start();
+
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| c == '=' || c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+
+        let key = rest[..end].to_ascii_lowercase();
+        rest = rest[end..].trim_start();
+
+        let value = if let Some(value) = rest.strip_prefix('=') {
+            let value = value.trim_start();
+
+            match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let value = &value[1..];
+                    let end = value.find(quote).unwrap_or(value.len());
+
+                    rest = value.get(end + 1..).unwrap_or("");
+                    &value[..end]
+                }
+                _ => {
+                    let end = value
+                        .find(|c: char| c.is_ascii_whitespace())
+                        .unwrap_or(value.len());
+
+                    rest = &value[end..];
+                    &value[..end]
+                }
+            }
+        } else {
+            ""
+        };
+
+        if !key.is_empty() {
+            attributes.push((key, decode_html(value)));
+        }
+
+        rest = rest.trim_start();
+    }
+
+    attributes
+}
+
+fn apply_css(style: &str, attributes: &mut Attributes) {
+    for declaration in style.split(';') {
+        let Some((property, value)) = declaration.split_once(':') else {
+            continue;
+        };
+
+        let value = value.trim().to_ascii_lowercase();
+
+        match property.trim().to_ascii_lowercase().as_str() {
+            "font-weight" => {
+                attributes.weight = match value.as_str() {
+                    "bold" | "bolder" => Some(font::Weight::Bold),
+                    "normal" => None,
+                    "lighter" => Some(font::Weight::Light),
+                    number => match number.parse::<u16>() {
+                        Ok(0..=149) => Some(font::Weight::Thin),
+                        Ok(150..=249) => Some(font::Weight::ExtraLight),
+                        Ok(250..=349) => Some(font::Weight::Light),
+                        Ok(350..=449) => None,
+                        Ok(450..=549) => Some(font::Weight::Medium),
+                        Ok(550..=649) => Some(font::Weight::Semibold),
+                        Ok(650..=749) => Some(font::Weight::Bold),
+                        Ok(750..=849) => Some(font::Weight::ExtraBold),
+                        Ok(_) => Some(font::Weight::Black),
+                        Err(_) => attributes.weight,
+                    },
+                };
+            }
+            "font-style" => {
+                attributes.style = match value.as_str() {
+                    "italic" => Some(font::Style::Italic),
+                    "oblique" => Some(font::Style::Oblique),
+                    "normal" => None,
+                    _ => attributes.style,
+                };
+            }
+            "color" => {
+                attributes.color = css_color(&value).or(attributes.color);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn css_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+
+    if value.starts_with('#') {
+        return value.parse().ok();
+    }
+
+    let channels = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+
+    let channels: Vec<f32> = channels
+        .split(',')
+        .map(|channel| channel.trim().parse().ok())
+        .collect::<Option<_>>()?;
+
+    match channels.as_slice() {
+        [r, g, b] => Some(Color::from_rgb8(*r as u8, *g as u8, *b as u8)),
+        [r, g, b, a] => Some(Color::from_rgba8(*r as u8, *g as u8, *b as u8, *a)),
+        _ => None,
+    }
+}
+
+fn decode_html(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..=end]);
+
+        let c = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+
+                match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => code.parse().ok(),
+                }
+                .and_then(char::from_u32)
+            }
+        });
+
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> Attributes {
+        Attributes {
+            weight: Some(font::Weight::Bold),
+            ..Attributes::default()
+        }
+    }
+
+    fn link(url: &str) -> Attributes {
+        Attributes {
+            link: Some(url.to_owned()),
+            ..Attributes::default()
+        }
+    }
+
+    fn line(text: &str, spans: &[(Range<usize>, Attributes)]) -> Line {
+        Line {
+            text: text.to_owned(),
+            spans: spans.to_vec(),
+        }
+    }
+
+    #[test]
+    fn inserted_text_follows_the_text_before_it() {
+        let line = line("a bold word", &[(2..6, bold())]);
+
+        let (typed, is_typed) = line.splice("a bolder word", None);
+        assert!(is_typed);
+        assert_eq!(typed.spans, vec![(2..8, bold())]);
+
+        let (typed, _) = line.splice("a bold new word", None);
+        assert_eq!(typed.spans, vec![(2..6, bold())]);
+
+        let (typed, _) = line.splice("a bold new word", Some(&bold()));
+        assert_eq!(typed.spans, vec![(2..6, bold()), (7..11, bold())]);
+
+        let (deleted, is_typed) = line.splice("a bd word", None);
+        assert!(!is_typed);
+        assert_eq!(deleted.spans, vec![(2..4, bold())]);
+    }
+
+    #[test]
+    fn links_only_grow_from_inside() {
+        let line = line("see docs", &[(4..8, link("https://iced.rs"))]);
+
+        let (typed, _) = line.splice("see docs!", None);
+        assert_eq!(typed.spans, vec![(4..8, link("https://iced.rs"))]);
+
+        let (typed, _) = line.splice("see dooocs", None);
+        assert_eq!(typed.spans, vec![(4..10, link("https://iced.rs"))]);
+    }
+
+    #[test]
+    fn attributes_survive_new_lines() {
+        let joined = Line::join(&[line("one two", &[(4..7, bold())])]);
+        let (split, _) = joined.splice("one\n two", None);
+
+        assert_eq!(
+            split.split(),
+            vec![line("one", &[]), line(" two", &[(1..4, bold())])]
+        );
+    }
+
+    #[test]
+    fn markdown_and_html_are_exported() {
+        let runs = vec![
+            (String::from("A "), Attributes::default()),
+            (String::from("bold"), bold()),
+            (String::from(" "), Attributes::default()),
+            (String::from("link"), link("https://iced.rs")),
+            (
+                String::from("\nnext line\n\nparagraph"),
+                Attributes::default(),
+            ),
+        ];
+
+        assert_eq!(
+            to_markdown(&runs),
+            "A **bold** [link](https://iced.rs)\\\nnext line\n\nparagraph"
+        );
+
+        assert_eq!(
+            to_html(&runs),
+            "<p>A <b>bold</b> <a href=\"https://iced.rs\">link</a></p>\
+            <p>next line</p><p><br></p><p>paragraph</p>"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "markdown")]
+    fn markdown_is_imported() {
+        let markdown = markdown::Content::parse(
+            "# Title\n\nSome *emphasis* and [a link](https://iced.rs)\n\n- one\n- two",
+        );
+
+        let runs = from_markdown(markdown.items());
+
+        assert_eq!(
+            runs,
+            vec![
+                (String::from("Title"), bold()),
+                (String::from("\n\nSome "), Attributes::default()),
+                (
+                    String::from("emphasis"),
+                    Attributes {
+                        style: Some(font::Style::Italic),
+                        ..Attributes::default()
+                    }
+                ),
+                (String::from(" and "), Attributes::default()),
+                (String::from("a link"), link("https://iced.rs")),
+                (String::from("\n\n- one\n- two"), Attributes::default()),
+            ]
+        );
+    }
+
+    #[test]
+    fn html_is_imported() {
+        let runs = from_html(
+            "<html><head><style>p { color: red; }</style></head><body>\n\
+            <p>A <strong>bold</strong>\n   <a href=\"https://iced.rs\">link</a> &amp; \
+            <span style=\"color: #ff0000; font-style: italic\">red</span></p>\n\
+            <p><br></p><div><p>last</p></div></body></html>",
+        );
+
+        assert_eq!(
+            runs,
+            vec![
+                (String::from("A "), Attributes::default()),
+                (String::from("bold"), bold()),
+                (String::from(" "), Attributes::default()),
+                (String::from("link"), link("https://iced.rs")),
+                (String::from(" & "), Attributes::default()),
+                (
+                    String::from("red"),
+                    Attributes {
+                        style: Some(font::Style::Italic),
+                        color: Some(Color::from_rgb8(255, 0, 0)),
+                        ..Attributes::default()
+                    }
+                ),
+                (String::from("\n\nlast"), Attributes::default()),
+            ]
+        );
+
+        assert_eq!(from_html(&to_html(&runs)), runs);
+    }
+}
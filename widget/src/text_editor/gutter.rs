@@ -0,0 +1,129 @@
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::{Color, Pixels, Size, alignment};
+
+/// A mark displayed next to a line in the gutter of a
+/// [`TextEditor`](super::TextEditor); like a breakpoint or a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decoration<Font = crate::core::Font> {
+    /// The line of the [`Decoration`].
+    pub line: usize,
+    /// The icon of the [`Decoration`].
+    pub icon: char,
+    /// The font of the icon; the font of the editor by default.
+    pub font: Option<Font>,
+    /// The color of the icon; the color of the line numbers by default.
+    pub color: Option<Color>,
+}
+
+impl<Font> Decoration<Font> {
+    /// Creates a new [`Decoration`] with the given icon for the given line.
+    pub fn new(line: usize, icon: char) -> Self {
+        Self {
+            line,
+            icon,
+            font: None,
+            color: None,
+        }
+    }
+
+    /// Sets the font of the icon of the [`Decoration`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the color of the icon of the [`Decoration`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The columns of the gutter of a [`TextEditor`](super::TextEditor), from
+/// left to right.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(super) struct Gutter {
+    pub decorations: f32,
+    pub line_numbers: f32,
+    pub folding: f32,
+    pub spacing: f32,
+}
+
+/// A column of a [`Gutter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Column {
+    Decorations,
+    LineNumbers,
+    Folding,
+}
+
+impl Gutter {
+    /// Lays out the columns of a [`Gutter`].
+    pub fn new<Renderer: text::Renderer>(
+        line_count: Option<usize>,
+        has_decorations: bool,
+        has_folding: bool,
+        font: Renderer::Font,
+        size: Pixels,
+        line_height: text::LineHeight,
+    ) -> Self {
+        let row = f32::from(line_height.to_absolute(size));
+
+        let line_numbers = line_count.map_or(0.0, |line_count| {
+            let digits = line_count.max(1).ilog10() as usize + 1;
+
+            Renderer::Paragraph::with_text(Text {
+                content: "0".repeat(digits).as_str(),
+                bounds: Size::INFINITE,
+                size,
+                line_height,
+                font,
+                align_x: text::Alignment::Default,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                wrapping: text::Wrapping::None,
+                ellipsis: text::Ellipsis::None,
+                hint_factor: None,
+            })
+            .min_width()
+            .ceil()
+        });
+
+        let gutter = Self {
+            decorations: if has_decorations { row } else { 0.0 },
+            line_numbers,
+            folding: if has_folding { row } else { 0.0 },
+            spacing: 0.0,
+        };
+
+        if gutter == Self::default() {
+            return gutter;
+        }
+
+        Self {
+            spacing: row / 2.0,
+            ..gutter
+        }
+    }
+
+    /// Returns the total width of the [`Gutter`].
+    pub fn width(&self) -> f32 {
+        self.decorations + self.line_numbers + self.folding + self.spacing
+    }
+
+    /// Returns the [`Column`] at the given horizontal offset from the
+    /// left of the [`Gutter`], if any.
+    pub fn column_at(&self, x: f32) -> Option<Column> {
+        if x < 0.0 {
+            None
+        } else if x < self.decorations {
+            Some(Column::Decorations)
+        } else if x < self.decorations + self.line_numbers {
+            Some(Column::LineNumbers)
+        } else if x < self.decorations + self.line_numbers + self.folding {
+            Some(Column::Folding)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use crate::core::text::editor::{Action, Cursor, Edit, Editor, LineEnding, Position};
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// The undo/redo history of some editor.
+///
+/// Instead of snapshotting the whole buffer, every change only keeps
+/// the lines surrounding the cursors before and after an [`Edit`].
+#[derive(Debug, Default)]
+pub struct History {
+    undo: VecDeque<Change>,
+    redo: Vec<Change>,
+    is_sealed: bool,
+}
+
+#[derive(Debug)]
+struct Change {
+    line: usize,
+    kind: Kind,
+    before: Snapshot,
+    after: Snapshot,
+}
+
+#[derive(Debug, PartialEq)]
+struct Snapshot {
+    text: Arc<String>,
+    lines: usize,
+    cursors: Vec<Cursor>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Typing { is_whitespace: bool },
+    Backspace,
+    Delete,
+    Other,
+}
+
+impl History {
+    const MAX_CHANGES: usize = 100;
+
+    /// Performs the given [`Edit`] in the [`Editor`] and records it.
+    ///
+    /// Consecutive typing is grouped into words.
+    pub fn edit(&mut self, editor: &mut impl Editor, edit: Edit) {
+        let kind = match &edit {
+            Edit::Insert(c) => Kind::Typing {
+                is_whitespace: c.is_whitespace(),
+            },
+            Edit::Backspace => Kind::Backspace,
+            Edit::Delete => Kind::Delete,
+            Edit::Paste(_) | Edit::Enter | Edit::Indent | Edit::Unindent | Edit::Replace { .. } => {
+                Kind::Other
+            }
+        };
+
+        let line_count = editor.line_count();
+        let cursors = editor.cursors();
+
+        let replaced = match &edit {
+            Edit::Replace { ranges, .. } => ranges.as_slice(),
+            _ => &[],
+        };
+
+        let (top, bottom) = cursors
+            .iter()
+            .flat_map(|cursor| [Some(cursor.position), cursor.selection])
+            .flatten()
+            .chain(replaced.iter().flat_map(|range| [range.start, range.end]))
+            .fold((usize::MAX, 0), |(top, bottom), position| {
+                (top.min(position.line), bottom.max(position.line))
+            });
+
+        // Edits may merge the lines next to the cursor
+        let line = top.saturating_sub(1);
+        let last = (bottom + 1).min(line_count.saturating_sub(1));
+
+        let before = Snapshot::new(editor, line, last, cursors);
+
+        editor.perform(Action::Edit(edit));
+
+        let last = (last + editor.line_count())
+            .saturating_sub(line_count)
+            .max(line);
+
+        let after = Snapshot::new(editor, line, last, editor.cursors());
+
+        if before.text == after.text {
+            return;
+        }
+
+        self.redo.clear();
+
+        if !self.is_sealed
+            && let Some(change) = self.undo.back_mut()
+            && change.line == line
+            && change.after == before
+            && kind.continues(change.kind)
+        {
+            change.kind = kind;
+            change.after = after;
+            return;
+        }
+
+        self.undo.push_back(Change {
+            line,
+            kind,
+            before,
+            after,
+        });
+
+        if self.undo.len() > Self::MAX_CHANGES {
+            let _ = self.undo.pop_front();
+        }
+
+        self.is_sealed = false;
+    }
+
+    /// Stops grouping any further edits with the last change.
+    pub fn seal(&mut self) {
+        self.is_sealed = true;
+    }
+
+    /// Reverts the last change in the [`Editor`], if any.
+    pub fn undo(&mut self, editor: &mut impl Editor) {
+        let Some(change) = self.undo.pop_back() else {
+            return;
+        };
+
+        replace(editor, change.line, &change.after, &change.before);
+
+        self.redo.push(change);
+        self.is_sealed = true;
+    }
+
+    /// Reapplies the last reverted change in the [`Editor`], if any.
+    pub fn redo(&mut self, editor: &mut impl Editor) {
+        let Some(change) = self.redo.pop() else {
+            return;
+        };
+
+        replace(editor, change.line, &change.before, &change.after);
+
+        self.undo.push_back(change);
+        self.is_sealed = true;
+    }
+
+    /// Returns whether there is any change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there is any change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+impl Kind {
+    fn continues(self, previous: Self) -> bool {
+        match (previous, self) {
+            (
+                Kind::Typing {
+                    is_whitespace: was_whitespace,
+                },
+                Kind::Typing { is_whitespace },
+            ) => is_whitespace || !was_whitespace,
+            (Kind::Backspace, Kind::Backspace) | (Kind::Delete, Kind::Delete) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Snapshot {
+    fn new(editor: &impl Editor, first: usize, last: usize, cursors: Vec<Cursor>) -> Self {
+        let mut text = String::new();
+
+        for index in first..=last {
+            let Some(line) = editor.line(index) else {
+                break;
+            };
+
+            text.push_str(&line.text);
+
+            if index < last {
+                text.push_str(if line.ending == LineEnding::None {
+                    LineEnding::default().as_str()
+                } else {
+                    line.ending.as_str()
+                });
+            }
+        }
+
+        Self {
+            text: Arc::new(text),
+            lines: last - first + 1,
+            cursors,
+        }
+    }
+}
+
+fn replace(editor: &mut impl Editor, line: usize, from: &Snapshot, to: &Snapshot) {
+    let last = line + from.lines - 1;
+    let column = editor.line(last).map_or(0, |line| line.text.len());
+
+    editor.move_to(Cursor {
+        position: Position { line: last, column },
+        selection: Some(Position { line, column: 0 }),
+    });
+
+    editor.perform(Action::Edit(Edit::Paste(to.text.clone())));
+    editor.move_to_all(&to.cursors);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::text;
+
+    fn type_text(history: &mut History, editor: &mut text::Editor, text: &str) {
+        for c in text.chars() {
+            history.edit(
+                editor,
+                if c == '\n' {
+                    Edit::Enter
+                } else {
+                    Edit::Insert(c)
+                },
+            );
+        }
+    }
+
+    fn contents(editor: &text::Editor) -> String {
+        (0..editor.line_count())
+            .filter_map(|index| editor.line(index))
+            .map(|line| line.text.into_owned())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn undo_groups_typing_into_words() {
+        let mut history = History::default();
+        let mut editor = text::Editor::with_text("");
+
+        type_text(&mut history, &mut editor, "hello world");
+        assert_eq!(contents(&editor), "hello world");
+
+        history.undo(&mut editor);
+        assert_eq!(contents(&editor), "hello ");
+
+        history.undo(&mut editor);
+        assert_eq!(contents(&editor), "");
+        assert!(!history.can_undo());
+
+        history.redo(&mut editor);
+        history.redo(&mut editor);
+        assert_eq!(contents(&editor), "hello world");
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn undo_restores_lines_and_cursor() {
+        let mut history = History::default();
+        let mut editor = text::Editor::with_text("first\nsecond\nthird");
+
+        editor.perform(Action::SelectAll);
+        let cursor = editor.cursor();
+
+        history.edit(&mut editor, Edit::Backspace);
+        history.seal();
+        type_text(&mut history, &mut editor, "a\nb");
+        assert_eq!(contents(&editor), "a\nb");
+
+        while history.can_undo() {
+            history.undo(&mut editor);
+        }
+
+        assert_eq!(contents(&editor), "first\nsecond\nthird");
+        assert_eq!(editor.cursor(), cursor);
+    }
+
+    #[test]
+    fn undo_restores_every_cursor() {
+        let mut history = History::default();
+        let mut editor = text::Editor::with_text("first\nsecond\nthird");
+
+        let at = |line, column| Cursor {
+            position: Position { line, column },
+            selection: None,
+        };
+
+        editor.move_to_all(&[at(0, 0), at(2, 0)]);
+
+        type_text(&mut history, &mut editor, "- ");
+        assert_eq!(contents(&editor), "- first\nsecond\n- third");
+
+        history.undo(&mut editor);
+        assert_eq!(contents(&editor), "first\nsecond\nthird");
+        assert_eq!(editor.cursors(), vec![at(0, 0), at(2, 0)]);
+
+        history.redo(&mut editor);
+        assert_eq!(contents(&editor), "- first\nsecond\n- third");
+        assert_eq!(editor.cursors(), vec![at(0, 2), at(2, 2)]);
+    }
+}
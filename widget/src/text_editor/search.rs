@@ -0,0 +1,169 @@
+use std::ops::Range;
+
+/// A query to find text in a [`Content`](super::Content).
+///
+/// Searches are case sensitive and match partial words by default.
+#[derive(Debug, Clone)]
+pub struct Search {
+    pattern: Pattern,
+    is_case_sensitive: bool,
+    is_whole_word: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Pattern {
+    Text(String),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Search {
+    /// Creates a new [`Search`] for the given plain text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            pattern: Pattern::Text(text.into()),
+            is_case_sensitive: true,
+            is_whole_word: false,
+        }
+    }
+
+    /// Creates a new [`Search`] for the given regular expression.
+    ///
+    /// Matches never span multiple lines, unless the regular expression
+    /// matches new lines explicitly.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            pattern: Pattern::Regex(regex::Regex::new(pattern)?),
+            is_case_sensitive: true,
+            is_whole_word: false,
+        })
+    }
+
+    /// Sets whether the [`Search`] tells uppercase and lowercase letters apart.
+    pub fn case_sensitive(mut self, is_case_sensitive: bool) -> Self {
+        #[cfg(feature = "regex")]
+        if let Pattern::Regex(regex) = &self.pattern
+            && is_case_sensitive != self.is_case_sensitive
+        {
+            self.pattern = Pattern::Regex(
+                regex::RegexBuilder::new(regex.as_str())
+                    .case_insensitive(!is_case_sensitive)
+                    .build()
+                    .expect("Regex should remain valid"),
+            );
+        }
+
+        self.is_case_sensitive = is_case_sensitive;
+        self
+    }
+
+    /// Sets whether the [`Search`] only matches whole words.
+    pub fn whole_word(mut self, is_whole_word: bool) -> Self {
+        self.is_whole_word = is_whole_word;
+        self
+    }
+
+    /// Returns the byte ranges of the matches of the [`Search`] in the
+    /// given text.
+    pub(super) fn matches(&self, text: &str) -> Vec<Range<usize>> {
+        let matches: Box<dyn Iterator<Item = Range<usize>>> = match &self.pattern {
+            Pattern::Text(needle) if needle.is_empty() => return Vec::new(),
+            Pattern::Text(needle) if self.is_case_sensitive => Box::new(
+                text.match_indices(needle.as_str())
+                    .map(|(start, needle)| start..start + needle.len()),
+            ),
+            Pattern::Text(needle) => Box::new(case_insensitive_matches(text, needle)),
+            #[cfg(feature = "regex")]
+            Pattern::Regex(regex) => Box::new(
+                regex
+                    .find_iter(text)
+                    .map(|found| found.range())
+                    .filter(|range| !range.is_empty()),
+            ),
+        };
+
+        matches
+            .filter(|range| !self.is_whole_word || is_whole_word(text, range))
+            .collect()
+    }
+}
+
+fn case_insensitive_matches<'a>(
+    text: &'a str,
+    needle: &'a str,
+) -> impl Iterator<Item = Range<usize>> + 'a {
+    let mut next = 0;
+
+    text.char_indices().filter_map(move |(start, _)| {
+        if start < next {
+            return None;
+        }
+
+        let mut chars = text[start..].char_indices();
+
+        for expected in needle.chars() {
+            let (_, c) = chars.next()?;
+
+            if !c.to_lowercase().eq(expected.to_lowercase()) {
+                return None;
+            }
+        }
+
+        let end = chars.next().map_or(text.len(), |(i, _)| start + i);
+        next = end;
+
+        Some(start..end)
+    })
+}
+
+fn is_whole_word(text: &str, range: &Range<usize>) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    !text[..range.start].chars().next_back().is_some_and(is_word)
+        && !text[range.end..].chars().next().is_some_and(is_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "Foo foo_bar food FOO";
+
+    #[test]
+    fn plain_text_matches() {
+        assert_eq!(Search::new("foo").matches(TEXT), vec![4..7, 12..15]);
+        assert!(Search::new("").matches(TEXT).is_empty());
+    }
+
+    #[test]
+    fn case_insensitive_matches() {
+        assert_eq!(
+            Search::new("foo").case_sensitive(false).matches(TEXT),
+            vec![0..3, 4..7, 12..15, 17..20]
+        );
+    }
+
+    #[test]
+    fn whole_word_matches() {
+        assert_eq!(
+            Search::new("foo")
+                .case_sensitive(false)
+                .whole_word(true)
+                .matches(TEXT),
+            vec![0..3, 17..20]
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_matches() {
+        let search = Search::regex("fo+d?").expect("Valid regex");
+
+        assert_eq!(search.matches(TEXT), vec![4..7, 12..16]);
+        assert_eq!(
+            search.case_sensitive(false).whole_word(true).matches(TEXT),
+            vec![0..3, 12..16, 17..20]
+        );
+    }
+}
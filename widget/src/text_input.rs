@@ -31,14 +31,18 @@
 //! }
 //! ```
 mod editor;
+mod history;
+mod mask;
 mod value;
 
 pub mod cursor;
 
 pub use cursor::Cursor;
+pub use mask::Mask;
 pub use value::Value;
 
-use editor::Editor;
+use editor::{Constraints, Editor};
+use history::History;
 
 use crate::core::alignment;
 use crate::core::clipboard;
@@ -53,6 +57,7 @@ use crate::core::text::{self, Text};
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -111,6 +116,7 @@ where
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    constraints: Constraints<'a>,
     icon: Option<Icon<Renderer::Font>>,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
@@ -142,6 +148,7 @@ where
             on_input: None,
             on_paste: None,
             on_submit: None,
+            constraints: Constraints::default(),
             icon: None,
             class: Theme::default(),
             last_status: None,
@@ -206,6 +213,30 @@ where
         self
     }
 
+    /// Sets the [`Mask`] of the [`TextInput`].
+    ///
+    /// Typed text is formatted with the [`Mask`] and any characters it does
+    /// not accept are dropped.
+    pub fn mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.constraints.mask = Some(mask.into());
+        self
+    }
+
+    /// Sets the filter of the [`TextInput`].
+    ///
+    /// Only the characters for which the filter returns `true` can be typed
+    /// or pasted.
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.constraints.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the maximum amount of characters of the [`TextInput`].
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.constraints.max_length = Some(max_length);
+        self
+    }
+
     /// Sets the [`Font`] of the [`TextInput`].
     ///
     /// [`Font`]: text::Renderer::Font
@@ -267,6 +298,11 @@ where
         self
     }
 
+    /// Replaces the [`Value`] of the [`TextInput`].
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -637,6 +673,21 @@ where
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        let semantics = Semantics::new(semantics::Role::TextInput)
+            .description(self.placeholder.as_str())
+            .focused(state.is_focused())
+            .disabled(self.on_input.is_none());
+
+        operation.semantics(
+            self.id.as_ref(),
+            layout.bounds(),
+            &if self.is_secure {
+                semantics.value(semantics::Value::Secure)
+            } else {
+                semantics.value(self.value.to_string())
+            },
+        );
+
         operation.text_input(self.id.as_ref(), layout.bounds(), state);
         operation.focusable(self.id.as_ref(), layout.bounds(), state);
     }
@@ -865,7 +916,9 @@ where
                                 ));
                             }
 
-                            let mut editor = Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor =
+                                Editor::new(&mut self.value, &mut state.cursor, &mut state.history)
+                                    .constrained(&self.constraints);
                             editor.delete();
 
                             let message = (on_input)(editor.contents());
@@ -894,7 +947,9 @@ where
                                 }
                             };
 
-                            let mut editor = Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor =
+                                Editor::new(&mut self.value, &mut state.cursor, &mut state.history)
+                                    .constrained(&self.constraints);
                             editor.paste(content.clone());
 
                             let message = if let Some(paste) = &self.on_paste {
@@ -909,6 +964,31 @@ where
                             update_cache(state, &self.value);
                             return;
                         }
+                        Some(c @ ('z' | 'y')) if state.keyboard_modifiers.command() => {
+                            let Some(on_input) = &self.on_input else {
+                                return;
+                            };
+
+                            let restored = if c == 'y' || state.keyboard_modifiers.shift() {
+                                state.history.redo(&self.value, state.cursor)
+                            } else {
+                                state.history.undo(&self.value, state.cursor)
+                            };
+
+                            if let Some((value, cursor)) = restored {
+                                self.value = value;
+                                state.cursor = cursor;
+
+                                let message = (on_input)(self.value.to_string());
+                                shell.publish(message);
+
+                                focus.updated_at = Instant::now();
+                                update_cache(state, &self.value);
+                            }
+
+                            shell.capture_event();
+                            return;
+                        }
                         Some('a') if state.keyboard_modifiers.command() => {
                             let cursor_before = state.cursor;
 
@@ -934,7 +1014,9 @@ where
                         state.is_pasting = None;
 
                         if let Some(c) = text.chars().next().filter(|c| !c.is_control()) {
-                            let mut editor = Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor =
+                                Editor::new(&mut self.value, &mut state.cursor, &mut state.history)
+                                    .constrained(&self.constraints);
 
                             editor.insert(c);
 
@@ -977,7 +1059,9 @@ where
                                 }
                             }
 
-                            let mut editor = Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor =
+                                Editor::new(&mut self.value, &mut state.cursor, &mut state.history)
+                                    .constrained(&self.constraints);
                             editor.backspace();
 
                             let message = (on_input)(editor.contents());
@@ -1004,7 +1088,9 @@ where
                                 }
                             }
 
-                            let mut editor = Editor::new(&mut self.value, &mut state.cursor);
+                            let mut editor =
+                                Editor::new(&mut self.value, &mut state.cursor, &mut state.history)
+                                    .constrained(&self.constraints);
                             editor.delete();
 
                             let message = (on_input)(editor.contents());
@@ -1162,7 +1248,9 @@ where
                 {
                     state.is_pasting = Some(Paste::Pasting(Value::new(text)));
 
-                    let mut editor = Editor::new(&mut self.value, &mut state.cursor);
+                    let mut editor =
+                        Editor::new(&mut self.value, &mut state.cursor, &mut state.history)
+                            .constrained(&self.constraints);
                     editor.paste(Value::new(text));
 
                     let message = if let Some(paste) = &self.on_paste {
@@ -1208,7 +1296,9 @@ where
                             return;
                         };
 
-                        let mut editor = Editor::new(&mut self.value, &mut state.cursor);
+                        let mut editor =
+                            Editor::new(&mut self.value, &mut state.cursor, &mut state.history)
+                                .constrained(&self.constraints);
                         editor.paste(Value::new(text));
 
                         focus.updated_at = Instant::now();
@@ -1370,6 +1460,7 @@ pub struct State<P: text::Paragraph> {
     preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
     keyboard_modifiers: keyboard::Modifiers,
     // TODO: Add stateful horizontal scrolling offset
 }
@@ -1,13 +1,98 @@
-use crate::text_input::{Cursor, Value};
+use crate::text_input::history::{self, History};
+use crate::text_input::{Cursor, Mask, Value};
 
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    history: &'a mut History,
+    constraints: Option<&'a Constraints<'a>>,
+}
+
+/// The constraints every edit of a text input must satisfy.
+#[derive(Default)]
+pub struct Constraints<'a> {
+    pub filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
+    pub mask: Option<Mask>,
+    pub max_length: Option<usize>,
+}
+
+impl Constraints<'_> {
+    fn is_empty(&self) -> bool {
+        self.filter.is_none() && self.mask.is_none() && self.max_length.is_none()
+    }
+
+    /// Conforms an edited [`Value`] to the constraints, keeping the
+    /// [`Cursor`] next to the same typed characters.
+    fn apply(&self, value: Value, cursor: Cursor) -> (Value, Cursor) {
+        let index = cursor.end(&value);
+
+        let mut before = value.until(index).to_string();
+        let mut after = value.select(index, value.len()).to_string();
+
+        if let Some(filter) = &self.filter {
+            before.retain(filter);
+            after.retain(filter);
+        }
+
+        let (text, offset) = match &self.mask {
+            Some(mask) => {
+                let (text, cursor) =
+                    mask.format(&format!("{before}{after}"), before.chars().count());
+
+                let offset = text
+                    .char_indices()
+                    .nth(cursor)
+                    .map_or(text.len(), |(offset, _)| offset);
+
+                (text, offset)
+            }
+            None => (format!("{before}{after}"), before.len()),
+        };
+
+        let mut index = Value::new(&text[..offset]).len();
+        let mut value = Value::new(&text);
+
+        if let Some(max_length) = self.max_length
+            && value.len() > max_length
+        {
+            // The excess is likely what was just inserted, right before the cursor
+            let start = index.saturating_sub(value.len() - max_length);
+
+            value.remove_many(start, index);
+            index = start;
+
+            if value.len() > max_length {
+                value.remove_many(max_length, value.len());
+            }
+        }
+
+        let mut cursor = Cursor::default();
+        cursor.move_to(index.min(value.len()));
+
+        (value, cursor)
+    }
 }
 
 impl<'a> Editor<'a> {
-    pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+    pub fn new(
+        value: &'a mut Value,
+        cursor: &'a mut Cursor,
+        history: &'a mut History,
+    ) -> Editor<'a> {
+        Editor {
+            value,
+            cursor,
+            history,
+            constraints: None,
+        }
+    }
+
+    pub fn constrained(mut self, constraints: &'a Constraints<'a>) -> Self {
+        if !constraints.is_empty() {
+            self.constraints = Some(constraints);
+        }
+
+        self
     }
 
     pub fn contents(&self) -> String {
@@ -15,39 +100,69 @@ impl<'a> Editor<'a> {
     }
 
     pub fn insert(&mut self, character: char) {
-        if let Some((left, right)) = self.cursor.selection(self.value) {
-            self.cursor.move_left(self.value);
-            self.value.remove_many(left, right);
+        let mut value = self.value.clone();
+        let mut cursor = *self.cursor;
+
+        if let Some((left, right)) = cursor.selection(&value) {
+            cursor.move_left(&value);
+            value.remove_many(left, right);
         }
 
-        self.value.insert(self.cursor.end(self.value), character);
-        self.cursor.move_right(self.value);
+        value.insert(cursor.end(&value), character);
+        cursor.move_right(&value);
+
+        let _ = self.commit(
+            value,
+            cursor,
+            history::Kind::Typing {
+                is_whitespace: character.is_whitespace(),
+            },
+        );
     }
 
     pub fn paste(&mut self, content: Value) {
+        let mut value = self.value.clone();
+        let mut cursor = *self.cursor;
+
         let length = content.len();
-        if let Some((left, right)) = self.cursor.selection(self.value) {
-            self.cursor.move_left(self.value);
-            self.value.remove_many(left, right);
+        if let Some((left, right)) = cursor.selection(&value) {
+            cursor.move_left(&value);
+            value.remove_many(left, right);
         }
 
-        self.value.insert_many(self.cursor.end(self.value), content);
+        value.insert_many(cursor.end(&value), content);
+        cursor.move_right_by_amount(&value, length);
 
-        self.cursor.move_right_by_amount(self.value, length);
+        let _ = self.commit(value, cursor, history::Kind::Paste);
     }
 
     pub fn backspace(&mut self) {
         match self.cursor.selection(self.value) {
             Some((start, end)) => {
-                self.cursor.move_left(self.value);
-                self.value.remove_many(start, end);
+                let mut value = self.value.clone();
+                let mut cursor = *self.cursor;
+
+                cursor.move_left(&value);
+                value.remove_many(start, end);
+
+                let _ = self.commit(value, cursor, history::Kind::Backspace);
             }
             None => {
-                let start = self.cursor.start(self.value);
+                // Constraints may put a removed character right back (e.g. a
+                // literal of a mask), so we keep going left until something
+                // actually changes.
+                for index in (1..=self.cursor.start(self.value)).rev() {
+                    let mut value = self.value.clone();
+                    let mut cursor = Cursor::default();
+
+                    cursor.move_to(index - 1);
+                    value.remove(index - 1);
 
-                if start > 0 {
-                    self.cursor.move_left(self.value);
-                    self.value.remove(start - 1);
+                    if self.commit(value, cursor, history::Kind::Backspace)
+                        || self.constraints.is_none()
+                    {
+                        break;
+                    }
                 }
             }
         }
@@ -61,10 +176,108 @@ impl<'a> Editor<'a> {
             None => {
                 let end = self.cursor.end(self.value);
 
-                if end < self.value.len() {
-                    self.value.remove(end);
+                for index in end..self.value.len() {
+                    let mut value = self.value.clone();
+                    let mut cursor = Cursor::default();
+
+                    cursor.move_to(end);
+                    value.remove(index);
+
+                    if self.commit(value, cursor, history::Kind::Delete)
+                        || self.constraints.is_none()
+                    {
+                        break;
+                    }
                 }
             }
         }
     }
+
+    /// Replaces the current [`Value`] with an edited one, conforming it to
+    /// the constraints first.
+    ///
+    /// Returns `false` if the constraints rejected the edit entirely.
+    fn commit(&mut self, value: Value, cursor: Cursor, kind: history::Kind) -> bool {
+        let (value, cursor) = match self.constraints {
+            Some(constraints) => constraints.apply(value, cursor),
+            None => (value, cursor),
+        };
+
+        if self.constraints.is_some() && value == *self.value {
+            return false;
+        }
+
+        self.history.record(self.value, *self.cursor, kind);
+
+        *self.value = value;
+        *self.cursor = cursor;
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(
+        constraints: &Constraints<'_>,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        f: impl FnOnce(&mut Editor<'_>),
+    ) {
+        let mut history = History::default();
+
+        f(&mut Editor::new(value, cursor, &mut history).constrained(constraints));
+    }
+
+    #[test]
+    fn masks_keep_the_cursor_next_to_typed_characters() {
+        let constraints = Constraints {
+            mask: Some(Mask::new("(999) 999-9999")),
+            ..Constraints::default()
+        };
+
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&constraints, &mut value, &mut cursor, |editor| {
+            "555x1234".chars().for_each(|c| editor.insert(c));
+        });
+        assert_eq!(value.to_string(), "(555) 123-4");
+        assert_eq!(cursor.end(&value), 11);
+
+        // Backspace skips over literals
+        cursor.move_to(6);
+        edit(&constraints, &mut value, &mut cursor, |editor| {
+            editor.backspace();
+        });
+        assert_eq!(value.to_string(), "(551) 234");
+        assert_eq!(cursor.end(&value), 3);
+    }
+
+    #[test]
+    fn filters_and_max_length_reject_edits() {
+        let constraints = Constraints {
+            filter: Some(Box::new(|c: char| c.is_ascii_hexdigit())),
+            max_length: Some(4),
+            ..Constraints::default()
+        };
+
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        edit(&constraints, &mut value, &mut cursor, |editor| {
+            editor.paste(Value::new("c0ffee"));
+        });
+        assert_eq!(value.to_string(), "c0ff");
+
+        cursor.move_to(1);
+        edit(&constraints, &mut value, &mut cursor, |editor| {
+            editor.insert('z');
+            editor.insert('a');
+        });
+        assert_eq!(value.to_string(), "c0ff");
+        assert_eq!(cursor.end(&value), 1);
+    }
 }
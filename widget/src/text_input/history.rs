@@ -0,0 +1,228 @@
+use crate::text_input::cursor;
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The undo/redo history of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    last: Option<(Kind, usize)>,
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: Value,
+    cursor: Cursor,
+}
+
+/// The kind of an edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Typing { is_whitespace: bool },
+    Backspace,
+    Delete,
+    Paste,
+}
+
+impl History {
+    const MAX_SNAPSHOTS: usize = 100;
+
+    /// Records the [`Value`] and [`Cursor`] right before an edit.
+    ///
+    /// Consecutive typing is grouped into words.
+    pub fn record(&mut self, value: &Value, cursor: Cursor, kind: Kind) {
+        let index = match cursor.state(value) {
+            cursor::State::Index(index) => Some(index),
+            cursor::State::Selection { .. } => None,
+        };
+
+        let continues = match (self.last, kind, index) {
+            (
+                Some((
+                    Kind::Typing {
+                        is_whitespace: was_whitespace,
+                    },
+                    last,
+                )),
+                Kind::Typing { is_whitespace },
+                Some(index),
+            ) => index == last + 1 && (is_whitespace || !was_whitespace),
+            (Some((Kind::Backspace, last)), Kind::Backspace, Some(index)) => index + 1 == last,
+            (Some((Kind::Delete, last)), Kind::Delete, Some(index)) => index == last,
+            _ => false,
+        };
+
+        self.last = index.map(|index| (kind, index));
+        self.redo.clear();
+
+        if continues {
+            return;
+        }
+
+        self.undo.push_back(Snapshot {
+            value: value.clone(),
+            cursor,
+        });
+
+        if self.undo.len() > Self::MAX_SNAPSHOTS {
+            let _ = self.undo.pop_front();
+        }
+    }
+
+    /// Returns the [`Value`] and [`Cursor`] before the last group of edits,
+    /// if any.
+    pub fn undo(&mut self, value: &Value, cursor: Cursor) -> Option<(Value, Cursor)> {
+        let snapshot = self.undo.pop_back()?;
+
+        self.redo.push(Snapshot {
+            value: value.clone(),
+            cursor,
+        });
+        self.last = None;
+
+        Some((snapshot.value, snapshot.cursor))
+    }
+
+    /// Returns the [`Value`] and [`Cursor`] before the last undo, if any.
+    pub fn redo(&mut self, value: &Value, cursor: Cursor) -> Option<(Value, Cursor)> {
+        let snapshot = self.redo.pop()?;
+
+        self.undo.push_back(Snapshot {
+            value: value.clone(),
+            cursor,
+        });
+        self.last = None;
+
+        Some((snapshot.value, snapshot.cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text_input::editor::Editor;
+
+    struct Input {
+        value: Value,
+        cursor: Cursor,
+        history: History,
+    }
+
+    impl Input {
+        fn new() -> Self {
+            Self {
+                value: Value::new(""),
+                cursor: Cursor::default(),
+                history: History::default(),
+            }
+        }
+
+        fn edit(&mut self, f: impl FnOnce(&mut Editor<'_>)) {
+            f(&mut Editor::new(
+                &mut self.value,
+                &mut self.cursor,
+                &mut self.history,
+            ));
+        }
+
+        fn type_text(&mut self, text: &str) {
+            self.edit(|editor| text.chars().for_each(|c| editor.insert(c)));
+        }
+
+        fn undo(&mut self) -> bool {
+            let Some((value, cursor)) = self.history.undo(&self.value, self.cursor) else {
+                return false;
+            };
+
+            self.value = value;
+            self.cursor = cursor;
+
+            true
+        }
+
+        fn redo(&mut self) -> bool {
+            let Some((value, cursor)) = self.history.redo(&self.value, self.cursor) else {
+                return false;
+            };
+
+            self.value = value;
+            self.cursor = cursor;
+
+            true
+        }
+
+        fn contents(&self) -> String {
+            self.value.to_string()
+        }
+    }
+
+    #[test]
+    fn undo_groups_typing_into_words() {
+        let mut input = Input::new();
+
+        input.type_text("hello big world");
+        assert_eq!(input.contents(), "hello big world");
+
+        assert!(input.undo());
+        assert_eq!(input.contents(), "hello big ");
+
+        assert!(input.undo());
+        assert_eq!(input.contents(), "hello ");
+
+        assert!(input.undo());
+        assert_eq!(input.contents(), "");
+        assert!(!input.undo());
+
+        assert!(input.redo());
+        assert!(input.redo());
+        assert!(input.redo());
+        assert_eq!(input.contents(), "hello big world");
+        assert!(!input.redo());
+    }
+
+    #[test]
+    fn undo_groups_backspace_and_delete_runs() {
+        let mut input = Input::new();
+
+        input.type_text("abcdef");
+        input.edit(|editor| {
+            editor.backspace();
+            editor.backspace();
+        });
+        assert_eq!(input.contents(), "abcd");
+
+        input.cursor.move_to(1);
+        input.edit(|editor| {
+            editor.delete();
+            editor.delete();
+        });
+        assert_eq!(input.contents(), "ad");
+
+        assert!(input.undo());
+        assert_eq!(input.contents(), "abcd");
+
+        assert!(input.undo());
+        assert_eq!(input.contents(), "abcdef");
+
+        assert!(input.undo());
+        assert_eq!(input.contents(), "");
+    }
+
+    #[test]
+    fn new_edit_clears_redo() {
+        let mut input = Input::new();
+
+        input.type_text("one two");
+        assert!(input.undo());
+        assert_eq!(input.contents(), "one ");
+
+        input.type_text("three");
+        assert_eq!(input.contents(), "one three");
+        assert!(!input.redo());
+
+        assert!(input.undo());
+        assert_eq!(input.contents(), "one ");
+    }
+}
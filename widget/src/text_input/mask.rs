@@ -0,0 +1,183 @@
+//! Constrain and format the value of a text input with a pattern.
+
+/// A pattern that constrains and formats the value of a [`TextInput`].
+///
+/// Every character of the pattern is either a slot, filled by the user,
+/// or a literal, inserted automatically while typing:
+///
+/// - `9` accepts a digit.
+/// - `a` accepts a letter.
+/// - `*` accepts a letter or a digit.
+/// - `\` turns the next character into a literal.
+///
+/// Any other character is a literal. For instance, `"(999) 999-9999"`
+/// formats a phone number.
+///
+/// [`TextInput`]: super::TextInput
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Literal(_) => false,
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        let mut slots = Vec::new();
+
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '9' => Slot::Digit,
+                'a' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some(c) => Slot::Literal(c),
+                    None => Slot::Literal('\\'),
+                },
+                c => Slot::Literal(c),
+            });
+        }
+
+        Self { slots }
+    }
+
+    /// Formats the given text with the [`Mask`], dropping the characters
+    /// it does not accept.
+    pub fn apply(&self, text: &str) -> String {
+        self.format(text, 0).0
+    }
+
+    /// Returns the characters filling the slots of some masked text,
+    /// without its literals.
+    pub fn unmask(&self, text: &str) -> String {
+        text.chars()
+            .zip(&self.slots)
+            .filter(|(_, slot)| !matches!(slot, Slot::Literal(_)))
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// Returns whether some masked text fills every slot of the [`Mask`].
+    pub fn is_complete(&self, text: &str) -> bool {
+        let filled = self.apply(text);
+
+        filled == text && filled.chars().count() == self.slots.len()
+    }
+
+    /// Formats the given text, returning it along with the new position of
+    /// the cursor, in characters.
+    ///
+    /// The cursor stays right after the same amount of typed characters.
+    pub(super) fn format(&self, text: &str, cursor: usize) -> (String, usize) {
+        let mut output = Vec::with_capacity(self.slots.len());
+        let mut slots = self.slots.iter().copied().peekable();
+        let mut filled = 0;
+        let mut typed = 0;
+
+        'text: for (i, c) in text.chars().enumerate() {
+            while let Some(slot) = slots.peek().copied() {
+                match slot {
+                    Slot::Literal(literal) => {
+                        output.push((literal, false));
+                        let _ = slots.next();
+
+                        if literal == c {
+                            continue 'text;
+                        }
+                    }
+                    slot if slot.accepts(c) => {
+                        output.push((c, true));
+                        let _ = slots.next();
+
+                        filled = output.len();
+
+                        if i < cursor {
+                            typed += 1;
+                        }
+
+                        continue 'text;
+                    }
+                    _ => continue 'text,
+                }
+            }
+
+            break;
+        }
+
+        output.truncate(filled);
+
+        let cursor = output
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, is_typed))| *is_typed)
+            .nth(typed)
+            .map_or(output.len(), |(i, _)| i);
+
+        (output.into_iter().map(|(c, _)| c).collect(), cursor)
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_inserts_literals() {
+        let mask = Mask::new("(999) 999-9999");
+
+        assert_eq!(mask.apply("5551234567"), "(555) 123-4567");
+        assert_eq!(mask.apply("555"), "(555");
+        assert_eq!(mask.apply("(555) 12"), "(555) 12");
+        assert_eq!(mask.apply("55a5x1"), "(555) 1");
+        assert_eq!(mask.apply("555123456789"), "(555) 123-4567");
+    }
+
+    #[test]
+    fn it_keeps_the_cursor_after_typed_characters() {
+        let mask = Mask::new("(999) 999-9999");
+
+        // Typing right before a literal jumps over it
+        assert_eq!(mask.format("(5554", 5), ("(555) 4".to_owned(), 7));
+
+        // Inserting in the middle shifts the rest
+        assert_eq!(mask.format("(1234) 567", 5), ("(123) 456-7".to_owned(), 7));
+
+        // Removing in the middle pulls the rest back
+        assert_eq!(mask.format("(12) 45", 3), ("(124) 5".to_owned(), 3));
+    }
+
+    #[test]
+    fn it_unmasks_and_completes() {
+        let mask = Mask::new("aa-\\9*");
+
+        assert_eq!(mask.apply("abc"), "ab-9c");
+        assert_eq!(mask.unmask("ab-9c"), "abc");
+        assert!(mask.is_complete("ab-9c"));
+        assert!(!mask.is_complete("ab-9"));
+    }
+}
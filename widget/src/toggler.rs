@@ -38,6 +38,7 @@ use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -82,6 +83,7 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<widget::Id>,
     is_toggled: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: Option<text::Fragment<'a>>,
@@ -116,6 +118,7 @@ where
     ///     `Message`.
     pub fn new(is_toggled: bool) -> Self {
         Toggler {
+            id: None,
             is_toggled,
             on_toggle: None,
             label: None,
@@ -133,6 +136,12 @@ where
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Toggler`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the label of the [`Toggler`].
     pub fn label(mut self, label: impl text::IntoFragment<'a>) -> Self {
         self.label = Some(label.into_fragment());
@@ -310,6 +319,30 @@ where
         )
     }
 
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let mut semantics = Semantics::new(semantics::Role::Switch)
+            .toggled(self.is_toggled)
+            .disabled(self.on_toggle.is_none());
+
+        if let Some(label) = self.label.as_deref() {
+            semantics = semantics.label(label);
+        }
+
+        operation.semantics(self.id.as_ref(), layout.bounds(), &semantics);
+
+        if let Some(label) = self.label.as_deref() {
+            operation.traverse(&mut |operation| {
+                operation.text(None, layout.bounds(), label);
+            });
+        }
+    }
+
     fn update(
         &mut self,
         _tree: &mut Tree,
@@ -411,6 +444,7 @@ where
                 state.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -0,0 +1,1377 @@
+//! Tree views display hierarchical data, like the files of a project.
+//!
+//! The children of a node are only requested once the node is expanded, so
+//! large or expensive hierarchies can be browsed lazily. Nodes can be selected
+//! with a click or with the arrow keys, and expanded or collapsed with their
+//! disclosure arrow, a double click, or the left and right arrow keys.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{text, tree_view};
+//! use iced::widget::tree_view::Node;
+//!
+//! use std::path::PathBuf;
+//!
+//! struct State {
+//!     root: PathBuf,
+//!     selected: Option<PathBuf>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Select(PathBuf),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     tree_view([state.root.clone()], |path: &PathBuf| {
+//!         let name = path.file_name().unwrap_or_default().to_string_lossy();
+//!         let node = Node::new(text(name.into_owned()));
+//!
+//!         if path.is_dir() {
+//!             let path = path.clone();
+//!
+//!             // Only read when the directory is expanded
+//!             node.children(move || {
+//!                 std::fs::read_dir(path)
+//!                     .into_iter()
+//!                     .flatten()
+//!                     .flatten()
+//!                     .map(|entry| entry.path())
+//!             })
+//!         } else {
+//!             node
+//!         }
+//!     })
+//!     .selected(|path| state.selected.as_ref() == Some(path))
+//!     .on_select(Message::Select)
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Select(path) => {
+//!             state.selected = Some(path);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::scrollable::AbsoluteOffset;
+use crate::core::widget::operation::semantics::{self, Semantics};
+use crate::core::widget::operation::{Focusable, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Color, Element, Event, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Theme, Vector, Widget,
+};
+use crate::scrollable::{self, Scrollable};
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+
+use std::any::Any;
+use std::hash::{Hash, Hasher as _};
+
+const DEFAULT_PADDING: Padding = Padding {
+    top: 2.0,
+    bottom: 2.0,
+    right: 6.0,
+    left: 2.0,
+};
+
+/// A list of hierarchical [`Node`]s that can be expanded and collapsed.
+///
+/// Every node is identified by a `Key`, which must be unique in the whole
+/// [`TreeView`]. The `view` closure turns a `Key` into a [`Node`], and the
+/// children of a [`Node`] are only requested while it is expanded.
+///
+/// By default, the [`TreeView`] keeps track of the expanded nodes on its own.
+/// This can be overridden with [`TreeView::expanded`].
+///
+/// The rows of a [`TreeView`] are displayed inside a vertical [`Scrollable`],
+/// which follows the focused row during keyboard navigation.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{text, tree_view};
+/// use iced::widget::tree_view::Node;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(u32),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tree_view([1], |n: &u32| {
+///         let n = *n;
+///
+///         Node::new(text(n)).children(move || [n * 10, n * 10 + 1])
+///     })
+///     .on_select(Message::Select)
+///     .into()
+/// }
+/// ```
+pub struct TreeView<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+{
+    id: Option<widget::Id>,
+    roots: Vec<Key>,
+    view: Box<dyn Fn(&Key) -> Node<'a, Key, Message, Theme, Renderer> + 'a>,
+    is_expanded: Option<Box<dyn Fn(&Key) -> bool + 'a>>,
+    is_selected: Option<Box<dyn Fn(&Key) -> bool + 'a>>,
+    on_select: Option<OnSelect<'a, Key, Message>>,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    indent: f32,
+    padding: Padding,
+    class: Theme::Class<'a>,
+}
+
+enum OnSelect<'a, Key, Message> {
+    Single(Box<dyn Fn(Key) -> Message + 'a>),
+    Multiple(Box<dyn Fn(Selection<Key>) -> Message + 'a>),
+}
+
+impl<'a, Key, Message, Theme, Renderer> TreeView<'a, Key, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`TreeView`] with the given root keys and a closure
+    /// that produces the [`Node`] of a key.
+    pub fn new(
+        roots: impl IntoIterator<Item = Key>,
+        view: impl Fn(&Key) -> Node<'a, Key, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            roots: roots.into_iter().collect(),
+            view: Box::new(view),
+            is_expanded: None,
+            is_selected: None,
+            on_select: None,
+            on_toggle: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            indent: 16.0,
+            padding: DEFAULT_PADDING,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`TreeView`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the closure that decides whether the [`Node`] of a key is expanded.
+    ///
+    /// When set, the [`TreeView`] stops tracking the expanded nodes on its
+    /// own and it only produces [`on_toggle`](Self::on_toggle) messages.
+    pub fn expanded(mut self, is_expanded: impl Fn(&Key) -> bool + 'a) -> Self {
+        self.is_expanded = Some(Box::new(is_expanded));
+        self
+    }
+
+    /// Sets the closure that decides whether the [`Node`] of a key is selected.
+    pub fn selected(mut self, is_selected: impl Fn(&Key) -> bool + 'a) -> Self {
+        self.is_selected = Some(Box::new(is_selected));
+        self
+    }
+
+    /// Sets the message that should be produced when a single [`Node`]
+    /// is selected.
+    pub fn on_select(mut self, on_select: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_select = Some(OnSelect::Single(Box::new(on_select)));
+        self
+    }
+
+    /// Enables the selection of multiple [`Node`]s and sets the message that
+    /// should be produced when the [`Selection`] changes.
+    ///
+    /// Holding the command key toggles a [`Node`], while holding shift
+    /// selects a range of [`Node`]s.
+    pub fn on_select_many(mut self, on_select: impl Fn(Selection<Key>) -> Message + 'a) -> Self {
+        self.on_select = Some(OnSelect::Multiple(Box::new(on_select)));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is expanded
+    /// or collapsed.
+    ///
+    /// The closure receives the key of the [`Node`] and whether it is now expanded.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(Key, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal space that each level of the [`TreeView`] is indented by.
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of each row of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TreeView`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// A node of a [`TreeView`].
+pub struct Node<'a, Key, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    label: Element<'a, Message, Theme, Renderer>,
+    children: Option<Box<dyn FnOnce() -> Vec<Key> + 'a>>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Node<'a, Key, Message, Theme, Renderer> {
+    /// Creates a new [`Node`] without children and with the given label.
+    pub fn new(label: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            label: label.into(),
+            children: None,
+        }
+    }
+
+    /// Sets the closure that produces the keys of the children of the [`Node`].
+    ///
+    /// The closure is only called while the [`Node`] is expanded. A [`Node`]
+    /// with children can be expanded even if the closure produces no keys.
+    pub fn children<I>(mut self, children: impl FnOnce() -> I + 'a) -> Self
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        self.children = Some(Box::new(move || children().into_iter().collect()));
+        self
+    }
+}
+
+/// A change to the selection of a [`TreeView`] with multiple selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection<Key> {
+    /// Only the given key should be selected.
+    Only(Key),
+    /// The given key should be added to or removed from the selection.
+    Toggle(Key),
+    /// Only the given keys should be selected.
+    ///
+    /// They are the visible keys between the last selected key and the
+    /// new one, in order.
+    Range(Vec<Key>),
+}
+
+/// A visible row of a [`TreeView`].
+struct Row<'a, Key, Message, Theme, Renderer> {
+    key: Key,
+    hash: u64,
+    depth: usize,
+    is_expanded: Option<bool>,
+    is_selected: bool,
+    label: Element<'a, Message, Theme, Renderer>,
+}
+
+/// The rows of a [`TreeView`], displayed inside a [`Scrollable`].
+struct Rows<'a, Key, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    id: Option<widget::Id>,
+    roots: Vec<Key>,
+    view: Box<dyn Fn(&Key) -> Node<'a, Key, Message, Theme, Renderer> + 'a>,
+    is_expanded: Option<Box<dyn Fn(&Key) -> bool + 'a>>,
+    is_selected: Option<Box<dyn Fn(&Key) -> bool + 'a>>,
+    on_select: Option<OnSelect<'a, Key, Message>>,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    width: Length,
+    indent: f32,
+    padding: Padding,
+    class: Theme::Class<'a>,
+    rows: Vec<Row<'a, Key, Message, Theme, Renderer>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_focused: bool,
+    /// The expanded nodes, unless they are controlled externally.
+    expanded: FxHashSet<u64>,
+    /// The row that is moved by the keyboard.
+    cursor: Option<u64>,
+    /// The row where a range selection starts.
+    anchor: Option<u64>,
+    /// Whether the cursor should be scrolled into view.
+    reveal: bool,
+    hovered: Option<Hover>,
+    modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+    keys: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hover {
+    index: usize,
+    is_over_toggle: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+fn hash<Key: Hash>(key: &Key) -> u64 {
+    let mut hasher = FxHasher::default();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<'a, Key, Message, Theme, Renderer> Rows<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Hash,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn flatten(
+        &self,
+        keys: Vec<Key>,
+        depth: usize,
+        expanded: &FxHashSet<u64>,
+        rows: &mut Vec<Row<'a, Key, Message, Theme, Renderer>>,
+    ) {
+        for key in keys {
+            let node = (self.view)(&key);
+            let hash = hash(&key);
+
+            let is_expanded = node.children.as_ref().map(|_| match &self.is_expanded {
+                Some(is_expanded) => is_expanded(&key),
+                None => expanded.contains(&hash),
+            });
+
+            let children = node
+                .children
+                .filter(|_| is_expanded == Some(true))
+                .map(|children| children());
+
+            rows.push(Row {
+                is_selected: self
+                    .is_selected
+                    .as_ref()
+                    .is_some_and(|is_selected| is_selected(&key)),
+                key,
+                hash,
+                depth,
+                is_expanded,
+                label: node.label,
+            });
+
+            if let Some(children) = children {
+                self.flatten(children, depth + 1, expanded, rows);
+            }
+        }
+    }
+
+    fn position(&self, hash: u64) -> Option<usize> {
+        self.rows.iter().position(|row| row.hash == hash)
+    }
+
+    fn parent(&self, index: usize) -> Option<usize> {
+        let depth = self.rows[index].depth;
+
+        self.rows[..index].iter().rposition(|row| row.depth < depth)
+    }
+
+    fn hover(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<Hover> {
+        let (index, row) = layout
+            .children()
+            .enumerate()
+            .find(|(_, row)| cursor.is_over(row.bounds()))?;
+
+        let is_over_toggle = self.rows[index].is_expanded.is_some()
+            && row
+                .children()
+                .nth(1)
+                .is_some_and(|toggle| cursor.is_over(toggle.bounds()));
+
+        Some(Hover {
+            index,
+            is_over_toggle,
+        })
+    }
+
+    fn select(
+        &self,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let row = &self.rows[index];
+
+        match &self.on_select {
+            Some(OnSelect::Single(on_select)) if !row.is_selected => {
+                shell.publish(on_select(row.key.clone()));
+            }
+            Some(OnSelect::Multiple(on_select)) => {
+                if modifiers.shift()
+                    && let Some(anchor) = state.anchor.and_then(|anchor| self.position(anchor))
+                {
+                    let range = anchor.min(index)..=anchor.max(index);
+
+                    shell.publish(on_select(Selection::Range(
+                        self.rows[range].iter().map(|row| row.key.clone()).collect(),
+                    )));
+
+                    return;
+                }
+
+                shell.publish(on_select(if modifiers.command() {
+                    Selection::Toggle(row.key.clone())
+                } else {
+                    Selection::Only(row.key.clone())
+                }));
+            }
+            Some(OnSelect::Single(_)) | None => {}
+        }
+
+        state.anchor = Some(row.hash);
+    }
+
+    fn toggle(&self, index: usize, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let row = &self.rows[index];
+
+        let Some(is_expanded) = row.is_expanded else {
+            return;
+        };
+
+        // The cursor cannot stay inside a collapsed node
+        if is_expanded
+            && let Some(cursor) = state.cursor.and_then(|cursor| self.position(cursor))
+            && cursor > index
+            && self.rows[index + 1..=cursor]
+                .iter()
+                .all(|descendant| descendant.depth > row.depth)
+        {
+            state.cursor = Some(row.hash);
+        }
+
+        if self.is_expanded.is_none() {
+            if is_expanded {
+                let _ = state.expanded.remove(&row.hash);
+            } else {
+                let _ = state.expanded.insert(row.hash);
+            }
+
+            shell.invalidate_layout();
+        }
+
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(row.key.clone(), !is_expanded));
+        }
+
+        shell.request_redraw();
+    }
+}
+
+impl<Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Rows<'_, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Hash,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // Diff is deferred to layout, once the expanded rows are known
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+        let limits = limits.width(self.width);
+        let max = limits.max();
+
+        let mut rows = Vec::new();
+        self.flatten(self.roots.clone(), 0, &state.expanded, &mut rows);
+        self.rows = rows;
+
+        let mut trees: FxHashMap<u64, Tree> =
+            state.keys.drain(..).zip(children.drain(..)).collect();
+
+        let offset = |depth: usize| self.padding.left + (depth + 1) as f32 * self.indent;
+
+        let labels: Vec<_> = self
+            .rows
+            .iter_mut()
+            .map(|row| {
+                let mut tree = match trees.remove(&row.hash) {
+                    Some(mut tree) => {
+                        tree.diff(&row.label);
+                        tree
+                    }
+                    None => Tree::new(&row.label),
+                };
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (max.width - offset(row.depth) - self.padding.right).max(0.0),
+                        f32::INFINITY,
+                    ),
+                );
+
+                let label = row
+                    .label
+                    .as_widget_mut()
+                    .layout(&mut tree, renderer, &limits);
+
+                state.keys.push(row.hash);
+                children.push(tree);
+
+                label
+            })
+            .collect();
+
+        let width = self
+            .rows
+            .iter()
+            .zip(&labels)
+            .map(|(row, label)| offset(row.depth) + label.size().width + self.padding.right)
+            .fold(0.0, f32::max);
+
+        let height = labels
+            .iter()
+            .map(|label| label.size().height + self.padding.y())
+            .sum();
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(width, height));
+        let mut y = 0.0;
+
+        let nodes = self
+            .rows
+            .iter()
+            .zip(labels)
+            .map(|(row, label)| {
+                let height = label.size().height;
+                let x = offset(row.depth);
+
+                let toggle = layout::Node::new(Size::new(self.indent, height))
+                    .move_to(Point::new(x - self.indent, self.padding.top));
+
+                let node = layout::Node::with_children(
+                    Size::new(size.width, height + self.padding.y()),
+                    vec![label.move_to(Point::new(x, self.padding.top)), toggle],
+                )
+                .move_to(Point::new(0.0, y));
+
+                y += node.size().height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.semantics(
+            self.id.as_ref(),
+            layout.bounds(),
+            &Semantics::new(semantics::Role::Tree).focused(state.is_focused),
+        );
+
+        operation.traverse(&mut |operation| {
+            for ((row, tree), layout) in self
+                .rows
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                let mut semantics =
+                    Semantics::new(semantics::Role::TreeItem).selected(row.is_selected);
+
+                if let Some(is_expanded) = row.is_expanded {
+                    semantics = semantics.expanded(is_expanded);
+                }
+
+                operation.semantics(None, layout.bounds(), &semantics);
+
+                operation.traverse(&mut |operation| {
+                    row.label.as_widget_mut().operate(
+                        tree,
+                        layout.children().next().expect("Row must have a label"),
+                        renderer,
+                        operation,
+                    );
+                });
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((row, tree), layout) in self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            row.label.as_widget_mut().update(
+                tree,
+                event,
+                layout.children().next().expect("Row must have a label"),
+                cursor,
+                renderer,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let hovered = self.hover(layout, cursor);
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(hover) = hovered else {
+                    if state.is_focused {
+                        state.is_focused = false;
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                state.is_focused = true;
+
+                if hover.is_over_toggle {
+                    self.toggle(hover.index, state, shell);
+                } else {
+                    let click = mouse::Click::new(
+                        cursor.position().unwrap_or_default(),
+                        mouse::Button::Left,
+                        state.last_click,
+                    );
+
+                    state.last_click = Some(click);
+                    state.cursor = Some(self.rows[hover.index].hash);
+
+                    self.select(hover.index, state.modifiers, state, shell);
+
+                    if click.kind() == mouse::click::Kind::Double {
+                        self.toggle(hover.index, state, shell);
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft)
+                if state.hovered != hovered =>
+            {
+                state.hovered = hovered;
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if state.is_focused =>
+            {
+                let count = self.rows.len();
+
+                if count == 0 {
+                    return;
+                }
+
+                let current = state
+                    .cursor
+                    .and_then(|cursor| self.position(cursor))
+                    .or_else(|| self.rows.iter().position(|row| row.is_selected));
+
+                let target = match (key.as_ref(), current) {
+                    (keyboard::Key::Named(key::Named::ArrowUp), Some(index)) => {
+                        index.saturating_sub(1)
+                    }
+                    (keyboard::Key::Named(key::Named::ArrowDown), Some(index)) => {
+                        (index + 1).min(count - 1)
+                    }
+                    (
+                        keyboard::Key::Named(key::Named::ArrowUp | key::Named::ArrowDown)
+                        | keyboard::Key::Named(key::Named::Home),
+                        _,
+                    ) => 0,
+                    (keyboard::Key::Named(key::Named::End), _) => count - 1,
+                    (keyboard::Key::Named(key::Named::ArrowRight), Some(index)) => {
+                        match self.rows[index].is_expanded {
+                            Some(false) => {
+                                self.toggle(index, state, shell);
+                                shell.capture_event();
+
+                                return;
+                            }
+                            Some(true)
+                                if self
+                                    .rows
+                                    .get(index + 1)
+                                    .is_some_and(|next| next.depth > self.rows[index].depth) =>
+                            {
+                                index + 1
+                            }
+                            _ => return,
+                        }
+                    }
+                    (keyboard::Key::Named(key::Named::ArrowLeft), Some(index)) => {
+                        if self.rows[index].is_expanded == Some(true) {
+                            self.toggle(index, state, shell);
+                            shell.capture_event();
+
+                            return;
+                        }
+
+                        let Some(parent) = self.parent(index) else {
+                            return;
+                        };
+
+                        parent
+                    }
+                    (keyboard::Key::Named(key::Named::Enter | key::Named::Space), Some(index)) => {
+                        self.select(index, *modifiers, state, shell);
+                        shell.capture_event();
+
+                        return;
+                    }
+                    _ => return,
+                };
+
+                state.cursor = Some(self.rows[target].hash);
+                state.reveal = true;
+
+                // Holding the command key moves the cursor of a multiple
+                // selection without changing it
+                if !(modifiers.command() && matches!(self.on_select, Some(OnSelect::Multiple(_)))) {
+                    self.select(target, *modifiers, state, shell);
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self
+            .hover(layout, cursor)
+            .is_some_and(|hover| hover.is_over_toggle)
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, tree), layout)| {
+                row.label.as_widget().mouse_interaction(
+                    tree,
+                    layout.children().next().expect("Row must have a label"),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let guide = theme.style(&self.class, Status::Active).guide;
+
+        for (index, ((row, tree), layout)) in self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .filter(|(_, (_, layout))| layout.bounds().intersects(viewport))
+        {
+            let bounds = layout.bounds();
+
+            let status = if row.is_selected {
+                Status::Selected {
+                    is_focused: state.is_focused,
+                }
+            } else if state.hovered.is_some_and(|hover| hover.index == index)
+                || (state.is_focused && state.cursor == Some(row.hash))
+            {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = theme.style(&self.class, status);
+
+            if style.background.is_some() || style.border.width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            if let Some(guide) = guide {
+                for level in 0..row.depth {
+                    let x = bounds.x + self.padding.left + (level as f32 + 0.5) * self.indent;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x.floor(),
+                                width: 1.0,
+                                ..bounds
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        guide,
+                    );
+                }
+            }
+
+            let mut children = layout.children();
+            let label = children.next().expect("Row must have a label");
+
+            row.label.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                label,
+                cursor,
+                viewport,
+            );
+
+            if let Some(is_expanded) = row.is_expanded
+                && let Some(toggle) = children.next()
+            {
+                let toggle = toggle.bounds();
+
+                renderer.fill_text(
+                    Text {
+                        content: if is_expanded {
+                            Renderer::SCROLL_DOWN_ICON
+                        } else {
+                            Renderer::SCROLL_RIGHT_ICON
+                        }
+                        .to_string(),
+                        bounds: toggle.size(),
+                        size: renderer.default_size() * 0.75,
+                        line_height: text::LineHeight::default(),
+                        font: Renderer::ICON_FONT,
+                        align_x: text::Alignment::Center,
+                        align_y: crate::core::alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                        ellipsis: text::Ellipsis::default(),
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    toggle.center(),
+                    style.text_color,
+                    *viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((row, tree), layout)| {
+                row.label.as_widget_mut().overlay(
+                    tree,
+                    layout.children().next().expect("Row must have a label"),
+                    renderer,
+                    viewport,
+                    translation,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+/// The [`Scrollable`] of a [`TreeView`].
+///
+/// It scrolls the cursor of the [`TreeView`] into view whenever it is
+/// moved with the keyboard.
+struct Viewport<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<Message, Theme, Renderer> Viewport<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn reveal(&mut self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) -> bool {
+        struct Find {
+            index: Option<usize>,
+        }
+
+        impl Operation for Find {
+            fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+                if self.index.is_none() {
+                    operate(self);
+                }
+            }
+
+            fn custom(
+                &mut self,
+                _id: Option<&widget::Id>,
+                _bounds: Rectangle,
+                state: &mut dyn Any,
+            ) {
+                if let Some(state) = state.downcast_mut::<State>()
+                    && std::mem::take(&mut state.reveal)
+                {
+                    self.index = state
+                        .cursor
+                        .and_then(|cursor| state.keys.iter().position(|key| *key == cursor));
+                }
+            }
+        }
+
+        struct Reveal {
+            target: Rectangle,
+        }
+
+        impl Operation for Reveal {
+            fn traverse(&mut self, _operate: &mut dyn FnMut(&mut dyn Operation)) {}
+
+            fn scrollable(
+                &mut self,
+                _id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+                translation: Vector,
+                state: &mut dyn widget::operation::Scrollable,
+            ) {
+                let top = bounds.y + translation.y;
+                let bottom = top + bounds.height;
+
+                let y = if self.target.y < top {
+                    self.target.y - top
+                } else if self.target.y + self.target.height > bottom {
+                    (self.target.y + self.target.height - bottom).min(self.target.y - top)
+                } else {
+                    return;
+                };
+
+                state.scroll_by(AbsoluteOffset { x: 0.0, y }, bounds, content_bounds);
+            }
+        }
+
+        let mut find = Find { index: None };
+
+        self.content
+            .as_widget_mut()
+            .operate(tree, layout, renderer, &mut find);
+
+        let Some(row) = find.index.and_then(|index| {
+            layout
+                .children()
+                .next()
+                .and_then(|rows| rows.children().nth(index))
+        }) else {
+            return false;
+        };
+
+        self.content.as_widget_mut().operate(
+            tree,
+            layout,
+            renderer,
+            &mut Reveal {
+                target: row.bounds(),
+            },
+        );
+
+        true
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Viewport<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event
+            && self.reveal(&mut tree.children[0], layout, renderer)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<TreeView<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + Hash + 'a,
+    Message: 'a,
+    Theme: Catalog + scrollable::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tree_view: TreeView<'a, Key, Message, Theme, Renderer>) -> Self {
+        let rows = Rows {
+            id: tree_view.id,
+            roots: tree_view.roots,
+            view: tree_view.view,
+            is_expanded: tree_view.is_expanded,
+            is_selected: tree_view.is_selected,
+            on_select: tree_view.on_select,
+            on_toggle: tree_view.on_toggle,
+            width: tree_view.width,
+            indent: tree_view.indent,
+            padding: tree_view.padding,
+            class: tree_view.class,
+            rows: Vec::new(),
+        };
+
+        Element::new(Viewport {
+            content: Scrollable::new(Element::new(rows))
+                .width(tree_view.width)
+                .height(tree_view.height)
+                .into(),
+        })
+    }
+}
+
+/// The possible status of a row of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The row is not selected.
+    Active,
+    /// The row is not selected and it is being hovered or focused.
+    Hovered,
+    /// The row is selected.
+    Selected {
+        /// Whether the [`TreeView`] is focused.
+        is_focused: bool,
+    },
+}
+
+/// The appearance of a row of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the row.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the row.
+    pub text_color: Color,
+    /// The [`Border`] of the row.
+    pub border: Border,
+    /// The [`Color`] of the indentation guides, if any.
+    ///
+    /// Only the [`Style`] of [`Status::Active`] is used to draw them.
+    pub guide: Option<Color>,
+}
+
+/// The theme catalog of a [`TreeView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`TreeView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`TreeView`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.palette();
+
+    let base = Style {
+        background: None,
+        text_color: palette.background.base.text,
+        border: border::rounded(2),
+        guide: Some(palette.background.strong.color.scale_alpha(0.5)),
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            background: Some(palette.background.weak.color.into()),
+            text_color: palette.background.weak.text,
+            ..base
+        },
+        Status::Selected { is_focused: true } => Style {
+            background: Some(palette.primary.base.color.into()),
+            text_color: palette.primary.base.text,
+            ..base
+        },
+        Status::Selected { is_focused: false } => Style {
+            background: Some(palette.background.strong.color.into()),
+            text_color: palette.background.strong.text,
+            ..base
+        },
+    }
+}
@@ -39,6 +39,8 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::semantics::{self, Semantics};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{self, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, Widget};
@@ -240,6 +242,25 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        operation.semantics(
+            None,
+            layout.bounds(),
+            &Semantics::new(semantics::Role::Slider).value(semantics::Value::Range {
+                value: self.value.into(),
+                min: (*self.range.start()).into(),
+                max: (*self.range.end()).into(),
+                step: self.step.into(),
+            }),
+        );
+    }
+
     fn update(
         &mut self,
         tree: &mut Tree,
@@ -0,0 +1,859 @@
+//! Display very large lists of items by only building the visible ones.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{scrollable, text, virtual_list};
+//!
+//! struct State {
+//!    entries: Vec<String>,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     scrollable(
+//!         virtual_list(state.entries.len(), |i| text(&state.entries[i]).into())
+//!             .row_height(20)
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::widget::operation::scrollable::{self, AbsoluteOffset, RelativeOffset};
+use crate::core::widget::operation::{Operation, Outcome};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use rustc_hash::{FxHashMap, FxHasher};
+
+use std::any::Any;
+use std::hash::{Hash, Hasher as _};
+
+/// The amount of pixels above and below the viewport that will be
+/// populated with rows, to reduce the amount of rebuilds while scrolling.
+const OVERSCAN: f32 = 200.0;
+
+/// The height of the viewport assumed before the actual one is known
+/// (e.g. during the first layout inside a [`Scrollable`](crate::Scrollable)).
+///
+/// It is tall enough to fill most screens.
+const INITIAL_VIEWPORT_HEIGHT: f32 = 1080.0;
+
+/// The distance the viewport can be above an anchored row before the
+/// anchor is dropped, to absorb rounding errors.
+const ANCHOR_TOLERANCE: f32 = 0.5;
+
+/// A list that only builds and lays out the rows that are visible.
+///
+/// A [`VirtualList`] is meant to be placed inside a vertical
+/// [`Scrollable`](crate::Scrollable). It will report the total height of all of
+/// its rows, but it will only call its `view` closure for the rows intersecting
+/// the viewport of the [`Scrollable`](crate::Scrollable).
+///
+/// Rows can either have a [fixed height](Self::row_height) or an
+/// [estimated height](Self::estimated_row_height), in which case the actual
+/// heights will be measured lazily once the rows are built.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{scrollable, text, virtual_list};
+///
+/// struct State {
+///    entries: Vec<String>,
+/// }
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     scrollable(
+///         virtual_list(state.entries.len(), |i| text(&state.entries[i]).into())
+///             .row_height(20)
+///     )
+///     .into()
+/// }
+/// ```
+pub struct VirtualList<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    id: Option<widget::Id>,
+    count: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    key: Option<Box<dyn Fn(usize) -> u64 + 'a>>,
+    row_height: RowHeight,
+    width: Length,
+    spacing: f32,
+    first: usize,
+    rows: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// The height strategy of the rows of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+    /// Every row has exactly the given height.
+    Fixed(f32),
+    /// Rows are expected to have roughly the given height.
+    ///
+    /// The actual height of a row will be measured once it is built.
+    Estimated(f32),
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        Self::Estimated(20.0)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`VirtualList`] with the given amount of rows and a
+    /// closure that produces the row at a given index.
+    pub fn new(
+        count: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            count,
+            view: Box::new(view),
+            key: None,
+            row_height: RowHeight::default(),
+            width: Length::Fill,
+            spacing: 0.0,
+            first: 0,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`VirtualList`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the closure that produces the key of the row at a given index.
+    ///
+    /// The state of a row is kept around as long as its key does not change
+    /// and the row stays visible. By default, rows are keyed by their index.
+    pub fn key<K>(mut self, key: impl Fn(usize) -> K + 'a) -> Self
+    where
+        K: Hash,
+    {
+        self.key = Some(Box::new(move |index| {
+            let mut hasher = FxHasher::default();
+            key(index).hash(&mut hasher);
+            hasher.finish()
+        }));
+        self
+    }
+
+    /// Makes every row of the [`VirtualList`] have the given fixed height.
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Fixed(height.into().0);
+        self
+    }
+
+    /// Sets the estimated height of the rows of the [`VirtualList`].
+    ///
+    /// The actual heights will be measured lazily, as the rows are built.
+    pub fn estimated_row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Estimated(height.into().0);
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    fn key_of(&self, index: usize) -> u64 {
+        match &self.key {
+            Some(key) => key(index),
+            None => index as u64,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    keys: Vec<u64>,
+    viewport: Option<Rectangle>,
+    heights: Heights,
+    /// The row that was last scrolled to, which is kept at its offset
+    /// until the viewport moves above it.
+    anchor: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualList<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // Diff is deferred to layout
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+        let limits = limits.width(self.width);
+        let max = limits.max();
+
+        state
+            .heights
+            .reset(self.count, self.row_height, self.spacing, |index| {
+                self.key_of(index)
+            });
+
+        let (top, bottom) = match state.viewport {
+            Some(viewport) => (
+                viewport.y - OVERSCAN,
+                viewport.y + viewport.height + OVERSCAN,
+            ),
+            None if max.height.is_finite() => (0.0, max.height),
+            None => (0.0, INITIAL_VIEWPORT_HEIGHT + OVERSCAN),
+        };
+
+        let mut trees: FxHashMap<u64, Tree> =
+            state.keys.drain(..).zip(children.drain(..)).collect();
+
+        // Rows above the anchor are not built, since measuring them
+        // could move the anchor away from its offset
+        self.first = state
+            .heights
+            .index_at(top)
+            .max(state.anchor.unwrap_or_default())
+            .min(self.count.saturating_sub(1));
+        self.rows.clear();
+
+        let mut nodes = Vec::new();
+        let mut width: f32 = 0.0;
+        let mut y = state.heights.offset(self.first);
+        let mut index = self.first;
+
+        while index < self.count && y < bottom {
+            let mut row = (self.view)(index);
+            let key = self.key_of(index);
+
+            let mut tree = match trees.remove(&key) {
+                Some(mut tree) => {
+                    tree.diff(&row);
+                    tree
+                }
+                None => Tree::new(&row),
+            };
+
+            let (node, height) = match self.row_height {
+                RowHeight::Fixed(height) => {
+                    let limits =
+                        layout::Limits::new(Size::new(0.0, height), Size::new(max.width, height));
+
+                    (
+                        row.as_widget_mut().layout(&mut tree, renderer, &limits),
+                        height,
+                    )
+                }
+                RowHeight::Estimated(_) => {
+                    let limits =
+                        layout::Limits::new(Size::ZERO, Size::new(max.width, f32::INFINITY));
+
+                    let node = row.as_widget_mut().layout(&mut tree, renderer, &limits);
+                    let height = node.size().height;
+
+                    state.heights.measure(index, key, height);
+
+                    (node, height)
+                }
+            };
+
+            width = width.max(node.size().width);
+            nodes.push(node.move_to(Point::new(0.0, y)));
+
+            state.keys.push(key);
+            children.push(tree);
+            self.rows.push(row);
+
+            y += height + self.spacing;
+            index += 1;
+        }
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width, state.heights.total()),
+        );
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.rows
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((row, state), layout)| {
+                    row.as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((row, tree), layout) in self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            row.as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, shell, viewport);
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let Some(visible) = (*viewport - Vector::new(bounds.x, bounds.y))
+            .intersection(&Rectangle::with_size(bounds.size()))
+        else {
+            return;
+        };
+
+        state.viewport = Some(visible);
+
+        let top = visible.y;
+        let bottom = visible.y + visible.height;
+
+        if let Some(anchor) = state.anchor
+            && top < state.heights.offset(anchor) - ANCHOR_TOLERANCE
+        {
+            state.anchor = None;
+        }
+
+        let first = layout.children().next().map(|row| row.bounds());
+        let last = layout.children().last().map(|row| row.bounds());
+
+        let is_stale = match (first, last) {
+            (Some(first), Some(last)) => {
+                let first_top = first.y - bounds.y;
+                let first_bottom = first_top + first.height;
+                let last_top = last.y - bounds.y;
+                let last_bottom = last_top + last.height;
+
+                let is_missing_rows = (self.first > 0 && top < first_top - ANCHOR_TOLERANCE)
+                    || (self.first + self.rows.len() < self.count && bottom > last_bottom);
+
+                let has_distant_rows =
+                    first_bottom < top - OVERSCAN * 2.0 || last_top > bottom + OVERSCAN * 2.0;
+
+                is_missing_rows || has_distant_rows
+            }
+            _ => self.count > 0,
+        };
+
+        if is_stale {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, tree), layout)| {
+                row.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((row, tree), layout) in self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            row.as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.rows,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the [`Scrollable`](crate::Scrollable)
+/// with the given `scrollable` id until the row at `index` of the [`VirtualList`]
+/// with the given `list` id is at the top of its viewport.
+///
+/// The [`VirtualList`] must be a descendant of the [`Scrollable`](crate::Scrollable).
+///
+/// The row will reach the top even if the rows have [estimated heights], since
+/// the rows above it will not be built (and measured) until the viewport moves
+/// above it.
+///
+/// [estimated heights]: VirtualList::estimated_row_height
+pub fn scroll_to<T>(
+    scrollable: impl Into<widget::Id>,
+    list: impl Into<widget::Id>,
+    index: usize,
+) -> impl Operation<T>
+where
+    T: 'static,
+{
+    ScrollTo {
+        scrollable: scrollable.into(),
+        list: list.into(),
+        index,
+        is_relative: false,
+        target: None,
+        offset: None,
+    }
+}
+
+/// Produces an [`Operation`] that snaps the [`Scrollable`](crate::Scrollable)
+/// with the given `scrollable` id to the relative offset where the row at `index`
+/// of the [`VirtualList`] with the given `list` id is at the top of its viewport.
+///
+/// This is the relative counterpart of [`scroll_to`]. Since the offset is
+/// relative, the [`Scrollable`](crate::Scrollable) will keep its relative
+/// position when resized—but the row may drift away from the top if the
+/// [estimated heights] of the rows below it are off.
+///
+/// [estimated heights]: VirtualList::estimated_row_height
+pub fn snap_to<T>(
+    scrollable: impl Into<widget::Id>,
+    list: impl Into<widget::Id>,
+    index: usize,
+) -> impl Operation<T>
+where
+    T: 'static,
+{
+    ScrollTo {
+        scrollable: scrollable.into(),
+        list: list.into(),
+        index,
+        is_relative: true,
+        target: None,
+        offset: None,
+    }
+}
+
+struct ScrollTo {
+    scrollable: widget::Id,
+    list: widget::Id,
+    index: usize,
+    is_relative: bool,
+    target: Option<(Rectangle, Rectangle)>,
+    offset: Option<f32>,
+}
+
+impl<T> Operation<T> for ScrollTo
+where
+    T: 'static,
+{
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        _translation: Vector,
+        _state: &mut dyn scrollable::Scrollable,
+    ) {
+        if Some(&self.scrollable) == id {
+            self.target = Some((bounds, content_bounds));
+        }
+    }
+
+    fn custom(&mut self, id: Option<&widget::Id>, bounds: Rectangle, state: &mut dyn Any) {
+        if Some(&self.list) != id {
+            return;
+        }
+
+        if let Some(state) = state.downcast_mut::<State>() {
+            state.anchor = Some(self.index);
+
+            self.offset = Some(bounds.y + state.heights.offset(self.index));
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        let (Some((bounds, content_bounds)), Some(offset)) = (self.target, self.offset) else {
+            return Outcome::None;
+        };
+
+        let y = offset - content_bounds.y;
+
+        if self.is_relative {
+            let scrollable_height = content_bounds.height - bounds.height;
+
+            Outcome::Chain(Box::new(scrollable::snap_to(
+                self.scrollable.clone(),
+                RelativeOffset {
+                    x: None,
+                    y: Some(if scrollable_height > 0.0 {
+                        (y / scrollable_height).clamp(0.0, 1.0)
+                    } else {
+                        0.0
+                    }),
+                },
+            )))
+        } else {
+            Outcome::Chain(Box::new(scrollable::scroll_to(
+                self.scrollable.clone(),
+                AbsoluteOffset {
+                    x: None,
+                    y: Some(y),
+                },
+            )))
+        }
+    }
+}
+
+/// The (partially measured) heights of the rows of a [`VirtualList`].
+///
+/// The extent (height plus spacing) of every row is stored in a Fenwick tree,
+/// so finding the offset of a row—or the row at an offset—takes logarithmic
+/// time.
+///
+/// Measured heights are remembered by row key, so they follow their rows
+/// when rows are added or removed. The heights of the rows that are built
+/// are always up to date.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Heights {
+    count: usize,
+    estimate: f32,
+    spacing: f32,
+    /// The measured heights of the rows, by key.
+    measured: FxHashMap<u64, f32>,
+    /// The measured heights of the rows, by index.
+    rows: FxHashMap<usize, f32>,
+    /// The Fenwick tree of row extents, 1-indexed.
+    tree: Vec<f64>,
+}
+
+impl Heights {
+    fn reset(
+        &mut self,
+        count: usize,
+        row_height: RowHeight,
+        spacing: f32,
+        key: impl Fn(usize) -> u64,
+    ) {
+        let estimate = match row_height {
+            RowHeight::Fixed(height) => {
+                self.measured.clear();
+                self.rows.clear();
+
+                height
+            }
+            RowHeight::Estimated(height) => height,
+        }
+        .max(0.0);
+
+        if count != self.count {
+            // Rows may have moved, find their measurements by key
+            self.rows.clear();
+
+            if !self.measured.is_empty() {
+                let mut measured = FxHashMap::default();
+
+                for index in 0..count {
+                    let key = key(index);
+
+                    if let Some(height) = self.measured.remove(&key) {
+                        let _ = self.rows.insert(index, height);
+                        let _ = measured.insert(key, height);
+                    }
+                }
+
+                self.measured = measured;
+            }
+        } else if estimate == self.estimate
+            && spacing == self.spacing
+            && self.tree.len() == count + 1
+        {
+            return;
+        }
+
+        self.count = count;
+        self.estimate = estimate;
+        self.spacing = spacing;
+
+        // Build the tree in linear time
+        self.tree = vec![0.0; count + 1];
+
+        for i in 1..=count {
+            let height = self.rows.get(&(i - 1)).copied().unwrap_or(estimate);
+
+            self.tree[i] += f64::from(height + spacing);
+
+            let parent = i + (i & i.wrapping_neg());
+
+            if parent <= count {
+                self.tree[parent] += self.tree[i];
+            }
+        }
+    }
+
+    fn measure(&mut self, index: usize, key: u64, height: f32) {
+        let _ = self.measured.insert(key, height);
+
+        let previous = self.rows.insert(index, height).unwrap_or(self.estimate);
+
+        if previous == height {
+            return;
+        }
+
+        let delta = f64::from(height) - f64::from(previous);
+        let mut i = index + 1;
+
+        while i <= self.count {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the vertical offset of the row at the given index.
+    fn offset(&self, index: usize) -> f32 {
+        let mut offset = 0.0;
+        let mut i = index.min(self.count);
+
+        while i > 0 {
+            offset += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+
+        offset as f32
+    }
+
+    /// Returns the total height of all the rows.
+    fn total(&self) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        self.offset(self.count) - self.spacing
+    }
+
+    /// Returns the index of the row at the given vertical offset.
+    fn index_at(&self, y: f32) -> usize {
+        if self.count == 0 {
+            return 0;
+        }
+
+        let mut remaining = f64::from(y.max(0.0));
+        let mut index = 0;
+        let mut step = 1 << self.count.ilog2();
+
+        // Find the last row that starts at or before the offset
+        while step > 0 {
+            let next = index + step;
+
+            if next <= self.count && self.tree[next] <= remaining {
+                index = next;
+                remaining -= self.tree[next];
+            }
+
+            step >>= 1;
+        }
+
+        index.min(self.count - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heights(row_height: RowHeight, count: usize, spacing: f32) -> Heights {
+        let mut heights = Heights::default();
+        heights.reset(count, row_height, spacing, |index| index as u64);
+        heights
+    }
+
+    #[test]
+    fn fixed_heights() {
+        let heights = heights(RowHeight::Fixed(10.0), 100, 2.0);
+
+        assert_eq!(heights.offset(0), 0.0);
+        assert_eq!(heights.offset(5), 60.0);
+        assert_eq!(heights.total(), 1198.0);
+
+        assert_eq!(heights.index_at(-5.0), 0);
+        assert_eq!(heights.index_at(59.0), 4);
+        assert_eq!(heights.index_at(60.0), 5);
+        assert_eq!(heights.index_at(10_000.0), 99);
+    }
+
+    #[test]
+    fn measured_heights() {
+        let mut heights = heights(RowHeight::Estimated(10.0), 100, 0.0);
+
+        heights.measure(2, 2, 30.0);
+        heights.measure(3, 3, 5.0);
+
+        assert_eq!(heights.offset(2), 20.0);
+        assert_eq!(heights.offset(3), 50.0);
+        assert_eq!(heights.offset(4), 55.0);
+        assert_eq!(heights.offset(5), 65.0);
+        assert_eq!(heights.total(), 1015.0);
+
+        assert_eq!(heights.index_at(19.0), 1);
+        assert_eq!(heights.index_at(20.0), 2);
+        assert_eq!(heights.index_at(49.0), 2);
+        assert_eq!(heights.index_at(52.0), 3);
+        assert_eq!(heights.index_at(55.0), 4);
+        assert_eq!(heights.index_at(65.0), 5);
+
+        heights.reset(3, RowHeight::Estimated(10.0), 0.0, |index| index as u64);
+
+        assert_eq!(heights.total(), 50.0);
+        assert_eq!(heights.index_at(1000.0), 2);
+    }
+
+    #[test]
+    fn measured_heights_follow_keys() {
+        let mut heights = heights(RowHeight::Estimated(10.0), 4, 0.0);
+
+        heights.measure(0, 100, 30.0);
+        heights.measure(1, 101, 5.0);
+
+        // A new row is inserted at the top
+        heights.reset(5, RowHeight::Estimated(10.0), 0.0, |index| {
+            [99, 100, 101, 102, 103][index]
+        });
+
+        assert_eq!(heights.offset(1), 10.0);
+        assert_eq!(heights.offset(2), 40.0);
+        assert_eq!(heights.offset(3), 45.0);
+        assert_eq!(heights.total(), 65.0);
+        assert_eq!(heights.index_at(44.0), 2);
+    }
+
+    #[test]
+    fn many_rows() {
+        let mut heights = heights(RowHeight::Estimated(20.0), 1_000_000, 0.0);
+
+        heights.measure(999_998, 999_998, 40.0);
+
+        assert_eq!(heights.offset(999_999), 20_000_000.0);
+        assert_eq!(heights.total(), 20_000_020.0);
+        assert_eq!(heights.index_at(19_999_979.0), 999_998);
+        assert_eq!(heights.index_at(20_000_000.0), 999_999);
+    }
+}
@@ -0,0 +1,485 @@
+//! Expose the semantics of a user interface to assistive technologies.
+use crate::Proxy;
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::widget;
+use crate::core::widget::operation::semantics;
+use crate::core::{self, Event, Rectangle};
+use crate::runtime::Action;
+use crate::runtime::user_interface::{self, UserInterface};
+
+use accesskit::{NodeId, TreeUpdate};
+use rustc_hash::FxHashMap;
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::mpsc;
+
+const ROOT: NodeId = NodeId(0);
+
+/// The accessibility state of a window.
+///
+/// It keeps the assistive technologies of the platform in sync
+/// with the [`semantics::Tree`] of the user interface of the window.
+pub struct Accessibility {
+    adapter: accesskit_winit::Adapter,
+    notifications: mpsc::Receiver<Notification>,
+    is_active: bool,
+    is_outdated: bool,
+    nodes: FxHashMap<NodeId, accesskit::Node>,
+    targets: FxHashMap<NodeId, Target>,
+    focus: NodeId,
+}
+
+impl Accessibility {
+    /// Creates a new [`Accessibility`] state for the given window.
+    ///
+    /// The window must not be visible yet.
+    pub fn new<Message>(
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        window: &winit::window::Window,
+        proxy: Proxy<Message>,
+    ) -> Self
+    where
+        Message: Send + 'static,
+    {
+        let (sender, notifications) = mpsc::channel();
+
+        let handler = || Handler {
+            sender: sender.clone(),
+            proxy: proxy.clone(),
+        };
+
+        let adapter = accesskit_winit::Adapter::with_direct_handlers(
+            event_loop,
+            window,
+            handler(),
+            handler(),
+            handler(),
+        );
+
+        Self {
+            adapter,
+            notifications,
+            is_active: false,
+            is_outdated: false,
+            nodes: FxHashMap::default(),
+            targets: FxHashMap::default(),
+            focus: ROOT,
+        }
+    }
+
+    /// Processes a window event before it is handled by the user interface.
+    pub fn process_event(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        self.adapter.process_event(window, event);
+    }
+
+    /// Returns the pending [`Request`]s of the assistive technologies.
+    pub fn requests(&mut self) -> Vec<Request> {
+        let mut requests = Vec::new();
+
+        while let Ok(notification) = self.notifications.try_recv() {
+            match notification {
+                Notification::Activated => {
+                    self.is_active = true;
+                    self.is_outdated = true;
+                    self.nodes.clear();
+                }
+                Notification::Deactivated => {
+                    self.is_active = false;
+                    self.nodes.clear();
+                    self.targets.clear();
+                }
+                Notification::Requested(request) => {
+                    if let Some(target) = self.targets.get(&request.target_node) {
+                        requests.push(Request {
+                            action: request.action,
+                            target: target.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        requests
+    }
+
+    /// Marks the semantics of the window as outdated, so they are rebuilt on
+    /// the next [`update`](Self::update).
+    pub fn invalidate(&mut self) {
+        self.is_outdated = true;
+    }
+
+    /// Returns true if assistive technologies are listening and the semantics
+    /// of the window are outdated.
+    pub fn is_outdated(&self) -> bool {
+        self.is_active && self.is_outdated
+    }
+
+    /// Updates the assistive technologies with the latest semantics of the
+    /// given [`UserInterface`], if they are listening and the semantics are
+    /// outdated.
+    ///
+    /// Only the nodes that changed since the last update are sent.
+    pub fn update<Message, Theme, Renderer>(
+        &mut self,
+        interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        scale_factor: f32,
+    ) where
+        Renderer: core::Renderer,
+    {
+        use widget::Operation;
+
+        if !self.is_outdated() {
+            return;
+        }
+
+        self.is_outdated = false;
+
+        let mut operation = semantics::tree();
+        interface.operate(renderer, &mut widget::operation::black_box(&mut operation));
+
+        let widget::operation::Outcome::Some(tree) = operation.finish() else {
+            return;
+        };
+
+        let mut nodes = FxHashMap::default();
+        let mut targets = FxHashMap::default();
+        let mut focus = ROOT;
+
+        let mut root = accesskit::Node::new(accesskit::Role::Window);
+        root.set_transform(accesskit::Affine::scale(f64::from(scale_factor)));
+
+        for (index, node) in tree.roots.iter().enumerate() {
+            root.push_child(insert(
+                node,
+                node_id(ROOT, index, node),
+                &mut nodes,
+                &mut targets,
+                &mut focus,
+            ));
+        }
+
+        let _ = nodes.insert(ROOT, root);
+
+        let is_initial = self.nodes.is_empty();
+
+        let changes: Vec<_> = nodes
+            .iter()
+            .filter(|(id, node)| is_initial || self.nodes.get(id) != Some(node))
+            .map(|(id, node)| (*id, node.clone()))
+            .collect();
+
+        if changes.is_empty() && focus == self.focus {
+            return;
+        }
+
+        self.adapter.update_if_active(|| TreeUpdate {
+            nodes: changes,
+            tree: is_initial.then(|| accesskit::Tree {
+                toolkit_name: Some(String::from("iced")),
+                ..accesskit::Tree::new(ROOT)
+            }),
+            tree_id: accesskit::TreeId::ROOT,
+            focus,
+        });
+
+        self.nodes = nodes;
+        self.targets = targets;
+        self.focus = focus;
+    }
+}
+
+impl fmt::Debug for Accessibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Accessibility")
+            .field("is_active", &self.is_active)
+            .field("nodes", &self.nodes.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A request of an assistive technology to interact with a widget.
+#[derive(Debug, Clone)]
+pub struct Request {
+    action: accesskit::Action,
+    target: Target,
+}
+
+impl Request {
+    /// Performs the [`Request`] in the given [`UserInterface`] by
+    /// emulating the user input that would satisfy it.
+    ///
+    /// Returns the resulting [`user_interface::State`], if any input
+    /// was emulated.
+    pub fn perform<Message, Theme, Renderer>(
+        self,
+        interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        messages: &mut Vec<Message>,
+    ) -> Option<user_interface::State>
+    where
+        Renderer: core::Renderer,
+    {
+        let events = match (self.action, self.target.id) {
+            (accesskit::Action::Focus, Some(id)) => {
+                interface.operate(renderer, &mut widget::operation::focusable::focus::<()>(id));
+
+                return None;
+            }
+            (accesskit::Action::Click | accesskit::Action::Focus, _) => vec![
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            ],
+            (accesskit::Action::Increment, _) => tap_key(keyboard::key::Named::ArrowUp),
+            (accesskit::Action::Decrement, _) => tap_key(keyboard::key::Named::ArrowDown),
+            _ => return None,
+        };
+
+        let cursor = mouse::Cursor::Available(self.target.bounds.center());
+        let (state, _statuses) = interface.update(&events, cursor, renderer, messages);
+
+        Some(state)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Target {
+    id: Option<widget::Id>,
+    bounds: Rectangle,
+}
+
+enum Notification {
+    Activated,
+    Requested(accesskit::ActionRequest),
+    Deactivated,
+}
+
+struct Handler<Message: 'static> {
+    sender: mpsc::Sender<Notification>,
+    proxy: Proxy<Message>,
+}
+
+impl<Message> Handler<Message> {
+    fn notify(&self, notification: Notification) {
+        if self.sender.send(notification).is_ok() {
+            // Wake up the event loop to process the notification
+            self.proxy.send_action(Action::Tick);
+        }
+    }
+}
+
+impl<Message> accesskit::ActivationHandler for Handler<Message> {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        self.notify(Notification::Activated);
+
+        None
+    }
+}
+
+impl<Message> accesskit::ActionHandler for Handler<Message> {
+    fn do_action(&mut self, request: accesskit::ActionRequest) {
+        self.notify(Notification::Requested(request));
+    }
+}
+
+impl<Message> accesskit::DeactivationHandler for Handler<Message> {
+    fn deactivate_accessibility(&mut self) {
+        self.notify(Notification::Deactivated);
+    }
+}
+
+fn insert(
+    node: &semantics::Node,
+    id: NodeId,
+    nodes: &mut FxHashMap<NodeId, accesskit::Node>,
+    targets: &mut FxHashMap<NodeId, Target>,
+    focus: &mut NodeId,
+) -> NodeId {
+    let semantics = &node.semantics;
+
+    let role = match (semantics.role, &semantics.value) {
+        (semantics::Role::Button, _) => accesskit::Role::Button,
+        (semantics::Role::CheckBox, _) => accesskit::Role::CheckBox,
+        (semantics::Role::Switch, _) => accesskit::Role::Switch,
+        (semantics::Role::RadioButton, _) => accesskit::Role::RadioButton,
+        (semantics::Role::TextInput, Some(semantics::Value::Secure)) => {
+            accesskit::Role::PasswordInput
+        }
+        (semantics::Role::TextInput, _) => accesskit::Role::TextInput,
+        (semantics::Role::Slider, _) => accesskit::Role::Slider,
+        (semantics::Role::ComboBox, _) => accesskit::Role::ComboBox,
+        (semantics::Role::ScrollView, _) => accesskit::Role::ScrollView,
+        (semantics::Role::Label, _) => accesskit::Role::Label,
+        (semantics::Role::MenuBar, _) => accesskit::Role::MenuBar,
+        (semantics::Role::Menu, _) => accesskit::Role::Menu,
+        (semantics::Role::MenuItem, _) if semantics.is_toggled.is_some() => {
+            accesskit::Role::MenuItemCheckBox
+        }
+        (semantics::Role::MenuItem, _) => accesskit::Role::MenuItem,
+        (semantics::Role::TabList, _) => accesskit::Role::TabList,
+        (semantics::Role::Tab, _) => accesskit::Role::Tab,
+        (semantics::Role::TabPanel, _) => accesskit::Role::TabPanel,
+        (semantics::Role::Tree, _) => accesskit::Role::Tree,
+        (semantics::Role::TreeItem, _) => accesskit::Role::TreeItem,
+    };
+
+    let mut raw = accesskit::Node::new(role);
+
+    raw.set_bounds(accesskit::Rect {
+        x0: f64::from(node.bounds.x),
+        y0: f64::from(node.bounds.y),
+        x1: f64::from(node.bounds.x + node.bounds.width),
+        y1: f64::from(node.bounds.y + node.bounds.height),
+    });
+
+    if semantics.role == semantics::Role::Label {
+        if let Some(label) = &semantics.label {
+            raw.set_value(label.as_ref());
+        }
+    } else if let Some(label) = node.label() {
+        raw.set_label(label.as_ref());
+    }
+
+    if let Some(description) = &semantics.description {
+        raw.set_description(description.as_ref());
+    }
+
+    match &semantics.value {
+        Some(semantics::Value::Text(text)) => {
+            raw.set_value(text.as_ref());
+        }
+        Some(semantics::Value::Range {
+            value,
+            min,
+            max,
+            step,
+        }) => {
+            raw.set_numeric_value(*value);
+            raw.set_min_numeric_value(*min);
+            raw.set_max_numeric_value(*max);
+            raw.set_numeric_value_step(*step);
+        }
+        Some(semantics::Value::Secure) | None => {}
+    }
+
+    if let Some(is_toggled) = semantics.is_toggled {
+        raw.set_toggled(accesskit::Toggled::from(is_toggled));
+    }
+
+    if let Some(is_expanded) = semantics.is_expanded {
+        raw.set_expanded(is_expanded);
+    }
+
+    if let Some(is_selected) = semantics.is_selected {
+        raw.set_selected(is_selected);
+    }
+
+    if semantics.is_disabled {
+        raw.set_disabled();
+    } else {
+        let actions: &[accesskit::Action] = match semantics.role {
+            semantics::Role::Button
+            | semantics::Role::CheckBox
+            | semantics::Role::Switch
+            | semantics::Role::RadioButton
+            | semantics::Role::ComboBox
+            | semantics::Role::MenuItem
+            | semantics::Role::Tab
+            | semantics::Role::TreeItem => &[accesskit::Action::Click],
+            semantics::Role::TextInput => &[accesskit::Action::Focus, accesskit::Action::Click],
+            semantics::Role::Slider => {
+                &[accesskit::Action::Increment, accesskit::Action::Decrement]
+            }
+            semantics::Role::ScrollView
+            | semantics::Role::Label
+            | semantics::Role::MenuBar
+            | semantics::Role::Menu
+            | semantics::Role::TabList
+            | semantics::Role::TabPanel
+            | semantics::Role::Tree => &[],
+        };
+
+        for action in actions {
+            raw.add_action(*action);
+        }
+
+        if !actions.is_empty() {
+            let _ = targets.insert(
+                id,
+                Target {
+                    id: node.id.clone(),
+                    bounds: node.bounds,
+                },
+            );
+        }
+    }
+
+    if semantics.is_focused == Some(true) {
+        *focus = id;
+    }
+
+    for (index, child) in node.children.iter().enumerate() {
+        raw.push_child(insert(
+            child,
+            node_id(id, index, child),
+            nodes,
+            targets,
+            focus,
+        ));
+    }
+
+    let _ = nodes.insert(id, raw);
+
+    id
+}
+
+/// Produces a [`NodeId`] that is stable across updates.
+///
+/// Widgets with an [`widget::Id`] are identified by it; the rest
+/// by their position in the tree.
+fn node_id(parent: NodeId, index: usize, node: &semantics::Node) -> NodeId {
+    let mut hasher = DefaultHasher::new();
+
+    if let Some(id) = &node.id {
+        id.hash(&mut hasher);
+    } else {
+        parent.0.hash(&mut hasher);
+        index.hash(&mut hasher);
+        node.semantics.role.hash(&mut hasher);
+    }
+
+    match hasher.finish() {
+        0 => NodeId(1),
+        id => NodeId(id),
+    }
+}
+
+fn tap_key(key: keyboard::key::Named) -> Vec<Event> {
+    let key = keyboard::Key::Named(key);
+    let physical_key =
+        keyboard::key::Physical::Unidentified(keyboard::key::NativeCode::Unidentified);
+
+    vec![
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key.clone(),
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            repeat: false,
+            text: None,
+        }),
+        Event::Keyboard(keyboard::Event::KeyReleased {
+            key: key.clone(),
+            modified_key: key,
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+        }),
+    ]
+}
@@ -29,6 +29,9 @@ pub use winit;
 pub mod clipboard;
 pub mod conversion;
 
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
+
 mod error;
 mod proxy;
 mod window;
@@ -153,6 +156,9 @@ where
         error: Option<Error>,
         system_theme: Option<oneshot::Sender<theme::Mode>>,
 
+        #[cfg(feature = "accessibility")]
+        proxy: Proxy<Message>,
+
         #[cfg(target_arch = "wasm32")]
         canvas: Option<web_sys::HtmlCanvasElement>,
     }
@@ -166,6 +172,9 @@ where
         error: None,
         system_theme: Some(system_theme_sender),
 
+        #[cfg(feature = "accessibility")]
+        proxy,
+
         #[cfg(target_arch = "wasm32")]
         canvas: None,
     };
@@ -174,6 +183,7 @@ where
 
     impl<Message, F> winit::application::ApplicationHandler<Action<Message>> for Runner<Message, F>
     where
+        Message: Send,
         F: Future<Output = ()>,
     {
         fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
@@ -260,6 +270,7 @@ where
 
     impl<Message, F> Runner<Message, F>
     where
+        Message: Send,
         F: Future<Output = ()>,
     {
         fn process_event(
@@ -346,6 +357,14 @@ where
                                     window.set_outer_position(position);
                                 }
 
+                                // The adapter must be created before the window is visible
+                                #[cfg(feature = "accessibility")]
+                                let accessibility = accessibility::Accessibility::new(
+                                    event_loop,
+                                    &window,
+                                    self.proxy.clone(),
+                                );
+
                                 #[cfg(target_arch = "wasm32")]
                                 {
                                     use winit::platform::web::WindowExtWebSys;
@@ -389,6 +408,8 @@ where
                                         exit_on_close_request,
                                         make_visible: visible,
                                         on_open,
+                                        #[cfg(feature = "accessibility")]
+                                        accessibility,
                                     },
                                 );
                             }
@@ -447,6 +468,8 @@ enum Event<Message: 'static> {
         exit_on_close_request: bool,
         make_visible: bool,
         on_open: oneshot::Sender<window::Id>,
+        #[cfg(feature = "accessibility")]
+        accessibility: accessibility::Accessibility,
     },
     EventLoopAwakened(winit::event::Event<Message>),
     Exit,
@@ -552,6 +575,8 @@ async fn run_instance<P>(
                 exit_on_close_request,
                 make_visible,
                 on_open,
+                #[cfg(feature = "accessibility")]
+                accessibility,
             } => {
                 if compositor.is_none() {
                     let (compositor_sender, compositor_receiver) = oneshot::channel();
@@ -666,6 +691,11 @@ async fn run_instance<P>(
                 );
                 let _ = ui_caches.insert(id, user_interface::Cache::default());
 
+                #[cfg(feature = "accessibility")]
+                {
+                    window.accessibility = Some(accessibility);
+                }
+
                 if make_visible {
                     window.raw.set_visible(true);
                 }
@@ -1000,6 +1030,11 @@ async fn run_instance<P>(
                             continue;
                         };
 
+                        #[cfg(feature = "accessibility")]
+                        if let Some(accessibility) = &mut window.accessibility {
+                            accessibility.process_event(&window.raw, &window_event);
+                        }
+
                         match window_event {
                             winit::event::WindowEvent::Resized(_) => {
                                 window.raw.request_redraw();
@@ -1054,11 +1089,65 @@ async fn run_instance<P>(
                             actions = 0;
                         }
 
-                        if events.is_empty() && messages.is_empty() && window_manager.is_idle() {
-                            continue;
+                        let mut uis_stale = false;
+
+                        #[cfg(feature = "accessibility")]
+                        for (id, window) in window_manager.iter_mut() {
+                            let interface =
+                                user_interfaces.get_mut(&id).expect("Get user interface");
+
+                            for request in window.accessibility_requests() {
+                                match request.perform(
+                                    interface,
+                                    &mut window.renderer,
+                                    &mut messages,
+                                ) {
+                                    Some(user_interface::State::Updated {
+                                        redraw_request: _redraw_request,
+                                        mouse_interaction,
+                                        clipboard: clipboard_requests,
+                                        ..
+                                    }) => {
+                                        window.update_mouse(mouse_interaction);
+
+                                        #[cfg(not(feature = "unconditional-rendering"))]
+                                        window.request_redraw(_redraw_request);
+
+                                        run_clipboard(
+                                            &mut proxy,
+                                            &mut clipboard,
+                                            clipboard_requests,
+                                            id,
+                                        );
+                                    }
+                                    Some(user_interface::State::Outdated) => {
+                                        uis_stale = true;
+                                    }
+                                    None => {
+                                        window.raw.request_redraw();
+                                    }
+                                }
+
+                                window.invalidate_accessibility();
+                            }
                         }
 
-                        let mut uis_stale = false;
+                        #[cfg(feature = "accessibility")]
+                        let is_accessibility_outdated = window_manager
+                            .iter_mut()
+                            .any(|(_id, window)| window.is_accessibility_outdated());
+
+                        #[cfg(not(feature = "accessibility"))]
+                        let is_accessibility_outdated = false;
+
+                        if events.is_empty()
+                            && messages.is_empty()
+                            && !uis_stale
+                            && !is_accessibility_outdated
+                            && window_manager.is_idle()
+                        {
+                            continue;
+                        }
 
                         for (id, window) in window_manager.iter_mut() {
                             let interact_span = debug::interact(id);
@@ -1087,6 +1176,9 @@ async fn run_instance<P>(
                                     &mut messages,
                                 );
 
+                            #[cfg(feature = "accessibility")]
+                            window.invalidate_accessibility();
+
                             #[cfg(feature = "unconditional-rendering")]
                             window.request_redraw(window::RedrawRequest::NextFrame);
 
@@ -1171,6 +1263,16 @@ async fn run_instance<P>(
 
                             for (_id, window) in window_manager.iter_mut() {
                                 window.raw.request_redraw();
+
+                                #[cfg(feature = "accessibility")]
+                                window.invalidate_accessibility();
+                            }
+                        }
+
+                        #[cfg(feature = "accessibility")]
+                        for (id, window) in window_manager.iter_mut() {
+                            if let Some(interface) = user_interfaces.get_mut(&id) {
+                                window.update_accessibility(interface);
                             }
                         }
 
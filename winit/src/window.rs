@@ -4,6 +4,8 @@ use state::State;
 
 pub use crate::core::window::{Event, Id, RedrawRequest, Settings};
 
+#[cfg(feature = "accessibility")]
+use crate::accessibility::{self, Accessibility};
 use crate::conversion;
 use crate::core::alignment;
 use crate::core::input_method;
@@ -15,6 +17,8 @@ use crate::core::time::Instant;
 use crate::core::{Color, InputMethod, Padding, Point, Rectangle, Size, Text, Vector};
 use crate::graphics::Compositor;
 use crate::program::{self, Program};
+#[cfg(feature = "accessibility")]
+use crate::runtime::UserInterface;
 use crate::runtime::window::raw_window_handle;
 
 use winit::dpi::{LogicalPosition, LogicalSize};
@@ -78,6 +82,8 @@ where
                 redraw_at: None,
                 preedit: None,
                 ime_state: None,
+                #[cfg(feature = "accessibility")]
+                accessibility: None,
             },
         );
 
@@ -167,6 +173,8 @@ where
     pub redraw_at: Option<Instant>,
     preedit: Option<Preedit<P::Renderer>>,
     ime_state: Option<(Rectangle, input_method::Purpose)>,
+    #[cfg(feature = "accessibility")]
+    pub accessibility: Option<Accessibility>,
 }
 
 impl<P, C> Window<P, C>
@@ -260,6 +268,38 @@ where
         }
     }
 
+    #[cfg(feature = "accessibility")]
+    pub fn accessibility_requests(&mut self) -> Vec<accessibility::Request> {
+        self.accessibility
+            .as_mut()
+            .map(Accessibility::requests)
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "accessibility")]
+    pub fn invalidate_accessibility(&mut self) {
+        if let Some(accessibility) = &mut self.accessibility {
+            accessibility.invalidate();
+        }
+    }
+
+    #[cfg(feature = "accessibility")]
+    pub fn is_accessibility_outdated(&self) -> bool {
+        self.accessibility
+            .as_ref()
+            .is_some_and(Accessibility::is_outdated)
+    }
+
+    #[cfg(feature = "accessibility")]
+    pub fn update_accessibility(
+        &mut self,
+        interface: &mut UserInterface<'_, P::Message, P::Theme, P::Renderer>,
+    ) {
+        if let Some(accessibility) = &mut self.accessibility {
+            accessibility.update(interface, &self.renderer, self.state.scale_factor());
+        }
+    }
+
     fn enable_ime(&mut self, cursor: Rectangle, purpose: input_method::Purpose) {
         if self.ime_state.is_none() {
             self.raw.set_ime_allowed(true);